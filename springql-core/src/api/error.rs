@@ -60,4 +60,16 @@ pub enum SpringError {
 
     #[error("SQL error")]
     Sql(anyhow::Error),
+
+    /// SQL text does not follow the grammar.
+    ///
+    /// `line` and `column` are 1-origin. `snippet` is the word (or the symbol) at the position, which is empty at the end of SQL.
+    /// `expected` lists the grammar rules which could continue from the position.
+    #[error("SQL syntax error at line {line}, column {column} (near `{snippet}`): expected one of {expected:?}")]
+    SqlSyntax {
+        line: usize,
+        column: usize,
+        snippet: String,
+        expected: Vec<String>,
+    },
 }
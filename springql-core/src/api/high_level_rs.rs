@@ -4,7 +4,12 @@
 
 use crate::{
     error::Result,
-    low_level_rs::{spring_command, spring_open, SpringConfig, SpringPipeline},
+    low_level_rs::{
+        spring_command, spring_command_with_params, spring_explain, spring_export_ddl, spring_open,
+        spring_register_aggregate_function, spring_register_function, spring_show,
+        SpringAccumulator, SpringConfig, SpringParams, SpringPipeline, SpringRow, SpringValue,
+        SqlType,
+    },
 };
 
 /// Pipeline.
@@ -22,8 +27,9 @@ impl SpringPipelineHL {
     ///
     /// # Failure
     ///
-    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    /// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
     ///   - Invalid SQL syntax.
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
     ///   - Refers to undefined objects (streams, pumps, etc)
     ///   - Other semantic errors.
     /// - [SpringError::InvalidOption](crate::error::SpringError::Sql) when:
@@ -31,6 +37,113 @@ impl SpringPipelineHL {
     pub fn command<S: AsRef<str>>(&self, sql: S) -> Result<()> {
         spring_command(&self.0, sql.as_ref())
     }
+
+    /// Execute commands (DDL) whose placeholders (`$1`, `:name`) are bound to `params`.
+    ///
+    /// ```text
+    /// pipeline.command_with_params(
+    ///     "CREATE SINK WRITER queue_trade FOR sink_trade TYPE IN_MEMORY_QUEUE OPTIONS (NAME :queue);",
+    ///     &SpringParams::default().bind(":queue", SpringValue::Text("q_vehicle_42".to_string())),
+    /// )?;
+    /// ```
+    ///
+    /// See [spring_command_with_params()](crate::low_level_rs::spring_command_with_params) for details.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+    ///   - Invalid SQL syntax.
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - A placeholder is not bound, or a bound parameter does not appear in `sql`.
+    ///   - A bound value does not match the type expected in its place.
+    ///   - Refers to undefined objects (streams, pumps, etc)
+    ///   - Other semantic errors.
+    /// - [SpringError::InvalidOption](crate::error::SpringError::Sql) when:
+    ///   - `OPTIONS` in `CREATE` statement includes invalid key or value.
+    pub fn command_with_params<S: AsRef<str>>(&self, sql: S, params: &SpringParams) -> Result<()> {
+        spring_command_with_params(&self.0, sql.as_ref(), params)
+    }
+
+    /// Renders how `EXPLAIN CREATE PUMP ...` or `EXPLAIN PIPELINE` would be executed.
+    ///
+    /// See [spring_explain()](crate::low_level_rs::spring_explain) for details.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+    ///   - Invalid SQL syntax.
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `sql` is not an EXPLAIN statement.
+    ///   - Refers to undefined objects (streams, pumps, etc)
+    ///   - Other semantic errors.
+    pub fn explain<S: AsRef<str>>(&self, sql: S) -> Result<String> {
+        spring_explain(&self.0, sql.as_ref())
+    }
+
+    /// Export the pipeline as DDL statements which recreate an equivalent pipeline by [command()](Self::command) in order.
+    ///
+    /// See [spring_export_ddl()](crate::low_level_rs::spring_export_ddl) for details.
+    pub fn export_ddl(&self) -> Result<Vec<String>> {
+        spring_export_ddl(&self.0)
+    }
+
+    /// Lists objects in the pipeline by `SHOW ...` or `DESCRIBE STREAM ...`.
+    ///
+    /// See [spring_show()](crate::low_level_rs::spring_show) for columns of returned rows.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+    ///   - Invalid SQL syntax.
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `sql` is not a SHOW or DESCRIBE statement.
+    ///   - Stream to describe does not exist.
+    pub fn show<S: AsRef<str>>(&self, sql: S) -> Result<Vec<SpringRow>> {
+        spring_show(&self.0, sql.as_ref())
+    }
+
+    /// Register a scalar function callable from SQL by `name` (case-insensitive).
+    ///
+    /// See [spring_register_function()](crate::low_level_rs::spring_register_function) for details.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `name` is not an identifier (e.g. a keyword).
+    ///   - a function named `name` is already registered.
+    pub fn register_function<F>(
+        &mut self,
+        name: &str,
+        arg_types: Vec<SqlType>,
+        return_type: SqlType,
+        func: F,
+    ) -> Result<()>
+    where
+        F: Fn(&[SpringValue]) -> Result<SpringValue> + Send + Sync + 'static,
+    {
+        spring_register_function(&mut self.0, name, arg_types, return_type, func)
+    }
+
+    /// Register an aggregate function callable from SQL by `name` (case-insensitive).
+    ///
+    /// See [spring_register_aggregate_function()](crate::low_level_rs::spring_register_aggregate_function) for details.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `name` is not an identifier (e.g. a keyword).
+    ///   - a function named `name` is already registered.
+    pub fn register_aggregate_function<A>(
+        &mut self,
+        name: &str,
+        arg_type: SqlType,
+        return_type: SqlType,
+    ) -> Result<()>
+    where
+        A: SpringAccumulator,
+    {
+        spring_register_aggregate_function::<A>(&mut self.0, name, arg_type, return_type)
+    }
 }
 
 impl SpringConfig {
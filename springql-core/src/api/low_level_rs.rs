@@ -5,15 +5,30 @@
 //! C API and high-level Rust API are provided separately.
 
 mod engine_mutex;
+mod spring_accumulator;
+mod spring_clock;
 mod spring_config;
+mod spring_params;
+mod spring_value;
 
+pub use crate::pipeline::relation::sql_type::SqlType;
+pub use spring_accumulator::*;
+pub use spring_clock::*;
 pub use spring_config::*;
+pub use spring_params::*;
+pub use spring_value::*;
 
 use std::sync::Once;
 
+use anyhow::anyhow;
+
 use crate::{
-    error::Result,
-    pipeline::name::QueueName,
+    error::{Result, SpringError},
+    expression::function_call::user_defined_function::UserDefinedFunction,
+    pipeline::{
+        name::QueueName,
+        pump_model::window_operation_parameter::aggregate::user_defined_aggregate_function::UserDefinedAggregateFunction,
+    },
     sql_processor::SqlProcessor,
     stream_engine::{command::Command, SinkRow, SqlConvertible, SqlValue},
 };
@@ -40,6 +55,7 @@ fn setup_logger() {
 pub struct SpringPipeline {
     engine: EngineMutex,
     sql_processor: SqlProcessor,
+    clock: SpringClock,
 }
 
 /// Row object from an in memory queue.
@@ -53,15 +69,20 @@ impl From<SinkRow> for SpringRow {
 }
 
 /// Creates and open an in-process stream pipeline.
+///
+/// Worker threads of the pipeline use `config.clock`.
 pub fn spring_open(config: &SpringConfig) -> Result<SpringPipeline> {
     setup_logger();
 
+    let _clock = config.clock.enter();
+
     let engine = EngineMutex::new(config);
     let sql_processor = SqlProcessor::default();
 
     Ok(SpringPipeline {
         engine,
         sql_processor,
+        clock: config.clock.clone(),
     })
 }
 
@@ -69,13 +90,15 @@ pub fn spring_open(config: &SpringConfig) -> Result<SpringPipeline> {
 ///
 /// # Failure
 ///
-/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+/// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
 ///   - Invalid SQL syntax.
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
 ///   - Refers to undefined objects (streams, pumps, etc)
 ///   - Other semantic errors.
 /// - [SpringError::InvalidOption](crate::error::SpringError::Sql) when:
 ///   - `OPTIONS` in `CREATE` statement includes invalid key or value.
 pub fn spring_command(pipeline: &SpringPipeline, sql: &str) -> Result<()> {
+    let _clock = pipeline.clock.enter();
     let mut engine = pipeline.engine.get()?;
 
     let command = pipeline
@@ -84,9 +107,197 @@ pub fn spring_command(pipeline: &SpringPipeline, sql: &str) -> Result<()> {
 
     match command {
         Command::AlterPipeline(c) => engine.alter_pipeline(c),
+        Command::Explain(_) => Err(SpringError::Sql(anyhow!(
+            "EXPLAIN does not alter pipeline. Use spring_explain() instead: {}",
+            sql
+        ))),
+        Command::Show(_) => Err(SpringError::Sql(anyhow!(
+            "SHOW and DESCRIBE do not alter pipeline. Use spring_show() instead: {}",
+            sql
+        ))),
+    }
+}
+
+/// Execute commands (DDL) whose placeholders (`$1`, `:name`) in constants and OPTIONS values are bound to `params`.
+///
+/// Bound values are type-checked in the same way as constants written in the places of placeholders.
+/// Values bound in OPTIONS must be TEXT or integers, and the OPTIONS are checked against the source reader or sink writer type.
+/// Values bound in `CREATE PUMP` are written as SQL literals (typed literals like `DOUBLE '0.1'` for types without plain ones) in the DDL exported by [spring_export_ddl()](spring_export_ddl).
+///
+/// # Failure
+///
+/// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+///   - Invalid SQL syntax.
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - A placeholder is not bound, or a bound parameter does not appear in `sql`.
+///   - A bound value does not match the type expected in its place.
+///   - Refers to undefined objects (streams, pumps, etc)
+///   - Other semantic errors.
+/// - [SpringError::InvalidOption](crate::error::SpringError::Sql) when:
+///   - `OPTIONS` in `CREATE` statement includes invalid key or value.
+pub fn spring_command_with_params(
+    pipeline: &SpringPipeline,
+    sql: &str,
+    params: &SpringParams,
+) -> Result<()> {
+    let _clock = pipeline.clock.enter();
+    let mut engine = pipeline.engine.get()?;
+
+    let command = pipeline.sql_processor.compile_with_params(
+        sql,
+        &params.to_bind_params(),
+        engine.current_pipeline(),
+    )?;
+
+    match command {
+        Command::AlterPipeline(c) => engine.alter_pipeline(c),
+        Command::Explain(_) | Command::Show(_) => Err(SpringError::Sql(anyhow!(
+            "only DDLs can be executed with parameters: {}",
+            sql
+        ))),
+    }
+}
+
+/// Renders how `EXPLAIN CREATE PUMP ...` or `EXPLAIN PIPELINE` would be executed.
+///
+/// The output lists query plans of pumps (upper and lower operations, window parameters and labeled expressions)
+/// followed by tasks and queues of the task graph. Pipeline is not altered.
+///
+/// # Failure
+///
+/// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+///   - Invalid SQL syntax.
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `sql` is not an EXPLAIN statement.
+///   - Refers to undefined objects (streams, pumps, etc)
+///   - Other semantic errors.
+pub fn spring_explain(pipeline: &SpringPipeline, sql: &str) -> Result<String> {
+    let _clock = pipeline.clock.enter();
+    let engine = pipeline.engine.get()?;
+
+    let command = pipeline
+        .sql_processor
+        .compile(sql, engine.current_pipeline())?;
+
+    match command {
+        Command::Explain(c) => engine.explain(c),
+        Command::AlterPipeline(_) | Command::Show(_) => Err(SpringError::Sql(anyhow!(
+            "only EXPLAIN statements can be explained: {}",
+            sql
+        ))),
     }
 }
 
+/// Export the pipeline as DDL statements which recreate an equivalent pipeline by [spring_command()](spring_command) in order.
+///
+/// Statements have no trailing `;`. Join them with `;\n` to save as a script.
+///
+/// Streams, source readers and sink writers are rendered from their definitions,
+/// while pumps are rendered as their `CREATE PUMP` statements were written (not normalized).
+pub fn spring_export_ddl(pipeline: &SpringPipeline) -> Result<Vec<String>> {
+    let engine = pipeline.engine.get()?;
+    Ok(engine.current_pipeline().to_ddl())
+}
+
+/// Lists objects in the pipeline by `SHOW ...` or `DESCRIBE STREAM ...`.
+///
+/// Returned rows have the following columns (all NOT NULL), which can be fetched by [spring_column_text()](spring_column_text) and [spring_column_i32()](spring_column_i32).
+///
+/// | Command | Columns |
+/// |---------|---------|
+/// | `SHOW STREAMS` | `name TEXT, column_count INTEGER` |
+/// | `SHOW PUMPS` | `name TEXT, upstreams TEXT, downstream TEXT, input_type TEXT` |
+/// | `SHOW SOURCE READERS` | `name TEXT, type TEXT, stream TEXT` |
+/// | `SHOW SINK WRITERS` | `name TEXT, type TEXT, stream TEXT` |
+/// | `DESCRIBE STREAM x` | `column_name TEXT, data_type TEXT, is_nullable TEXT, is_rowtime TEXT` |
+///
+/// Rows are ordered by name except for `DESCRIBE STREAM`, whose rows are ordered as the column definitions.
+/// `upstreams` of a pump are joined by `, `. `input_type` is `ROW` or `WINDOW`. `is_nullable` and `is_rowtime` are `YES` or `NO`.
+///
+/// # Failure
+///
+/// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+///   - Invalid SQL syntax.
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `sql` is not a SHOW or DESCRIBE statement.
+///   - Stream to describe does not exist.
+pub fn spring_show(pipeline: &SpringPipeline, sql: &str) -> Result<Vec<SpringRow>> {
+    let _clock = pipeline.clock.enter();
+    let engine = pipeline.engine.get()?;
+
+    let command = pipeline
+        .sql_processor
+        .compile(sql, engine.current_pipeline())?;
+
+    match command {
+        Command::Show(c) => Ok(engine.show(c)?.into_iter().map(SpringRow::from).collect()),
+        Command::AlterPipeline(_) | Command::Explain(_) => Err(SpringError::Sql(anyhow!(
+            "only SHOW and DESCRIBE statements can be shown: {}",
+            sql
+        ))),
+    }
+}
+
+/// Register a scalar function callable from SQL by `name` (case-insensitive).
+///
+/// Arguments are converted into `arg_types` before passed to `func`, and its return value is converted into `return_type`.
+/// NULL is passed and returned as [SpringValue::Null](SpringValue::Null).
+///
+/// Functions must be registered before commands (DDL) calling them.
+///
+/// # Failure
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `name` is not an identifier (e.g. a keyword).
+///   - a function named `name` is already registered.
+///
+/// Errors from `func` and failures of conversions are raised as [SpringError::Sql](crate::error::SpringError::Sql) while the pipeline runs.
+pub fn spring_register_function<F>(
+    pipeline: &mut SpringPipeline,
+    name: &str,
+    arg_types: Vec<SqlType>,
+    return_type: SqlType,
+    func: F,
+) -> Result<()>
+where
+    F: Fn(&[SpringValue]) -> Result<SpringValue> + Send + Sync + 'static,
+{
+    let function =
+        UserDefinedFunction::new(name.to_string(), arg_types, return_type, Box::new(func));
+    pipeline.sql_processor.register_function(function)
+}
+
+/// Register an aggregate function callable from SQL by `name` (case-insensitive).
+///
+/// The function is called like `AVG` in a select field of a pump with GROUP BY and window clause.
+/// An accumulator `A` is created for each group in a window pane.
+/// Aggregated values are converted into `arg_type` before accumulated, and results are converted into `return_type`.
+///
+/// Functions must be registered before commands (DDL) calling them.
+///
+/// # Failure
+///
+/// Same as [spring_register_function()](spring_register_function).
+///
+/// Aggregated values and results which fail in accumulators or in conversions are dropped with warning logs while the pipeline runs.
+pub fn spring_register_aggregate_function<A>(
+    pipeline: &mut SpringPipeline,
+    name: &str,
+    arg_type: SqlType,
+    return_type: SqlType,
+) -> Result<()>
+where
+    A: SpringAccumulator,
+{
+    let function = UserDefinedAggregateFunction::new(
+        name.to_string(),
+        arg_type,
+        return_type,
+        Box::new(|| Box::new(A::init())),
+    );
+    pipeline.sql_processor.register_aggregate_function(function)
+}
+
 /// Pop a row from an in memory queue. This is a blocking function.
 ///
 /// # Failure
@@ -94,6 +305,7 @@ pub fn spring_command(pipeline: &SpringPipeline, sql: &str) -> Result<()> {
 /// - [SpringError::Unavailable](crate::error::SpringError::Unavailable) when:
 ///   - queue named `queue` does not exist.
 pub fn spring_pop(pipeline: &SpringPipeline, queue: &str) -> Result<SpringRow> {
+    let _clock = pipeline.clock.enter();
     let mut engine = pipeline.engine.get()?;
     let sink_row = engine.pop_in_memory_queue(QueueName::new(queue.to_string()))?;
     Ok(SpringRow::from(sink_row))
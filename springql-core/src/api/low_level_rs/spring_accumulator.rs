@@ -0,0 +1,56 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::fmt::Debug;
+
+use anyhow::anyhow;
+
+use crate::error::{Result, SpringError};
+
+use super::SpringValue;
+
+/// Accumulator of an aggregate function.
+///
+/// An accumulator is created for each group in a window pane, accumulates aggregated values of rows in the group,
+/// and is finalized into the result of the group.
+///
+/// Implement this trait and register it by [spring_register_aggregate_function()](crate::low_level_rs::spring_register_aggregate_function)
+/// to call it like `AVG` in windowed pumps.
+pub trait SpringAccumulator: Debug + Send + 'static {
+    /// Creates an accumulator without any value.
+    fn init() -> Self
+    where
+        Self: Sized;
+
+    /// Accumulates a value, which is already converted into the argument type.
+    /// NULL is not passed.
+    fn accumulate(&mut self, value: SpringValue) -> Result<()>;
+
+    /// Merges values accumulated in `other` into this accumulator.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the function does not support merge (default).
+    fn merge(&mut self, other: Self) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let _ = other;
+        Err(SpringError::Sql(anyhow!(
+            "merge is not supported by this aggregate function"
+        )))
+    }
+
+    /// Result of values accumulated so far.
+    ///
+    /// Called for partial results (`EMIT EVERY`) as well as for the final result on pane close.
+    /// The returned value is converted into the return type.
+    fn finalize(&self) -> Result<SpringValue>;
+
+    /// Approximate bytes held by this accumulator, used to account memory of window panes.
+    ///
+    /// Defaults to the size of the accumulator itself. Override it if the accumulator holds heap memory (e.g. `Vec`).
+    fn mem_size_hint(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
@@ -0,0 +1,63 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// Clock to drive time in a stream pipeline.
+///
+/// The clock is consumed by:
+///
+/// - ROWTIME of rows without event-time column (arrival time).
+/// - Processing-time features (processing-time windows, `EMIT EVERY duration`, `IDLE TIMEOUT`).
+/// - Sleep intervals of worker threads and timeouts of source readers.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SpringClock {
+    /// System (wall-clock) time.
+    System,
+
+    /// Manually advanced time, intended for deterministic tests.
+    ///
+    /// Time does not move until [ManualClock::advance()](ManualClock::advance) is called.
+    /// Sleeps in the pipeline do not wait for the clock to advance but only yield for a short real time.
+    Manual(ManualClock),
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for SpringClock {
+    fn default() -> Self {
+        Self::System
+    }
+}
+
+/// Manually advanced clock. Starts from `1970-01-01 00:00:00`.
+///
+/// Clones share the same time, so that a test can advance the clock injected into a pipeline.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock(Arc<Mutex<Duration>>);
+
+impl ManualClock {
+    /// Advance the clock by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Elapsed time from the start of the clock.
+    pub fn elapsed(&self) -> Duration {
+        *self.lock()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Duration> {
+        self.0
+            .lock()
+            .expect("another thread sharing the same ManualClock got panic")
+    }
+}
+
+impl PartialEq for ManualClock {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+impl Eq for ManualClock {}
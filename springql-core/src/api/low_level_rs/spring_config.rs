@@ -4,6 +4,8 @@ use serde::Deserialize;
 
 use crate::error::{Result, SpringError};
 
+use super::spring_clock::SpringClock;
+
 /// Default configuration.
 ///
 /// Default key-values are overwritten by `overwrite_config` parameter in `spring_open()`.
@@ -90,6 +92,10 @@ pub struct SpringConfig {
     pub web_console: SpringWebConsoleConfig,
     pub source_reader: SpringSourceReaderConfig,
     pub sink_writer: SpringSinkWriterConfig,
+
+    /// Clock of the pipeline. Not configurable from TOML.
+    #[serde(skip)]
+    pub clock: SpringClock,
 }
 
 impl Default for SpringConfig {
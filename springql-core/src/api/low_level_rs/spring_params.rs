@@ -0,0 +1,27 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::stream_engine::SqlValue;
+
+use super::SpringValue;
+
+/// Values bound to placeholders in SQL: positional (`$1`, `$2`, ...) or named (`:name`).
+///
+/// Placeholders can be written in the places of constants and OPTIONS values.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SpringParams(Vec<(String, SpringValue)>);
+
+impl SpringParams {
+    /// Binds `value` to `placeholder` (`"$1"` or `":name"`, for example). A value already bound to `placeholder` is replaced.
+    pub fn bind(mut self, placeholder: &str, value: SpringValue) -> Self {
+        self.0.retain(|(name, _)| name != placeholder);
+        self.0.push((placeholder.to_string(), value));
+        self
+    }
+
+    pub(crate) fn to_bind_params(&self) -> Vec<(String, SqlValue)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.clone(), SqlValue::from(value.clone())))
+            .collect()
+    }
+}
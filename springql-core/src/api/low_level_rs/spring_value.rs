@@ -0,0 +1,166 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use ordered_float::OrderedFloat;
+
+use crate::stream_engine::{
+    time::{
+        duration::{event_duration::EventDuration, SpringDuration},
+        timestamp::{timestamp_tz::TimestampTz, Timestamp},
+    },
+    Decimal, NnSqlValue, SqlValue,
+};
+
+/// Value passed to and returned from user-defined functions.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SpringValue {
+    /// NULL
+    Null,
+    /// SMALLINT
+    SmallInt(i16),
+    /// INTEGER
+    Integer(i32),
+    /// BIGINT
+    BigInt(i64),
+    /// SMALLINT UNSIGNED
+    UnsignedSmallInt(u16),
+    /// INTEGER UNSIGNED
+    UnsignedInteger(u32),
+    /// BIGINT UNSIGNED
+    UnsignedBigInt(u64),
+    /// FLOAT
+    Float(f32),
+    /// DOUBLE
+    Double(f64),
+    /// DECIMAL: `mantissa * 10^(-scale)`. Scale larger than 38 is rounded to 38.
+    Decimal {
+        /// Digits without the decimal point
+        mantissa: i128,
+        /// Number of digits after the decimal point
+        scale: u8,
+    },
+    /// TEXT
+    Text(String),
+    /// BOOLEAN
+    Boolean(bool),
+    /// TIMESTAMP (in UTC)
+    Timestamp(NaiveDateTime),
+    /// TIMESTAMPTZ
+    TimestampTz(DateTime<FixedOffset>),
+    /// DURATION
+    Duration(Duration),
+    /// BLOB
+    Blob(Vec<u8>),
+    /// ARRAY
+    Array(Vec<SpringValue>),
+    /// STRUCT: (field name, value) in declared order
+    Struct(Vec<(String, SpringValue)>),
+}
+
+impl From<SqlValue> for SpringValue {
+    fn from(sql_value: SqlValue) -> Self {
+        match sql_value {
+            SqlValue::Null => Self::Null,
+            SqlValue::NotNull(nn_sql_value) => match nn_sql_value {
+                NnSqlValue::SmallInt(v) => Self::SmallInt(v),
+                NnSqlValue::Integer(v) => Self::Integer(v),
+                NnSqlValue::BigInt(v) => Self::BigInt(v),
+                NnSqlValue::UnsignedSmallInt(v) => Self::UnsignedSmallInt(v),
+                NnSqlValue::UnsignedInteger(v) => Self::UnsignedInteger(v),
+                NnSqlValue::UnsignedBigInt(v) => Self::UnsignedBigInt(v),
+                NnSqlValue::Float(v) => Self::Float(v.into_inner()),
+                NnSqlValue::Double(v) => Self::Double(v.into_inner()),
+                NnSqlValue::Decimal(v) => Self::Decimal {
+                    mantissa: v.mantissa(),
+                    scale: v.scale(),
+                },
+                NnSqlValue::Text(v) => Self::Text(v),
+                NnSqlValue::Boolean(v) => Self::Boolean(v),
+                NnSqlValue::Timestamp(v) => Self::Timestamp(*v.as_naive_date_time()),
+                NnSqlValue::TimestampTz(v) => Self::TimestampTz(*v.as_date_time()),
+                NnSqlValue::Duration(v) => Self::Duration(*v.as_std()),
+                NnSqlValue::Blob(v) => Self::Blob(v),
+                NnSqlValue::Array(v) => Self::Array(v.into_iter().map(Self::from).collect()),
+                NnSqlValue::Struct(v) => Self::Struct(
+                    v.into_iter()
+                        .map(|(name, value)| (name, Self::from(value)))
+                        .collect(),
+                ),
+            },
+        }
+    }
+}
+
+impl From<SpringValue> for SqlValue {
+    fn from(spring_value: SpringValue) -> Self {
+        let nn_sql_value = match spring_value {
+            SpringValue::Null => return SqlValue::Null,
+            SpringValue::SmallInt(v) => NnSqlValue::SmallInt(v),
+            SpringValue::Integer(v) => NnSqlValue::Integer(v),
+            SpringValue::BigInt(v) => NnSqlValue::BigInt(v),
+            SpringValue::UnsignedSmallInt(v) => NnSqlValue::UnsignedSmallInt(v),
+            SpringValue::UnsignedInteger(v) => NnSqlValue::UnsignedInteger(v),
+            SpringValue::UnsignedBigInt(v) => NnSqlValue::UnsignedBigInt(v),
+            SpringValue::Float(v) => NnSqlValue::Float(OrderedFloat(v)),
+            SpringValue::Double(v) => NnSqlValue::Double(OrderedFloat(v)),
+            SpringValue::Decimal { mantissa, scale } => {
+                NnSqlValue::Decimal(Decimal::new_rounded(mantissa, scale))
+            }
+            SpringValue::Text(v) => NnSqlValue::Text(v),
+            SpringValue::Boolean(v) => NnSqlValue::Boolean(v),
+            SpringValue::Timestamp(v) => NnSqlValue::Timestamp(Timestamp::new(v)),
+            SpringValue::TimestampTz(v) => NnSqlValue::TimestampTz(TimestampTz::new(v)),
+            SpringValue::Duration(v) => NnSqlValue::Duration(EventDuration::from_std(v)),
+            SpringValue::Blob(v) => NnSqlValue::Blob(v),
+            SpringValue::Array(v) => NnSqlValue::Array(v.into_iter().map(SqlValue::from).collect()),
+            SpringValue::Struct(v) => NnSqlValue::Struct(
+                v.into_iter()
+                    .map(|(name, value)| (name, SqlValue::from(value)))
+                    .collect(),
+            ),
+        };
+        SqlValue::NotNull(nn_sql_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sql_value_round_trip() {
+        let spring_values = vec![
+            SpringValue::Null,
+            SpringValue::Integer(42),
+            SpringValue::UnsignedBigInt(u64::MAX),
+            SpringValue::Float(1.5),
+            SpringValue::Double(35.681236),
+            SpringValue::Decimal {
+                mantissa: -12345,
+                scale: 2,
+            },
+            SpringValue::Text("abc".to_string()),
+            SpringValue::Timestamp(NaiveDateTime::from_timestamp_opt(1577836800, 0).unwrap()),
+            SpringValue::TimestampTz(
+                DateTime::parse_from_rfc3339("2020-01-01T09:00:00+09:00").unwrap(),
+            ),
+            SpringValue::Duration(Duration::from_secs(10)),
+            SpringValue::Blob(vec![0x00, 0xff]),
+            SpringValue::Array(vec![SpringValue::Integer(1), SpringValue::Null]),
+            SpringValue::Struct(vec![
+                ("id".to_string(), SpringValue::BigInt(1)),
+                (
+                    "tags".to_string(),
+                    SpringValue::Array(vec![SpringValue::Text("a".to_string())]),
+                ),
+            ]),
+        ];
+
+        for spring_value in spring_values {
+            let round_trip = SpringValue::from(SqlValue::from(spring_value.clone()));
+            assert_eq!(round_trip, spring_value);
+        }
+    }
+}
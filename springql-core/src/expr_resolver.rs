@@ -3,14 +3,16 @@
 pub(crate) mod expr_label;
 
 use crate::error::{Result, SpringError};
-use crate::expression::{AggrExpr, ValueExpr};
+use crate::expression::{function_call::FunctionCall, AggrExpr, AnalyticExpr, ValueExpr};
 use crate::pipeline::name::{AggrAlias, ValueAlias};
 use crate::sql_processor::sql_parser::syntax::SelectFieldSyntax;
 use crate::stream_engine::{SqlValue, Tuple};
 use anyhow::anyhow;
 use std::collections::HashMap;
 
-use self::expr_label::{AggrExprLabel, ExprLabelGenerator, ValueExprLabel};
+use self::expr_label::{
+    AggrExprLabel, AnalyticExprLabel, ExprLabel, ExprLabelGenerator, ValueExprLabel,
+};
 
 /// ExprResolver is to:
 ///
@@ -27,23 +29,32 @@ pub(crate) struct ExprResolver {
     aggr_expressions: HashMap<AggrExprLabel, AggrExpr>,
     aggr_aliased_labels: HashMap<AggrAlias, AggrExprLabel>,
     aggr_expression_results: HashMap<AggrExprLabel, SqlValue>,
+
+    analytic_expressions: HashMap<AnalyticExprLabel, AnalyticExpr>,
 }
 
 impl ExprResolver {
     /// # Returns
     ///
-    /// `(instance, value expr labels in select_list, aggr expr labels in select_list)
+    /// `(instance, value expr labels in select_list, aggr expr labels in select_list, analytic expr labels in select_list)
     pub(crate) fn new(
         select_list: Vec<SelectFieldSyntax>,
-    ) -> (Self, Vec<ValueExprLabel>, Vec<AggrExprLabel>) {
+    ) -> (
+        Self,
+        Vec<ValueExprLabel>,
+        Vec<AggrExprLabel>,
+        Vec<AnalyticExprLabel>,
+    ) {
         let mut label_gen = ExprLabelGenerator::default();
         let mut value_expressions = HashMap::new();
         let mut value_aliased_labels = HashMap::new();
         let mut aggr_expressions = HashMap::new();
         let mut aggr_aliased_labels = HashMap::new();
+        let mut analytic_expressions = HashMap::new();
 
         let mut value_expr_labels = Vec::new();
         let mut aggr_expr_labels = Vec::new();
+        let mut analytic_expr_labels = Vec::new();
 
         select_list
             .into_iter()
@@ -64,6 +75,14 @@ impl ExprResolver {
                     }
                     aggr_expr_labels.push(label);
                 }
+                SelectFieldSyntax::AnalyticExpr { analytic_expr, .. } => {
+                    let label = label_gen.next_analytic();
+                    analytic_expressions.insert(label, analytic_expr);
+                    analytic_expr_labels.push(label);
+                }
+                SelectFieldSyntax::Wildcard { .. } => {
+                    unreachable!("wildcard is expanded by SelectSyntaxAnalyzer")
+                }
             });
 
         (
@@ -74,9 +93,11 @@ impl ExprResolver {
                 aggr_expressions,
                 aggr_aliased_labels,
                 aggr_expression_results: HashMap::new(),
+                analytic_expressions,
             },
             value_expr_labels,
             aggr_expr_labels,
+            analytic_expr_labels,
         )
     }
 
@@ -123,6 +144,36 @@ impl ExprResolver {
             .unwrap_or_else(|| panic!("label {:?} not found", label))
     }
 
+    /// # Panics
+    ///
+    /// -  `label` is not found
+    pub(crate) fn resolve_analytic_expr(&self, label: AnalyticExprLabel) -> &AnalyticExpr {
+        self.analytic_expressions
+            .get(&label)
+            .unwrap_or_else(|| panic!("label {:?} not found", label))
+    }
+
+    /// Registered expressions rendered with their labels, ordered by label (for EXPLAIN).
+    pub(crate) fn labeled_expressions(&self) -> Vec<(ExprLabel, String)> {
+        let mut labeled = self
+            .value_expressions
+            .iter()
+            .map(|(label, expr)| (ExprLabel::Value(*label), format!("{:?}", expr)))
+            .chain(
+                self.aggr_expressions
+                    .iter()
+                    .map(|(label, expr)| (ExprLabel::Aggr(*label), format!("{:?}", expr))),
+            )
+            .chain(
+                self.analytic_expressions
+                    .iter()
+                    .map(|(label, expr)| (ExprLabel::Analytic(*label), format!("{:?}", expr))),
+            )
+            .collect::<Vec<_>>();
+        labeled.sort_by_key(|(label, _)| *label);
+        labeled
+    }
+
     /// Register value expression which is not in select_list
     pub(crate) fn register_value_expr(&mut self, value_expr: ValueExpr) -> ValueExprLabel {
         let label = self.label_gen.next_value();
@@ -140,6 +191,32 @@ impl ExprResolver {
         label
     }
 
+    /// Whether the value expression calls WINDOW_START() or WINDOW_END().
+    ///
+    /// # Panics
+    ///
+    /// -  `label` is not found
+    pub(crate) fn has_window_function(&self, label: ValueExprLabel) -> bool {
+        self.value_expressions
+            .get(&label)
+            .unwrap_or_else(|| panic!("label {:?} not found", label))
+            .has_window_function()
+    }
+
+    /// Whether the value expression is `UNNEST(...)`.
+    ///
+    /// # Panics
+    ///
+    /// -  `label` is not found
+    pub(crate) fn is_unnest(&self, label: ValueExprLabel) -> bool {
+        matches!(
+            self.value_expressions
+                .get(&label)
+                .unwrap_or_else(|| panic!("label {:?} not found", label)),
+            ValueExpr::FunctionCall(FunctionCall::Unnest { .. })
+        )
+    }
+
     /// label -> (internal) value expression + tuple (for ColumnReference) -> SqlValue.
     ///
     /// # Panics
@@ -183,6 +260,39 @@ impl ExprResolver {
         let value_expr_ph2 = value_expr.resolve_colref(tuple)?;
         value_expr_ph2.eval()
     }
+
+    /// label -> (internal) argument and partition key inside analytic expr + tuple (for ColumnReference) -> SqlValue.
+    ///
+    /// # Returns
+    ///
+    /// `(argument value, partition key value)`. NULL for omitted argument and partition key.
+    ///
+    /// # Panics
+    ///
+    /// -  `label` is not found
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - column reference in expression is not found in `tuple`.
+    ///   - somehow failed to eval expression.
+    pub(crate) fn eval_analytic_expr_inner(
+        &self,
+        label: AnalyticExprLabel,
+        tuple: &Tuple,
+    ) -> Result<(SqlValue, SqlValue)> {
+        let analytic_expr = self.resolve_analytic_expr(label);
+        let eval = |expr: &Option<ValueExpr>| -> Result<SqlValue> {
+            match expr {
+                Some(expr) => expr.clone().resolve_colref(tuple)?.eval(),
+                None => Ok(SqlValue::Null),
+            }
+        };
+        Ok((
+            eval(&analytic_expr.argument)?,
+            eval(&analytic_expr.partition_by)?,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -210,7 +320,7 @@ mod tests {
             },
         ];
 
-        let (mut resolver, value_labels_select_list, _aggr_labels_select_list) =
+        let (mut resolver, value_labels_select_list, _aggr_labels_select_list, _) =
             ExprResolver::new(select_list);
 
         assert_eq!(
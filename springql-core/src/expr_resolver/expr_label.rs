@@ -4,6 +4,7 @@
 pub(crate) struct ExprLabelGenerator {
     value: u16,
     aggr: u16,
+    analytic: u16,
 }
 
 impl ExprLabelGenerator {
@@ -18,10 +19,27 @@ impl ExprLabelGenerator {
         self.aggr += 1;
         label
     }
+
+    pub(crate) fn next_analytic(&mut self) -> AnalyticExprLabel {
+        let label = AnalyticExprLabel(self.analytic);
+        self.analytic += 1;
+        label
+    }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub(crate) struct ValueExprLabel(u16);
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub(crate) struct AggrExprLabel(u16);
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) struct AnalyticExprLabel(u16);
+
+/// Label of a value expression, an aggregate expression or an analytic expression.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) enum ExprLabel {
+    Value(ValueExprLabel),
+    Aggr(AggrExprLabel),
+    Analytic(AnalyticExprLabel),
+}
@@ -4,6 +4,7 @@
 //!
 //! 1. Value expression, which is evaluated into an SqlValue from a row.
 //! 2. Aggregate expression, which is evaluated into an SqlValue from set of rows.
+//! 3. Analytic expression, which is evaluated into an SqlValue from a row and its preceding rows.
 //!
 //! Since SQL parser cannot distinguish column reference and value expression,
 //! `ValueExprOrAlias` is used for value expressions excluding select_list.
@@ -12,16 +13,26 @@ pub(crate) mod boolean_expression;
 pub(crate) mod function_call;
 pub(crate) mod operator;
 
+use std::sync::Arc;
+
 use anyhow::anyhow;
 
 use crate::{
     error::{Result, SpringError},
     pipeline::{
         field::field_name::ColumnReference,
-        pump_model::window_operation_parameter::aggregate::AggregateFunctionParameter,
+        name::ColumnName,
+        pump_model::{
+            analytic_parameter::AnalyticFunctionParameter,
+            window_operation_parameter::aggregate::AggregateFunctionParameter,
+        },
     },
     stream_engine::{
-        time::duration::{event_duration::EventDuration, SpringDuration},
+        blob,
+        time::{
+            duration::{event_duration::EventDuration, SpringDuration},
+            timestamp::timestamp_tz::{parse_time_zone, TimestampTz},
+        },
         NnSqlValue, SqlCompareResult, SqlValue, Tuple,
     },
 };
@@ -31,7 +42,7 @@ use self::{
         comparison_function::ComparisonFunction, logical_function::LogicalFunction,
         numerical_function::NumericalFunction, BinaryExpr,
     },
-    function_call::FunctionCall,
+    function_call::{user_defined_function::UserDefinedFunction, FunctionCall},
     operator::UnaryOperator,
 };
 
@@ -49,10 +60,280 @@ pub(crate) enum ValueExpr {
     FunctionCall(FunctionCall<Self>),
 
     ColumnReference(ColumnReference),
+    /// Column reference without stream name, qualified by `SelectSyntaxAnalyzer` before query planning.
+    UnqualifiedColumnReference(ColumnName),
 }
 impl ValueExprType for ValueExpr {}
 
 impl ValueExpr {
+    /// Whether this expression contains `WINDOW_START()` or `WINDOW_END()`.
+    pub(crate) fn has_window_function(&self) -> bool {
+        match self {
+            Self::Constant(_) | Self::ColumnReference(_) | Self::UnqualifiedColumnReference(_) => {
+                false
+            }
+            Self::UnaryOperator(_, expr) => expr.has_window_function(),
+            Self::BinaryExpr(bool_expr) => match bool_expr {
+                BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant { left, right })
+                | BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                    left,
+                    right,
+                })
+                | BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
+                    left,
+                    right,
+                })
+                | BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
+                    left,
+                    right,
+                }) => left.has_window_function() || right.has_window_function(),
+            },
+            Self::FunctionCall(function_call) => match function_call {
+                FunctionCall::WindowStart | FunctionCall::WindowEnd | FunctionCall::EmitTiming => {
+                    true
+                }
+                FunctionCall::DurationMillis { duration_millis } => {
+                    duration_millis.has_window_function()
+                }
+                FunctionCall::DurationSecs { duration_secs } => duration_secs.has_window_function(),
+                FunctionCall::FloorTime { target, resolution } => {
+                    target.has_window_function() || resolution.has_window_function()
+                }
+                FunctionCall::Hex { blob }
+                | FunctionCall::FromHex { hex: blob }
+                | FunctionCall::Base64 { blob }
+                | FunctionCall::Length { target: blob }
+                | FunctionCall::FieldAccess { target: blob, .. }
+                | FunctionCall::Cardinality { array: blob }
+                | FunctionCall::Unnest { array: blob } => blob.has_window_function(),
+                FunctionCall::Subscript { array, index } => {
+                    array.has_window_function() || index.has_window_function()
+                }
+                FunctionCall::GetByte { blob, offset } => {
+                    blob.has_window_function() || offset.has_window_function()
+                }
+                FunctionCall::AtTimeZone { target, zone } => {
+                    target.has_window_function() || zone.has_window_function()
+                }
+                FunctionCall::ToChar { target, format } => {
+                    target.has_window_function() || format.has_window_function()
+                }
+                FunctionCall::GetBits { blob, offset, len } => {
+                    blob.has_window_function()
+                        || offset.has_window_function()
+                        || len.has_window_function()
+                }
+                FunctionCall::UnresolvedUserDefined { arguments, .. }
+                | FunctionCall::UserDefined { arguments, .. } => {
+                    arguments.iter().any(Self::has_window_function)
+                }
+            },
+        }
+    }
+
+    /// Replaces column references in this expression by `f`.
+    ///
+    /// # Failures
+    ///
+    /// Errors from `f`.
+    pub(crate) fn map_column_references<F>(self, f: &mut F) -> Result<Self>
+    where
+        F: FnMut(ColumnReference) -> Result<ColumnReference>,
+    {
+        self.map_nodes(&mut |expr| match expr {
+            Self::ColumnReference(colref) => Ok(Self::ColumnReference(f(colref)?)),
+            _ => Ok(expr),
+        })
+    }
+
+    /// Qualifies unqualified column references in this expression by `f`.
+    ///
+    /// # Failures
+    ///
+    /// Errors from `f`.
+    pub(crate) fn qualify_column_references<F>(self, f: &mut F) -> Result<Self>
+    where
+        F: FnMut(ColumnName) -> Result<ColumnReference>,
+    {
+        self.map_nodes(&mut |expr| match expr {
+            Self::UnqualifiedColumnReference(column_name) => {
+                Ok(Self::ColumnReference(f(column_name)?))
+            }
+            _ => Ok(expr),
+        })
+    }
+
+    /// Replaces `a.b` parsed as a column reference by `FieldAccess` to field `b` of column `a`, where `is_field(&colref)` tells `a` is not a stream but a column.
+    pub(crate) fn resolve_field_references<F>(self, is_field: &F) -> Result<Self>
+    where
+        F: Fn(&ColumnReference) -> bool,
+    {
+        self.map_nodes(&mut |expr| match expr {
+            Self::ColumnReference(colref) if is_field(&colref) => {
+                Ok(Self::FunctionCall(FunctionCall::FieldAccess {
+                    target: Box::new(Self::UnqualifiedColumnReference(ColumnName::new(
+                        colref.stream_name.to_string(),
+                    ))),
+                    field: colref.column_name.to_string(),
+                }))
+            }
+            _ => Ok(expr),
+        })
+    }
+
+    /// Resolves calls of user-defined functions in this expression by `f`, which takes a function name and the number of arguments.
+    ///
+    /// # Failures
+    ///
+    /// Errors from `f`.
+    pub(crate) fn resolve_user_defined_functions<F>(self, f: &mut F) -> Result<Self>
+    where
+        F: FnMut(&str, usize) -> Result<Arc<UserDefinedFunction>>,
+    {
+        self.map_nodes(&mut |expr| match expr {
+            Self::FunctionCall(FunctionCall::UnresolvedUserDefined { name, arguments }) => {
+                let function = f(&name, arguments.len())?;
+                Ok(Self::FunctionCall(FunctionCall::UserDefined {
+                    function,
+                    arguments,
+                }))
+            }
+            _ => Ok(expr),
+        })
+    }
+
+    /// Replaces every node in this expression by `f`, from leaves to the root.
+    fn map_nodes<F>(self, f: &mut F) -> Result<Self>
+    where
+        F: FnMut(Self) -> Result<Self>,
+    {
+        let mut map_box = |expr: Box<Self>| expr.map_nodes(f).map(Box::new);
+
+        match self {
+            Self::Constant(_) | Self::ColumnReference(_) | Self::UnqualifiedColumnReference(_) => {
+                f(self)
+            }
+            Self::UnaryOperator(op, expr) => {
+                let expr = map_box(expr)?;
+                f(Self::UnaryOperator(op, expr))
+            }
+            Self::BinaryExpr(bool_expr) => {
+                let bool_expr = match bool_expr {
+                    BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant {
+                        left: map_box(left)?,
+                        right: map_box(right)?,
+                    }),
+                    BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                        left: map_box(left)?,
+                        right: map_box(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
+                        left: map_box(left)?,
+                        right: map_box(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
+                        left: map_box(left)?,
+                        right: map_box(right)?,
+                    }),
+                };
+                f(Self::BinaryExpr(bool_expr))
+            }
+            Self::FunctionCall(function_call) => {
+                let function_call = match function_call {
+                    FunctionCall::DurationMillis { duration_millis } => {
+                        FunctionCall::DurationMillis {
+                            duration_millis: map_box(duration_millis)?,
+                        }
+                    }
+                    FunctionCall::DurationSecs { duration_secs } => FunctionCall::DurationSecs {
+                        duration_secs: map_box(duration_secs)?,
+                    },
+                    FunctionCall::FloorTime { target, resolution } => FunctionCall::FloorTime {
+                        target: map_box(target)?,
+                        resolution: map_box(resolution)?,
+                    },
+                    FunctionCall::Hex { blob } => FunctionCall::Hex {
+                        blob: map_box(blob)?,
+                    },
+                    FunctionCall::FromHex { hex } => FunctionCall::FromHex { hex: map_box(hex)? },
+                    FunctionCall::Base64 { blob } => FunctionCall::Base64 {
+                        blob: map_box(blob)?,
+                    },
+                    FunctionCall::GetByte { blob, offset } => FunctionCall::GetByte {
+                        blob: map_box(blob)?,
+                        offset: map_box(offset)?,
+                    },
+                    FunctionCall::GetBits { blob, offset, len } => FunctionCall::GetBits {
+                        blob: map_box(blob)?,
+                        offset: map_box(offset)?,
+                        len: map_box(len)?,
+                    },
+                    FunctionCall::Length { target } => FunctionCall::Length {
+                        target: map_box(target)?,
+                    },
+                    FunctionCall::Subscript { array, index } => FunctionCall::Subscript {
+                        array: map_box(array)?,
+                        index: map_box(index)?,
+                    },
+                    FunctionCall::FieldAccess { target, field } => FunctionCall::FieldAccess {
+                        target: map_box(target)?,
+                        field,
+                    },
+                    FunctionCall::Cardinality { array } => FunctionCall::Cardinality {
+                        array: map_box(array)?,
+                    },
+                    FunctionCall::Unnest { array } => FunctionCall::Unnest {
+                        array: map_box(array)?,
+                    },
+                    FunctionCall::AtTimeZone { target, zone } => FunctionCall::AtTimeZone {
+                        target: map_box(target)?,
+                        zone: map_box(zone)?,
+                    },
+                    FunctionCall::ToChar { target, format } => FunctionCall::ToChar {
+                        target: map_box(target)?,
+                        format: map_box(format)?,
+                    },
+                    FunctionCall::WindowStart
+                    | FunctionCall::WindowEnd
+                    | FunctionCall::EmitTiming => function_call,
+                    FunctionCall::UnresolvedUserDefined { name, arguments } => {
+                        FunctionCall::UnresolvedUserDefined {
+                            name,
+                            arguments: Self::map_nodes_seq(arguments, f)?,
+                        }
+                    }
+                    FunctionCall::UserDefined {
+                        function,
+                        arguments,
+                    } => FunctionCall::UserDefined {
+                        function,
+                        arguments: Self::map_nodes_seq(arguments, f)?,
+                    },
+                };
+                f(Self::FunctionCall(function_call))
+            }
+        }
+    }
+
+    fn map_nodes_seq<F>(exprs: Vec<Self>, f: &mut F) -> Result<Vec<Self>>
+    where
+        F: FnMut(Self) -> Result<Self>,
+    {
+        exprs.into_iter().map(|expr| expr.map_nodes(f)).collect()
+    }
+
     pub(crate) fn resolve_colref(self, tuple: &Tuple) -> Result<ValueExprPh2> {
         match self {
             Self::Constant(value) => Ok(ValueExprPh2::Constant(value)),
@@ -61,6 +342,10 @@ impl ValueExpr {
                 let value = tuple.get_value(&colref)?;
                 Ok(ValueExprPh2::Constant(value))
             }
+            Self::UnqualifiedColumnReference(column_name) => Err(SpringError::Sql(anyhow!(
+                "column reference `{}` is not qualified by a stream",
+                column_name
+            ))),
 
             Self::FunctionCall(function_call) => match function_call {
                 FunctionCall::DurationMillis { duration_millis } => {
@@ -83,6 +368,126 @@ impl ValueExpr {
                         resolution: Box::new(resolution_ph2),
                     }))
                 }
+                FunctionCall::Hex { blob } => {
+                    let blob_ph2 = blob.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Hex {
+                        blob: Box::new(blob_ph2),
+                    }))
+                }
+                FunctionCall::FromHex { hex } => {
+                    let hex_ph2 = hex.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::FromHex {
+                        hex: Box::new(hex_ph2),
+                    }))
+                }
+                FunctionCall::Base64 { blob } => {
+                    let blob_ph2 = blob.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Base64 {
+                        blob: Box::new(blob_ph2),
+                    }))
+                }
+                FunctionCall::GetByte { blob, offset } => {
+                    let blob_ph2 = blob.resolve_colref(tuple)?;
+                    let offset_ph2 = offset.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::GetByte {
+                        blob: Box::new(blob_ph2),
+                        offset: Box::new(offset_ph2),
+                    }))
+                }
+                FunctionCall::GetBits { blob, offset, len } => {
+                    let blob_ph2 = blob.resolve_colref(tuple)?;
+                    let offset_ph2 = offset.resolve_colref(tuple)?;
+                    let len_ph2 = len.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::GetBits {
+                        blob: Box::new(blob_ph2),
+                        offset: Box::new(offset_ph2),
+                        len: Box::new(len_ph2),
+                    }))
+                }
+                FunctionCall::Length { target } => {
+                    let target_ph2 = target.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Length {
+                        target: Box::new(target_ph2),
+                    }))
+                }
+                FunctionCall::Subscript { array, index } => {
+                    let array_ph2 = array.resolve_colref(tuple)?;
+                    let index_ph2 = index.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Subscript {
+                        array: Box::new(array_ph2),
+                        index: Box::new(index_ph2),
+                    }))
+                }
+                FunctionCall::FieldAccess { target, field } => {
+                    let target_ph2 = target.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::FieldAccess {
+                        target: Box::new(target_ph2),
+                        field,
+                    }))
+                }
+                FunctionCall::Cardinality { array } => {
+                    let array_ph2 = array.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Cardinality {
+                        array: Box::new(array_ph2),
+                    }))
+                }
+                FunctionCall::Unnest { array } => {
+                    let array_ph2 = array.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Unnest {
+                        array: Box::new(array_ph2),
+                    }))
+                }
+                FunctionCall::AtTimeZone { target, zone } => {
+                    let target_ph2 = target.resolve_colref(tuple)?;
+                    let zone_ph2 = zone.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::AtTimeZone {
+                        target: Box::new(target_ph2),
+                        zone: Box::new(zone_ph2),
+                    }))
+                }
+                FunctionCall::ToChar { target, format } => {
+                    let target_ph2 = target.resolve_colref(tuple)?;
+                    let format_ph2 = format.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::ToChar {
+                        target: Box::new(target_ph2),
+                        format: Box::new(format_ph2),
+                    }))
+                }
+                FunctionCall::WindowStart => {
+                    let window_start = tuple.window_start()?;
+                    Ok(ValueExprPh2::Constant(SqlValue::NotNull(
+                        NnSqlValue::Timestamp(window_start),
+                    )))
+                }
+                FunctionCall::WindowEnd => {
+                    let window_end = tuple.window_end()?;
+                    Ok(ValueExprPh2::Constant(SqlValue::NotNull(
+                        NnSqlValue::Timestamp(window_end),
+                    )))
+                }
+                FunctionCall::EmitTiming => {
+                    let emit_timing = tuple.emit_timing()?;
+                    Ok(ValueExprPh2::Constant(SqlValue::NotNull(NnSqlValue::Text(
+                        emit_timing.to_string(),
+                    ))))
+                }
+                FunctionCall::UnresolvedUserDefined { name, .. } => Err(SpringError::Sql(anyhow!(
+                    "function `{}` is not resolved",
+                    name
+                ))),
+                FunctionCall::UserDefined {
+                    function,
+                    arguments,
+                } => {
+                    let arguments_ph2 = arguments
+                        .into_iter()
+                        .map(|argument| argument.resolve_colref(tuple))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::UserDefined {
+                        function,
+                        arguments: arguments_ph2,
+                    }))
+                }
             },
             Self::UnaryOperator(op, expr_ph1) => {
                 let expr_ph2 = expr_ph1.resolve_colref(tuple)?;
@@ -234,6 +639,47 @@ impl ValueExprPh2 {
             FunctionCall::DurationSecs { duration_secs } => {
                 Self::eval_function_duration_secs(*duration_secs)
             }
+            FunctionCall::Hex { blob } => Self::eval_function_hex(*blob),
+            FunctionCall::FromHex { hex } => Self::eval_function_from_hex(*hex),
+            FunctionCall::Base64 { blob } => Self::eval_function_base64(*blob),
+            FunctionCall::GetByte { blob, offset } => Self::eval_function_get_byte(*blob, *offset),
+            FunctionCall::GetBits { blob, offset, len } => {
+                Self::eval_function_get_bits(*blob, *offset, *len)
+            }
+            FunctionCall::Length { target } => Self::eval_function_length(*target),
+            FunctionCall::Subscript { array, index } => {
+                Self::eval_function_subscript(*array, *index)
+            }
+            FunctionCall::FieldAccess { target, field } => {
+                Self::eval_function_field_access(*target, &field)
+            }
+            FunctionCall::Cardinality { array } => Self::eval_function_cardinality(*array),
+            FunctionCall::AtTimeZone { target, zone } => {
+                Self::eval_function_at_time_zone(*target, *zone)
+            }
+            FunctionCall::ToChar { target, format } => {
+                Self::eval_function_to_char(*target, *format)
+            }
+            // Expanded into rows by the projection.
+            FunctionCall::Unnest { array } => array.eval(),
+            FunctionCall::WindowStart | FunctionCall::WindowEnd | FunctionCall::EmitTiming => {
+                unreachable!(
+                    "WINDOW_START(), WINDOW_END() and EMIT_TIMING() are resolved into constants by a tuple"
+                )
+            }
+            FunctionCall::UnresolvedUserDefined { .. } => {
+                unreachable!("unresolved function call is rejected by resolve_colref()")
+            }
+            FunctionCall::UserDefined {
+                function,
+                arguments,
+            } => {
+                let argument_values = arguments
+                    .into_iter()
+                    .map(Self::eval)
+                    .collect::<Result<Vec<_>>>()?;
+                function.call(argument_values)
+            }
         }
     }
 
@@ -283,6 +729,135 @@ impl ValueExprPh2 {
             )))
         }
     }
+
+    /// Evaluates `args` and applies `f` to them, or returns NULL if any of them is NULL.
+    fn eval_null_propagating<const N: usize, F>(args: [Self; N], f: F) -> Result<SqlValue>
+    where
+        F: FnOnce([NnSqlValue; N]) -> Result<NnSqlValue>,
+    {
+        let mut nn_values = Vec::with_capacity(N);
+        for arg in args {
+            match arg.eval()? {
+                SqlValue::Null => return Ok(SqlValue::Null),
+                SqlValue::NotNull(nn_value) => nn_values.push(nn_value),
+            }
+        }
+        let nn_values: [NnSqlValue; N] = nn_values
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("N values are evaluated"));
+        f(nn_values).map(SqlValue::NotNull)
+    }
+
+    fn eval_function_hex(blob: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([blob], |[blob]| {
+            Ok(NnSqlValue::Text(blob::to_hex(&blob.unpack::<Vec<u8>>()?)))
+        })
+    }
+    fn eval_function_from_hex(hex: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([hex], |[hex]| {
+            blob::from_hex(&hex.unpack::<String>()?).map(NnSqlValue::Blob)
+        })
+    }
+    fn eval_function_base64(blob: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([blob], |[blob]| {
+            Ok(NnSqlValue::Text(blob::to_base64(
+                &blob.unpack::<Vec<u8>>()?,
+            )))
+        })
+    }
+    fn eval_function_get_byte(blob: Self, offset: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([blob, offset], |[blob, offset]| {
+            let byte = blob::get_byte(&blob.unpack::<Vec<u8>>()?, offset.unpack::<i64>()?)?;
+            Ok(NnSqlValue::BigInt(byte as i64))
+        })
+    }
+    fn eval_function_get_bits(blob: Self, offset: Self, len: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([blob, offset, len], |[blob, offset, len]| {
+            blob::get_bits(
+                &blob.unpack::<Vec<u8>>()?,
+                offset.unpack::<i64>()?,
+                len.unpack::<i64>()?,
+            )
+            .map(NnSqlValue::BigInt)
+        })
+    }
+    fn eval_function_length(target: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([target], |[target]| {
+            let length = match &target {
+                NnSqlValue::Blob(b) => b.len(),
+                NnSqlValue::Text(s) => s.chars().count(),
+                _ => {
+                    return Err(SpringError::Sql(anyhow!(
+                        "LENGTH takes BLOB or TEXT but got `{}`",
+                        target
+                    )))
+                }
+            };
+            Ok(NnSqlValue::BigInt(length as i64))
+        })
+    }
+    fn eval_function_subscript(array: Self, index: Self) -> Result<SqlValue> {
+        let array_value = array.eval()?;
+        let index_value = index.eval()?;
+        match (&array_value, &index_value) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(NnSqlValue::Array(elements)), SqlValue::NotNull(index)) => {
+                let index = index.unpack::<i64>()?;
+                Ok(usize::try_from(index - 1)
+                    .ok()
+                    .and_then(|i| elements.get(i))
+                    .cloned()
+                    .unwrap_or(SqlValue::Null))
+            }
+            _ => Err(SpringError::Sql(anyhow!(
+                "invalid subscript: `{}[{}]`",
+                array_value,
+                index_value
+            ))),
+        }
+    }
+    fn eval_function_field_access(target: Self, field: &str) -> Result<SqlValue> {
+        match target.eval()? {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(NnSqlValue::Struct(fields)) => Ok(fields
+                .into_iter()
+                .find(|(name, _)| name == field)
+                .map(|(_, value)| value)
+                .unwrap_or(SqlValue::Null)),
+            SqlValue::NotNull(v) => Err(SpringError::Sql(anyhow!(
+                "field `{}` is accessed in non-STRUCT value `{}`",
+                field,
+                v
+            ))),
+        }
+    }
+    fn eval_function_cardinality(array: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([array], |[array]| match array {
+            NnSqlValue::Array(elements) => Ok(NnSqlValue::BigInt(elements.len() as i64)),
+            _ => Err(SpringError::Sql(anyhow!(
+                "CARDINALITY takes ARRAY but got `{}`",
+                array
+            ))),
+        })
+    }
+
+    fn eval_function_at_time_zone(target: Self, zone: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([target, zone], |[target, zone]| {
+            let offset = parse_time_zone(&zone.unpack::<String>()?)?;
+            Ok(NnSqlValue::TimestampTz(
+                target.unpack::<TimestampTz>()?.with_offset(offset),
+            ))
+        })
+    }
+
+    fn eval_function_to_char(target: Self, format: Self) -> Result<SqlValue> {
+        Self::eval_null_propagating([target, format], |[target, format]| {
+            target
+                .unpack::<TimestampTz>()?
+                .format(&format.unpack::<String>()?)
+                .map(NnSqlValue::Text)
+        })
+    }
 }
 
 /// Aggregate expression.
@@ -291,3 +866,29 @@ pub(crate) struct AggrExpr {
     pub(crate) func: AggregateFunctionParameter,
     pub(crate) aggregated: ValueExpr,
 }
+
+/// Analytic expression (`func(...) OVER (PARTITION BY ... ORDER BY ROWTIME ROWS n PRECEDING)`).
+///
+/// Evaluated for each row from the row and preceding rows in the same partition.
+/// Rows are ordered by their arrival to the pump.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct AnalyticExpr {
+    pub(crate) func: AnalyticFunctionParameter,
+    /// None for functions without argument (`ROW_NUMBER()`).
+    pub(crate) argument: Option<ValueExpr>,
+    /// None for a single partition.
+    pub(crate) partition_by: Option<ValueExpr>,
+    /// `ROWS n PRECEDING`: the frame is the current row and `n` preceding rows.
+    /// None for all the preceding rows in the partition.
+    pub(crate) preceding_rows: Option<u64>,
+}
+
+impl AnalyticExpr {
+    /// Whether the argument or the partition key contains `WINDOW_START()` or `WINDOW_END()`.
+    pub(crate) fn has_window_function(&self) -> bool {
+        self.argument
+            .iter()
+            .chain(self.partition_by.iter())
+            .any(|expr| expr.has_window_function())
+    }
+}
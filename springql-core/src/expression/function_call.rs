@@ -1,5 +1,10 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+pub(crate) mod user_defined_function;
+
+use std::sync::Arc;
+
+use self::user_defined_function::UserDefinedFunction;
 use super::ValueExprType;
 
 #[derive(Clone, PartialEq, Hash, Debug)]
@@ -21,4 +26,126 @@ where
     /// FLOOR_TIME("2020-01-01 01:11:11.000000000", DURATION_SECS(10 * 60)) -> "2020-01-01 01:10:00.000000000"
     /// ```
     FloorTime { target: Box<E>, resolution: Box<E> },
+
+    /// ```text
+    /// HEX(FROM_HEX('0aff')) -> '0AFF'
+    /// ```
+    Hex { blob: Box<E> },
+
+    /// ```text
+    /// FROM_HEX('0aff') -> BLOB [0x0a, 0xff]
+    /// ```
+    FromHex { hex: Box<E> },
+
+    /// ```text
+    /// BASE64(FROM_HEX('00ff10')) -> 'AP8Q'
+    /// ```
+    Base64 { blob: Box<E> },
+
+    /// 0-origin byte offset.
+    ///
+    /// ```text
+    /// GET_BYTE(FROM_HEX('12ab'), 1) -> 171
+    /// ```
+    GetByte { blob: Box<E>, offset: Box<E> },
+
+    /// Unsigned integer of `len` bits from `offset`-th bit (bits are numbered from the LSB of each byte).
+    ///
+    /// ```text
+    /// GET_BITS(FROM_HEX('3412'), 4, 8) -> 35 (0x23)
+    /// ```
+    GetBits {
+        blob: Box<E>,
+        offset: Box<E>,
+        len: Box<E>,
+    },
+
+    /// Number of bytes of a BLOB or number of characters of a TEXT.
+    ///
+    /// ```text
+    /// LENGTH(FROM_HEX('12ab')) -> 2
+    /// ```
+    Length { target: Box<E> },
+
+    /// `array[index]`: 1-origin element of an ARRAY. NULL if `index` is out of range.
+    ///
+    /// ```text
+    /// tags[1] -> 'a' (for tags = ['a', 'b'])
+    /// ```
+    Subscript { array: Box<E>, index: Box<E> },
+
+    /// `target.field`: field of a STRUCT.
+    ///
+    /// ```text
+    /// position.lat -> 35.68 (for position = {lat: 35.68, lon: 139.76})
+    /// ```
+    FieldAccess { target: Box<E>, field: String },
+
+    /// Number of elements of an ARRAY.
+    ///
+    /// ```text
+    /// CARDINALITY(tags) -> 2 (for tags = ['a', 'b'])
+    /// ```
+    Cardinality { array: Box<E> },
+
+    /// `target AT TIME ZONE zone`: the same instant in a time zone (`UTC` or a fixed offset like `+09:00`).
+    /// TIMESTAMP values are regarded as in UTC.
+    ///
+    /// ```text
+    /// TIMESTAMP '2020-01-01 00:00:00' AT TIME ZONE '+09:00' -> TIMESTAMPTZ '2020-01-01 09:00:00+09:00'
+    /// ```
+    AtTimeZone { target: Box<E>, zone: Box<E> },
+
+    /// Local time of a TIMESTAMPTZ (or a TIMESTAMP in UTC) formatted by a chrono strftime pattern.
+    ///
+    /// ```text
+    /// TO_CHAR(ts AT TIME ZONE '+09:00', '%Y/%m/%d %H:%M') -> '2020/01/01 09:00' (for ts = '2020-01-01 00:00:00')
+    /// ```
+    ToChar { target: Box<E>, format: Box<E> },
+
+    /// Expands an ARRAY into rows, one for each element.
+    /// Only available as a select field by itself.
+    ///
+    /// ```text
+    /// SELECT STREAM id, UNNEST(tags) -> (1, 'a'), (1, 'b') (for id = 1, tags = ['a', 'b'])
+    /// ```
+    Unnest { array: Box<E> },
+
+    /// Open time of the window pane a row belongs to.
+    /// Only available in pumps with window clause.
+    ///
+    /// ```text
+    /// WINDOW_START() -> "2020-01-01 00:00:00.000000000" (for a pane [:00, :10))
+    /// ```
+    WindowStart,
+
+    /// Close time of the window pane a row belongs to.
+    /// Only available in pumps with window clause.
+    ///
+    /// ```text
+    /// WINDOW_END() -> "2020-01-01 00:00:10.000000000" (for a pane [:00, :10))
+    /// ```
+    WindowEnd,
+
+    /// Whether the row is an early (partial) result of a window pane or the final result on pane close.
+    /// Only available in pumps with window clause.
+    ///
+    /// ```text
+    /// EMIT_TIMING() -> 'EARLY' (emitted by EMIT EVERY trigger) or 'FINAL' (emitted on pane close)
+    /// ```
+    EmitTiming,
+
+    /// Call of a function by a name other than built-in functions.
+    /// Resolved into `UserDefined` by `SqlProcessor` before query planning.
+    UnresolvedUserDefined { name: String, arguments: Vec<E> },
+
+    /// Scalar function registered from Rust.
+    ///
+    /// ```text
+    /// decode_speed(raw_speed) -> (return value of the registered closure)
+    /// ```
+    UserDefined {
+        function: Arc<UserDefinedFunction>,
+        arguments: Vec<E>,
+    },
 }
@@ -0,0 +1,161 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
+
+use anyhow::anyhow;
+
+use crate::{
+    error::{Result, SpringError},
+    low_level_rs::SpringValue,
+    pipeline::relation::sql_type::SqlType,
+    stream_engine::SqlValue,
+};
+
+/// Body of a user-defined function.
+pub(crate) type UserDefinedFunctionBody =
+    dyn Fn(&[SpringValue]) -> Result<SpringValue> + Send + Sync;
+
+/// Scalar function registered from Rust.
+///
+/// Functions are identified by their name (case-insensitive).
+#[derive(new)]
+pub(crate) struct UserDefinedFunction {
+    name: String,
+    arg_types: Vec<SqlType>,
+    return_type: SqlType,
+    body: Box<UserDefinedFunctionBody>,
+}
+
+impl Debug for UserDefinedFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserDefinedFunction")
+            .field("name", &self.name)
+            .field("arg_types", &self.arg_types)
+            .field("return_type", &self.return_type)
+            .finish()
+    }
+}
+
+impl PartialEq for UserDefinedFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Hash for UserDefinedFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.to_lowercase().hash(state);
+    }
+}
+
+impl UserDefinedFunction {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn arity(&self) -> usize {
+        self.arg_types.len()
+    }
+
+    pub(crate) fn arg_types(&self) -> &[SqlType] {
+        &self.arg_types
+    }
+
+    pub(crate) fn return_type(&self) -> &SqlType {
+        &self.return_type
+    }
+
+    /// Calls the body with `args` converted into the argument types.
+    /// NULL is passed as it is.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - an argument cannot be converted into its argument type.
+    ///   - the body returns an error.
+    ///   - the return value cannot be converted into the return type.
+    pub(crate) fn call(&self, args: Vec<SqlValue>) -> Result<SqlValue> {
+        let args = args
+            .into_iter()
+            .zip(self.arg_types.iter())
+            .map(|(arg, arg_type)| match arg {
+                SqlValue::Null => Ok(SpringValue::Null),
+                SqlValue::NotNull(nn_arg) => nn_arg
+                    .try_convert(arg_type)
+                    .map(|nn_arg| SpringValue::from(SqlValue::NotNull(nn_arg))),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                SpringError::Sql(anyhow!(
+                    "invalid argument to user-defined function `{}`: {:?}",
+                    self.name,
+                    e
+                ))
+            })?;
+
+        let ret = (self.body)(&args).map_err(|e| {
+            SpringError::Sql(anyhow!(
+                "user-defined function `{}` failed: {:?}",
+                self.name,
+                e
+            ))
+        })?;
+
+        match SqlValue::from(ret) {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(nn_ret) => nn_ret
+                .try_convert(&self.return_type)
+                .map(SqlValue::NotNull)
+                .map_err(|e| {
+                    SpringError::Sql(anyhow!(
+                        "user-defined function `{}` returned a value not convertible into {:?}: {:?}",
+                        self.name,
+                        self.return_type,
+                        e
+                    ))
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream_engine::NnSqlValue;
+
+    fn decode_speed() -> UserDefinedFunction {
+        UserDefinedFunction::new(
+            "decode_speed".to_string(),
+            vec![SqlType::integer()],
+            SqlType::float(),
+            Box::new(|args| match args {
+                [SpringValue::Integer(raw)] => Ok(SpringValue::Float(*raw as f32 * 0.5)),
+                [SpringValue::Null] => Ok(SpringValue::Null),
+                _ => Err(SpringError::Sql(anyhow!("unexpected arguments"))),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_call() {
+        let udf = decode_speed();
+
+        // SMALLINT argument is converted into INTEGER
+        assert_eq!(
+            udf.call(vec![SqlValue::NotNull(NnSqlValue::SmallInt(100))])
+                .unwrap(),
+            SqlValue::NotNull(NnSqlValue::Float(50.0.into()))
+        );
+        assert!(matches!(
+            udf.call(vec![SqlValue::Null]).unwrap(),
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            udf.call(vec![SqlValue::NotNull(NnSqlValue::Text("a".to_string()))]),
+            Err(SpringError::Sql(_))
+        ));
+    }
+}
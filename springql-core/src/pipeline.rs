@@ -70,6 +70,11 @@ impl Pipeline {
         self.graph.get_stream(stream)
     }
 
+    /// Whether `name` is already used by a stream or a pump in the pipeline.
+    pub(super) fn has_name(&self, name: &str) -> bool {
+        self.object_names.contains(name)
+    }
+
     /// # Failure
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
@@ -107,6 +112,33 @@ impl Pipeline {
         self.graph.add_sink_writer(sink_writer)
     }
 
+    /// DDL statements (without trailing `;`) which recreate an equivalent pipeline.
+    ///
+    /// Statements are ordered as streams, pumps, sink writers and source readers so that rows are read after all the others are created.
+    /// Pumps are exported as their `CREATE PUMP` statements.
+    pub(super) fn to_ddl(&self) -> Vec<String> {
+        let streams = self.all_streams();
+        let pumps = self.all_pumps();
+        let mut sinks = self.all_sinks();
+        sinks.sort_by(|a, b| a.name().cmp(b.name()));
+        let mut sources = self.all_sources();
+        sources.sort_by(|a, b| a.name().cmp(b.name()));
+
+        streams
+            .iter()
+            .map(|stream| stream.ddl())
+            .chain(pumps.iter().map(|pump| pump.ddl().to_string()))
+            .chain(sinks.iter().map(|sink| sink.ddl()))
+            .chain(sources.iter().map(|source| source.ddl()))
+            .collect()
+    }
+
+    pub(super) fn all_streams(&self) -> Vec<Arc<StreamModel>> {
+        self.graph.all_streams()
+    }
+    pub(super) fn all_pumps(&self) -> Vec<&PumpModel> {
+        self.graph.all_pumps()
+    }
     pub(super) fn all_sources(&self) -> Vec<&SourceReaderModel> {
         self.graph.all_sources()
     }
@@ -38,6 +38,10 @@ pub(crate) struct ValueAlias(String);
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize, new)]
 pub(crate) struct AggrAlias(String);
 
+/// Pattern variable in `MATCH_RECOGNIZE`.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize, new)]
+pub(crate) struct PatternSymbol(String);
+
 impl Display for StreamName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -88,6 +92,11 @@ impl Display for AggrAlias {
         write!(f, "{}", self.0)
     }
 }
+impl Display for PatternSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 impl AsRef<str> for StreamName {
     fn as_ref(&self) -> &str {
@@ -134,6 +143,11 @@ impl AsRef<str> for AggrAlias {
         &self.0
     }
 }
+impl AsRef<str> for PatternSymbol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
 
 impl ColumnName {
     pub(crate) fn arrival_rowtime() -> Self {
@@ -167,3 +181,65 @@ impl MemSize for ColumnName {
         self.0.capacity()
     }
 }
+
+/// Suffix of "not found" error messages which suggests the candidate most similar to a misspelled `name`
+/// (`; did you mean "trade"?`), or an empty string when no candidate is similar enough.
+pub(crate) fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| format!(r#"; did you mean "{}"?"#, candidate))
+        .unwrap_or_default()
+}
+
+/// Levenshtein distance in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev_row[j] + usize::from(ca != *cb);
+            row.push(substitution.min(prev_row[j + 1] + 1).min(row[j] + 1));
+        }
+        prev_row = row;
+    }
+    prev_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("trade", "trade"), 0);
+        assert_eq!(edit_distance("trde", "trade"), 1);
+        assert_eq!(edit_distance("tarde", "trade"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let candidates = ["source_trade", "trade", "sink_trade"];
+
+        assert_eq!(
+            did_you_mean("trde", candidates),
+            r#"; did you mean "trade"?"#
+        );
+        assert_eq!(
+            did_you_mean("Sink_Trade", candidates),
+            r#"; did you mean "sink_trade"?"#
+        );
+        assert_eq!(did_you_mean("amount", candidates), "");
+    }
+}
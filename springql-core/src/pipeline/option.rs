@@ -8,7 +8,7 @@ pub(crate) mod net_options;
 use crate::error::{Result, SpringError};
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
 use self::options_builder::OptionsBuilder;
 
@@ -35,6 +35,45 @@ impl Options {
                 source: e,
             })
     }
+
+    /// `None` if key is not found in this Options.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::InvalidOption](crate SpringError::InvalidOption) when:
+    ///   - value is found but cannot be parsed.
+    pub(crate) fn get_optional<V, F>(&self, key: &str, value_parser: F) -> Result<Option<V>>
+    where
+        F: FnOnce(&String) -> std::result::Result<V, anyhow::Error>,
+    {
+        self.0
+            .get(key)
+            .map(|value| {
+                value_parser(value).map_err(|e| SpringError::InvalidOption {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    source: e,
+                })
+            })
+            .transpose()
+    }
+}
+
+/// `OPTIONS (...)` clause in DDL, ordered by keys. Empty string if no option is specified.
+impl Display for Options {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let mut options = self.0.iter().collect::<Vec<_>>();
+        options.sort();
+        let options = options
+            .iter()
+            .map(|(key, value)| format!("    {} '{}'", key, value.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        write!(f, "OPTIONS (\n{}\n  )", options)
+    }
 }
 
 impl From<OptionsBuilder> for Options {
@@ -21,8 +21,11 @@ use petgraph::{
 use self::{edge::Edge, stream_node::StreamNode};
 
 use super::{
-    name::StreamName, pump_model::PumpModel, sink_writer_model::SinkWriterModel,
-    source_reader_model::SourceReaderModel, stream_model::StreamModel,
+    name::{did_you_mean, StreamName},
+    pump_model::PumpModel,
+    sink_writer_model::SinkWriterModel,
+    source_reader_model::SourceReaderModel,
+    stream_model::StreamModel,
 };
 use crate::error::{Result, SpringError};
 use anyhow::anyhow;
@@ -48,6 +51,16 @@ impl Default for PipelineGraph {
     }
 }
 
+/// Graphs are equal when they have the same streams and the same edges between them, regardless of the order they are added.
+impl PartialEq for PipelineGraph {
+    fn eq(&self, other: &Self) -> bool {
+        let (edges, other_edges) = (self.edges_with_nodes(), other.edges_with_nodes());
+        self.all_streams() == other.all_streams()
+            && edges.len() == other_edges.len()
+            && edges.iter().all(|edge| other_edges.contains(edge))
+    }
+}
+
 impl PipelineGraph {
     pub(super) fn add_stream(&mut self, stream: Arc<StreamModel>) -> Result<()> {
         let st_name = stream.name().clone();
@@ -85,7 +98,7 @@ impl PipelineGraph {
         self.graph
             .edge_references()
             .filter_map(|edge| match edge.weight() {
-                Edge::Pump { .. } | Edge::Sink(_) => None,
+                Edge::Pump { .. } | Edge::LateRows { .. } | Edge::Sink(_) => None,
                 Edge::Source(s) => Some(s),
             })
             .collect()
@@ -94,12 +107,55 @@ impl PipelineGraph {
         self.graph
             .edge_references()
             .filter_map(|edge| match edge.weight() {
-                Edge::Pump { .. } | Edge::Source(_) => None,
+                Edge::Pump { .. } | Edge::LateRows { .. } | Edge::Source(_) => None,
                 Edge::Sink(s) => Some(s),
             })
             .collect()
     }
 
+    /// (upstream node, downstream node, edge)
+    fn edges_with_nodes(&self) -> Vec<(&StreamNode, &StreamNode, &Edge)> {
+        self.graph
+            .edge_references()
+            .map(|edge| {
+                (
+                    &self.graph[edge.source()],
+                    &self.graph[edge.target()],
+                    edge.weight(),
+                )
+            })
+            .collect()
+    }
+
+    /// Streams ordered by name.
+    pub(super) fn all_streams(&self) -> Vec<Arc<StreamModel>> {
+        let mut streams = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                StreamNode::Stream(stream) => Some(stream.clone()),
+                StreamNode::VirtualRoot | StreamNode::VirtualLeaf { .. } => None,
+            })
+            .collect::<Vec<_>>();
+        streams.sort_by(|a, b| a.name().cmp(b.name()));
+        streams
+    }
+
+    /// Pumps ordered by name. A pump with 2 or more upstreams appears once.
+    pub(super) fn all_pumps(&self) -> Vec<&PumpModel> {
+        let mut pumps = self
+            .graph
+            .edge_weights()
+            .filter_map(|edge| match edge {
+                Edge::Pump { pump_model, .. } => Some(pump_model.as_ref()),
+                Edge::LateRows { .. } | Edge::Source(_) | Edge::Sink(_) => None,
+            })
+            .collect::<Vec<_>>();
+        pumps.sort_by_key(|pump| pump.name());
+        pumps.dedup_by(|a, b| a.name() == b.name());
+        pumps
+    }
+
     pub(super) fn add_pump(&mut self, pump: PumpModel) -> Result<()> {
         let pump = Arc::new(pump);
 
@@ -128,12 +184,44 @@ impl PipelineGraph {
             );
         }
 
+        if let Some(late_rows_stream) = pump.late_rows_stream() {
+            let late_rows_node = self.stream_nodes.get(late_rows_stream).ok_or_else(|| {
+                SpringError::Sql(anyhow!(
+                    r#"late rows stream "{}" does not exist in pipeline"#,
+                    late_rows_stream
+                ))
+            })?;
+            // LATE ROWS INTO is only for pumps with a single upstream.
+            let upstream_node = self
+                .stream_nodes
+                .get(pump.upstreams()[0])
+                .expect("checked above");
+
+            let _ = self.graph.add_edge(
+                *upstream_node,
+                *late_rows_node,
+                Edge::LateRows {
+                    pump_model: pump.clone(),
+                },
+            );
+        }
+
         Ok(())
     }
 
     fn _find_stream(&self, name: &StreamName) -> Result<NodeIndex> {
         Ok(*self.stream_nodes.get(name).ok_or_else(|| {
-            SpringError::Sql(anyhow!(r#"stream "{}" does not exist in pipeline"#, name))
+            SpringError::Sql(anyhow!(
+                r#"stream "{}" does not exist in pipeline{}"#,
+                name,
+                did_you_mean(
+                    name.as_ref(),
+                    self.stream_nodes
+                        .keys()
+                        .filter(|stream_name| **stream_name != StreamName::virtual_root())
+                        .map(|stream_name| stream_name.as_ref())
+                )
+            ))
         })?)
     }
 
@@ -7,13 +7,18 @@ use crate::pipeline::{
     source_reader_model::SourceReaderModel,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub(crate) enum Edge {
     Pump {
         /// A pump can have 2 or more upstreams (on JOIN, for example). Then, graph edges share the same PumpModel.
         pump_model: Arc<PumpModel>,
         upstream: StreamName,
     },
+    /// Late rows of a windowed pump flow into `LATE ROWS INTO` stream through this edge.
+    /// It shares the PumpModel (and the pump task) with the pump's main edge.
+    LateRows {
+        pump_model: Arc<PumpModel>,
+    },
     Source(SourceReaderModel),
     Sink(SinkWriterModel),
 }
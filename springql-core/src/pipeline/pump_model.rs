@@ -1,8 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+pub(crate) mod analytic_parameter;
+pub(crate) mod deduplicate_parameter;
+pub(crate) mod match_recognize_parameter;
+pub(crate) mod order_limit_parameter;
 pub(crate) mod pump_input_type;
 pub(crate) mod window_operation_parameter;
 pub(crate) mod window_parameter;
+pub(crate) mod window_trigger;
 
 use crate::stream_engine::command::{insert_plan::InsertPlan, query_plan::QueryPlan};
 
@@ -15,6 +20,8 @@ pub(crate) struct PumpModel {
     name: PumpName,
     query_plan: QueryPlan,
     insert_plan: InsertPlan,
+    /// `CREATE PUMP ...` statement from which this pump is created.
+    ddl: String,
 }
 
 impl PumpModel {
@@ -34,6 +41,13 @@ impl PumpModel {
         &self.insert_plan
     }
 
+    /// `CREATE PUMP ...` statement as written (without trailing `;`).
+    ///
+    /// Query plans lose aliases and syntax sugar. The original statement is kept to recreate the pump.
+    pub(crate) fn ddl(&self) -> &str {
+        &self.ddl
+    }
+
     /// Has more than 1 upstreams on JOIN, for example.
     pub(crate) fn upstreams(&self) -> Vec<&StreamName> {
         self.query_plan.upstreams()
@@ -42,4 +56,9 @@ impl PumpModel {
     pub(crate) fn downstream(&self) -> &StreamName {
         self.insert_plan.stream()
     }
+
+    /// Stream to which late rows are inserted (`LATE ROWS INTO`).
+    pub(crate) fn late_rows_stream(&self) -> Option<&StreamName> {
+        self.insert_plan.late_rows_stream()
+    }
 }
@@ -0,0 +1,24 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+/// Function of an analytic expression (`func(...) OVER (...)`).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum AnalyticFunctionParameter {
+    /// Argument value of the row `offset` rows before the current row in the partition (NULL if no such row).
+    ///
+    /// Frame clause does not affect LAG.
+    Lag { offset: u64 },
+
+    /// Argument value of the first row in the frame.
+    FirstValue,
+
+    /// Sum of non-NULL argument values in the frame.
+    Sum,
+
+    /// Average of non-NULL argument values in the frame.
+    Avg,
+
+    /// Sequential number of the current row in the partition, starting from 1.
+    ///
+    /// Frame clause does not affect ROW_NUMBER.
+    RowNumber,
+}
@@ -0,0 +1,34 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    expr_resolver::expr_label::ValueExprLabel,
+    pipeline::pump_model::window_parameter::WindowTimeDomain,
+    stream_engine::time::duration::event_duration::EventDuration,
+};
+
+/// Parameters to drop rows whose key was already emitted within a horizon.
+///
+/// - `SELECT DISTINCT ... FIXED WINDOW ...`: the key is select_list, and the horizon is the pane of a row.
+/// - `DEDUPLICATE BY expr, ... WITHIN duration`: the key is `expr, ...`, and the horizon is `duration` from the emitted row.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct DeduplicateParameter {
+    /// Key expressions. Empty for `SELECT DISTINCT`, whose key is the values of select_list.
+    pub(crate) keys: Vec<ValueExprLabel>,
+
+    pub(crate) horizon: DeduplicateHorizon,
+
+    pub(crate) time_domain: WindowTimeDomain,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum DeduplicateHorizon {
+    /// Rows are deduplicated in each pane of a time-based fixed window.
+    /// Rows behind the watermark (`max ROWTIME - allowed_delay`) are dropped.
+    Pane {
+        length: EventDuration,
+        allowed_delay: EventDuration,
+    },
+
+    /// A row is dropped if a row with the same key is emitted within the duration before it.
+    Duration(EventDuration),
+}
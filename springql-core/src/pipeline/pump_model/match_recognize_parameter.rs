@@ -0,0 +1,83 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    expr_resolver::expr_label::ValueExprLabel,
+    pipeline::name::{ColumnName, PatternSymbol, StreamName},
+    stream_engine::time::duration::event_duration::EventDuration,
+};
+
+/// Parameters of `MATCH_RECOGNIZE (...)`.
+///
+/// A match is a sequence of contiguous rows in a partition whose pattern symbols (satisfying DEFINE conditions) match `pattern`,
+/// and whose ROWTIMEs are within `within` from the first row.
+/// A match emits a tuple of measures, and the next match starts after the last row of the match (`AFTER MATCH SKIP PAST LAST ROW`).
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct MatchRecognizeParameter {
+    /// None for a single partition.
+    pub(crate) partition_by: Option<ValueExprLabel>,
+
+    pub(crate) pattern: RowPattern,
+
+    pub(crate) within: EventDuration,
+
+    /// Symbols appearing in `pattern` and their DEFINE conditions.
+    /// A symbol without DEFINE condition matches any row.
+    pub(crate) symbols: Vec<(PatternSymbol, Option<ValueExprLabel>)>,
+
+    pub(crate) measures: Vec<MeasureParameter>,
+
+    /// Correlation name to reference measures from select_list (`MATCH_RECOGNIZE (...) AS alias`).
+    pub(crate) alias: StreamName,
+}
+
+/// `MEASURES expr AS alias`.
+///
+/// `expr` is evaluated with the last row matched to `symbol` (NULL if no row is matched to `symbol`).
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct MeasureParameter {
+    pub(crate) symbol: PatternSymbol,
+    pub(crate) expr: ValueExprLabel,
+    pub(crate) alias: ColumnName,
+}
+
+/// Regular expression over pattern symbols (`PATTERN (...)`).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) enum RowPattern {
+    Symbol(PatternSymbol),
+    Concatenation(Vec<RowPattern>),
+    Alternation(Vec<RowPattern>),
+    Quantified(Box<RowPattern>, PatternQuantifier),
+}
+
+impl RowPattern {
+    /// Symbols in appearance order, without duplicates.
+    pub(crate) fn symbols(&self) -> Vec<PatternSymbol> {
+        let mut symbols = Vec::new();
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols(&self, symbols: &mut Vec<PatternSymbol>) {
+        match self {
+            RowPattern::Symbol(symbol) => {
+                if !symbols.contains(symbol) {
+                    symbols.push(symbol.clone())
+                }
+            }
+            RowPattern::Concatenation(patterns) | RowPattern::Alternation(patterns) => patterns
+                .iter()
+                .for_each(|pattern| pattern.collect_symbols(symbols)),
+            RowPattern::Quantified(pattern, _) => pattern.collect_symbols(symbols),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum PatternQuantifier {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
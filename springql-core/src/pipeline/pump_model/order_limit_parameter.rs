@@ -0,0 +1,47 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    pipeline::pump_model::window_parameter::WindowTimeDomain,
+    stream_engine::time::duration::event_duration::EventDuration,
+};
+
+/// Parameters to sort and limit output rows in each pane (`ORDER BY field [ASC|DESC], ... LIMIT n`).
+///
+/// - With GROUP BY aggregation: results of groups in a pane are ranked when the pane closes.
+/// - Without aggregation: rows in a pane of time-based fixed window are ranked when the pane closes.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct OrderLimitParameter {
+    /// Empty for `LIMIT` without `ORDER BY`, which keeps rows in arrival order.
+    pub(crate) sort_keys: Vec<SortKey>,
+    /// Max number of rows emitted per pane.
+    pub(crate) limit: Option<u64>,
+
+    pub(crate) panes: OrderLimitPanes,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct SortKey {
+    /// Position of the sort key in select_list.
+    pub(crate) field_index: usize,
+    pub(crate) order: SortOrder,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum OrderLimitPanes {
+    /// Panes of GROUP BY aggregation.
+    Aggregate,
+
+    /// Panes of time-based fixed window over rows.
+    /// Rows behind the watermark (`max ROWTIME - allowed_delay`) are dropped.
+    Fixed {
+        length: EventDuration,
+        allowed_delay: EventDuration,
+        time_domain: WindowTimeDomain,
+    },
+}
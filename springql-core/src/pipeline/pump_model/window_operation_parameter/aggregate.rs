@@ -1,6 +1,15 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel};
+pub(crate) mod user_defined_aggregate_function;
+
+use std::sync::Arc;
+
+use crate::{
+    expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel},
+    pipeline::relation::sql_type::SqlType,
+};
+
+use self::user_defined_aggregate_function::UserDefinedAggregateFunction;
 
 /// TODO [support complex expression with aggregations](https://gh01.base.toyota-tokyo.tech/SpringQL-internal/SpringQL/issues/152)
 ///
@@ -10,7 +19,7 @@ use crate::expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel};
 ///   GROUP BY group_by
 ///   SLIDING WINDOW ...;
 /// ```
-#[derive(Copy, Clone, PartialEq, Debug, new)]
+#[derive(Clone, PartialEq, Debug, new)]
 pub(crate) struct GroupAggregateParameter {
     // TODO multiple aggr_expr
     pub(crate) aggr_func: AggregateFunctionParameter,
@@ -19,7 +28,28 @@ pub(crate) struct GroupAggregateParameter {
     pub(crate) group_by: ValueExprLabel, // TODO multiple group by expression
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) enum AggregateFunctionParameter {
     Avg,
+
+    /// Aggregate function registered from Rust.
+    UserDefined(Arc<UserDefinedAggregateFunction>),
+}
+
+impl AggregateFunctionParameter {
+    /// Type aggregated values are converted into.
+    pub(crate) fn arg_type(&self) -> SqlType {
+        match self {
+            AggregateFunctionParameter::Avg => SqlType::double(),
+            AggregateFunctionParameter::UserDefined(function) => function.arg_type().clone(),
+        }
+    }
+
+    /// Type results are converted into.
+    pub(crate) fn return_type(&self) -> SqlType {
+        match self {
+            AggregateFunctionParameter::Avg => SqlType::double(),
+            AggregateFunctionParameter::UserDefined(function) => function.return_type().clone(),
+        }
+    }
 }
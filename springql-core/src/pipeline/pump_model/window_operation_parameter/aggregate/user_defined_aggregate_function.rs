@@ -0,0 +1,55 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::fmt::Debug;
+
+use crate::{low_level_rs::SpringAccumulator, pipeline::relation::sql_type::SqlType};
+
+/// Creates an accumulator of a user-defined aggregate function.
+pub(crate) type AccumulatorInit = dyn Fn() -> Box<dyn SpringAccumulator> + Send + Sync;
+
+/// Aggregate function registered from Rust.
+///
+/// Functions are identified by their name (case-insensitive).
+#[derive(new)]
+pub(crate) struct UserDefinedAggregateFunction {
+    name: String,
+    arg_type: SqlType,
+    return_type: SqlType,
+    init: Box<AccumulatorInit>,
+}
+
+impl Debug for UserDefinedAggregateFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserDefinedAggregateFunction")
+            .field("name", &self.name)
+            .field("arg_type", &self.arg_type)
+            .field("return_type", &self.return_type)
+            .finish()
+    }
+}
+
+impl PartialEq for UserDefinedAggregateFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+impl Eq for UserDefinedAggregateFunction {}
+
+impl UserDefinedAggregateFunction {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn arg_type(&self) -> &SqlType {
+        &self.arg_type
+    }
+
+    pub(crate) fn return_type(&self) -> &SqlType {
+        &self.return_type
+    }
+
+    /// Creates an accumulator for a group.
+    pub(crate) fn init(&self) -> Box<dyn SpringAccumulator> {
+        (self.init)()
+    }
+}
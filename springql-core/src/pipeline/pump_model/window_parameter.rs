@@ -1,8 +1,9 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::stream_engine::time::duration::event_duration::EventDuration;
+use crate::stream_engine::time::duration::{event_duration::EventDuration, SpringDuration};
 
 /// Window parameters
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) enum WindowParameter {
     /// Time-based sliding window
@@ -21,6 +22,7 @@ pub(crate) enum WindowParameter {
         length: EventDuration,
         period: EventDuration,
         allowed_delay: EventDuration,
+        time_domain: WindowTimeDomain,
     },
 
     /// Time-based fixed window
@@ -38,28 +40,102 @@ pub(crate) enum WindowParameter {
     TimedFixedWindow {
         length: EventDuration,
         allowed_delay: EventDuration,
+        time_domain: WindowTimeDomain,
     },
+
+    /// Count-based sliding window.
+    ///
+    /// Rows are counted for each group (GROUP BY key) independently.
+    /// Watermark is not used to close panes: a pane is closed right after it gets `length` rows.
+    ///
+    /// ```text
+    /// length = 4 rows, period = 2 rows;
+    ///
+    /// pane1 |r1 r2 r3 r4|
+    /// pane2       |r3 r4 r5 r6|
+    /// pane3             |r5 r6 r7 r8|
+    ///
+    /// -----------------------------------> rows in a group
+    /// ```
+    CountSlidingWindow { length: u64, period: u64 },
+
+    /// Count-based fixed window.
+    ///
+    /// Rows are counted for each group (GROUP BY key) independently.
+    /// Watermark is not used to close panes: a pane is closed right after it gets `length` rows.
+    ///
+    /// ```text
+    /// length = 4 rows;
+    ///
+    /// pane1 |r1 r2 r3 r4|
+    /// pane2             |r5 r6 r7 r8|
+    ///
+    /// -----------------------------------> rows in a group
+    /// ```
+    CountFixedWindow { length: u64 },
 }
 
 impl WindowParameter {
-    pub(crate) fn length(&self) -> EventDuration {
+    /// Time length of a pane. `None` for count-based windows.
+    pub(crate) fn length(&self) -> Option<EventDuration> {
         match self {
-            WindowParameter::TimedSlidingWindow { length, .. } => *length,
-            WindowParameter::TimedFixedWindow { length, .. } => *length,
+            WindowParameter::TimedSlidingWindow { length, .. } => Some(*length),
+            WindowParameter::TimedFixedWindow { length, .. } => Some(*length),
+            WindowParameter::CountSlidingWindow { .. }
+            | WindowParameter::CountFixedWindow { .. } => None,
         }
     }
 
-    pub(crate) fn period(&self) -> EventDuration {
+    /// Time period between panes. `None` for count-based windows.
+    pub(crate) fn period(&self) -> Option<EventDuration> {
         match self {
-            WindowParameter::TimedSlidingWindow { period, .. } => *period,
-            WindowParameter::TimedFixedWindow { length, .. } => *length,
+            WindowParameter::TimedSlidingWindow { period, .. } => Some(*period),
+            WindowParameter::TimedFixedWindow { length, .. } => Some(*length),
+            WindowParameter::CountSlidingWindow { .. }
+            | WindowParameter::CountFixedWindow { .. } => None,
         }
     }
 
+    /// Count-based windows do not wait for delayed rows (always zero).
     pub(crate) fn allowed_delay(&self) -> EventDuration {
         match self {
             WindowParameter::TimedSlidingWindow { allowed_delay, .. } => *allowed_delay,
             WindowParameter::TimedFixedWindow { allowed_delay, .. } => *allowed_delay,
+            WindowParameter::CountSlidingWindow { .. }
+            | WindowParameter::CountFixedWindow { .. } => EventDuration::from_secs(0),
         }
     }
+
+    /// Count-based windows are always in event time (ROWTIME is only used for pane bounds).
+    pub(crate) fn time_domain(&self) -> WindowTimeDomain {
+        match self {
+            WindowParameter::TimedSlidingWindow { time_domain, .. }
+            | WindowParameter::TimedFixedWindow { time_domain, .. } => *time_domain,
+            WindowParameter::CountSlidingWindow { .. }
+            | WindowParameter::CountFixedWindow { .. } => WindowTimeDomain::EventTime,
+        }
+    }
+
+    /// `Some((length, period))` in number of rows if this is a count-based window.
+    pub(crate) fn count_length_period(&self) -> Option<(u64, u64)> {
+        match self {
+            WindowParameter::TimedSlidingWindow { .. }
+            | WindowParameter::TimedFixedWindow { .. } => None,
+            WindowParameter::CountSlidingWindow { length, period } => Some((*length, *period)),
+            WindowParameter::CountFixedWindow { length } => Some((*length, *length)),
+        }
+    }
+}
+
+/// Which timestamp a time-based window uses to assign rows to panes.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum WindowTimeDomain {
+    /// ROWTIME of rows (default).
+    EventTime,
+
+    /// Wall-clock time when a row is dispatched to the window.
+    ///
+    /// Useful when ROWTIME is unreliable (e.g. devices without a stable RTC).
+    /// No row is late in processing time.
+    ProcessingTime,
 }
@@ -0,0 +1,17 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::stream_engine::time::duration::wall_clock_duration::WallClockDuration;
+
+/// Trigger to emit early (partial) results of window aggregation before panes close.
+///
+/// Final results are emitted on pane close regardless of the trigger.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum WindowTrigger {
+    /// Emit early results every `n` rows dispatched to the window.
+    EveryRows(u64),
+
+    /// Emit early results when the interval (processing time) has passed since the last emission.
+    ///
+    /// The interval is checked when a row is dispatched to the window, because early results only change on new rows.
+    EveryInterval(WallClockDuration),
+}
@@ -2,8 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::stream_engine::time::timestamp::timestamp_format::TimestampFormat;
+
 /// Column with data type.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize, new)]
 pub(crate) enum ColumnConstraint {
     Rowtime,
+
+    /// `FORMAT '...'` to parse source values of a TIMESTAMP or TIMESTAMPTZ column.
+    Format(TimestampFormat),
 }
@@ -1,8 +1,12 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
-use crate::pipeline::name::ColumnName;
+use crate::{
+    pipeline::name::ColumnName, stream_engine::time::timestamp::timestamp_format::TimestampFormat,
+};
 
 use super::{column_constraint::ColumnConstraint, column_data_type::ColumnDataType};
 
@@ -25,4 +29,46 @@ impl ColumnDefinition {
     pub(crate) fn column_constraints(&self) -> &[ColumnConstraint] {
         &self.column_constraints
     }
+
+    /// Format given by `FORMAT '...'` constraint.
+    pub(crate) fn timestamp_format(&self) -> Option<&TimestampFormat> {
+        self.column_constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                ColumnConstraint::Format(format) => Some(format),
+                ColumnConstraint::Rowtime => None,
+            })
+    }
+
+    /// Add ROWTIME constraint if not yet.
+    pub(crate) fn with_rowtime(mut self) -> Self {
+        if !self.column_constraints.contains(&ColumnConstraint::Rowtime) {
+            self.column_constraints.push(ColumnConstraint::Rowtime);
+        }
+        self
+    }
+}
+
+/// Column definition in DDL (`ts TIMESTAMP NOT NULL ROWTIME FORMAT 'EPOCH_MILLIS'`, for example).
+impl Display for ColumnDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.column_name(),
+            self.column_data_type.sql_type()
+        )?;
+        if !self.column_data_type.nullable() {
+            write!(f, " NOT NULL")?;
+        }
+        for constraint in &self.column_constraints {
+            match constraint {
+                ColumnConstraint::Rowtime => write!(f, " ROWTIME")?,
+                ColumnConstraint::Format(format) => {
+                    write!(f, " FORMAT '{}'", format.to_string().replace('\'', "''"))?
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
 /// SQL type.
@@ -10,7 +12,7 @@ use serde::{Deserialize, Serialize};
 ///   - Loose types: values can be typed as 1 specific Rust type.
 ///     - SQL types: corresponds to an SQL type.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
-pub(crate) enum SqlType {
+pub enum SqlType {
     /// Numeric types
     NumericComparable(NumericComparableType),
 
@@ -21,10 +23,19 @@ pub(crate) enum SqlType {
     BooleanComparable,
 
     /// Timestamp types
-    TimestampComparable,
+    TimestampComparable(TimestampComparableType),
 
     /// Duration types
     DurationComparable,
+
+    /// Binary types (byte sequences)
+    BinaryComparable,
+
+    /// Array types (`ARRAY<element type>`). Not comparable.
+    Array(Box<SqlType>),
+
+    /// Struct types (`STRUCT<field_name type, ...>`). Not comparable.
+    Struct(Vec<(String, SqlType)>),
 }
 
 impl SqlType {
@@ -41,16 +52,53 @@ impl SqlType {
         SqlType::NumericComparable(NumericComparableType::I64Loose(I64LooseType::BigInt))
     }
 
+    /// Constructor of SmallInt Unsigned
+    pub fn unsigned_small_int() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::SmallInt))
+    }
+    /// Constructor of Integer Unsigned
+    pub fn unsigned_integer() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::Integer))
+    }
+    /// Constructor of BigInt Unsigned
+    pub fn unsigned_big_int() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::BigInt))
+    }
+
     /// Constructor of Flaot
     pub fn float() -> SqlType {
         SqlType::NumericComparable(NumericComparableType::F32Loose(F32LooseType::Float))
     }
+    /// Constructor of Double
+    pub fn double() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::F64Loose(F64LooseType::Double))
+    }
+
+    /// Constructor of Decimal
+    pub fn decimal(precision: u8, scale: u8) -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::Decimal { precision, scale })
+    }
 
     /// Constructor of Text
     pub fn text() -> SqlType {
         SqlType::StringComparableLoose(StringComparableLoseType::Text)
     }
 
+    /// Constructor of Blob (Bytea)
+    pub fn blob() -> SqlType {
+        SqlType::BinaryComparable
+    }
+
+    /// Constructor of Array
+    pub fn array(element_type: SqlType) -> SqlType {
+        SqlType::Array(Box::new(element_type))
+    }
+
+    /// Constructor of Struct
+    pub fn structure(fields: Vec<(String, SqlType)>) -> SqlType {
+        SqlType::Struct(fields)
+    }
+
     /// Constructor of Boolean
     pub fn boolean() -> SqlType {
         SqlType::BooleanComparable
@@ -58,7 +106,11 @@ impl SqlType {
 
     /// Constructor of Timestamp
     pub fn timestamp() -> SqlType {
-        SqlType::TimestampComparable
+        SqlType::TimestampComparable(TimestampComparableType::Timestamp)
+    }
+    /// Constructor of TimestampTz
+    pub fn timestamp_tz() -> SqlType {
+        SqlType::TimestampComparable(TimestampComparableType::TimestampTz)
     }
 
     /// Constructor of Duration
@@ -68,15 +120,121 @@ impl SqlType {
 }
 
 /// Numeric types (comparable).
+/// Type name in SQL (`INTEGER`, `TEXT`, ...).
+impl Display for SqlType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SqlType::NumericComparable(NumericComparableType::I64Loose(I64LooseType::SmallInt)) => {
+                "SMALLINT"
+            }
+            SqlType::NumericComparable(NumericComparableType::I64Loose(I64LooseType::Integer)) => {
+                "INTEGER"
+            }
+            SqlType::NumericComparable(NumericComparableType::I64Loose(I64LooseType::BigInt)) => {
+                "BIGINT"
+            }
+            SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::SmallInt)) => {
+                "SMALLINT UNSIGNED"
+            }
+            SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::Integer)) => {
+                "INTEGER UNSIGNED"
+            }
+            SqlType::NumericComparable(NumericComparableType::U64Loose(U64LooseType::BigInt)) => {
+                "BIGINT UNSIGNED"
+            }
+            SqlType::NumericComparable(NumericComparableType::F32Loose(F32LooseType::Float)) => {
+                "FLOAT"
+            }
+            SqlType::NumericComparable(NumericComparableType::F64Loose(F64LooseType::Double)) => {
+                "DOUBLE"
+            }
+            SqlType::NumericComparable(NumericComparableType::Decimal { precision, scale }) => {
+                return write!(f, "DECIMAL({}, {})", precision, scale);
+            }
+            SqlType::StringComparableLoose(StringComparableLoseType::Text) => "TEXT",
+            SqlType::BooleanComparable => "BOOLEAN",
+            SqlType::TimestampComparable(TimestampComparableType::Timestamp) => "TIMESTAMP",
+            SqlType::TimestampComparable(TimestampComparableType::TimestampTz) => "TIMESTAMPTZ",
+            SqlType::DurationComparable => "DURATION",
+            SqlType::BinaryComparable => "BLOB",
+            SqlType::Array(element_type) => return write!(f, "ARRAY<{}>", element_type),
+            SqlType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, typ)| format!("{} {}", name, typ))
+                    .collect::<Vec<_>>();
+                return write!(f, "STRUCT<{}>", fields.join(", "));
+            }
+        };
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum NumericComparableType {
     /// Loosely typed as i64
     I64Loose(I64LooseType),
 
+    /// Loosely typed as u64
+    U64Loose(U64LooseType),
+
     /// Loosely typed as f32
     F32Loose(F32LooseType),
+
+    /// Loosely typed as f64
+    F64Loose(F64LooseType),
+
+    /// Fixed-point number with `precision` digits in total and `scale` digits after the decimal point.
+    Decimal {
+        /// 1 - 38
+        precision: u8,
+        /// 0 - `precision`
+        scale: u8,
+    },
+}
+
+impl NumericComparableType {
+    /// Type which two numeric values are converted into for arithmetic operations and comparisons.
+    ///
+    /// - Signed integers are calculated in BIGINT, and unsigned integers in BIGINT UNSIGNED.
+    /// - Signed and unsigned integers are calculated in DECIMAL not to fail on negative values.
+    /// - Integers and DECIMAL are calculated in DECIMAL (with the larger scale).
+    /// - FLOAT and DOUBLE are calculated in DOUBLE.
+    ///
+    /// Exact numbers (integers and DECIMAL) and approximate numbers (FLOAT and DOUBLE) cannot be mixed.
+    pub(crate) fn promote(&self, other: &Self) -> Option<NumericComparableType> {
+        use NumericComparableType::*;
+
+        match (self, other) {
+            (I64Loose(_), I64Loose(_)) => Some(I64Loose(I64LooseType::BigInt)),
+            (U64Loose(_), U64Loose(_)) => Some(U64Loose(U64LooseType::BigInt)),
+            (
+                I64Loose(_) | U64Loose(_) | Decimal { .. },
+                I64Loose(_) | U64Loose(_) | Decimal { .. },
+            ) => Some(Decimal {
+                precision: MAX_DECIMAL_PRECISION,
+                scale: self.scale().max(other.scale()),
+            }),
+            (F32Loose(_), F32Loose(_)) => Some(F32Loose(F32LooseType::Float)),
+            (F32Loose(_) | F64Loose(_), F32Loose(_) | F64Loose(_)) => {
+                Some(F64Loose(F64LooseType::Double))
+            }
+            _ => None,
+        }
+    }
+
+    /// Number of digits after the decimal point (0 for integers).
+    pub(crate) fn scale(&self) -> u8 {
+        match self {
+            NumericComparableType::Decimal { scale, .. } => *scale,
+            _ => 0,
+        }
+    }
 }
 
+/// Max precision of DECIMAL.
+pub(crate) const MAX_DECIMAL_PRECISION: u8 = 38;
+
 /// Integer types (loosely typed as i64).
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum I64LooseType {
@@ -90,16 +248,46 @@ pub enum I64LooseType {
     BigInt,
 }
 
-/// Float types (loosely typed as f64).
+/// Unsigned integer types (loosely typed as u64).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum U64LooseType {
+    /// 2-byte unsigned integer.
+    SmallInt,
+
+    /// 4-byte unsigned integer.
+    Integer,
+
+    /// 8-byte unsigned integer.
+    BigInt,
+}
+
+/// Float types (loosely typed as f32).
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum F32LooseType {
     /// fp32
     Float,
 }
 
+/// Double precision float types (loosely typed as f64).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum F64LooseType {
+    /// fp64
+    Double,
+}
+
 /// Text types (comparable, loosely typed as String).
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub enum StringComparableLoseType {
     /// Arbitrary length text (UTF-8).
     Text,
 }
+
+/// Timestamp types (comparable and ordered by instants).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum TimestampComparableType {
+    /// Timestamp in UTC.
+    Timestamp,
+
+    /// Timestamp with a time zone offset (`TIMESTAMP WITH TIME ZONE`).
+    TimestampTz,
+}
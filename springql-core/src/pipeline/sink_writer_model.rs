@@ -35,4 +35,14 @@ impl SinkWriterModel {
     pub(crate) fn options(&self) -> &Options {
         &self.options
     }
+
+    /// `CREATE SINK WRITER ...` statement (without trailing `;`).
+    pub(crate) fn ddl(&self) -> String {
+        format!(
+            "CREATE SINK WRITER {} FOR {}\n  TYPE {} {}",
+            self.name, self.from_sink_stream, self.sink_writer_type, self.options
+        )
+        .trim_end()
+        .to_string()
+    }
 }
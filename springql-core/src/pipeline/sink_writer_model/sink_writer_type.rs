@@ -1,5 +1,7 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
 use crate::pipeline::name::SinkWriterName;
@@ -10,6 +12,17 @@ pub(crate) enum SinkWriterType {
     InMemoryQueue,
 }
 
+/// Type name in `CREATE SINK WRITER ... TYPE`.
+impl Display for SinkWriterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SinkWriterType::Net => "NET_CLIENT",
+            SinkWriterType::InMemoryQueue => "IN_MEMORY_QUEUE",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl From<&SinkWriterType> for SinkWriterName {
     fn from(sink_writer_type: &SinkWriterType) -> Self {
         match sink_writer_type {
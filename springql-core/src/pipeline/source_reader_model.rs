@@ -5,6 +5,7 @@ pub(crate) mod source_reader_type;
 use serde::{Deserialize, Serialize};
 
 use self::source_reader_type::SourceReaderType;
+use crate::{error::Result, stream_engine::time::timestamp::timestamp_format::TimestampFormat};
 
 use super::{
     name::{SourceReaderName, StreamName},
@@ -35,4 +36,26 @@ impl SourceReaderModel {
     pub(crate) fn options(&self) -> &Options {
         &self.options
     }
+
+    /// `TIMESTAMP_FORMAT` option, which applies to TIMESTAMP and TIMESTAMPTZ columns without `FORMAT '...'`.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::InvalidOption](crate::error::SpringError::InvalidOption) when:
+    ///   - the option value is not a valid format.
+    pub(crate) fn timestamp_format(&self) -> Result<Option<TimestampFormat>> {
+        self.options.get_optional("TIMESTAMP_FORMAT", |format_str| {
+            format_str.parse().map_err(anyhow::Error::from)
+        })
+    }
+
+    /// `CREATE SOURCE READER ...` statement (without trailing `;`).
+    pub(crate) fn ddl(&self) -> String {
+        format!(
+            "CREATE SOURCE READER {} FOR {}\n  TYPE {} {}",
+            self.name, self.dest_source_stream, self.source_reader_type, self.options
+        )
+        .trim_end()
+        .to_string()
+    }
 }
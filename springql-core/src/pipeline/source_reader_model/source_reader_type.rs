@@ -1,5 +1,7 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
@@ -7,3 +9,14 @@ pub(crate) enum SourceReaderType {
     NetClient,
     NetServer,
 }
+
+/// Type name in `CREATE SOURCE READER ... TYPE`.
+impl Display for SourceReaderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SourceReaderType::NetClient => "NET_CLIENT",
+            SourceReaderType::NetServer => "NET_SERVER",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -1,16 +1,18 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+pub(crate) mod stream_kind;
 pub(crate) mod stream_shape;
 
 use serde::{Deserialize, Serialize};
 
-use self::stream_shape::StreamShape;
+use self::{stream_kind::StreamKind, stream_shape::StreamShape};
 
 use super::{field::field_name::ColumnReference, name::StreamName};
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, new)]
 pub(crate) struct StreamModel {
     name: StreamName,
+    kind: StreamKind,
     shape: StreamShape,
 }
 
@@ -23,6 +25,18 @@ impl StreamModel {
         &self.shape
     }
 
+    /// `CREATE [SOURCE | SINK] STREAM ...` statement (without trailing `;`).
+    pub(crate) fn ddl(&self) -> String {
+        let columns = self
+            .shape
+            .columns()
+            .iter()
+            .map(|column| format!("  {}", column))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!("CREATE {} {} (\n{}\n)", self.kind, self.name, columns)
+    }
+
     pub(crate) fn column_references(&self) -> Vec<ColumnReference> {
         self.shape
             .column_names()
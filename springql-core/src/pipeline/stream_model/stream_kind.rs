@@ -0,0 +1,28 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of a stream given by its `CREATE` statement.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub(crate) enum StreamKind {
+    /// `SOURCE STREAM`
+    Source,
+    /// `STREAM`
+    Native,
+    /// `SINK STREAM`
+    Sink,
+}
+
+/// Keywords in `CREATE ... STREAM`.
+impl Display for StreamKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StreamKind::Source => "SOURCE STREAM",
+            StreamKind::Native => "STREAM",
+            StreamKind::Sink => "SINK STREAM",
+        };
+        write!(f, "{}", s)
+    }
+}
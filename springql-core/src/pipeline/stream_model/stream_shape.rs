@@ -21,9 +21,11 @@ impl StreamShape {
     /// # Failure
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
-    ///   - ROWTIME column in `cols` is not a `TIMESTAMP NOT NULL` type.
+    ///   - ROWTIME column in `cols` is not a `TIMESTAMP NOT NULL` (or `TIMESTAMPTZ NOT NULL`) type.
     ///   - 2 or more column have ROWTIME constraints
+    ///   - FORMAT constraint is given to a column other than TIMESTAMP or TIMESTAMPTZ.
     pub(in crate) fn new(cols: Vec<ColumnDefinition>) -> Result<Self> {
+        Self::validate_timestamp_formats(&cols)?;
         let promoted_rowtime = Self::extract_promoted_rowtime(&cols)?;
 
         let _ = if let Some(rowtime_col) = &promoted_rowtime {
@@ -77,6 +79,21 @@ impl StreamShape {
         }
     }
 
+    fn validate_timestamp_formats(cols: &[ColumnDefinition]) -> Result<()> {
+        cols.iter()
+            .filter(|coldef| coldef.timestamp_format().is_some())
+            .try_for_each(|coldef| {
+                if let SqlType::TimestampComparable(_) = coldef.column_data_type().sql_type() {
+                    Ok(())
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        r#"FORMAT is given to column "{}" but it is not TIMESTAMP or TIMESTAMPTZ type"#,
+                        coldef.column_name(),
+                    )))
+                }
+            })
+    }
+
     fn validate_rowtime_column(rowtime_col: &ColumnName, cols: &[ColumnDefinition]) -> Result<()> {
         let rowtime_coldef = cols
             .iter()
@@ -89,11 +106,11 @@ impl StreamShape {
             })
             .map_err(SpringError::Sql)?;
 
-        if let SqlType::TimestampComparable = rowtime_coldef.column_data_type().sql_type() {
+        if let SqlType::TimestampComparable(_) = rowtime_coldef.column_data_type().sql_type() {
             Ok(())
         } else {
             Err(SpringError::Sql(anyhow!(
-                r#"ROWTIME column "{}" is not TIMESTAMP type in stream definition"#,
+                r#"ROWTIME column "{}" is not TIMESTAMP or TIMESTAMPTZ type in stream definition"#,
                 rowtime_col,
             )))
         }?;
@@ -22,6 +22,12 @@ impl StreamName {
     }
 }
 
+impl ColumnName {
+    pub(crate) fn factory(name: &str) -> Self {
+        Self::new(name.to_string())
+    }
+}
+
 impl ValueExpr {
     pub fn factory_null() -> Self {
         Self::Constant(SqlValue::Null)
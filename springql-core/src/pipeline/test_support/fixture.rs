@@ -18,7 +18,7 @@ use crate::{
         },
         sink_writer_model::{sink_writer_type::SinkWriterType, SinkWriterModel},
         source_reader_model::{source_reader_type::SourceReaderType, SourceReaderModel},
-        stream_model::{stream_shape::StreamShape, StreamModel},
+        stream_model::{stream_kind::StreamKind, stream_shape::StreamShape, StreamModel},
         Pipeline,
     },
 };
@@ -48,7 +48,10 @@ impl Pipeline {
     /// (0)--a-->[1]
     /// ```
     pub(crate) fn fx_source_only() -> Self {
-        let st_1 = Arc::new(StreamModel::fx_trade_with_name(StreamName::factory("st_1")));
+        let st_1 = Arc::new(StreamModel::fx_trade_with_name(
+            StreamName::factory("st_1"),
+            StreamKind::Source,
+        ));
 
         let mut pipeline = Pipeline::new(PipelineVersion::new());
         pipeline.add_stream(st_1).unwrap();
@@ -56,9 +59,10 @@ impl Pipeline {
     }
 
     pub(crate) fn fx_sink_only() -> Self {
-        let sink_1 = Arc::new(StreamModel::fx_trade_with_name(StreamName::factory(
-            "sink_1",
-        )));
+        let sink_1 = Arc::new(StreamModel::fx_trade_with_name(
+            StreamName::factory("sink_1"),
+            StreamKind::Sink,
+        ));
 
         let mut pipeline = Pipeline::new(PipelineVersion::new());
         pipeline.add_stream(sink_1).unwrap();
@@ -93,16 +97,21 @@ impl StreamModel {
     pub(crate) fn fx_city_temperature() -> Self {
         Self::new(
             StreamName::fx_city_temperature(),
+            StreamKind::Source,
             StreamShape::fx_city_temperature(),
         )
     }
 
     pub(crate) fn fx_trade() -> Self {
-        Self::new(StreamName::fx_trade(), StreamShape::fx_trade())
+        Self::new(
+            StreamName::fx_trade(),
+            StreamKind::Source,
+            StreamShape::fx_trade(),
+        )
     }
 
-    pub(crate) fn fx_trade_with_name(name: StreamName) -> Self {
-        Self::new(name, StreamShape::fx_trade())
+    pub(crate) fn fx_trade_with_name(name: StreamName, kind: StreamKind) -> Self {
+        Self::new(name, kind, StreamShape::fx_trade())
     }
 }
 
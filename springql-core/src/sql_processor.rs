@@ -2,37 +2,120 @@
 
 pub(crate) mod sql_parser;
 
+mod function_registry;
 mod query_planner;
+mod semantic_analyzer;
 
 use self::{
+    function_registry::FunctionRegistry,
     query_planner::QueryPlanner,
-    sql_parser::{parse_success::CreatePump, syntax::SelectStreamSyntax, SqlParser},
+    semantic_analyzer::SemanticAnalyzer,
+    sql_parser::{parse_success::CreatePump, SqlParser},
 };
 use crate::{
-    error::Result,
+    error::{Result, SpringError},
+    expression::function_call::user_defined_function::UserDefinedFunction,
     pipeline::{
-        pump_model::PumpModel, sink_writer_model::SinkWriterModel,
-        source_reader_model::SourceReaderModel, stream_model::StreamModel, Pipeline,
+        pump_model::{
+            window_operation_parameter::aggregate::user_defined_aggregate_function::UserDefinedAggregateFunction,
+            PumpModel,
+        },
+        sink_writer_model::SinkWriterModel,
+        source_reader_model::SourceReaderModel,
+        stream_model::StreamModel,
+        Pipeline,
     },
     sql_processor::sql_parser::parse_success::ParseSuccess,
     stream_engine::command::{
-        alter_pipeline_command::AlterPipelineCommand, query_plan::QueryPlan, Command,
+        alter_pipeline_command::AlterPipelineCommand, explain_command::ExplainCommand,
+        show_command::ShowCommand, Command,
     },
+    stream_engine::SqlValue,
 };
 
+use anyhow::anyhow;
+
 #[derive(Debug, Default)]
-pub(crate) struct SqlProcessor(SqlParser);
+pub(crate) struct SqlProcessor {
+    parser: SqlParser,
+    functions: FunctionRegistry,
+}
 
 impl SqlProcessor {
+    /// Registers a function callable from SQL.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the function name is not an identifier (e.g. a keyword).
+    ///   - the function name is a built-in function (case-insensitive).
+    ///   - a function with the same name is already registered.
+    pub(crate) fn register_function(&mut self, function: UserDefinedFunction) -> Result<()> {
+        self.validate_function_name(function.name())?;
+        self.functions.register(function)
+    }
+
+    /// Registers an aggregate function callable from SQL.
+    ///
+    /// # Failures
+    ///
+    /// Same as [register_function()](Self::register_function).
+    pub(crate) fn register_aggregate_function(
+        &mut self,
+        function: UserDefinedAggregateFunction,
+    ) -> Result<()> {
+        self.validate_function_name(function.name())?;
+        self.functions.register_aggregate(function)
+    }
+
+    fn validate_function_name(&self, name: &str) -> Result<()> {
+        if !self.parser.is_identifier(name) {
+            Err(SpringError::Sql(anyhow!(
+                "`{}` cannot be a function name",
+                name
+            )))
+        } else if self.parser.is_builtin_function(name) {
+            Err(SpringError::Sql(anyhow!(
+                "`{}` is a built-in function name",
+                name
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
     /// # Failures
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) on syntax and semantics error.
     pub(crate) fn compile<S: Into<String>>(&self, sql: S, pipeline: &Pipeline) -> Result<Command> {
-        let command = match self.0.parse(sql)? {
+        self.compile_with_params(sql, &[], pipeline)
+    }
+
+    /// Compiles `sql` whose placeholders (`$1`, `:name`) are bound to values in `bind_params`.
+    /// Bound values are type-checked in the same way as constants written in their places.
+    /// OPTIONS of a source reader or a sink writer are validated here if `bind_params` is given.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) on syntax and semantics error.
+    ///   - a placeholder is not bound or a bound parameter does not appear in `sql`.
+    /// - [SpringError::InvalidOption](crate::error::SpringError::InvalidOption) when:
+    ///   - OPTIONS with bound values are invalid for the source reader or sink writer type.
+    pub(crate) fn compile_with_params<S: Into<String>>(
+        &self,
+        sql: S,
+        bind_params: &[(String, SqlValue)],
+        pipeline: &Pipeline,
+    ) -> Result<Command> {
+        let command = match self.parser.parse(sql, bind_params)? {
             ParseSuccess::CreateSourceStream(source_stream_model) => {
                 self.compile_create_source_stream(source_stream_model, pipeline)?
             }
             ParseSuccess::CreateSourceReader(source_reader_model) => {
+                if !bind_params.is_empty() {
+                    SemanticAnalyzer::new(pipeline)
+                        .validate_source_reader_options(&source_reader_model)?;
+                }
                 self.compile_create_source_reader(source_reader_model, pipeline)?
             }
             ParseSuccess::CreateStream(stream_model) => {
@@ -42,11 +125,22 @@ impl SqlProcessor {
                 self.compile_create_sink_stream(sink_stream_model, pipeline)?
             }
             ParseSuccess::CreateSinkWriter(sink_writer_model) => {
+                if !bind_params.is_empty() {
+                    SemanticAnalyzer::new(pipeline)
+                        .validate_sink_writer_options(&sink_writer_model)?;
+                }
                 self.compile_create_sink_writer(sink_writer_model, pipeline)?
             }
             ParseSuccess::CreatePump(create_pump) => {
-                self.compile_create_pump(*create_pump, pipeline)?
+                let pump = self.compile_create_pump(*create_pump, pipeline)?;
+                Command::AlterPipeline(AlterPipelineCommand::CreatePump(Box::new(pump)))
             }
+            ParseSuccess::ExplainCreatePump(create_pump) => {
+                let pump = self.compile_create_pump(*create_pump, pipeline)?;
+                Command::Explain(ExplainCommand::CreatePump(Box::new(pump)))
+            }
+            ParseSuccess::ExplainPipeline => Command::Explain(ExplainCommand::Pipeline),
+            ParseSuccess::Show(show_command) => self.compile_show(show_command, pipeline)?,
         };
         Ok(command)
     }
@@ -54,9 +148,9 @@ impl SqlProcessor {
     fn compile_create_source_stream(
         &self,
         source_stream_model: StreamModel,
-        _pipeline: &Pipeline,
+        pipeline: &Pipeline,
     ) -> Result<Command> {
-        // TODO semantic check
+        SemanticAnalyzer::new(pipeline).validate_stream(&source_stream_model)?;
         Ok(Command::AlterPipeline(
             AlterPipelineCommand::CreateSourceStream(source_stream_model),
         ))
@@ -65,9 +159,9 @@ impl SqlProcessor {
     fn compile_create_source_reader(
         &self,
         source_reader_model: SourceReaderModel,
-        _pipeline: &Pipeline,
+        pipeline: &Pipeline,
     ) -> Result<Command> {
-        // TODO semantic check
+        SemanticAnalyzer::new(pipeline).validate_source_reader(&source_reader_model)?;
         Ok(Command::AlterPipeline(
             AlterPipelineCommand::CreateSourceReader(source_reader_model),
         ))
@@ -76,9 +170,9 @@ impl SqlProcessor {
     fn compile_create_stream(
         &self,
         stream_model: StreamModel,
-        _pipeline: &Pipeline,
+        pipeline: &Pipeline,
     ) -> Result<Command> {
-        // TODO semantic check
+        SemanticAnalyzer::new(pipeline).validate_stream(&stream_model)?;
         Ok(Command::AlterPipeline(AlterPipelineCommand::CreateStream(
             stream_model,
         )))
@@ -87,9 +181,9 @@ impl SqlProcessor {
     fn compile_create_sink_stream(
         &self,
         sink_stream_model: StreamModel,
-        _pipeline: &Pipeline,
+        pipeline: &Pipeline,
     ) -> Result<Command> {
-        // TODO semantic check
+        SemanticAnalyzer::new(pipeline).validate_stream(&sink_stream_model)?;
         Ok(Command::AlterPipeline(
             AlterPipelineCommand::CreateSinkStream(sink_stream_model),
         ))
@@ -98,29 +192,74 @@ impl SqlProcessor {
     fn compile_create_sink_writer(
         &self,
         sink_writer_model: SinkWriterModel,
-        _pipeline: &Pipeline,
+        pipeline: &Pipeline,
     ) -> Result<Command> {
-        // TODO semantic check
+        SemanticAnalyzer::new(pipeline).validate_sink_writer(&sink_writer_model)?;
         Ok(Command::AlterPipeline(
             AlterPipelineCommand::CreateSinkWriter(sink_writer_model),
         ))
     }
 
-    fn compile_create_pump(&self, create_pump: CreatePump, pipeline: &Pipeline) -> Result<Command> {
-        let query_plan = self.compile_select_stream(create_pump.select_stream_syntax, pipeline)?;
-        let pump = PumpModel::new(create_pump.pump_name, query_plan, create_pump.insert_plan);
-        Ok(Command::AlterPipeline(AlterPipelineCommand::CreatePump(
-            Box::new(pump),
-        )))
-    }
-
-    fn compile_select_stream(
+    fn compile_create_pump(
         &self,
-        select_stream_syntax: SelectStreamSyntax,
+        mut create_pump: CreatePump,
         pipeline: &Pipeline,
-    ) -> Result<QueryPlan> {
-        let planner = QueryPlanner::new(select_stream_syntax);
-        planner.plan(pipeline)
+    ) -> Result<PumpModel> {
+        for syntax in std::iter::once(&mut create_pump.select_stream_syntax)
+            .chain(create_pump.union_all_syntaxes.iter_mut())
+        {
+            self.functions.resolve_select_syntax(syntax)?;
+        }
+
+        let planner = QueryPlanner::new(create_pump.select_stream_syntax, pipeline)?;
+        let union_all_planners = create_pump
+            .union_all_syntaxes
+            .into_iter()
+            .map(|syntax| QueryPlanner::new(syntax, pipeline))
+            .collect::<Result<Vec<_>>>()?;
+
+        let analyzer = SemanticAnalyzer::new(pipeline);
+        analyzer.validate_pump_name(&create_pump.pump_name)?;
+        let branch_field_types = std::iter::once(&planner)
+            .chain(union_all_planners.iter())
+            .map(|planner| analyzer.select_field_types(planner.select_syntax()))
+            .collect::<Result<Vec<_>>>()?;
+        analyzer.validate_union_all(&branch_field_types)?;
+        for (planner, field_types) in std::iter::once(&planner)
+            .chain(union_all_planners.iter())
+            .zip(branch_field_types.iter())
+        {
+            analyzer.validate_insert(
+                &create_pump.insert_plan,
+                planner.select_list(),
+                field_types,
+            )?;
+        }
+
+        let mut query_plan = planner.plan(pipeline)?;
+        query_plan.union_all = union_all_planners
+            .into_iter()
+            .map(|planner| planner.plan(pipeline))
+            .collect::<Result<Vec<_>>>()?;
+        analyzer.validate_upstreams(&query_plan)?;
+
+        if let Some(late_rows_stream) = create_pump.insert_plan.late_rows_stream() {
+            analyzer.validate_late_rows_stream(late_rows_stream, &query_plan)?;
+        }
+
+        Ok(PumpModel::new(
+            create_pump.pump_name,
+            query_plan,
+            create_pump.insert_plan,
+            create_pump.ddl,
+        ))
+    }
+
+    fn compile_show(&self, show_command: ShowCommand, pipeline: &Pipeline) -> Result<Command> {
+        if let ShowCommand::DescribeStream(stream_name) = &show_command {
+            let _ = pipeline.get_stream(stream_name)?;
+        }
+        Ok(Command::Show(show_command))
     }
 }
 
@@ -134,7 +273,7 @@ mod tests {
             pipeline_version::PipelineVersion,
             sink_writer_model::{sink_writer_type::SinkWriterType, SinkWriterModel},
             source_reader_model::{source_reader_type::SourceReaderType, SourceReaderModel},
-            stream_model::{stream_shape::StreamShape, StreamModel},
+            stream_model::{stream_kind::StreamKind, stream_shape::StreamShape, StreamModel},
         },
         stream_engine::command::alter_pipeline_command::AlterPipelineCommand,
     };
@@ -155,8 +294,38 @@ mod tests {
         let command = processor.compile(sql, &pipeline).unwrap();
 
         let expected_shape = StreamShape::fx_trade();
-        let expected_stream =
-            StreamModel::new(StreamName::new("source_trade".to_string()), expected_shape);
+        let expected_stream = StreamModel::new(
+            StreamName::new("source_trade".to_string()),
+            StreamKind::Source,
+            expected_shape,
+        );
+
+        assert_eq!(
+            command,
+            Command::AlterPipeline(AlterPipelineCommand::CreateSourceStream(expected_stream))
+        );
+    }
+
+    #[test]
+    fn test_create_source_stream_rowtime_event_time() {
+        let processor = SqlProcessor::default();
+        let pipeline = Pipeline::new(PipelineVersion::new());
+
+        let sql = "
+            CREATE SOURCE STREAM source_trade (
+              ts TIMESTAMP NOT NULL,
+              ticker TEXT NOT NULL,
+              amount INTEGER NOT NULL
+            ) ROWTIME EVENT_TIME ts;
+            ";
+        let command = processor.compile(sql, &pipeline).unwrap();
+
+        let expected_shape = StreamShape::fx_trade();
+        let expected_stream = StreamModel::new(
+            StreamName::new("source_trade".to_string()),
+            StreamKind::Source,
+            expected_shape,
+        );
 
         assert_eq!(
             command,
@@ -164,6 +333,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_source_stream_rowtime_ingestion_time_conflict() {
+        let processor = SqlProcessor::default();
+        let pipeline = Pipeline::new(PipelineVersion::new());
+
+        let sql = "
+            CREATE SOURCE STREAM source_trade (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              ticker TEXT NOT NULL,
+              amount INTEGER NOT NULL
+            ) ROWTIME INGESTION_TIME;
+            ";
+        assert!(matches!(
+            processor.compile(sql, &pipeline),
+            Err(SpringError::Sql(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_source_stream_timestamp_format() {
+        let processor = SqlProcessor::default();
+        let pipeline = Pipeline::new(PipelineVersion::new());
+
+        let sql = "
+            CREATE SOURCE STREAM source_trade (
+              ts TIMESTAMP WITH TIME ZONE NOT NULL ROWTIME FORMAT 'epoch_millis',
+              traded_at TIMESTAMP FORMAT '%d/%m/%Y %H:%M:%S%z',
+              ticker TEXT NOT NULL
+            );
+            ";
+        let command = processor.compile(sql, &pipeline).unwrap();
+
+        if let Command::AlterPipeline(AlterPipelineCommand::CreateSourceStream(stream)) = command {
+            let columns = stream
+                .shape()
+                .columns()
+                .iter()
+                .map(|coldef| coldef.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                columns,
+                vec![
+                    "ts TIMESTAMPTZ NOT NULL ROWTIME FORMAT 'EPOCH_MILLIS'",
+                    "traded_at TIMESTAMP FORMAT '%d/%m/%Y %H:%M:%S%z'",
+                    "ticker TEXT NOT NULL",
+                ]
+            );
+        } else {
+            unreachable!("CREATE SOURCE STREAM is compiled into {:?}", command);
+        }
+
+        for sql in [
+            "CREATE SOURCE STREAM s (ticker TEXT FORMAT 'EPOCH_SECS');",
+            "CREATE SOURCE STREAM s (ts TIMESTAMP FORMAT '%Y-%Q');",
+            "CREATE SOURCE STREAM s (d DURATION);",
+            "CREATE SOURCE STREAM s (ds ARRAY<DURATION>);",
+        ] {
+            assert!(matches!(
+                processor.compile(sql, &pipeline),
+                Err(SpringError::Sql(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_create_source_reader() {
         let processor = SqlProcessor::default();
@@ -211,8 +444,11 @@ mod tests {
         let command = processor.compile(sql, &pipeline).unwrap();
 
         let expected_shape = StreamShape::fx_trade();
-        let expected_stream =
-            StreamModel::new(StreamName::new("sink_trade".to_string()), expected_shape);
+        let expected_stream = StreamModel::new(
+            StreamName::new("sink_trade".to_string()),
+            StreamKind::Sink,
+            expected_shape,
+        );
 
         assert_eq!(
             command,
@@ -248,4 +484,61 @@ mod tests {
             Command::AlterPipeline(AlterPipelineCommand::CreateSinkWriter(expected_sink))
         );
     }
+
+    #[test]
+    fn test_explain_pipeline() {
+        let processor = SqlProcessor::default();
+        let pipeline = Pipeline::fx_sink_only();
+
+        let command = processor.compile("EXPLAIN PIPELINE;", &pipeline).unwrap();
+        assert_eq!(command, Command::Explain(ExplainCommand::Pipeline));
+    }
+
+    #[test]
+    fn test_describe_stream() {
+        let processor = SqlProcessor::default();
+        let pipeline = Pipeline::fx_sink_only();
+
+        let command = processor
+            .compile("DESCRIBE STREAM sink_1;", &pipeline)
+            .unwrap();
+        assert_eq!(
+            command,
+            Command::Show(ShowCommand::DescribeStream(StreamName::new(
+                "sink_1".to_string()
+            )))
+        );
+
+        assert!(matches!(
+            processor.compile("DESCRIBE STREAM no_such_stream;", &pipeline),
+            Err(SpringError::Sql(_))
+        ));
+    }
+
+    #[test]
+    fn test_builtin_function_name() {
+        let parser = SqlParser::default();
+
+        for name in [
+            "avg",
+            "SUM",
+            "lag",
+            "First_Value",
+            "row_number",
+            "duration_millis",
+            "floor",
+            "floor_time",
+            "hex",
+            "base64",
+            "to_char",
+            "unnest",
+            "window_start",
+            "emit_timing",
+        ] {
+            assert!(parser.is_builtin_function(name), "{}", name);
+        }
+        for name in ["rms", "lag2", "sum_of", "my_hex"] {
+            assert!(!parser.is_builtin_function(name), "{}", name);
+        }
+    }
 }
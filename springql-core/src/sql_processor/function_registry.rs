@@ -0,0 +1,206 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::anyhow;
+
+use crate::{
+    error::{Result, SpringError},
+    expression::{
+        function_call::{user_defined_function::UserDefinedFunction, FunctionCall},
+        AggrExpr, ValueExpr,
+    },
+    pipeline::{
+        name::AggrAlias,
+        pump_model::window_operation_parameter::aggregate::{
+            user_defined_aggregate_function::UserDefinedAggregateFunction,
+            AggregateFunctionParameter,
+        },
+    },
+    sql_processor::sql_parser::syntax::{
+        FromItemSyntax, GroupingElementSyntax, SelectFieldSyntax, SelectStreamSyntax,
+    },
+};
+
+/// User-defined functions by their lower-case name.
+/// Scalar functions and aggregate functions share the namespace.
+#[derive(Debug, Default)]
+pub(in crate::sql_processor) struct FunctionRegistry {
+    scalars: HashMap<String, Arc<UserDefinedFunction>>,
+    aggregates: HashMap<String, Arc<UserDefinedAggregateFunction>>,
+}
+
+impl FunctionRegistry {
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a function with the same name is already registered.
+    pub(in crate::sql_processor) fn register(
+        &mut self,
+        function: UserDefinedFunction,
+    ) -> Result<()> {
+        let key = self.vacant_key(function.name())?;
+        self.scalars.insert(key, Arc::new(function));
+        Ok(())
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a function with the same name is already registered.
+    pub(in crate::sql_processor) fn register_aggregate(
+        &mut self,
+        function: UserDefinedAggregateFunction,
+    ) -> Result<()> {
+        let key = self.vacant_key(function.name())?;
+        self.aggregates.insert(key, Arc::new(function));
+        Ok(())
+    }
+
+    fn vacant_key(&self, name: &str) -> Result<String> {
+        let key = name.to_lowercase();
+        if self.scalars.contains_key(&key) || self.aggregates.contains_key(&key) {
+            Err(SpringError::Sql(anyhow!(
+                "function `{}` is already registered",
+                name
+            )))
+        } else {
+            Ok(key)
+        }
+    }
+
+    /// Resolves calls of user-defined functions in every expression of `syntax`.
+    ///
+    /// A select field calling an aggregate function is parsed as a value expression,
+    /// and it is replaced with an aggregate expression here.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a function is not registered.
+    ///   - a function is called with wrong number of arguments.
+    ///   - an aggregate function is called inside an expression.
+    pub(in crate::sql_processor) fn resolve_select_syntax(
+        &self,
+        syntax: &mut SelectStreamSyntax,
+    ) -> Result<()> {
+        syntax.fields = syntax
+            .fields
+            .drain(..)
+            .map(|field| self.resolve_aggregate_field(field))
+            .collect::<Result<Vec<_>>>()?;
+
+        for field in syntax.fields.iter_mut() {
+            match field {
+                SelectFieldSyntax::ValueExpr { value_expr, .. } => self.resolve_expr(value_expr)?,
+                SelectFieldSyntax::AggrExpr { aggr_expr, .. } => {
+                    self.resolve_expr(&mut aggr_expr.aggregated)?
+                }
+                SelectFieldSyntax::AnalyticExpr { analytic_expr, .. } => {
+                    for expr in analytic_expr
+                        .argument
+                        .iter_mut()
+                        .chain(analytic_expr.partition_by.iter_mut())
+                    {
+                        self.resolve_expr(expr)?;
+                    }
+                }
+                SelectFieldSyntax::Wildcard { .. } => {}
+            }
+        }
+
+        if let FromItemSyntax::JoinVariant { on_expr, .. } = &mut syntax.from_item {
+            self.resolve_expr(on_expr)?;
+        }
+
+        if let Some(match_recognize) = &mut syntax.match_recognize {
+            for expr in match_recognize
+                .partition_by
+                .iter_mut()
+                .chain(match_recognize.measures.iter_mut().map(|(expr, _)| expr))
+                .chain(match_recognize.defines.iter_mut().map(|(_, expr)| expr))
+            {
+                self.resolve_expr(expr)?;
+            }
+        }
+
+        if let Some(deduplicate) = &mut syntax.deduplicate {
+            for expr in deduplicate.keys.iter_mut() {
+                self.resolve_expr(expr)?;
+            }
+        }
+
+        if let Some(GroupingElementSyntax::ValueExpr(expr)) = &mut syntax.grouping_element {
+            self.resolve_expr(expr)?;
+        }
+
+        for sort_spec in syntax.order_by.iter_mut() {
+            self.resolve_expr(&mut sort_spec.expr)?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_aggregate_field(&self, field: SelectFieldSyntax) -> Result<SelectFieldSyntax> {
+        match field {
+            SelectFieldSyntax::ValueExpr {
+                value_expr:
+                    ValueExpr::FunctionCall(FunctionCall::UnresolvedUserDefined {
+                        name,
+                        mut arguments,
+                    }),
+                alias,
+            } if self.aggregates.contains_key(&name.to_lowercase()) => {
+                let function = &self.aggregates[&name.to_lowercase()];
+                match (arguments.pop(), arguments.is_empty()) {
+                    (Some(aggregated), true) => Ok(SelectFieldSyntax::AggrExpr {
+                        aggr_expr: AggrExpr {
+                            func: AggregateFunctionParameter::UserDefined(function.clone()),
+                            aggregated,
+                        },
+                        alias: alias.map(|alias| AggrAlias::new(alias.as_ref().to_string())),
+                    }),
+                    _ => Err(SpringError::Sql(anyhow!(
+                        "{}() takes exactly one parameter",
+                        function.name()
+                    ))),
+                }
+            }
+            _ => Ok(field),
+        }
+    }
+
+    fn resolve_expr(&self, expr: &mut ValueExpr) -> Result<()> {
+        *expr = expr
+            .clone()
+            .resolve_user_defined_functions(&mut |name, n_arguments| {
+                self.resolve(name, n_arguments)
+            })?;
+        Ok(())
+    }
+
+    fn resolve(&self, name: &str, n_arguments: usize) -> Result<Arc<UserDefinedFunction>> {
+        if self.aggregates.contains_key(&name.to_lowercase()) {
+            return Err(SpringError::Sql(anyhow!(
+                "aggregate function {}() must be a select field by itself",
+                name.to_lowercase()
+            )));
+        }
+
+        let function = self
+            .scalars
+            .get(&name.to_lowercase())
+            .ok_or_else(|| SpringError::Sql(anyhow!("unknown function {}", name.to_lowercase())))?;
+
+        if function.arity() == n_arguments {
+            Ok(function.clone())
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "{}() takes exactly {} parameter(s) but {} given",
+                function.name(),
+                function.arity(),
+                n_arguments
+            )))
+        }
+    }
+}
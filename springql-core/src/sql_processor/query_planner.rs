@@ -58,8 +58,11 @@
 mod select_syntax_analyzer;
 
 use crate::{
-    error::Result,
-    expr_resolver::ExprResolver,
+    error::{Result, SpringError},
+    expr_resolver::{
+        expr_label::{AggrExprLabel, AnalyticExprLabel, ExprLabel, ValueExprLabel},
+        ExprResolver,
+    },
     pipeline::{
         pump_model::{
             window_operation_parameter::{
@@ -77,7 +80,9 @@ use crate::{
 
 use self::select_syntax_analyzer::SelectSyntaxAnalyzer;
 
-use super::sql_parser::syntax::{GroupingElementSyntax, SelectStreamSyntax};
+use super::sql_parser::syntax::{GroupingElementSyntax, SelectFieldSyntax, SelectStreamSyntax};
+
+use anyhow::anyhow;
 
 #[derive(Debug)]
 pub(crate) struct QueryPlanner {
@@ -85,26 +90,60 @@ pub(crate) struct QueryPlanner {
 }
 
 impl QueryPlanner {
-    pub(in crate::sql_processor) fn new(select_stream_syntax: SelectStreamSyntax) -> Self {
-        Self {
-            analyzer: SelectSyntaxAnalyzer::new(select_stream_syntax),
-        }
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a column reference cannot be resolved against FROM item.
+    pub(in crate::sql_processor) fn new(
+        select_stream_syntax: SelectStreamSyntax,
+        pipeline: &Pipeline,
+    ) -> Result<Self> {
+        let analyzer =
+            SelectSyntaxAnalyzer::new(select_stream_syntax).resolve_column_references(pipeline)?;
+        Ok(Self { analyzer })
+    }
+
+    /// Select list with column references qualified and wildcards expanded.
+    pub(in crate::sql_processor) fn select_list(&self) -> &[SelectFieldSyntax] {
+        self.analyzer.select_list()
+    }
+
+    /// Select syntax with column references qualified and wildcards expanded.
+    pub(in crate::sql_processor) fn select_syntax(&self) -> &SelectStreamSyntax {
+        self.analyzer.select_syntax()
     }
 
     pub(crate) fn plan(self, pipeline: &Pipeline) -> Result<QueryPlan> {
-        let (mut expr_resolver, value_labels_select_list, aggr_labels_select_list) =
-            ExprResolver::new(self.analyzer.select_list().to_vec());
-        let projection = ProjectionOp {
-            value_expr_labels: value_labels_select_list,
-            aggr_expr_labels: aggr_labels_select_list,
-        };
+        self.validate_window_functions()?;
+        self.validate_analytic_functions()?;
+        self.validate_match_recognize()?;
+        self.validate_deduplicate()?;
+        self.validate_order_limit()?;
+
+        let (
+            mut expr_resolver,
+            value_labels_select_list,
+            aggr_labels_select_list,
+            analytic_labels_select_list,
+        ) = ExprResolver::new(self.analyzer.select_list().to_vec());
+        let projection = self.create_projection_op(
+            value_labels_select_list,
+            aggr_labels_select_list,
+            analytic_labels_select_list,
+        );
 
         let group_aggr_window =
             self.create_group_aggr_window_op(&projection, &mut expr_resolver)?;
+        let match_recognize = self.analyzer.match_recognize_param(&mut expr_resolver)?;
+        let deduplicate = self.analyzer.deduplicate_param(&mut expr_resolver)?;
+        let order_limit = self.analyzer.order_limit_param()?;
 
         let upper_ops = UpperOps {
             projection,
             group_aggr_window,
+            match_recognize,
+            deduplicate,
+            order_limit,
         };
 
         let join = self.create_join_op(&mut expr_resolver, pipeline)?;
@@ -113,6 +152,146 @@ impl QueryPlanner {
         Ok(QueryPlan::new(upper_ops, lower_ops, expr_resolver))
     }
 
+    /// WINDOW_START() / WINDOW_END() / EMIT_TIMING() are only available with window clause.
+    fn validate_window_functions(&self) -> Result<()> {
+        let has_window_function = self.analyzer.select_list().iter().any(|field| match field {
+            SelectFieldSyntax::ValueExpr { value_expr, .. } => value_expr.has_window_function(),
+            SelectFieldSyntax::AggrExpr { aggr_expr, .. } => {
+                aggr_expr.aggregated.has_window_function()
+            }
+            SelectFieldSyntax::AnalyticExpr { analytic_expr, .. } => {
+                analytic_expr.has_window_function()
+            }
+            SelectFieldSyntax::Wildcard { .. } => false,
+        });
+
+        if has_window_function && self.analyzer.window_parameter().is_none() {
+            Err(SpringError::Sql(anyhow!(
+                "WINDOW_START(), WINDOW_END() and EMIT_TIMING() require window clause"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Analytic functions (OVER clause) emit a row for each input row, while GROUP BY aggregation emits rows on pane close.
+    fn validate_analytic_functions(&self) -> Result<()> {
+        let has_analytic_function = self
+            .analyzer
+            .select_list()
+            .iter()
+            .any(|field| matches!(field, SelectFieldSyntax::AnalyticExpr { .. }));
+        let has_aggregate_function = self
+            .analyzer
+            .select_list()
+            .iter()
+            .any(|field| matches!(field, SelectFieldSyntax::AggrExpr { .. }));
+
+        if has_analytic_function
+            && (has_aggregate_function || self.analyzer.grouping_element().is_some())
+        {
+            Err(SpringError::Sql(anyhow!(
+                "analytic functions (OVER clause) are not available with GROUP BY aggregation"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// MATCH_RECOGNIZE emits a row for each match, and select_list references its measures.
+    fn validate_match_recognize(&self) -> Result<()> {
+        let has_non_value_expr = self
+            .analyzer
+            .select_list()
+            .iter()
+            .any(|field| !matches!(field, SelectFieldSyntax::ValueExpr { .. }));
+
+        if self.analyzer.has_match_recognize()
+            && (has_non_value_expr
+                || self.analyzer.grouping_element().is_some()
+                || self.analyzer.window_parameter().is_some())
+        {
+            Err(SpringError::Sql(anyhow!(
+                "MATCH_RECOGNIZE is not available with aggregation, analytic functions or window clause"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Deduplication compares rows projected by value expressions.
+    fn validate_deduplicate(&self) -> Result<()> {
+        let has_non_value_expr = self
+            .analyzer
+            .select_list()
+            .iter()
+            .any(|field| !matches!(field, SelectFieldSyntax::ValueExpr { .. }));
+
+        if self.analyzer.has_deduplicate()
+            && (has_non_value_expr
+                || self.analyzer.grouping_element().is_some()
+                || self.analyzer.has_match_recognize())
+        {
+            Err(SpringError::Sql(anyhow!(
+                "SELECT DISTINCT and DEDUPLICATE BY are not available with aggregation, analytic functions or MATCH_RECOGNIZE"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ORDER BY and LIMIT rank rows of a pane, while analytic functions and MATCH_RECOGNIZE emit rows without panes.
+    fn validate_order_limit(&self) -> Result<()> {
+        let has_analytic_function = self
+            .analyzer
+            .select_list()
+            .iter()
+            .any(|field| matches!(field, SelectFieldSyntax::AnalyticExpr { .. }));
+
+        if self.analyzer.has_order_limit()
+            && (has_analytic_function || self.analyzer.has_match_recognize())
+        {
+            Err(SpringError::Sql(anyhow!(
+                "ORDER BY and LIMIT are not available with analytic functions or MATCH_RECOGNIZE"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn create_projection_op(
+        &self,
+        value_labels_select_list: Vec<ValueExprLabel>,
+        aggr_labels_select_list: Vec<AggrExprLabel>,
+        analytic_labels_select_list: Vec<AnalyticExprLabel>,
+    ) -> ProjectionOp {
+        let mut value_labels = value_labels_select_list.into_iter();
+        let mut aggr_labels = aggr_labels_select_list.into_iter();
+        let mut analytic_labels = analytic_labels_select_list.into_iter();
+
+        let expr_labels = self
+            .analyzer
+            .select_list()
+            .iter()
+            .map(|field| match field {
+                SelectFieldSyntax::ValueExpr { .. } => {
+                    ExprLabel::Value(value_labels.next().expect("same length as select_list"))
+                }
+                SelectFieldSyntax::AggrExpr { .. } => {
+                    ExprLabel::Aggr(aggr_labels.next().expect("same length as select_list"))
+                }
+                SelectFieldSyntax::AnalyticExpr { .. } => {
+                    ExprLabel::Analytic(analytic_labels.next().expect("same length as select_list"))
+                }
+                SelectFieldSyntax::Wildcard { .. } => {
+                    unreachable!("wildcard is expanded by SelectSyntaxAnalyzer")
+                }
+            })
+            .collect();
+
+        ProjectionOp { expr_labels }
+    }
+
     fn create_group_aggr_window_op(
         &self,
         projection_op: &ProjectionOp,
@@ -120,12 +299,29 @@ impl QueryPlanner {
     ) -> Result<Option<GroupAggregateWindowOp>> {
         let window_param = self.create_window_param();
         let group_aggr_param = self.create_group_aggr_param(expr_resolver, projection_op)?;
+        let idle_timeout = self.analyzer.idle_timeout();
+        let trigger = self.analyzer.emit_trigger();
 
         match (window_param, group_aggr_param) {
+            (Some(window_param), _)
+                if idle_timeout.is_some() && window_param.count_length_period().is_some() =>
+            {
+                Err(SpringError::Sql(anyhow!(
+                    "IDLE TIMEOUT is not available with ROWS WINDOW, which does not use watermark"
+                )))
+            }
             (Some(window_param), Some(group_aggr_param)) => Ok(Some(GroupAggregateWindowOp {
                 window_param,
                 op_param: WindowOperationParameter::GroupAggregation(group_aggr_param),
+                idle_timeout,
+                trigger,
             })),
+            _ if idle_timeout.is_some() => Err(SpringError::Sql(anyhow!(
+                "IDLE TIMEOUT requires GROUP BY aggregation with window clause"
+            ))),
+            _ if trigger.is_some() => Err(SpringError::Sql(anyhow!(
+                "EMIT EVERY requires GROUP BY aggregation with window clause"
+            ))),
             _ => Ok(None),
         }
     }
@@ -140,11 +336,11 @@ impl QueryPlanner {
         projection_op: &ProjectionOp,
     ) -> Result<Option<GroupAggregateParameter>> {
         let opt_grouping_elem = self.analyzer.grouping_element();
-        let aggr_labels = &projection_op.aggr_expr_labels;
+        let aggr_labels = projection_op.aggr_expr_labels();
 
         match (opt_grouping_elem, aggr_labels.len()) {
             (Some(grouping_elem), 1) => {
-                let aggr_label = aggr_labels.iter().next().expect("len checked");
+                let aggr_label = aggr_labels.first().expect("len checked");
                 let aggr_func = expr_resolver.resolve_aggr_expr(*aggr_label).func;
 
                 let group_by_label = match grouping_elem {
@@ -2,9 +2,13 @@
 
 use crate::sql_processor::sql_parser::syntax::SelectStreamSyntax;
 
+mod column_reference;
+mod deduplicate;
 mod field;
 mod from_item;
 mod group_aggregate;
+mod match_recognize;
+mod order_limit;
 mod window;
 
 #[derive(Clone, Debug, new)]
@@ -0,0 +1,345 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use super::SelectSyntaxAnalyzer;
+use crate::{
+    error::{Result, SpringError},
+    expression::ValueExpr,
+    pipeline::{
+        field::field_name::ColumnReference,
+        name::{did_you_mean, ColumnName, StreamName, ValueAlias},
+        Pipeline,
+    },
+    sql_processor::sql_parser::syntax::{
+        FromItemSyntax, GroupingElementSyntax, MatchRecognizeSyntax, SelectFieldSyntax,
+    },
+};
+
+/// Streams (and their columns) which column references can be qualified with.
+#[derive(Debug)]
+struct Scope(Vec<(StreamName, Vec<ColumnName>)>);
+
+impl Scope {
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - no stream has the column.
+    ///   - more than one stream has the column.
+    fn qualify(&self, column_name: ColumnName) -> Result<ColumnReference> {
+        let mut streams = self
+            .0
+            .iter()
+            .filter(|(_, columns)| columns.contains(&column_name))
+            .map(|(stream_name, _)| stream_name);
+
+        match (streams.next(), streams.next()) {
+            (Some(stream_name), None) => Ok(ColumnReference::new(stream_name.clone(), column_name)),
+            (None, _) => Err(SpringError::Sql(anyhow!(
+                "column `{}` is not found in FROM item{}",
+                column_name,
+                did_you_mean(
+                    column_name.as_ref(),
+                    self.0
+                        .iter()
+                        .flat_map(|(_, columns)| columns.iter().map(|c| c.as_ref()))
+                )
+            ))),
+            (Some(s1), Some(s2)) => Err(SpringError::Sql(anyhow!(
+                "column `{}` is ambiguous: both `{}` and `{}` have it",
+                column_name,
+                s1,
+                s2
+            ))),
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `stream_name` is not in the scope.
+    fn expand_wildcard(&self, stream_name: Option<StreamName>) -> Result<Vec<SelectFieldSyntax>> {
+        let streams = match stream_name {
+            None => self.0.iter().collect::<Vec<_>>(),
+            Some(stream_name) => {
+                let stream = self
+                    .0
+                    .iter()
+                    .find(|(s, _)| s == &stream_name)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!("`{}.*` is not found in FROM item", stream_name))
+                    })?;
+                vec![stream]
+            }
+        };
+
+        Ok(streams
+            .into_iter()
+            .flat_map(|(stream_name, columns)| {
+                columns
+                    .iter()
+                    .map(|column_name| SelectFieldSyntax::ValueExpr {
+                        value_expr: ValueExpr::ColumnReference(ColumnReference::new(
+                            stream_name.clone(),
+                            column_name.clone(),
+                        )),
+                        alias: None,
+                    })
+            })
+            .collect())
+    }
+
+    /// `a.b` is a field `b` of column `a` if `a` is not a stream but a column in the scope.
+    fn qualify_expr(&self, expr: ValueExpr) -> Result<ValueExpr> {
+        expr.resolve_field_references(&|colref| {
+            let is_stream = self
+                .0
+                .iter()
+                .any(|(stream_name, _)| stream_name == &colref.stream_name);
+            let is_column = self.0.iter().any(|(_, columns)| {
+                columns
+                    .iter()
+                    .any(|column_name| column_name.as_ref() == colref.stream_name.as_ref())
+            });
+            !is_stream && is_column
+        })?
+        .qualify_column_references(&mut |column_name| self.qualify(column_name))
+    }
+}
+
+impl SelectSyntaxAnalyzer {
+    /// Qualifies column references without stream name and expands wildcards in select_list.
+    ///
+    /// Unqualified columns are resolved against the streams in FROM item.
+    /// With MATCH_RECOGNIZE, select_list references its measures instead of the streams.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - stream in FROM item is not found in the pipeline.
+    ///   - unqualified column is not found in (or is ambiguous among) FROM item.
+    ///   - `stream.*` references a stream not in FROM item.
+    pub(in super::super) fn resolve_column_references(
+        mut self,
+        pipeline: &Pipeline,
+    ) -> Result<Self> {
+        let from_scope = Scope(
+            Self::from_streams(&self.select_syntax.from_item)
+                .into_iter()
+                .map(|stream_name| {
+                    let columns = pipeline.get_stream(stream_name)?.shape().column_names();
+                    Ok((stream_name.clone(), columns))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        );
+        let select_scope = self
+            .select_syntax
+            .match_recognize
+            .as_ref()
+            .map(Self::measures_scope);
+        let select_scope = select_scope.as_ref().unwrap_or(&from_scope);
+
+        let syntax = &mut self.select_syntax;
+
+        syntax.fields = syntax
+            .fields
+            .drain(..)
+            .map(|field| Self::resolve_select_field(field, select_scope))
+            .collect::<Result<Vec<_>>>()?
+            .concat();
+
+        let value_aliases = syntax
+            .fields
+            .iter()
+            .filter_map(|field| match field {
+                SelectFieldSyntax::ValueExpr {
+                    alias: Some(alias), ..
+                } => Some(alias.as_ref().to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        syntax.grouping_element = match syntax.grouping_element.take() {
+            // `GROUP BY name` references an alias in select_list rather than a column.
+            Some(GroupingElementSyntax::ValueExpr(ValueExpr::UnqualifiedColumnReference(
+                column_name,
+            ))) if value_aliases.contains(&column_name.as_ref().to_string()) => Some(
+                GroupingElementSyntax::ValueAlias(ValueAlias::new(column_name.to_string())),
+            ),
+            Some(GroupingElementSyntax::ValueExpr(expr)) => Some(GroupingElementSyntax::ValueExpr(
+                from_scope.qualify_expr(expr)?,
+            )),
+            other => other,
+        };
+
+        if let FromItemSyntax::JoinVariant { on_expr, .. } = &mut syntax.from_item {
+            *on_expr = from_scope.qualify_expr(on_expr.clone())?;
+        }
+
+        if let Some(match_recognize) = &mut syntax.match_recognize {
+            match_recognize.partition_by = match_recognize
+                .partition_by
+                .take()
+                .map(|expr| from_scope.qualify_expr(expr))
+                .transpose()?;
+            for (expr, _) in match_recognize.measures.iter_mut() {
+                *expr = from_scope.qualify_expr(expr.clone())?;
+            }
+            for (_, expr) in match_recognize.defines.iter_mut() {
+                *expr = from_scope.qualify_expr(expr.clone())?;
+            }
+        }
+
+        if let Some(deduplicate) = &mut syntax.deduplicate {
+            for expr in deduplicate.keys.iter_mut() {
+                *expr = from_scope.qualify_expr(expr.clone())?;
+            }
+        }
+
+        // `ORDER BY name` references an alias in select_list rather than a column.
+        let aliases = syntax
+            .fields
+            .iter()
+            .filter_map(Self::field_alias)
+            .collect::<Vec<_>>();
+        for sort_spec in syntax.order_by.iter_mut() {
+            match &sort_spec.expr {
+                ValueExpr::UnqualifiedColumnReference(column_name)
+                    if aliases.contains(&column_name.as_ref().to_string()) => {}
+                expr => sort_spec.expr = select_scope.qualify_expr(expr.clone())?,
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn resolve_select_field(
+        field: SelectFieldSyntax,
+        scope: &Scope,
+    ) -> Result<Vec<SelectFieldSyntax>> {
+        match field {
+            SelectFieldSyntax::Wildcard { stream_name } => scope.expand_wildcard(stream_name),
+            SelectFieldSyntax::ValueExpr { value_expr, alias } => {
+                Ok(vec![SelectFieldSyntax::ValueExpr {
+                    value_expr: scope.qualify_expr(value_expr)?,
+                    alias,
+                }])
+            }
+            SelectFieldSyntax::AggrExpr {
+                mut aggr_expr,
+                alias,
+            } => {
+                aggr_expr.aggregated = scope.qualify_expr(aggr_expr.aggregated)?;
+                Ok(vec![SelectFieldSyntax::AggrExpr { aggr_expr, alias }])
+            }
+            SelectFieldSyntax::AnalyticExpr {
+                mut analytic_expr,
+                alias,
+            } => {
+                analytic_expr.argument = analytic_expr
+                    .argument
+                    .map(|expr| scope.qualify_expr(expr))
+                    .transpose()?;
+                analytic_expr.partition_by = analytic_expr
+                    .partition_by
+                    .map(|expr| scope.qualify_expr(expr))
+                    .transpose()?;
+                Ok(vec![SelectFieldSyntax::AnalyticExpr {
+                    analytic_expr,
+                    alias,
+                }])
+            }
+        }
+    }
+
+    fn from_streams(from_item: &FromItemSyntax) -> Vec<&StreamName> {
+        match from_item {
+            FromItemSyntax::StreamVariant(sub_from_item) => vec![&sub_from_item.stream_name],
+            FromItemSyntax::JoinVariant { left, right, .. } => {
+                let mut streams = vec![&left.stream_name];
+                streams.extend(Self::from_streams(right));
+                streams
+            }
+        }
+    }
+
+    /// Output of MATCH_RECOGNIZE is a stream named by its alias, whose columns are measures.
+    fn measures_scope(match_recognize: &MatchRecognizeSyntax) -> Scope {
+        let columns = match_recognize
+            .measures
+            .iter()
+            .map(|(_, alias)| ColumnName::new(alias.to_string()))
+            .collect();
+        Scope(vec![(
+            StreamName::new(match_recognize.alias.to_string()),
+            columns,
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope() -> Scope {
+        Scope(vec![
+            (
+                StreamName::factory("trade"),
+                vec![
+                    ColumnName::factory("ts"),
+                    ColumnName::factory("ticker"),
+                    ColumnName::factory("amount"),
+                ],
+            ),
+            (
+                StreamName::factory("city_temperature"),
+                vec![
+                    ColumnName::factory("ts"),
+                    ColumnName::factory("temperature"),
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_qualify() {
+        assert_eq!(
+            scope().qualify(ColumnName::factory("amount")).unwrap(),
+            ColumnReference::factory("trade", "amount")
+        );
+        assert!(matches!(
+            scope().qualify(ColumnName::factory("ts")),
+            Err(SpringError::Sql(_))
+        ));
+        assert!(matches!(
+            scope().qualify(ColumnName::factory("unknown")),
+            Err(SpringError::Sql(_))
+        ));
+    }
+
+    #[test]
+    fn test_expand_wildcard() {
+        assert_eq!(scope().expand_wildcard(None).unwrap().len(), 5);
+
+        let fields = scope()
+            .expand_wildcard(Some(StreamName::factory("city_temperature")))
+            .unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                SelectFieldSyntax::ValueExpr {
+                    value_expr: ValueExpr::factory_colref("city_temperature", "ts"),
+                    alias: None,
+                },
+                SelectFieldSyntax::ValueExpr {
+                    value_expr: ValueExpr::factory_colref("city_temperature", "temperature"),
+                    alias: None,
+                },
+            ]
+        );
+
+        assert!(matches!(
+            scope().expand_wildcard(Some(StreamName::factory("unknown"))),
+            Err(SpringError::Sql(_))
+        ));
+    }
+}
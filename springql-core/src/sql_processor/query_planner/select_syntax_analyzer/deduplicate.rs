@@ -0,0 +1,65 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use super::SelectSyntaxAnalyzer;
+use crate::{
+    error::{Result, SpringError},
+    expr_resolver::ExprResolver,
+    pipeline::pump_model::{
+        deduplicate_parameter::{DeduplicateHorizon, DeduplicateParameter},
+        window_parameter::{WindowParameter, WindowTimeDomain},
+    },
+};
+
+impl SelectSyntaxAnalyzer {
+    pub(in super::super) fn has_deduplicate(&self) -> bool {
+        self.select_syntax.distinct || self.select_syntax.deduplicate.is_some()
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - both `SELECT DISTINCT` and `DEDUPLICATE BY` are used.
+    ///   - `SELECT DISTINCT` is used without time-based FIXED WINDOW.
+    pub(in super::super) fn deduplicate_param(
+        &self,
+        expr_resolver: &mut ExprResolver,
+    ) -> Result<Option<DeduplicateParameter>> {
+        match (self.select_syntax.distinct, &self.select_syntax.deduplicate) {
+            (false, None) => Ok(None),
+            (true, Some(_)) => Err(SpringError::Sql(anyhow!(
+                "SELECT DISTINCT and DEDUPLICATE BY cannot be used together"
+            ))),
+            (true, None) => match self.window_parameter() {
+                Some(WindowParameter::TimedFixedWindow {
+                    length,
+                    allowed_delay,
+                    time_domain,
+                }) => Ok(Some(DeduplicateParameter {
+                    keys: vec![],
+                    horizon: DeduplicateHorizon::Pane {
+                        length,
+                        allowed_delay,
+                    },
+                    time_domain,
+                })),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "SELECT DISTINCT requires time-based FIXED WINDOW to bound its state"
+                ))),
+            },
+            (false, Some(deduplicate)) => {
+                let keys = deduplicate
+                    .keys
+                    .iter()
+                    .map(|expr| expr_resolver.register_value_expr(expr.clone()))
+                    .collect();
+                Ok(Some(DeduplicateParameter {
+                    keys,
+                    horizon: DeduplicateHorizon::Duration(deduplicate.within),
+                    time_domain: WindowTimeDomain::EventTime,
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use super::SelectSyntaxAnalyzer;
+use crate::{
+    error::{Result, SpringError},
+    expr_resolver::ExprResolver,
+    pipeline::{
+        field::field_name::ColumnReference,
+        name::{ColumnName, PatternSymbol, StreamName},
+        pump_model::match_recognize_parameter::{MatchRecognizeParameter, MeasureParameter},
+    },
+    sql_processor::sql_parser::syntax::{FromItemSyntax, MatchRecognizeSyntax},
+};
+
+impl SelectSyntaxAnalyzer {
+    pub(in super::super) fn has_match_recognize(&self) -> bool {
+        self.select_syntax.match_recognize.is_some()
+    }
+
+    /// Column references qualified by a pattern symbol (`A.c1`) are resolved into the source stream's columns.
+    ///
+    /// - In DEFINE condition of symbol `A`, `A.c1` and `source_stream.c1` reference the current row.
+    /// - In MEASURES, an expression references the last row matched to a symbol (`A.c1`).
+    pub(in super::super) fn match_recognize_param(
+        &self,
+        expr_resolver: &mut ExprResolver,
+    ) -> Result<Option<MatchRecognizeParameter>> {
+        match &self.select_syntax.match_recognize {
+            None => Ok(None),
+            Some(syntax) => {
+                let stream_name = match &self.select_syntax.from_item {
+                    FromItemSyntax::StreamVariant(sub_from_item) => {
+                        sub_from_item.stream_name.clone()
+                    }
+                    FromItemSyntax::JoinVariant { .. } => {
+                        return Err(SpringError::Sql(anyhow!(
+                            "MATCH_RECOGNIZE is not available with JOIN"
+                        )))
+                    }
+                };
+                Self::match_recognize_param_inner(syntax, stream_name, expr_resolver).map(Some)
+            }
+        }
+    }
+
+    fn match_recognize_param_inner(
+        syntax: &MatchRecognizeSyntax,
+        stream_name: StreamName,
+        expr_resolver: &mut ExprResolver,
+    ) -> Result<MatchRecognizeParameter> {
+        let pattern_symbols = syntax.pattern.symbols();
+
+        for (i, (symbol, _)) in syntax.defines.iter().enumerate() {
+            if !pattern_symbols.contains(symbol) {
+                return Err(SpringError::Sql(anyhow!(
+                    "pattern symbol `{}` in DEFINE does not appear in PATTERN",
+                    symbol
+                )));
+            }
+            if syntax.defines[..i].iter().any(|(s, _)| s == symbol) {
+                return Err(SpringError::Sql(anyhow!(
+                    "pattern symbol `{}` is defined twice",
+                    symbol
+                )));
+            }
+        }
+
+        let symbols = pattern_symbols
+            .iter()
+            .map(|symbol| {
+                let define_label = syntax
+                    .defines
+                    .iter()
+                    .find(|(s, _)| s == symbol)
+                    .map(|(_, condition)| {
+                        let condition = condition.clone().map_column_references(&mut |colref| {
+                            let qualifier = PatternSymbol::new(colref.stream_name.to_string());
+                            if &qualifier == symbol {
+                                Ok(ColumnReference::new(
+                                    stream_name.clone(),
+                                    colref.column_name,
+                                ))
+                            } else if pattern_symbols.contains(&qualifier) {
+                                Err(SpringError::Sql(anyhow!(
+                                    "DEFINE of `{}` cannot reference other pattern symbol `{}`",
+                                    symbol,
+                                    qualifier
+                                )))
+                            } else {
+                                Ok(colref)
+                            }
+                        })?;
+                        Ok(expr_resolver.register_value_expr(condition))
+                    })
+                    .transpose()?;
+                Ok((symbol.clone(), define_label))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let measures = syntax
+            .measures
+            .iter()
+            .map(|(expr, alias)| {
+                let mut measure_symbol: Option<PatternSymbol> = None;
+                let expr = expr.clone().map_column_references(&mut |colref| {
+                    let qualifier = PatternSymbol::new(colref.stream_name.to_string());
+                    if !pattern_symbols.contains(&qualifier) {
+                        Err(SpringError::Sql(anyhow!(
+                            "column reference `{}.{}` in MEASURES must be qualified by a pattern symbol",
+                            colref.stream_name,
+                            colref.column_name
+                        )))
+                    } else if matches!(&measure_symbol, Some(s) if s != &qualifier) {
+                        Err(SpringError::Sql(anyhow!(
+                            "measure `{}` references more than one pattern symbol",
+                            alias
+                        )))
+                    } else {
+                        measure_symbol = Some(qualifier);
+                        Ok(ColumnReference::new(
+                            stream_name.clone(),
+                            colref.column_name,
+                        ))
+                    }
+                })?;
+                let symbol = measure_symbol.ok_or_else(|| {
+                    SpringError::Sql(anyhow!(
+                        "measure `{}` must reference a column qualified by a pattern symbol",
+                        alias
+                    ))
+                })?;
+
+                Ok(MeasureParameter {
+                    symbol,
+                    expr: expr_resolver.register_value_expr(expr),
+                    alias: ColumnName::new(alias.to_string()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let partition_by = syntax
+            .partition_by
+            .clone()
+            .map(|expr| expr_resolver.register_value_expr(expr));
+
+        Ok(MatchRecognizeParameter {
+            partition_by,
+            pattern: syntax.pattern.clone(),
+            within: syntax.within,
+            symbols,
+            measures,
+            alias: StreamName::new(syntax.alias.to_string()),
+        })
+    }
+}
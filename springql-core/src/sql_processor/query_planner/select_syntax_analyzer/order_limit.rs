@@ -0,0 +1,107 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use super::SelectSyntaxAnalyzer;
+use crate::{
+    error::{Result, SpringError},
+    expression::ValueExpr,
+    pipeline::pump_model::{
+        order_limit_parameter::{OrderLimitPanes, OrderLimitParameter, SortKey},
+        window_parameter::WindowParameter,
+    },
+    sql_processor::sql_parser::syntax::SelectFieldSyntax,
+};
+
+impl SelectSyntaxAnalyzer {
+    pub(in super::super) fn has_order_limit(&self) -> bool {
+        !self.select_syntax.order_by.is_empty() || self.select_syntax.limit.is_some()
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a sort key is neither an alias nor an expression in select_list.
+    ///   - ORDER BY or LIMIT is used without GROUP BY aggregation nor time-based FIXED WINDOW.
+    pub(in super::super) fn order_limit_param(&self) -> Result<Option<OrderLimitParameter>> {
+        if !self.has_order_limit() {
+            return Ok(None);
+        }
+
+        let sort_keys = self
+            .select_syntax
+            .order_by
+            .iter()
+            .map(|sort_spec| {
+                let field_index = self.sort_key_index(&sort_spec.expr)?;
+                Ok(SortKey {
+                    field_index,
+                    order: sort_spec.order,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let panes = if self.grouping_element().is_some() {
+            OrderLimitPanes::Aggregate
+        } else {
+            match self.window_parameter() {
+                Some(WindowParameter::TimedFixedWindow {
+                    length,
+                    allowed_delay,
+                    time_domain,
+                }) => OrderLimitPanes::Fixed {
+                    length,
+                    allowed_delay,
+                    time_domain,
+                },
+                _ => {
+                    return Err(SpringError::Sql(anyhow!(
+                        "ORDER BY and LIMIT require GROUP BY aggregation or time-based FIXED WINDOW to bound its state"
+                    )))
+                }
+            }
+        };
+
+        Ok(Some(OrderLimitParameter {
+            sort_keys,
+            limit: self.select_syntax.limit,
+            panes,
+        }))
+    }
+
+    /// A sort key references a field by its alias, or by the same expression.
+    fn sort_key_index(&self, expr: &ValueExpr) -> Result<usize> {
+        let fields = self.select_list();
+
+        let index = match expr {
+            ValueExpr::UnqualifiedColumnReference(name) => fields
+                .iter()
+                .position(|field| Self::field_alias(field).as_deref() == Some(name.as_ref())),
+            _ => fields.iter().position(|field| {
+                matches!(field, SelectFieldSyntax::ValueExpr { value_expr, .. } if value_expr == expr)
+            }),
+        };
+
+        index.ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "sort key {:?} in ORDER BY must be an alias or an expression in select list",
+                expr
+            ))
+        })
+    }
+
+    pub(super) fn field_alias(field: &SelectFieldSyntax) -> Option<String> {
+        match field {
+            SelectFieldSyntax::ValueExpr { alias, .. } => {
+                alias.as_ref().map(|alias| alias.as_ref().to_string())
+            }
+            SelectFieldSyntax::AggrExpr { alias, .. } => {
+                alias.as_ref().map(|alias| alias.as_ref().to_string())
+            }
+            SelectFieldSyntax::AnalyticExpr { alias, .. } => {
+                alias.as_ref().map(|alias| alias.as_ref().to_string())
+            }
+            SelectFieldSyntax::Wildcard { .. } => None,
+        }
+    }
+}
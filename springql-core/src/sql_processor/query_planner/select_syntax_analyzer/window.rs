@@ -2,10 +2,21 @@
 
 use super::SelectSyntaxAnalyzer;
 
-use crate::pipeline::pump_model::window_parameter::WindowParameter;
+use crate::{
+    pipeline::pump_model::{window_parameter::WindowParameter, window_trigger::WindowTrigger},
+    stream_engine::time::duration::wall_clock_duration::WallClockDuration,
+};
 
 impl SelectSyntaxAnalyzer {
     pub(in super::super) fn window_parameter(&self) -> Option<WindowParameter> {
         self.select_syntax.window_clause.clone()
     }
+
+    pub(in super::super) fn idle_timeout(&self) -> Option<WallClockDuration> {
+        self.select_syntax.idle_timeout
+    }
+
+    pub(in super::super) fn emit_trigger(&self) -> Option<WindowTrigger> {
+        self.select_syntax.emit_trigger
+    }
 }
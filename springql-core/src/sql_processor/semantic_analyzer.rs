@@ -0,0 +1,1157 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Semantic analysis between parsing and query planning.
+//!
+//! Names in a DDL are resolved against the current pipeline and expressions are type-checked against `SqlType`,
+//! so that bad DDLs are rejected by `spring_command()` instead of failing inside worker threads.
+//!
+//! Types are inferred as `Option<SqlType>`: `None` stands for unknown (NULL constant), which is compatible with any type.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+use crate::{
+    error::{Result, SpringError},
+    expression::{
+        boolean_expression::{
+            comparison_function::ComparisonFunction, logical_function::LogicalFunction,
+            numerical_function::NumericalFunction, BinaryExpr,
+        },
+        function_call::FunctionCall,
+        operator::UnaryOperator,
+        AggrExpr, AnalyticExpr, ValueExpr,
+    },
+    pipeline::{
+        field::field_name::ColumnReference,
+        name::{did_you_mean, ColumnName, PumpName, StreamName},
+        option::{
+            in_memory_queue_options::InMemoryQueueOptions,
+            net_options::{NetClientOptions, NetServerOptions},
+        },
+        pump_model::{
+            analytic_parameter::AnalyticFunctionParameter,
+            window_operation_parameter::aggregate::AggregateFunctionParameter,
+        },
+        relation::sql_type::{
+            I64LooseType, NumericComparableType, SqlType, U64LooseType, MAX_DECIMAL_PRECISION,
+        },
+        sink_writer_model::{sink_writer_type::SinkWriterType, SinkWriterModel},
+        source_reader_model::{source_reader_type::SourceReaderType, SourceReaderModel},
+        stream_model::StreamModel,
+        Pipeline,
+    },
+    sql_processor::sql_parser::syntax::{
+        FromItemSyntax, GroupingElementSyntax, SelectFieldSyntax, SelectStreamSyntax,
+    },
+    stream_engine::{
+        command::{insert_plan::InsertPlan, query_plan::QueryPlan},
+        SqlValue,
+    },
+};
+
+#[derive(Debug, new)]
+pub(in crate::sql_processor) struct SemanticAnalyzer<'a> {
+    pipeline: &'a Pipeline,
+}
+
+impl<'a> SemanticAnalyzer<'a> {
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the stream name is already used in the pipeline.
+    ///   - a column is defined twice.
+    pub(in crate::sql_processor) fn validate_stream(&self, stream: &StreamModel) -> Result<()> {
+        self.validate_new_name(stream.name().as_ref())?;
+
+        let column_names = stream.shape().column_names();
+        column_names
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, column_name)| {
+                if column_names[..i].contains(column_name) {
+                    Err(SpringError::Sql(anyhow!(
+                        r#"column "{}" is defined twice in stream "{}""#,
+                        column_name,
+                        stream.name()
+                    )))
+                } else {
+                    Ok(())
+                }
+            })
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the destination stream is not in the pipeline.
+    /// - [SpringError::InvalidOption](crate::error::SpringError::InvalidOption) when:
+    ///   - `TIMESTAMP_FORMAT` option is not a valid format.
+    pub(in crate::sql_processor) fn validate_source_reader(
+        &self,
+        source_reader: &SourceReaderModel,
+    ) -> Result<()> {
+        self.pipeline
+            .get_stream(source_reader.dest_source_stream())?;
+        source_reader.timestamp_format().map(|_| ())
+    }
+
+    /// Options are usually validated when the source reader starts.
+    /// This validates them beforehand, for options given by placeholders.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::InvalidOption](crate::error::SpringError::InvalidOption) when:
+    ///   - an option required by the source reader type is missing or invalid.
+    pub(in crate::sql_processor) fn validate_source_reader_options(
+        &self,
+        source_reader: &SourceReaderModel,
+    ) -> Result<()> {
+        let options = source_reader.options();
+        match source_reader.source_reader_type() {
+            SourceReaderType::NetClient => NetClientOptions::try_from(options).map(|_| ()),
+            SourceReaderType::NetServer => NetServerOptions::try_from(options).map(|_| ()),
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the stream to write from is not in the pipeline.
+    pub(in crate::sql_processor) fn validate_sink_writer(
+        &self,
+        sink_writer: &SinkWriterModel,
+    ) -> Result<()> {
+        self.pipeline
+            .get_stream(sink_writer.from_sink_stream())
+            .map(|_| ())
+    }
+
+    /// Options are usually validated when the sink writer starts.
+    /// This validates them beforehand, for options given by placeholders.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::InvalidOption](crate::error::SpringError::InvalidOption) when:
+    ///   - an option required by the sink writer type is missing or invalid.
+    pub(in crate::sql_processor) fn validate_sink_writer_options(
+        &self,
+        sink_writer: &SinkWriterModel,
+    ) -> Result<()> {
+        let options = sink_writer.options();
+        match sink_writer.sink_writer_type() {
+            SinkWriterType::Net => NetClientOptions::try_from(options).map(|_| ()),
+            SinkWriterType::InMemoryQueue => InMemoryQueueOptions::try_from(options).map(|_| ()),
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the pump name is already used in the pipeline.
+    pub(in crate::sql_processor) fn validate_pump_name(&self, pump_name: &PumpName) -> Result<()> {
+        self.validate_new_name(pump_name.as_ref())
+    }
+
+    /// Type-checks expressions in `select_syntax`, whose column references are already qualified, and returns types of select fields.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a column reference is not resolved into a column of a stream in FROM item (or a measure of MATCH_RECOGNIZE).
+    ///   - an operator or a function is applied to values of incompatible types.
+    ///   - JOIN condition or DEFINE condition of MATCH_RECOGNIZE is not BOOLEAN.
+    ///   - `UNNEST(...)` is not a select field by itself, is used twice, or is used with aggregation.
+    pub(in crate::sql_processor) fn select_field_types(
+        &self,
+        select_syntax: &SelectStreamSyntax,
+    ) -> Result<Vec<Option<SqlType>>> {
+        validate_unnest(select_syntax)?;
+        let mut scope = TypeScope::new(select_syntax, self.pipeline)?;
+
+        if let FromItemSyntax::JoinVariant { on_expr, .. } = &select_syntax.from_item {
+            scope.validate_condition(on_expr, "JOIN condition")?;
+        }
+        if let Some(GroupingElementSyntax::ValueExpr(expr)) = &select_syntax.grouping_element {
+            let _ = scope.value_expr_type(expr)?;
+        }
+        if let Some(deduplicate) = &select_syntax.deduplicate {
+            deduplicate
+                .keys
+                .iter()
+                .try_for_each(|key| scope.value_expr_type(key).map(|_| ()))?;
+        }
+
+        let field_types = select_syntax
+            .fields
+            .iter()
+            .map(|field| scope.select_field_type(field))
+            .collect::<Result<Vec<_>>>()?;
+
+        scope.aliases = select_syntax
+            .fields
+            .iter()
+            .zip(field_types.iter())
+            .filter_map(|(field, typ)| {
+                field_alias(field).map(|alias| (ColumnName::new(alias), typ.clone()))
+            })
+            .collect();
+        select_syntax
+            .order_by
+            .iter()
+            .try_for_each(|sort_spec| scope.value_expr_type(&sort_spec.expr).map(|_| ()))?;
+
+        Ok(field_types)
+    }
+
+    /// Select fields are inserted into the columns listed in `insert_plan` in order.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the target stream is not in the pipeline.
+    ///   - a listed column is not in the target stream, or is listed twice.
+    ///   - a column of the target stream is not listed.
+    ///   - the number of select fields differs from the number of listed columns.
+    ///   - a select field is not convertible into the type of its column (including NULL into NOT NULL column).
+    pub(in crate::sql_processor) fn validate_insert(
+        &self,
+        insert_plan: &InsertPlan,
+        fields: &[SelectFieldSyntax],
+        field_types: &[Option<SqlType>],
+    ) -> Result<()> {
+        let stream = self.pipeline.get_stream(insert_plan.stream())?;
+        let column_order = insert_plan.column_order();
+
+        let column_data_types = column_order
+            .iter()
+            .enumerate()
+            .map(|(i, column_name)| {
+                if column_order[..i].contains(column_name) {
+                    return Err(SpringError::Sql(anyhow!(
+                        r#"column "{}" is listed twice in INSERT INTO "{}""#,
+                        column_name,
+                        stream.name()
+                    )));
+                }
+                stream
+                    .shape()
+                    .columns()
+                    .iter()
+                    .map(|coldef| coldef.column_data_type())
+                    .find(|cdt| cdt.column_name() == column_name)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!(
+                            r#"column "{}" is not in stream "{}""#,
+                            column_name,
+                            stream.name()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(missing) = stream
+            .shape()
+            .column_names()
+            .into_iter()
+            .find(|column_name| !column_order.contains(column_name))
+        {
+            return Err(SpringError::Sql(anyhow!(
+                r#"column "{}" of stream "{}" is not listed in INSERT INTO"#,
+                missing,
+                stream.name()
+            )));
+        }
+
+        if fields.len() != column_order.len() {
+            return Err(SpringError::Sql(anyhow!(
+                r#"INSERT INTO "{}" lists {} columns but the query has {} select fields"#,
+                stream.name(),
+                column_order.len(),
+                fields.len()
+            )));
+        }
+
+        fields
+            .iter()
+            .zip(field_types.iter())
+            .zip(column_data_types)
+            .enumerate()
+            .try_for_each(|(i, ((field, field_type), cdt))| {
+                let is_null = matches!(
+                    field,
+                    SelectFieldSyntax::ValueExpr {
+                        value_expr: ValueExpr::Constant(SqlValue::Null),
+                        ..
+                    }
+                );
+                match field_type {
+                    _ if is_null && !cdt.nullable() => Err(SpringError::Sql(anyhow!(
+                        r#"select field #{} is NULL but column "{}" of stream "{}" is NOT NULL"#,
+                        i + 1,
+                        cdt.column_name(),
+                        stream.name()
+                    ))),
+                    Some(field_type) if !is_convertible(field_type, cdt.sql_type()) => {
+                        Err(SpringError::Sql(anyhow!(
+                            r#"select field #{} of type {} cannot be inserted into column "{}" of type {} in stream "{}""#,
+                            i + 1,
+                            field_type,
+                            cdt.column_name(),
+                            cdt.sql_type(),
+                            stream.name()
+                        )))
+                    }
+                    _ => Ok(()),
+                }
+            })
+    }
+
+    /// Branches of UNION ALL must have the same number of select fields.
+    /// Select fields at the same position must have the same data type (NULL is compatible with any type).
+    ///
+    /// `branch_field_types` are types of select fields of each branch, from [select_field_types()](Self::select_field_types).
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - branches have different number of select fields.
+    ///   - select fields at the same position have different data types.
+    pub(in crate::sql_processor) fn validate_union_all(
+        &self,
+        branch_field_types: &[Vec<Option<SqlType>>],
+    ) -> Result<()> {
+        let (first_types, rest) = match branch_field_types.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+        rest.iter().try_for_each(|types| {
+            if types.len() != first_types.len() {
+                return Err(SpringError::Sql(anyhow!(
+                    "UNION ALL branches have different number of select fields: {} and {}",
+                    first_types.len(),
+                    types.len()
+                )));
+            }
+            first_types
+                .iter()
+                .zip(types.iter())
+                .enumerate()
+                .try_for_each(|(i, pair)| match pair {
+                    (Some(t1), Some(t2)) if t1 != t2 => Err(SpringError::Sql(anyhow!(
+                        "UNION ALL branches have different data types in select field #{}: {} and {}",
+                        i + 1,
+                        t1,
+                        t2
+                    ))),
+                    _ => Ok(()),
+                })
+        })
+    }
+
+    /// A pump has an input queue for each upstream.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a stream is read more than once in the pump (by JOIN or UNION ALL).
+    pub(in crate::sql_processor) fn validate_upstreams(
+        &self,
+        query_plan: &QueryPlan,
+    ) -> Result<()> {
+        let upstreams = query_plan.upstreams();
+        match upstreams
+            .iter()
+            .enumerate()
+            .find(|(i, upstream)| upstreams[..*i].contains(upstream))
+        {
+            Some((_, upstream)) => Err(SpringError::Sql(anyhow!(
+                r#"stream "{}" is read more than once in a pump"#,
+                upstream
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Late rows of the upstream are inserted into `late_rows_stream` by column names.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the pump is not a GROUP BY aggregation with window clause.
+    ///   - `late_rows_stream` is not in the pipeline.
+    ///   - a column of `late_rows_stream` is not in the upstream with the same data type.
+    pub(in crate::sql_processor) fn validate_late_rows_stream(
+        &self,
+        late_rows_stream: &StreamName,
+        query_plan: &QueryPlan,
+    ) -> Result<()> {
+        let upstream = match (
+            &query_plan.upper_ops.group_aggr_window,
+            query_plan.upstreams().as_slice(),
+        ) {
+            (Some(_), [upstream]) => Ok(*upstream),
+            _ => Err(SpringError::Sql(anyhow!(
+                "LATE ROWS INTO requires GROUP BY aggregation with window clause (without JOIN)"
+            ))),
+        }?;
+
+        let upstream_model = self.pipeline.get_stream(upstream)?;
+        let upstream_columns = upstream_model.shape().columns();
+        let late_rows_stream_model = self.pipeline.get_stream(late_rows_stream)?;
+
+        late_rows_stream_model
+            .shape()
+            .columns()
+            .iter()
+            .try_for_each(|late_col| {
+                if upstream_columns
+                    .iter()
+                    .any(|col| col.column_data_type() == late_col.column_data_type())
+                {
+                    Ok(())
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        r#"column "{}" of late rows stream "{}" is not in upstream "{}" with the same data type{}"#,
+                        late_col.column_name(),
+                        late_rows_stream,
+                        upstream,
+                        did_you_mean(
+                            late_col.column_name().as_ref(),
+                            upstream_columns.iter().map(|col| col.column_name().as_ref())
+                        )
+                    )))
+                }
+            })
+    }
+
+    fn validate_new_name(&self, name: &str) -> Result<()> {
+        if self.pipeline.has_name(name) {
+            Err(SpringError::Sql(anyhow!(
+                r#"name "{}" already exists in pipeline"#,
+                name
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Columns and their types (None for unknown).
+type ColumnTypes = Vec<(ColumnName, Option<SqlType>)>;
+
+/// Types of columns which column references in a select syntax are resolved into.
+#[derive(Debug)]
+struct TypeScope {
+    /// Streams in FROM item, and pattern symbols of MATCH_RECOGNIZE qualifying columns of the stream in FROM item.
+    streams: Vec<(StreamName, Arc<StreamModel>)>,
+    /// Measures of MATCH_RECOGNIZE, qualified by its alias.
+    measures: Option<(StreamName, ColumnTypes)>,
+    /// Select fields referenced by their aliases from ORDER BY.
+    aliases: ColumnTypes,
+}
+
+impl TypeScope {
+    fn new(select_syntax: &SelectStreamSyntax, pipeline: &Pipeline) -> Result<Self> {
+        let mut streams = from_streams(&select_syntax.from_item)
+            .into_iter()
+            .map(|stream_name| Ok((stream_name.clone(), pipeline.get_stream(stream_name)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let match_recognize = match &select_syntax.match_recognize {
+            None => {
+                return Ok(Self {
+                    streams,
+                    measures: None,
+                    aliases: vec![],
+                })
+            }
+            Some(match_recognize) => match_recognize,
+        };
+
+        if let [(_, stream)] = streams.as_slice() {
+            let stream = stream.clone();
+            streams.extend(
+                match_recognize
+                    .pattern
+                    .symbols()
+                    .into_iter()
+                    .map(|symbol| (StreamName::new(symbol.to_string()), stream.clone())),
+            );
+        }
+        let mut scope = Self {
+            streams,
+            measures: None,
+            aliases: vec![],
+        };
+
+        if let Some(partition_by) = &match_recognize.partition_by {
+            let _ = scope.value_expr_type(partition_by)?;
+        }
+        match_recognize
+            .defines
+            .iter()
+            .try_for_each(|(symbol, condition)| {
+                scope.validate_condition(condition, &format!("DEFINE of `{}`", symbol))
+            })?;
+        let measures = match_recognize
+            .measures
+            .iter()
+            .map(|(expr, alias)| {
+                Ok((
+                    ColumnName::new(alias.to_string()),
+                    scope.value_expr_type(expr)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        scope.measures = Some((StreamName::new(match_recognize.alias.to_string()), measures));
+
+        Ok(scope)
+    }
+
+    fn select_field_type(&self, field: &SelectFieldSyntax) -> Result<Option<SqlType>> {
+        match field {
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ValueExpr::FunctionCall(FunctionCall::Unnest { array }),
+                ..
+            } => match self.value_expr_type(array)? {
+                Some(SqlType::Array(element_type)) => Ok(Some(*element_type)),
+                None => Ok(None),
+                Some(typ) => Err(SpringError::Sql(anyhow!(
+                    "`UNNEST` takes ARRAY argument but got {}",
+                    typ
+                ))),
+            },
+            SelectFieldSyntax::ValueExpr { value_expr, .. } => self.value_expr_type(value_expr),
+            SelectFieldSyntax::AggrExpr { aggr_expr, .. } => self.aggr_expr_type(aggr_expr),
+            SelectFieldSyntax::AnalyticExpr { analytic_expr, .. } => {
+                self.analytic_expr_type(analytic_expr)
+            }
+            SelectFieldSyntax::Wildcard { .. } => {
+                unreachable!("wildcard is expanded by SelectSyntaxAnalyzer")
+            }
+        }
+    }
+
+    fn validate_condition(&self, condition: &ValueExpr, clause: &str) -> Result<()> {
+        match self.value_expr_type(condition)? {
+            Some(typ) if typ != SqlType::boolean() => Err(SpringError::Sql(anyhow!(
+                "{} must be BOOLEAN but is {}",
+                clause,
+                typ
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn value_expr_type(&self, expr: &ValueExpr) -> Result<Option<SqlType>> {
+        match expr {
+            ValueExpr::Constant(SqlValue::Null) => Ok(None),
+            ValueExpr::Constant(SqlValue::NotNull(nn_sql_value)) => {
+                Ok(Some(nn_sql_value.sql_type()))
+            }
+            ValueExpr::UnaryOperator(UnaryOperator::Minus, child) => {
+                match self.value_expr_type(child)? {
+                    Some(typ) if !matches!(typ, SqlType::NumericComparable(_)) => {
+                        Err(SpringError::Sql(anyhow!("{} value cannot be negated", typ)))
+                    }
+                    typ => Ok(typ),
+                }
+            }
+            ValueExpr::BinaryExpr(binary_expr) => self.binary_expr_type(binary_expr),
+            ValueExpr::FunctionCall(function_call) => self.function_call_type(function_call),
+            ValueExpr::ColumnReference(colref) => self.column_type(colref),
+            ValueExpr::UnqualifiedColumnReference(column_name) => self
+                .aliases
+                .iter()
+                .find(|(alias, _)| alias == column_name)
+                .map(|(_, typ)| typ.clone())
+                .ok_or_else(|| {
+                    SpringError::Sql(anyhow!(
+                        "column `{}` is not found{}",
+                        column_name,
+                        did_you_mean(
+                            column_name.as_ref(),
+                            self.aliases.iter().map(|(alias, _)| alias.as_ref())
+                        )
+                    ))
+                }),
+        }
+    }
+
+    fn binary_expr_type(&self, binary_expr: &BinaryExpr<ValueExpr>) -> Result<Option<SqlType>> {
+        match binary_expr {
+            BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant { left, right }) => {
+                for operand in [left, right] {
+                    match self.value_expr_type(operand)? {
+                        Some(typ) if typ != SqlType::boolean() => {
+                            return Err(SpringError::Sql(anyhow!(
+                                "operand of AND must be BOOLEAN but is {}",
+                                typ
+                            )))
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(Some(SqlType::boolean()))
+            }
+            BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                left,
+                right,
+            }) => match (self.value_expr_type(left)?, self.value_expr_type(right)?) {
+                (Some(left_type), Some(right_type)) if !is_comparable(&left_type, &right_type) => {
+                    Err(SpringError::Sql(anyhow!(
+                        "{} and {} cannot be compared",
+                        left_type,
+                        right_type
+                    )))
+                }
+                _ => Ok(Some(SqlType::boolean())),
+            },
+            BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant { left, right }) => {
+                self.arithmetic_type("+", left, right)
+            }
+            BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant { left, right }) => {
+                self.arithmetic_type("*", left, right)
+            }
+        }
+    }
+
+    /// Operands are promoted by [NumericComparableType::promote()](crate::pipeline::relation::sql_type::NumericComparableType::promote).
+    fn arithmetic_type(
+        &self,
+        operator: &str,
+        left: &ValueExpr,
+        right: &ValueExpr,
+    ) -> Result<Option<SqlType>> {
+        let err = |left_type: &SqlType, right_type: &SqlType| {
+            SpringError::Sql(anyhow!(
+                "`{}` cannot be applied to {} and {}",
+                operator,
+                left_type,
+                right_type
+            ))
+        };
+
+        match (self.value_expr_type(left)?, self.value_expr_type(right)?) {
+            (Some(left_type), Some(right_type)) => match (&left_type, &right_type) {
+                (SqlType::NumericComparable(left_n), SqlType::NumericComparable(right_n)) => {
+                    match left_n.promote(right_n) {
+                        Some(NumericComparableType::Decimal { .. }) => {
+                            Ok(Some(decimal_arithmetic_type(operator, left_n, right_n)))
+                        }
+                        Some(promoted) => Ok(Some(SqlType::NumericComparable(promoted))),
+                        None => Err(err(&left_type, &right_type)),
+                    }
+                }
+                _ => Err(err(&left_type, &right_type)),
+            },
+            (Some(typ), None) | (None, Some(typ)) => match typ {
+                SqlType::NumericComparable(_) => Ok(None),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "`{}` cannot be applied to {}",
+                    operator,
+                    typ
+                ))),
+            },
+            (None, None) => Ok(None),
+        }
+    }
+
+    fn function_call_type(
+        &self,
+        function_call: &FunctionCall<ValueExpr>,
+    ) -> Result<Option<SqlType>> {
+        match function_call {
+            FunctionCall::DurationMillis {
+                duration_millis: arg,
+            }
+            | FunctionCall::DurationSecs { duration_secs: arg } => {
+                self.validate_argument(
+                    arg,
+                    &SqlType::big_int(),
+                    "DURATION_MILLIS / DURATION_SECS",
+                )?;
+                Ok(Some(SqlType::duration()))
+            }
+            FunctionCall::FloorTime { target, resolution } => {
+                for (arg, typ) in [
+                    (target, SqlType::timestamp()),
+                    (resolution, SqlType::duration()),
+                ] {
+                    match self.value_expr_type(arg)? {
+                        Some(arg_type) if arg_type != typ => {
+                            return Err(SpringError::Sql(anyhow!(
+                                "FLOOR_TIME takes (TIMESTAMP, DURATION) but got {} for {}",
+                                arg_type,
+                                typ
+                            )))
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(Some(SqlType::timestamp()))
+            }
+            FunctionCall::Hex { blob } | FunctionCall::Base64 { blob } => {
+                self.validate_argument(blob, &SqlType::blob(), "HEX / BASE64")?;
+                Ok(Some(SqlType::text()))
+            }
+            FunctionCall::FromHex { hex } => {
+                self.validate_argument(hex, &SqlType::text(), "FROM_HEX")?;
+                Ok(Some(SqlType::blob()))
+            }
+            FunctionCall::GetByte { blob, offset } => {
+                self.validate_argument(blob, &SqlType::blob(), "GET_BYTE")?;
+                self.validate_argument(offset, &SqlType::big_int(), "GET_BYTE")?;
+                Ok(Some(SqlType::big_int()))
+            }
+            FunctionCall::GetBits { blob, offset, len } => {
+                self.validate_argument(blob, &SqlType::blob(), "GET_BITS")?;
+                self.validate_argument(offset, &SqlType::big_int(), "GET_BITS")?;
+                self.validate_argument(len, &SqlType::big_int(), "GET_BITS")?;
+                Ok(Some(SqlType::big_int()))
+            }
+            FunctionCall::Length { target } => match self.value_expr_type(target)? {
+                Some(SqlType::BinaryComparable | SqlType::StringComparableLoose(_)) | None => {
+                    Ok(Some(SqlType::big_int()))
+                }
+                Some(typ) => Err(SpringError::Sql(anyhow!(
+                    "`LENGTH` takes BLOB or TEXT argument but got {}",
+                    typ
+                ))),
+            },
+            FunctionCall::Subscript { array, index } => {
+                self.validate_argument(index, &SqlType::big_int(), "array subscript")?;
+                match self.value_expr_type(array)? {
+                    Some(SqlType::Array(element_type)) => Ok(Some(*element_type)),
+                    None => Ok(None),
+                    Some(typ) => Err(SpringError::Sql(anyhow!(
+                        "subscript `[]` is applied to non-ARRAY value of {}",
+                        typ
+                    ))),
+                }
+            }
+            FunctionCall::FieldAccess { target, field } => match self.value_expr_type(target)? {
+                Some(SqlType::Struct(fields)) => fields
+                    .iter()
+                    .find(|(name, _)| name == field)
+                    .map(|(_, typ)| Some(typ.clone()))
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!(
+                            "field `{}` is not in {}{}",
+                            field,
+                            SqlType::Struct(fields.clone()),
+                            did_you_mean(field, fields.iter().map(|(name, _)| name.as_str()))
+                        ))
+                    }),
+                None => Ok(None),
+                Some(typ) => Err(SpringError::Sql(anyhow!(
+                    "field `{}` is accessed in non-STRUCT value of {}",
+                    field,
+                    typ
+                ))),
+            },
+            FunctionCall::Cardinality { array } => match self.value_expr_type(array)? {
+                Some(SqlType::Array(_)) | None => Ok(Some(SqlType::big_int())),
+                Some(typ) => Err(SpringError::Sql(anyhow!(
+                    "`CARDINALITY` takes ARRAY argument but got {}",
+                    typ
+                ))),
+            },
+            FunctionCall::AtTimeZone { target, zone } => {
+                self.validate_argument(target, &SqlType::timestamp_tz(), "AT TIME ZONE")?;
+                self.validate_argument(zone, &SqlType::text(), "AT TIME ZONE")?;
+                Ok(Some(SqlType::timestamp_tz()))
+            }
+            FunctionCall::ToChar { target, format } => {
+                self.validate_argument(target, &SqlType::timestamp_tz(), "TO_CHAR")?;
+                self.validate_argument(format, &SqlType::text(), "TO_CHAR")?;
+                Ok(Some(SqlType::text()))
+            }
+            FunctionCall::Unnest { .. } => Err(SpringError::Sql(anyhow!(
+                "`UNNEST` is only available as a select field by itself"
+            ))),
+            FunctionCall::WindowStart | FunctionCall::WindowEnd => Ok(Some(SqlType::timestamp())),
+            FunctionCall::EmitTiming => Ok(Some(SqlType::text())),
+            FunctionCall::UnresolvedUserDefined { name, .. } => {
+                Err(SpringError::Sql(anyhow!("unknown function `{}`", name)))
+            }
+            FunctionCall::UserDefined {
+                function,
+                arguments,
+            } => {
+                arguments
+                    .iter()
+                    .zip(function.arg_types())
+                    .try_for_each(|(arg, arg_type)| {
+                        self.validate_argument(arg, arg_type, function.name())
+                    })?;
+                Ok(Some(function.return_type().clone()))
+            }
+        }
+    }
+
+    fn aggr_expr_type(&self, aggr_expr: &AggrExpr) -> Result<Option<SqlType>> {
+        let function_name = match &aggr_expr.func {
+            AggregateFunctionParameter::Avg => "AVG",
+            AggregateFunctionParameter::UserDefined(function) => function.name(),
+        };
+        self.validate_argument(
+            &aggr_expr.aggregated,
+            &aggr_expr.func.arg_type(),
+            function_name,
+        )?;
+        Ok(Some(aggr_expr.func.return_type()))
+    }
+
+    fn analytic_expr_type(&self, analytic_expr: &AnalyticExpr) -> Result<Option<SqlType>> {
+        if let Some(partition_by) = &analytic_expr.partition_by {
+            let _ = self.value_expr_type(partition_by)?;
+        }
+        let argument_type = analytic_expr
+            .argument
+            .as_ref()
+            .map(|argument| self.value_expr_type(argument))
+            .transpose()?
+            .flatten();
+
+        match (analytic_expr.func, argument_type) {
+            (AnalyticFunctionParameter::RowNumber, _) => Ok(Some(SqlType::big_int())),
+            (
+                AnalyticFunctionParameter::Lag { .. } | AnalyticFunctionParameter::FirstValue,
+                typ,
+            ) => Ok(typ),
+            (AnalyticFunctionParameter::Sum, Some(SqlType::NumericComparable(n))) => {
+                Ok(Some(match n {
+                    NumericComparableType::I64Loose(_) => SqlType::big_int(),
+                    NumericComparableType::U64Loose(_) => SqlType::unsigned_big_int(),
+                    NumericComparableType::F32Loose(_) => SqlType::float(),
+                    NumericComparableType::F64Loose(_) => SqlType::double(),
+                    NumericComparableType::Decimal { scale, .. } => {
+                        SqlType::decimal(MAX_DECIMAL_PRECISION, scale)
+                    }
+                }))
+            }
+            (AnalyticFunctionParameter::Avg, Some(SqlType::NumericComparable(_))) => {
+                Ok(Some(SqlType::double()))
+            }
+            (AnalyticFunctionParameter::Sum | AnalyticFunctionParameter::Avg, Some(typ)) => Err(
+                SpringError::Sql(anyhow!("SUM and AVG cannot take {} argument", typ)),
+            ),
+            (AnalyticFunctionParameter::Sum, None) => Ok(None),
+            (AnalyticFunctionParameter::Avg, None) => Ok(Some(SqlType::double())),
+        }
+    }
+
+    fn validate_argument(
+        &self,
+        arg: &ValueExpr,
+        arg_type: &SqlType,
+        function_name: &str,
+    ) -> Result<()> {
+        match self.value_expr_type(arg)? {
+            Some(typ) if !is_convertible(&typ, arg_type) => Err(SpringError::Sql(anyhow!(
+                "`{}` takes {} argument but got {}",
+                function_name,
+                arg_type,
+                typ
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn column_type(&self, colref: &ColumnReference) -> Result<Option<SqlType>> {
+        let column_not_found = |candidates: Vec<&str>| {
+            SpringError::Sql(anyhow!(
+                r#"column "{}" is not in stream "{}"{}"#,
+                colref.column_name,
+                colref.stream_name,
+                did_you_mean(colref.column_name.as_ref(), candidates)
+            ))
+        };
+
+        if let Some((_, stream)) = self
+            .streams
+            .iter()
+            .find(|(stream_name, _)| stream_name == &colref.stream_name)
+        {
+            let cdts = stream
+                .shape()
+                .columns()
+                .iter()
+                .map(|coldef| coldef.column_data_type())
+                .collect::<Vec<_>>();
+            cdts.iter()
+                .find(|cdt| cdt.column_name() == &colref.column_name)
+                .map(|cdt| Some(cdt.sql_type().clone()))
+                .ok_or_else(|| {
+                    column_not_found(cdts.iter().map(|cdt| cdt.column_name().as_ref()).collect())
+                })
+        } else {
+            match &self.measures {
+                Some((alias, measures)) if alias == &colref.stream_name => measures
+                    .iter()
+                    .find(|(measure, _)| measure == &colref.column_name)
+                    .map(|(_, typ)| typ.clone())
+                    .ok_or_else(|| {
+                        column_not_found(
+                            measures
+                                .iter()
+                                .map(|(measure, _)| measure.as_ref())
+                                .collect(),
+                        )
+                    }),
+                _ => Err(SpringError::Sql(anyhow!(
+                    r#"stream "{}" is not in FROM item{}"#,
+                    colref.stream_name,
+                    did_you_mean(
+                        colref.stream_name.as_ref(),
+                        self.streams
+                            .iter()
+                            .map(|(stream_name, _)| stream_name.as_ref())
+                    )
+                ))),
+            }
+        }
+    }
+}
+
+fn from_streams(from_item: &FromItemSyntax) -> Vec<&StreamName> {
+    match from_item {
+        FromItemSyntax::StreamVariant(sub_from_item) => vec![&sub_from_item.stream_name],
+        FromItemSyntax::JoinVariant { left, right, .. } => {
+            let mut streams = vec![&left.stream_name];
+            streams.extend(from_streams(right));
+            streams
+        }
+    }
+}
+
+fn field_alias(field: &SelectFieldSyntax) -> Option<String> {
+    match field {
+        SelectFieldSyntax::ValueExpr {
+            alias: Some(alias), ..
+        }
+        | SelectFieldSyntax::AnalyticExpr {
+            alias: Some(alias), ..
+        } => Some(alias.to_string()),
+        SelectFieldSyntax::AggrExpr {
+            alias: Some(alias), ..
+        } => Some(alias.to_string()),
+        _ => None,
+    }
+}
+
+/// At most one `UNNEST(...)` in select_list, without aggregate or analytic functions.
+fn validate_unnest(select_syntax: &SelectStreamSyntax) -> Result<()> {
+    let n_unnest = select_syntax
+        .fields
+        .iter()
+        .filter(|field| {
+            matches!(
+                field,
+                SelectFieldSyntax::ValueExpr {
+                    value_expr: ValueExpr::FunctionCall(FunctionCall::Unnest { .. }),
+                    ..
+                }
+            )
+        })
+        .count();
+    let has_aggregation = select_syntax.fields.iter().any(|field| {
+        matches!(
+            field,
+            SelectFieldSyntax::AggrExpr { .. } | SelectFieldSyntax::AnalyticExpr { .. }
+        )
+    });
+
+    if n_unnest > 1 {
+        Err(SpringError::Sql(anyhow!(
+            "`UNNEST` can be used only once in select list"
+        )))
+    } else if n_unnest == 1 && has_aggregation {
+        Err(SpringError::Sql(anyhow!(
+            "`UNNEST` cannot be used with aggregate or analytic functions"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether a value of `from` type is converted into `to` type on insertion and function calls.
+///
+/// ARRAY elements and STRUCT fields are converted one by one. STRUCT fields are matched by name and missing fields are NULL.
+fn is_convertible(from: &SqlType, to: &SqlType) -> bool {
+    match (from, to) {
+        (SqlType::Array(from_element), SqlType::Array(to_element)) => {
+            is_convertible(from_element, to_element)
+        }
+        (SqlType::Struct(from_fields), SqlType::Struct(to_fields)) => {
+            to_fields.iter().all(|(name, to_type)| {
+                from_fields
+                    .iter()
+                    .filter(|(n, _)| n == name)
+                    .all(|(_, from_type)| is_convertible(from_type, to_type))
+            })
+        }
+        _ => matches!(
+            (from, to),
+            (SqlType::NumericComparable(_), SqlType::NumericComparable(_))
+                | (
+                    SqlType::StringComparableLoose(_) | SqlType::TimestampComparable(_),
+                    SqlType::StringComparableLoose(_) | SqlType::TimestampComparable(_)
+                )
+                | (SqlType::BooleanComparable, SqlType::BooleanComparable)
+                | (SqlType::DurationComparable, SqlType::DurationComparable)
+                | (SqlType::BinaryComparable, SqlType::BinaryComparable)
+        ),
+    }
+}
+
+/// Whether values of `left` and `right` types are compared by `=`.
+fn is_comparable(left: &SqlType, right: &SqlType) -> bool {
+    match (left, right) {
+        (SqlType::NumericComparable(left_n), SqlType::NumericComparable(right_n)) => {
+            left_n.promote(right_n).is_some()
+        }
+        _ => matches!(
+            (left, right),
+            (
+                SqlType::StringComparableLoose(_),
+                SqlType::StringComparableLoose(_)
+            ) | (SqlType::BooleanComparable, SqlType::BooleanComparable)
+                | (
+                    SqlType::TimestampComparable(_),
+                    SqlType::TimestampComparable(_)
+                )
+                | (SqlType::BinaryComparable, SqlType::BinaryComparable)
+        ),
+    }
+}
+
+/// `DECIMAL(precision, scale)` of `left + right` or `left * right`, where integers are regarded as DECIMAL with scale 0.
+fn decimal_arithmetic_type(
+    operator: &str,
+    left: &NumericComparableType,
+    right: &NumericComparableType,
+) -> SqlType {
+    let ((left_p, left_s), (right_p, right_s)) =
+        (exact_precision_scale(left), exact_precision_scale(right));
+
+    if operator == "*" {
+        SqlType::decimal(
+            (left_p + right_p).min(MAX_DECIMAL_PRECISION),
+            (left_s + right_s).min(MAX_DECIMAL_PRECISION),
+        )
+    } else {
+        let scale = left_s.max(right_s);
+        let integer_digits = (left_p - left_s).max(right_p - right_s) + 1;
+        SqlType::decimal((integer_digits + scale).min(MAX_DECIMAL_PRECISION), scale)
+    }
+}
+
+/// `(precision, scale)` of DECIMAL which holds any value of an exact numeric type.
+fn exact_precision_scale(typ: &NumericComparableType) -> (u8, u8) {
+    match typ {
+        NumericComparableType::I64Loose(I64LooseType::SmallInt)
+        | NumericComparableType::U64Loose(U64LooseType::SmallInt) => (5, 0),
+        NumericComparableType::I64Loose(I64LooseType::Integer)
+        | NumericComparableType::U64Loose(U64LooseType::Integer) => (10, 0),
+        NumericComparableType::I64Loose(I64LooseType::BigInt) => (19, 0),
+        NumericComparableType::U64Loose(U64LooseType::BigInt) => (20, 0),
+        NumericComparableType::Decimal { precision, scale } => (*precision, *scale),
+        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_) => {
+            unreachable!("approximate numbers are not promoted into DECIMAL")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_convertible() {
+        assert!(is_convertible(&SqlType::integer(), &SqlType::float()));
+        assert!(is_convertible(&SqlType::text(), &SqlType::timestamp()));
+        assert!(!is_convertible(&SqlType::text(), &SqlType::integer()));
+        assert!(!is_convertible(&SqlType::duration(), &SqlType::timestamp()));
+        assert!(is_convertible(
+            &SqlType::timestamp_tz(),
+            &SqlType::timestamp()
+        ));
+        assert!(is_convertible(
+            &SqlType::array(SqlType::integer()),
+            &SqlType::array(SqlType::big_int())
+        ));
+        assert!(!is_convertible(
+            &SqlType::array(SqlType::text()),
+            &SqlType::array(SqlType::integer())
+        ));
+        assert!(is_convertible(
+            &SqlType::structure(vec![("a".to_string(), SqlType::integer())]),
+            &SqlType::structure(vec![
+                ("a".to_string(), SqlType::double()),
+                ("b".to_string(), SqlType::text())
+            ])
+        ));
+        assert!(!is_convertible(
+            &SqlType::structure(vec![("a".to_string(), SqlType::text())]),
+            &SqlType::structure(vec![("a".to_string(), SqlType::integer())])
+        ));
+    }
+
+    #[test]
+    fn test_decimal_arithmetic_type() {
+        let dec = |precision, scale| NumericComparableType::Decimal { precision, scale };
+        let integer = NumericComparableType::I64Loose(I64LooseType::Integer);
+        let unsigned_small_int = NumericComparableType::U64Loose(U64LooseType::SmallInt);
+        let unsigned_big_int = NumericComparableType::U64Loose(U64LooseType::BigInt);
+
+        assert_eq!(
+            decimal_arithmetic_type("+", &dec(10, 2), &dec(5, 4)),
+            SqlType::decimal(13, 4)
+        );
+        assert_eq!(
+            decimal_arithmetic_type("*", &dec(10, 2), &integer),
+            SqlType::decimal(20, 2)
+        );
+        assert_eq!(
+            decimal_arithmetic_type("*", &dec(30, 20), &dec(30, 20)),
+            SqlType::decimal(38, 38)
+        );
+
+        // signed and unsigned integers
+        assert_eq!(
+            integer.promote(&unsigned_small_int),
+            Some(NumericComparableType::Decimal {
+                precision: MAX_DECIMAL_PRECISION,
+                scale: 0
+            })
+        );
+        assert_eq!(
+            decimal_arithmetic_type("+", &unsigned_small_int, &integer),
+            SqlType::decimal(11, 0)
+        );
+        assert_eq!(
+            decimal_arithmetic_type("*", &integer, &unsigned_big_int),
+            SqlType::decimal(30, 0)
+        );
+    }
+
+    #[test]
+    fn test_is_comparable() {
+        assert!(is_comparable(&SqlType::small_int(), &SqlType::big_int()));
+        assert!(is_comparable(
+            &SqlType::unsigned_big_int(),
+            &SqlType::decimal(10, 2)
+        ));
+        assert!(is_comparable(&SqlType::float(), &SqlType::double()));
+        assert!(!is_comparable(&SqlType::integer(), &SqlType::float()));
+        assert!(!is_comparable(&SqlType::decimal(10, 2), &SqlType::double()));
+        assert!(!is_comparable(&SqlType::duration(), &SqlType::duration()));
+        assert!(is_comparable(
+            &SqlType::timestamp(),
+            &SqlType::timestamp_tz()
+        ));
+    }
+}
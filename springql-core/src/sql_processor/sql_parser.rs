@@ -6,7 +6,7 @@ pub(in crate::sql_processor) mod parse_success;
 
 mod pest_parser_impl;
 
-use crate::error::Result;
+use crate::{error::Result, stream_engine::SqlValue};
 
 use self::parse_success::ParseSuccess;
 use self::pest_parser_impl::PestParserImpl;
@@ -15,9 +15,24 @@ use self::pest_parser_impl::PestParserImpl;
 pub(in crate::sql_processor) struct SqlParser(PestParserImpl);
 
 impl SqlParser {
-    pub(in crate::sql_processor) fn parse<S: Into<String>>(&self, sql: S) -> Result<ParseSuccess> {
+    /// Parses `sql` whose placeholders (`$1`, `:name`) are replaced with values in `bind_params`.
+    pub(in crate::sql_processor) fn parse<S: Into<String>>(
+        &self,
+        sql: S,
+        bind_params: &[(String, SqlValue)],
+    ) -> Result<ParseSuccess> {
         let sql = sql.into();
         log::debug!("start parsing SQL: {}", &sql);
-        self.0.parse(sql)
+        self.0.parse(sql, bind_params)
+    }
+
+    /// Whether `name` is an identifier (not a keyword).
+    pub(in crate::sql_processor) fn is_identifier(&self, name: &str) -> bool {
+        self.0.is_identifier(name)
+    }
+
+    /// Whether `name` is a built-in function (case-insensitive).
+    pub(in crate::sql_processor) fn is_builtin_function(&self, name: &str) -> bool {
+        self.0.is_builtin_function(name)
     }
 }
@@ -5,7 +5,7 @@ use crate::{
         name::PumpName, sink_writer_model::SinkWriterModel, source_reader_model::SourceReaderModel,
         stream_model::StreamModel,
     },
-    stream_engine::command::insert_plan::InsertPlan,
+    stream_engine::command::{insert_plan::InsertPlan, show_command::ShowCommand},
 };
 
 use super::syntax::SelectStreamSyntax;
@@ -19,11 +19,21 @@ pub(in crate::sql_processor) enum ParseSuccess {
     CreateSinkStream(StreamModel),
     CreateSinkWriter(SinkWriterModel),
     CreatePump(Box<CreatePump>),
+    /// `EXPLAIN CREATE PUMP ...`
+    ExplainCreatePump(Box<CreatePump>),
+    /// `EXPLAIN PIPELINE`
+    ExplainPipeline,
+    /// `SHOW ...` and `DESCRIBE STREAM ...`
+    Show(ShowCommand),
 }
 
 #[derive(Clone, PartialEq, Debug)]
 pub(in crate::sql_processor) struct CreatePump {
     pub(in crate::sql_processor) pump_name: PumpName,
     pub(in crate::sql_processor) select_stream_syntax: SelectStreamSyntax,
+    /// `UNION ALL SELECT ...` following `select_stream_syntax`.
+    pub(in crate::sql_processor) union_all_syntaxes: Vec<SelectStreamSyntax>,
     pub(in crate::sql_processor) insert_plan: InsertPlan,
+    /// `CREATE PUMP ...` statement as written (without trailing `;`), whose placeholders are replaced with literals of the bound values.
+    pub(in crate::sql_processor) ddl: String,
 }
@@ -10,39 +10,54 @@ use crate::expression::boolean_expression::numerical_function::NumericalFunction
 use crate::expression::boolean_expression::BinaryExpr;
 use crate::expression::function_call::FunctionCall;
 use crate::expression::operator::{BinaryOperator, UnaryOperator};
-use crate::expression::{AggrExpr, ValueExpr};
+use crate::expression::{AggrExpr, AnalyticExpr, ValueExpr};
 use crate::pipeline::field::field_name::ColumnReference;
 use crate::pipeline::name::{
-    AggrAlias, ColumnName, CorrelationAlias, PumpName, SinkWriterName, SourceReaderName,
-    StreamName, ValueAlias,
+    AggrAlias, ColumnName, CorrelationAlias, PatternSymbol, PumpName, SinkWriterName,
+    SourceReaderName, StreamName, ValueAlias,
 };
 use crate::pipeline::option::options_builder::OptionsBuilder;
+use crate::pipeline::pump_model::analytic_parameter::AnalyticFunctionParameter;
+use crate::pipeline::pump_model::match_recognize_parameter::{PatternQuantifier, RowPattern};
+use crate::pipeline::pump_model::order_limit_parameter::SortOrder;
 use crate::pipeline::pump_model::window_operation_parameter::aggregate::AggregateFunctionParameter;
 use crate::pipeline::pump_model::window_operation_parameter::join_parameter::JoinType;
-use crate::pipeline::pump_model::window_parameter::WindowParameter;
+use crate::pipeline::pump_model::window_parameter::{WindowParameter, WindowTimeDomain};
+use crate::pipeline::pump_model::window_trigger::WindowTrigger;
 use crate::pipeline::relation::column::column_constraint::ColumnConstraint;
 use crate::pipeline::relation::column::column_data_type::ColumnDataType;
 use crate::pipeline::relation::column::column_definition::ColumnDefinition;
-use crate::pipeline::relation::sql_type::SqlType;
+use crate::pipeline::relation::sql_type::{SqlType, MAX_DECIMAL_PRECISION};
 use crate::pipeline::sink_writer_model::sink_writer_type::SinkWriterType;
 use crate::pipeline::sink_writer_model::SinkWriterModel;
 use crate::pipeline::source_reader_model::source_reader_type::SourceReaderType;
 use crate::pipeline::source_reader_model::SourceReaderModel;
+use crate::pipeline::stream_model::stream_kind::StreamKind;
 use crate::pipeline::stream_model::stream_shape::StreamShape;
 use crate::pipeline::stream_model::StreamModel;
 use crate::sql_processor::sql_parser::syntax::{
-    ColumnConstraintSyntax, OptionSyntax, SelectStreamSyntax,
+    ColumnConstraintSyntax, DeduplicateSyntax, ElementAccessorSyntax, MatchRecognizeSyntax,
+    OptionSyntax, SelectStreamSyntax, SortSpecificationSyntax, SourceRowtimeSyntax,
 };
 use crate::stream_engine::command::insert_plan::InsertPlan;
+use crate::stream_engine::command::show_command::ShowCommand;
 use crate::stream_engine::time::duration::event_duration::EventDuration;
+use crate::stream_engine::time::duration::wall_clock_duration::WallClockDuration;
 use crate::stream_engine::time::duration::SpringDuration;
+use crate::stream_engine::time::timestamp::timestamp_format::TimestampFormat;
 use crate::stream_engine::{NnSqlValue, SqlValue};
-use anyhow::{anyhow, Context};
+use anyhow::anyhow;
 use generated_parser::{GeneratedParser, Rule};
-use helper::{parse_child, parse_child_seq, self_as_str, try_parse_child, FnParseParams};
+use helper::{
+    normalize_type_name, parse_child, parse_child_seq, self_as_str, try_parse_child, FnParseParams,
+};
 use ordered_float::OrderedFloat;
-use pest::{iterators::Pairs, Parser};
-use std::convert::identity;
+use pest::{
+    error::{ErrorVariant, InputLocation, LineColLocation},
+    iterators::Pairs,
+    Parser,
+};
+use std::{cmp::Reverse, convert::identity, iter};
 
 use super::parse_success::{CreatePump, ParseSuccess};
 use super::syntax::{
@@ -53,20 +68,164 @@ use super::syntax::{
 pub(super) struct PestParserImpl;
 
 impl PestParserImpl {
-    pub(super) fn parse<S: Into<String>>(&self, sql: S) -> Result<ParseSuccess> {
+    /// # Failures
+    ///
+    /// - [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax) when:
+    ///   - `sql` does not follow the grammar.
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a placeholder in `sql` is not bound by `bind_params`.
+    ///   - a parameter in `bind_params` does not appear in `sql`.
+    pub(super) fn parse<S: Into<String>>(
+        &self,
+        sql: S,
+        bind_params: &[(String, SqlValue)],
+    ) -> Result<ParseSuccess> {
         let sql = sql.into();
 
-        let pairs: Pairs<Rule> = GeneratedParser::parse(Rule::command, &sql)
-            .context("failed to parse SQL")
-            .map_err(SpringError::Sql)?;
+        let pairs: Pairs<Rule> =
+            GeneratedParser::parse(Rule::command, &sql).map_err(|e| Self::syntax_error(&sql, e))?;
+        Self::check_placeholders(&pairs, bind_params)?;
 
         let mut params = FnParseParams {
             sql: &sql,
             children_pairs: pairs.collect(),
             self_string: sql.clone(),
+            self_start: 0,
+            bind_params,
+        };
+
+        match parse_child(&mut params, Rule::command, Self::parse_command, identity)? {
+            // Created from the DDL with literals of the bound values to be the same as the pump created from exported DDL.
+            ParseSuccess::CreatePump(create_pump) if !bind_params.is_empty() => {
+                self.parse(create_pump.ddl.as_str(), &[]).map_err(|e| {
+                    SpringError::Sql(anyhow!(
+                        "bound values cannot be written as SQL literals in `{}`: {}",
+                        create_pump.ddl,
+                        e
+                    ))
+                })
+            }
+            parse_success => Ok(parse_success),
+        }
+    }
+
+    fn check_placeholders(pairs: &Pairs<Rule>, bind_params: &[(String, SqlValue)]) -> Result<()> {
+        let placeholders = pairs
+            .clone()
+            .flatten()
+            .filter(|pair| pair.as_rule() == Rule::placeholder)
+            .map(|pair| pair.as_str())
+            .collect::<Vec<_>>();
+
+        if let Some(unbound) = placeholders
+            .iter()
+            .find(|placeholder| !bind_params.iter().any(|(name, _)| name == *placeholder))
+        {
+            Err(SpringError::Sql(anyhow!(
+                "placeholder `{}` is not bound",
+                unbound
+            )))
+        } else if let Some((unused, _)) = bind_params
+            .iter()
+            .find(|(name, _)| !placeholders.contains(&name.as_str()))
+        {
+            Err(SpringError::Sql(anyhow!(
+                "parameter `{}` is bound but does not appear in SQL",
+                unused
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `self_string` whose placeholders are replaced with SQL literals of the bound values.
+    fn self_string_with_literals(params: &FnParseParams) -> String {
+        let mut placeholders = params
+            .children_pairs
+            .iter()
+            .flat_map(|pair| iter::once(pair.clone()).chain(pair.clone().into_inner().flatten()))
+            .filter(|pair| pair.as_rule() == Rule::placeholder)
+            .collect::<Vec<_>>();
+        placeholders.sort_by_key(|pair| Reverse(pair.as_span().start()));
+
+        let mut s = params.self_string.clone();
+        for placeholder in placeholders {
+            let value = params
+                .bind_params
+                .iter()
+                .find(|(name, _)| name == placeholder.as_str())
+                .map(|(_, value)| value);
+            if let Some(value) = value {
+                let span = placeholder.as_span();
+                s.replace_range(
+                    (span.start() - params.self_start)..(span.end() - params.self_start),
+                    &value.to_sql_literal(),
+                );
+            }
+        }
+        s
+    }
+
+    /// Converts pest's error into [SpringError::SqlSyntax](crate::error::SpringError::SqlSyntax).
+    fn syntax_error(sql: &str, e: pest::error::Error<Rule>) -> SpringError {
+        let (line, column) = match e.line_col {
+            LineColLocation::Pos(pos) | LineColLocation::Span(pos, _) => pos,
+        };
+        let pos = match e.location {
+            InputLocation::Pos(pos) | InputLocation::Span((pos, _)) => pos,
+        };
+        let rest = &sql[pos..];
+        let snippet = match rest.find(|c: char| !(c.is_alphanumeric() || c == '_')) {
+            Some(0) => rest
+                .chars()
+                .next()
+                .filter(|c| !c.is_whitespace())
+                .map(String::from)
+                .unwrap_or_default(),
+            Some(word_len) => rest[..word_len].to_string(),
+            None => rest.to_string(),
+        };
+
+        let mut expected = match e.variant {
+            ErrorVariant::ParsingError { positives, .. } => positives
+                .iter()
+                .map(|rule| format!("{:?}", rule))
+                .collect::<Vec<_>>(),
+            ErrorVariant::CustomError { .. } => vec![],
+        };
+        expected.sort();
+        expected.dedup();
+
+        SpringError::SqlSyntax {
+            line,
+            column,
+            snippet,
+            expected,
+        }
+    }
+
+    pub(super) fn is_identifier(&self, name: &str) -> bool {
+        GeneratedParser::parse(Rule::identifier, name)
+            .map(|mut pairs| matches!(pairs.next(), Some(pair) if pair.as_str() == name))
+            .unwrap_or(false)
+    }
+
+    /// Whether `name` is a built-in function (case-insensitive), parsed by its name instead of as a user-defined function.
+    pub(super) fn is_builtin_function(&self, name: &str) -> bool {
+        let parses_as = |rule: Rule, s: &str| {
+            GeneratedParser::parse(rule, s)
+                .map(|mut pairs| matches!(pairs.next(), Some(pair) if pair.as_str() == s))
+                .unwrap_or(false)
         };
 
-        parse_child(&mut params, Rule::command, Self::parse_command, identity)
+        // aggregate and analytic functions are named in the grammar
+        let named_in_grammar = [format!("{}()", name), format!("{}(0)", name)]
+            .iter()
+            .any(|call| {
+                parses_as(Rule::aggr_expr, call) || parses_as(Rule::analytic_function, call)
+            });
+
+        named_in_grammar || Self::builtin_function_call(&name.to_lowercase(), &[]).is_some()
     }
 
     /*
@@ -112,9 +271,32 @@ impl PestParserImpl {
             Self::parse_duration_constant,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::typed_constant,
+            Self::parse_typed_constant,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::placeholder,
+            Self::parse_placeholder,
+            identity,
+        )?)
         .ok_or_else(|| SpringError::Sql(anyhow!("Does not match any child rule of constant.",)))
     }
 
+    fn parse_placeholder(mut params: FnParseParams) -> Result<SqlValue> {
+        let bind_params = params.bind_params;
+        let placeholder = self_as_str(&mut params);
+
+        bind_params
+            .iter()
+            .find(|(name, _)| name == placeholder)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| SpringError::Sql(anyhow!("placeholder `{}` is not bound", placeholder)))
+    }
+
     fn parse_numeric_constant(mut params: FnParseParams) -> Result<SqlValue> {
         try_parse_child(
             &mut params,
@@ -192,7 +374,7 @@ impl PestParserImpl {
 
     fn parse_string_content(mut params: FnParseParams) -> Result<String> {
         let s = self_as_str(&mut params);
-        Ok(s.into())
+        Ok(s.replace("''", "'"))
     }
 
     fn parse_duration_constant(mut params: FnParseParams) -> Result<SqlValue> {
@@ -221,6 +403,27 @@ impl PestParserImpl {
         Ok(SqlValue::NotNull(NnSqlValue::Duration(event_duration)))
     }
 
+    fn parse_typed_constant(mut params: FnParseParams) -> Result<SqlValue> {
+        let sql_type = parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            identity,
+        )?;
+        let text = parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            identity,
+        )?;
+        match text {
+            SqlValue::NotNull(NnSqlValue::Text(text)) => {
+                SqlValue::from_typed_literal(&sql_type, &text)
+            }
+            _ => unreachable!("string constant is TEXT"),
+        }
+    }
+
     fn parse_duration_function(mut params: FnParseParams) -> Result<DurationFunction> {
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_ref() {
@@ -305,6 +508,24 @@ impl PestParserImpl {
             Self::parse_create_pump_command,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::explain_command,
+            Self::parse_explain_command,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::show_command,
+            Self::parse_show_command,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::describe_stream_command,
+            Self::parse_describe_stream_command,
+            identity,
+        )?)
         .ok_or_else(|| {
             SpringError::Sql(anyhow!(
                 "Does not match any child rule of command: {}",
@@ -332,13 +553,104 @@ impl PestParserImpl {
             &Self::parse_column_definition,
             &identity,
         )?;
+        let rowtime_syntax = try_parse_child(
+            &mut params,
+            Rule::source_rowtime_clause,
+            Self::parse_source_rowtime_clause,
+            identity,
+        )?;
+
+        let column_definitions = match rowtime_syntax {
+            Some(rowtime_syntax) => Self::apply_source_rowtime(column_definitions, rowtime_syntax)?,
+            None => column_definitions,
+        };
 
         let stream_shape = StreamShape::new(column_definitions)?;
-        let source_stream = StreamModel::new(source_stream_name, stream_shape);
+        let source_stream = StreamModel::new(source_stream_name, StreamKind::Source, stream_shape);
 
         Ok(ParseSuccess::CreateSourceStream(source_stream))
     }
 
+    fn parse_source_rowtime_clause(mut params: FnParseParams) -> Result<SourceRowtimeSyntax> {
+        try_parse_child(
+            &mut params,
+            Rule::rowtime_ingestion_time,
+            |_| Ok(SourceRowtimeSyntax::IngestionTime),
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::rowtime_event_time,
+            Self::parse_rowtime_event_time,
+            identity,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of source ROWTIME clause: {}",
+                params.sql
+            ))
+        })
+    }
+
+    fn parse_rowtime_event_time(mut params: FnParseParams) -> Result<SourceRowtimeSyntax> {
+        let column_name = parse_child(
+            &mut params,
+            Rule::column_name,
+            Self::parse_column_name,
+            identity,
+        )?;
+        Ok(SourceRowtimeSyntax::EventTime(column_name))
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `ROWTIME INGESTION_TIME` is specified but a column has ROWTIME constraint.
+    ///   - `ROWTIME EVENT_TIME col` is specified but `col` is not in `column_definitions`.
+    fn apply_source_rowtime(
+        column_definitions: Vec<ColumnDefinition>,
+        rowtime_syntax: SourceRowtimeSyntax,
+    ) -> Result<Vec<ColumnDefinition>> {
+        match rowtime_syntax {
+            SourceRowtimeSyntax::IngestionTime => {
+                if let Some(rowtime_coldef) = column_definitions.iter().find(|coldef| {
+                    coldef
+                        .column_constraints()
+                        .contains(&ColumnConstraint::Rowtime)
+                }) {
+                    Err(SpringError::Sql(anyhow!(
+                        r#"ROWTIME INGESTION_TIME is specified but column "{}" has ROWTIME constraint"#,
+                        rowtime_coldef.column_name()
+                    )))
+                } else {
+                    Ok(column_definitions)
+                }
+            }
+            SourceRowtimeSyntax::EventTime(rowtime_col) => {
+                if column_definitions
+                    .iter()
+                    .any(|coldef| coldef.column_name() == &rowtime_col)
+                {
+                    Ok(column_definitions
+                        .into_iter()
+                        .map(|coldef| {
+                            if coldef.column_name() == &rowtime_col {
+                                coldef.with_rowtime()
+                            } else {
+                                coldef
+                            }
+                        })
+                        .collect())
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        r#"ROWTIME column "{}" is not in stream definition"#,
+                        rowtime_col
+                    )))
+                }
+            }
+        }
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * CREATE SOURCE READER
@@ -410,7 +722,7 @@ impl PestParserImpl {
         )?;
 
         let stream_shape = StreamShape::new(column_definitions)?;
-        let stream = StreamModel::new(stream_name, stream_shape);
+        let stream = StreamModel::new(stream_name, StreamKind::Native, stream_shape);
 
         Ok(ParseSuccess::CreateStream(stream))
     }
@@ -436,7 +748,7 @@ impl PestParserImpl {
         )?;
 
         let stream_shape = StreamShape::new(column_definitions)?;
-        let sink_stream = StreamModel::new(sink_stream_name, stream_shape);
+        let sink_stream = StreamModel::new(sink_stream_name, StreamKind::Sink, stream_shape);
 
         Ok(ParseSuccess::CreateSinkStream(sink_stream))
     }
@@ -498,6 +810,8 @@ impl PestParserImpl {
      */
 
     fn parse_create_pump_command(mut params: FnParseParams) -> Result<ParseSuccess> {
+        let ddl = Self::self_string_with_literals(&params).trim().to_string();
+
         let pump_name = parse_child(
             &mut params,
             Rule::pump_name,
@@ -522,14 +836,119 @@ impl PestParserImpl {
             Self::parse_select_stream,
             identity,
         )?;
+        let union_all_syntaxes = parse_child_seq(
+            &mut params,
+            Rule::select_stream_command,
+            &Self::parse_select_stream,
+            &identity,
+        )?;
+        let late_rows_stream = try_parse_child(
+            &mut params,
+            Rule::late_rows_clause,
+            Self::parse_late_rows_clause,
+            identity,
+        )?;
 
         Ok(ParseSuccess::CreatePump(Box::new(CreatePump {
             pump_name,
             select_stream_syntax,
-            insert_plan: InsertPlan::new(into_stream, insert_column_names),
+            union_all_syntaxes,
+            insert_plan: InsertPlan::new(into_stream, insert_column_names, late_rows_stream),
+            ddl,
         })))
     }
 
+    fn parse_late_rows_clause(mut params: FnParseParams) -> Result<StreamName> {
+        parse_child(
+            &mut params,
+            Rule::stream_name,
+            Self::parse_stream_name,
+            identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * EXPLAIN
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_explain_command(mut params: FnParseParams) -> Result<ParseSuccess> {
+        try_parse_child(
+            &mut params,
+            Rule::create_pump_command,
+            Self::parse_create_pump_command,
+            |create_pump| match create_pump {
+                ParseSuccess::CreatePump(create_pump) => {
+                    ParseSuccess::ExplainCreatePump(create_pump)
+                }
+                _ => unreachable!("create_pump_command is parsed into CreatePump"),
+            },
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::explain_pipeline,
+            |_| Ok(ParseSuccess::ExplainPipeline),
+            identity,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of EXPLAIN: {}",
+                params.sql
+            ))
+        })
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * SHOW / DESCRIBE
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_show_command(mut params: FnParseParams) -> Result<ParseSuccess> {
+        let show_command = try_parse_child(
+            &mut params,
+            Rule::show_streams,
+            |_| Ok(ShowCommand::Streams),
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::show_pumps,
+            |_| Ok(ShowCommand::Pumps),
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::show_source_readers,
+            |_| Ok(ShowCommand::SourceReaders),
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::show_sink_writers,
+            |_| Ok(ShowCommand::SinkWriters),
+            identity,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of SHOW: {}",
+                params.sql
+            ))
+        })?;
+        Ok(ParseSuccess::Show(show_command))
+    }
+
+    fn parse_describe_stream_command(mut params: FnParseParams) -> Result<ParseSuccess> {
+        let stream_name = parse_child(
+            &mut params,
+            Rule::stream_name,
+            Self::parse_stream_name,
+            identity,
+        )?;
+        Ok(ParseSuccess::Show(ShowCommand::DescribeStream(stream_name)))
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * SELECT
@@ -537,6 +956,8 @@ impl PestParserImpl {
      */
 
     fn parse_select_stream(mut params: FnParseParams) -> Result<SelectStreamSyntax> {
+        let distinct =
+            try_parse_child(&mut params, Rule::select_distinct, |_| Ok(()), identity)?.is_some();
         let fields = parse_child_seq(
             &mut params,
             Rule::select_field,
@@ -549,6 +970,18 @@ impl PestParserImpl {
             Self::parse_from_item,
             identity,
         )?;
+        let match_recognize = try_parse_child(
+            &mut params,
+            Rule::match_recognize_clause,
+            Self::parse_match_recognize_clause,
+            identity,
+        )?;
+        let deduplicate = try_parse_child(
+            &mut params,
+            Rule::deduplicate_clause,
+            Self::parse_deduplicate_clause,
+            identity,
+        )?;
         let grouping_element = try_parse_child(
             &mut params,
             Rule::grouping_element,
@@ -561,17 +994,75 @@ impl PestParserImpl {
             Self::parse_window_clause,
             identity,
         )?;
+        let idle_timeout = try_parse_child(
+            &mut params,
+            Rule::idle_timeout_clause,
+            Self::parse_idle_timeout_clause,
+            identity,
+        )?;
+        let emit_trigger = try_parse_child(
+            &mut params,
+            Rule::emit_clause,
+            Self::parse_emit_clause,
+            identity,
+        )?;
+
+        let order_by = try_parse_child(
+            &mut params,
+            Rule::order_by_clause,
+            Self::parse_order_by_clause,
+            identity,
+        )?
+        .unwrap_or_default();
+        let limit = try_parse_child(
+            &mut params,
+            Rule::limit_clause,
+            Self::parse_limit_clause,
+            identity,
+        )?;
 
         Ok(SelectStreamSyntax {
+            distinct,
             fields,
             from_item,
+            match_recognize,
+            deduplicate,
             grouping_element,
             window_clause,
+            idle_timeout,
+            emit_trigger,
+            order_by,
+            limit,
         })
     }
 
     fn parse_select_field(mut params: FnParseParams) -> Result<SelectFieldSyntax> {
         try_parse_child(
+            &mut params,
+            Rule::select_wildcard,
+            Self::parse_select_wildcard,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::analytic_expr,
+            Self::parse_analytic_expr,
+            identity,
+        )?
+        .map(|analytic_expr| {
+            let alias = try_parse_child(
+                &mut params,
+                Rule::value_alias,
+                Self::parse_value_alias,
+                identity,
+            )?;
+            Ok(SelectFieldSyntax::AnalyticExpr {
+                analytic_expr,
+                alias,
+            })
+        })
+        .transpose()?)
+        .or(try_parse_child(
             &mut params,
             Rule::value_expr,
             Self::parse_value_expr,
@@ -586,7 +1077,7 @@ impl PestParserImpl {
             )?;
             Ok(SelectFieldSyntax::ValueExpr { value_expr, alias })
         })
-        .transpose()?
+        .transpose()?)
         .or(try_parse_child(
             &mut params,
             Rule::aggr_expr,
@@ -614,6 +1105,16 @@ impl PestParserImpl {
         })
     }
 
+    fn parse_select_wildcard(mut params: FnParseParams) -> Result<SelectFieldSyntax> {
+        let stream_name = try_parse_child(
+            &mut params,
+            Rule::correlation,
+            Self::parse_correlation,
+            identity,
+        )?;
+        Ok(SelectFieldSyntax::Wildcard { stream_name })
+    }
+
     fn parse_from_item(mut params: FnParseParams) -> Result<FromItemSyntax> {
         let sub_from_item = parse_child(
             &mut params,
@@ -711,6 +1212,12 @@ impl PestParserImpl {
             Self::parse_sliding_window_clause,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::rows_window_clause,
+            Self::parse_rows_window_clause,
+            identity,
+        )?)
         .ok_or_else(|| SpringError::Sql(anyhow!("Failed to parse window clause: {}", params.sql)))
     }
 
@@ -731,9 +1238,18 @@ impl PestParserImpl {
         )?;
         let allowed_delay = allowed_delay.to_event_duration()?;
 
+        let time_domain = try_parse_child(
+            &mut params,
+            Rule::window_time_domain,
+            Self::parse_window_time_domain,
+            identity,
+        )?
+        .unwrap_or(WindowTimeDomain::EventTime);
+
         Ok(WindowParameter::TimedFixedWindow {
             length,
             allowed_delay,
+            time_domain,
         })
     }
 
@@ -762,20 +1278,62 @@ impl PestParserImpl {
         )?;
         let allowed_delay = allowed_delay.to_event_duration()?;
 
+        let time_domain = try_parse_child(
+            &mut params,
+            Rule::window_time_domain,
+            Self::parse_window_time_domain,
+            identity,
+        )?
+        .unwrap_or(WindowTimeDomain::EventTime);
+
         Ok(WindowParameter::TimedSlidingWindow {
             length,
             period,
             allowed_delay,
+            time_domain,
         })
     }
 
-    fn parse_window_length(mut params: FnParseParams) -> Result<SqlValue> {
-        parse_child(
+    fn parse_window_time_domain(mut params: FnParseParams) -> Result<WindowTimeDomain> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_ref() {
+            "event_time" => Ok(WindowTimeDomain::EventTime),
+            "processing_time" => Ok(WindowTimeDomain::ProcessingTime),
+            _ => Err(SpringError::Sql(anyhow!(
+                "window time domain `{}` is invalid",
+                s
+            ))),
+        }
+    }
+
+    fn parse_rows_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
+        let length = parse_child(
             &mut params,
-            Rule::duration_constant,
-            Self::parse_duration_constant,
+            Rule::window_rows_length,
+            Self::parse_window_rows,
             identity,
-        )
+        )?;
+
+        let opt_period = try_parse_child(
+            &mut params,
+            Rule::window_rows_period,
+            Self::parse_window_rows,
+            identity,
+        )?;
+
+        match opt_period {
+            Some(period) => Ok(WindowParameter::CountSlidingWindow { length, period }),
+            None => Ok(WindowParameter::CountFixedWindow { length }),
+        }
+    }
+
+    fn parse_window_length(mut params: FnParseParams) -> Result<SqlValue> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )
     }
     fn parse_window_period(mut params: FnParseParams) -> Result<SqlValue> {
         parse_child(
@@ -793,6 +1351,139 @@ impl PestParserImpl {
             identity,
         )
     }
+    fn parse_window_rows(mut params: FnParseParams) -> Result<u64> {
+        let n = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .to_i64()?;
+
+        if n > 0 {
+            Ok(n as u64)
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "number of rows in ROWS WINDOW must be positive but got {}",
+                n
+            )))
+        }
+    }
+
+    fn parse_idle_timeout_clause(mut params: FnParseParams) -> Result<WallClockDuration> {
+        let idle_timeout = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?
+        .to_event_duration()?;
+
+        Ok(WallClockDuration::from_std(*idle_timeout.as_std()))
+    }
+
+    fn parse_emit_clause(mut params: FnParseParams) -> Result<WindowTrigger> {
+        try_parse_child(
+            &mut params,
+            Rule::emit_every_rows,
+            Self::parse_emit_every_rows,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::emit_every_interval,
+            Self::parse_emit_every_interval,
+            identity,
+        )?)
+        .ok_or_else(|| SpringError::Sql(anyhow!("Failed to parse emit clause: {}", params.sql)))
+    }
+
+    fn parse_emit_every_rows(mut params: FnParseParams) -> Result<WindowTrigger> {
+        let n = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .to_i64()?;
+
+        if n > 0 {
+            Ok(WindowTrigger::EveryRows(n as u64))
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "number of rows in EMIT EVERY must be positive but got {}",
+                n
+            )))
+        }
+    }
+
+    fn parse_order_by_clause(mut params: FnParseParams) -> Result<Vec<SortSpecificationSyntax>> {
+        parse_child_seq(
+            &mut params,
+            Rule::sort_specification,
+            &Self::parse_sort_specification,
+            &identity,
+        )
+    }
+
+    fn parse_sort_specification(mut params: FnParseParams) -> Result<SortSpecificationSyntax> {
+        let expr = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        let order = try_parse_child(
+            &mut params,
+            Rule::sort_order,
+            Self::parse_sort_order,
+            identity,
+        )?
+        .unwrap_or(SortOrder::Asc);
+        Ok(SortSpecificationSyntax { expr, order })
+    }
+
+    fn parse_sort_order(mut params: FnParseParams) -> Result<SortOrder> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_limit_clause(mut params: FnParseParams) -> Result<u64> {
+        let n = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .to_i64()?;
+
+        if n > 0 {
+            Ok(n as u64)
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "number of rows in LIMIT must be positive but got {}",
+                n
+            )))
+        }
+    }
+
+    fn parse_emit_every_interval(mut params: FnParseParams) -> Result<WindowTrigger> {
+        let interval = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?
+        .to_event_duration()?;
+
+        Ok(WindowTrigger::EveryInterval(WallClockDuration::from_std(
+            *interval.as_std(),
+        )))
+    }
 
     /*
      * ================================================================================================
@@ -862,7 +1553,7 @@ impl PestParserImpl {
     }
 
     fn parse_sub_value_expr(mut params: FnParseParams) -> Result<ValueExpr> {
-        try_parse_child(
+        let expr = try_parse_child(
             &mut params,
             Rule::constant,
             Self::parse_constant,
@@ -872,7 +1563,7 @@ impl PestParserImpl {
             &mut params,
             Rule::column_reference,
             Self::parse_column_reference,
-            ValueExpr::ColumnReference,
+            identity,
         )?)
         .or({
             if let Some(uni_op) = try_parse_child(
@@ -899,131 +1590,767 @@ impl PestParserImpl {
         )?)
         .ok_or_else(|| {
             SpringError::Sql(anyhow!("Does not match any child rule of sub_value_expr.",))
+        })?;
+
+        let accessors = parse_child_seq(
+            &mut params,
+            Rule::element_accessor,
+            &Self::parse_element_accessor,
+            &identity,
+        )?;
+        let expr = accessors.into_iter().fold(expr, |expr, accessor| {
+            ValueExpr::FunctionCall(match accessor {
+                ElementAccessorSyntax::Subscript(index) => FunctionCall::Subscript {
+                    array: Box::new(expr),
+                    index: Box::new(index),
+                },
+                ElementAccessorSyntax::Field(field) => FunctionCall::FieldAccess {
+                    target: Box::new(expr),
+                    field,
+                },
+            })
+        });
+
+        let zones = parse_child_seq(
+            &mut params,
+            Rule::at_time_zone,
+            &Self::parse_at_time_zone,
+            &identity,
+        )?;
+        Ok(zones.into_iter().fold(expr, |expr, zone| {
+            ValueExpr::FunctionCall(FunctionCall::AtTimeZone {
+                target: Box::new(expr),
+                zone: Box::new(zone),
+            })
+        }))
+    }
+
+    fn parse_at_time_zone(mut params: FnParseParams) -> Result<ValueExpr> {
+        try_parse_child(
+            &mut params,
+            Rule::constant,
+            Self::parse_constant,
+            ValueExpr::Constant,
+        )?
+        .map_or_else(
+            || {
+                parse_child(
+                    &mut params,
+                    Rule::column_reference,
+                    Self::parse_column_reference,
+                    identity,
+                )
+            },
+            Ok,
+        )
+    }
+
+    fn parse_element_accessor(mut params: FnParseParams) -> Result<ElementAccessorSyntax> {
+        try_parse_child(
+            &mut params,
+            Rule::subscript,
+            Self::parse_subscript,
+            ElementAccessorSyntax::Subscript,
+        )?
+        .map_or_else(
+            || {
+                parse_child(
+                    &mut params,
+                    Rule::field_accessor,
+                    Self::parse_field_accessor,
+                    ElementAccessorSyntax::Field,
+                )
+            },
+            Ok,
+        )
+    }
+
+    fn parse_subscript(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )
+    }
+
+    fn parse_field_accessor(mut params: FnParseParams) -> Result<String> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            identity,
+        )
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Column Reference
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_column_reference(mut params: FnParseParams) -> Result<ValueExpr> {
+        let correlation = try_parse_child(
+            &mut params,
+            Rule::correlation,
+            Self::parse_correlation,
+            identity,
+        )?;
+        let column_name = parse_child(
+            &mut params,
+            Rule::column_name,
+            Self::parse_column_name,
+            identity,
+        )?;
+        Ok(match correlation {
+            Some(correlation) => {
+                ValueExpr::ColumnReference(ColumnReference::new(correlation, column_name))
+            }
+            None => ValueExpr::UnqualifiedColumnReference(column_name),
         })
     }
 
-    /*
-     * ----------------------------------------------------------------------------
-     * Column Reference
-     * ----------------------------------------------------------------------------
-     */
+    /*
+     * ----------------------------------------------------------------------------
+     * Function
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_function_call(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
+        let function_name = parse_child(
+            &mut params,
+            Rule::function_name,
+            Self::parse_function_name,
+            identity,
+        )?;
+        let parameters = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+
+        Self::builtin_function_call(&function_name.to_lowercase(), &parameters).unwrap_or(Ok(
+            FunctionCall::UnresolvedUserDefined {
+                name: function_name,
+                arguments: parameters,
+            },
+        ))
+    }
+
+    /// # Returns
+    ///
+    /// None if `name` (lower-case) is not a built-in function.
+    fn builtin_function_call(
+        name: &str,
+        parameters: &[ValueExpr],
+    ) -> Option<Result<FunctionCall<ValueExpr>>> {
+        let function_call = match name {
+            "duration_millis" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationMillis {
+                        duration_millis: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_millis() takes exactly one parameter (duration_millis)."
+                    )))
+                }
+            }
+            "duration_secs" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationSecs {
+                        duration_secs: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_secs() takes exactly one parameter (duration_secs)."
+                    )))
+                }
+            }
+            "floor_time" => {
+                if parameters.len() == 2 {
+                    Ok(FunctionCall::FloorTime {
+                        target: Box::new(parameters[0].clone()),
+                        resolution: Box::new(parameters[1].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "floor_time() takes exactly two parameters (target, resolution)."
+                    )))
+                }
+            }
+            "hex" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Hex {
+                        blob: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "hex() takes exactly one parameter (blob)."
+                    )))
+                }
+            }
+            "from_hex" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::FromHex {
+                        hex: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "from_hex() takes exactly one parameter (hex)."
+                    )))
+                }
+            }
+            "base64" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Base64 {
+                        blob: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "base64() takes exactly one parameter (blob)."
+                    )))
+                }
+            }
+            "get_byte" => {
+                if parameters.len() == 2 {
+                    Ok(FunctionCall::GetByte {
+                        blob: Box::new(parameters[0].clone()),
+                        offset: Box::new(parameters[1].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "get_byte() takes exactly two parameters (blob, offset)."
+                    )))
+                }
+            }
+            "get_bits" => {
+                if parameters.len() == 3 {
+                    Ok(FunctionCall::GetBits {
+                        blob: Box::new(parameters[0].clone()),
+                        offset: Box::new(parameters[1].clone()),
+                        len: Box::new(parameters[2].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "get_bits() takes exactly three parameters (blob, offset, len)."
+                    )))
+                }
+            }
+            "length" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Length {
+                        target: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "length() takes exactly one parameter (target)."
+                    )))
+                }
+            }
+            "cardinality" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Cardinality {
+                        array: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "cardinality() takes exactly one parameter (array)."
+                    )))
+                }
+            }
+            "to_char" => {
+                if parameters.len() == 2 {
+                    Ok(FunctionCall::ToChar {
+                        target: Box::new(parameters[0].clone()),
+                        format: Box::new(parameters[1].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "to_char() takes exactly two parameters (timestamp, format)."
+                    )))
+                }
+            }
+            "unnest" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Unnest {
+                        array: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "unnest() takes exactly one parameter (array)."
+                    )))
+                }
+            }
+            "window_start" => {
+                if parameters.is_empty() {
+                    Ok(FunctionCall::WindowStart)
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "window_start() does not take any parameter."
+                    )))
+                }
+            }
+            "window_end" => {
+                if parameters.is_empty() {
+                    Ok(FunctionCall::WindowEnd)
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "window_end() does not take any parameter."
+                    )))
+                }
+            }
+            "emit_timing" => {
+                if parameters.is_empty() {
+                    Ok(FunctionCall::EmitTiming)
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "emit_timing() does not take any parameter."
+                    )))
+                }
+            }
+            "floor" => Err(SpringError::Sql(anyhow!("floor() is not supported yet."))),
+            _ => return None,
+        };
+        Some(function_call)
+    }
+
+    fn parse_function_name(mut params: FnParseParams) -> Result<String> {
+        Ok(self_as_str(&mut params).to_string())
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Aggregate
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_aggr_expr(mut params: FnParseParams) -> Result<AggrExpr> {
+        let func = parse_child(
+            &mut params,
+            Rule::aggregate_name,
+            Self::parse_aggregate_name,
+            identity,
+        )?;
+        let aggregated = parse_child(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        Ok(AggrExpr { func, aggregated })
+    }
+
+    fn parse_aggregate_name(mut params: FnParseParams) -> Result<AggregateFunctionParameter> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "avg" => Ok(AggregateFunctionParameter::Avg),
+            _ => Err(SpringError::Sql(anyhow!(
+                "unknown aggregate function {}",
+                s.to_lowercase()
+            ))),
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * MATCH_RECOGNIZE
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_match_recognize_clause(mut params: FnParseParams) -> Result<MatchRecognizeSyntax> {
+        let partition_by = try_parse_child(
+            &mut params,
+            Rule::partition_clause,
+            Self::parse_partition_clause,
+            identity,
+        )?;
+        try_parse_child(
+            &mut params,
+            Rule::analytic_order_clause,
+            |_| Ok(()),
+            identity,
+        )?;
+        let measures = parse_child(
+            &mut params,
+            Rule::measures_clause,
+            Self::parse_measures_clause,
+            identity,
+        )?;
+        let pattern = parse_child(
+            &mut params,
+            Rule::pattern_clause,
+            Self::parse_pattern_clause,
+            identity,
+        )?;
+        let within = parse_child(
+            &mut params,
+            Rule::within_clause,
+            Self::parse_within_clause,
+            identity,
+        )?;
+        let defines = try_parse_child(
+            &mut params,
+            Rule::define_clause,
+            Self::parse_define_clause,
+            identity,
+        )?
+        .unwrap_or_default();
+        let alias = parse_child(
+            &mut params,
+            Rule::correlation_alias,
+            Self::parse_correlation_alias,
+            identity,
+        )?;
+
+        Ok(MatchRecognizeSyntax {
+            partition_by,
+            measures,
+            pattern,
+            within,
+            defines,
+            alias,
+        })
+    }
+
+    fn parse_measures_clause(mut params: FnParseParams) -> Result<Vec<(ValueExpr, ValueAlias)>> {
+        parse_child_seq(&mut params, Rule::measure, &Self::parse_measure, &identity)
+    }
+
+    fn parse_measure(mut params: FnParseParams) -> Result<(ValueExpr, ValueAlias)> {
+        let value_expr = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        let alias = parse_child(
+            &mut params,
+            Rule::value_alias,
+            Self::parse_value_alias,
+            identity,
+        )?;
+        Ok((value_expr, alias))
+    }
+
+    fn parse_pattern_clause(mut params: FnParseParams) -> Result<RowPattern> {
+        parse_child(
+            &mut params,
+            Rule::pattern_alternation,
+            Self::parse_pattern_alternation,
+            identity,
+        )
+    }
+
+    fn parse_pattern_alternation(mut params: FnParseParams) -> Result<RowPattern> {
+        let mut patterns = parse_child_seq(
+            &mut params,
+            Rule::pattern_concatenation,
+            &Self::parse_pattern_concatenation,
+            &identity,
+        )?;
+        if patterns.len() == 1 {
+            Ok(patterns.remove(0))
+        } else {
+            Ok(RowPattern::Alternation(patterns))
+        }
+    }
+
+    fn parse_pattern_concatenation(mut params: FnParseParams) -> Result<RowPattern> {
+        let mut patterns = parse_child_seq(
+            &mut params,
+            Rule::pattern_quantified,
+            &Self::parse_pattern_quantified,
+            &identity,
+        )?;
+        if patterns.len() == 1 {
+            Ok(patterns.remove(0))
+        } else {
+            Ok(RowPattern::Concatenation(patterns))
+        }
+    }
+
+    fn parse_pattern_quantified(mut params: FnParseParams) -> Result<RowPattern> {
+        let pattern = parse_child(
+            &mut params,
+            Rule::pattern_primary,
+            Self::parse_pattern_primary,
+            identity,
+        )?;
+        let quantifier = try_parse_child(
+            &mut params,
+            Rule::pattern_quantifier,
+            Self::parse_pattern_quantifier,
+            identity,
+        )?;
+        Ok(match quantifier {
+            Some(quantifier) => RowPattern::Quantified(Box::new(pattern), quantifier),
+            None => pattern,
+        })
+    }
+
+    fn parse_pattern_primary(mut params: FnParseParams) -> Result<RowPattern> {
+        try_parse_child(
+            &mut params,
+            Rule::pattern_symbol,
+            Self::parse_pattern_symbol,
+            RowPattern::Symbol,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::pattern_alternation,
+            Self::parse_pattern_alternation,
+            identity,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of row pattern: {}",
+                params.sql
+            ))
+        })
+    }
+
+    fn parse_pattern_quantifier(mut params: FnParseParams) -> Result<PatternQuantifier> {
+        let s = self_as_str(&mut params);
+        match s {
+            "*" => Ok(PatternQuantifier::ZeroOrMore),
+            "+" => Ok(PatternQuantifier::OneOrMore),
+            "?" => Ok(PatternQuantifier::ZeroOrOne),
+            _ => Err(SpringError::Sql(anyhow!(
+                "unknown pattern quantifier {}",
+                s
+            ))),
+        }
+    }
+
+    fn parse_within_clause(mut params: FnParseParams) -> Result<EventDuration> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?
+        .to_event_duration()
+    }
+
+    fn parse_deduplicate_clause(mut params: FnParseParams) -> Result<DeduplicateSyntax> {
+        let keys = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        let within = parse_child(
+            &mut params,
+            Rule::within_clause,
+            Self::parse_within_clause,
+            identity,
+        )?;
+        Ok(DeduplicateSyntax { keys, within })
+    }
+
+    fn parse_define_clause(mut params: FnParseParams) -> Result<Vec<(PatternSymbol, ValueExpr)>> {
+        parse_child_seq(
+            &mut params,
+            Rule::symbol_definition,
+            &Self::parse_symbol_definition,
+            &identity,
+        )
+    }
 
-    fn parse_column_reference(mut params: FnParseParams) -> Result<ColumnReference> {
-        let correlation = parse_child(
+    fn parse_symbol_definition(mut params: FnParseParams) -> Result<(PatternSymbol, ValueExpr)> {
+        let symbol = parse_child(
             &mut params,
-            Rule::correlation,
-            Self::parse_correlation,
+            Rule::pattern_symbol,
+            Self::parse_pattern_symbol,
             identity,
         )?;
-        let column_name = parse_child(
+        let condition = parse_child(
             &mut params,
-            Rule::column_name,
-            Self::parse_column_name,
+            Rule::condition,
+            Self::parse_condition,
             identity,
         )?;
-        Ok(ColumnReference::new(correlation, column_name))
+        Ok((symbol, condition))
     }
 
     /*
      * ----------------------------------------------------------------------------
-     * Function
+     * Analytic
      * ----------------------------------------------------------------------------
      */
 
-    fn parse_function_call(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
-        let function_name = parse_child(
+    fn parse_analytic_expr(mut params: FnParseParams) -> Result<AnalyticExpr> {
+        let (func, argument) = parse_child(
             &mut params,
-            Rule::function_name,
-            Self::parse_function_name,
+            Rule::analytic_function,
+            Self::parse_analytic_function,
             identity,
         )?;
-        let parameters = parse_child_seq(
+        let partition_by = try_parse_child(
             &mut params,
-            Rule::value_expr,
-            &Self::parse_value_expr,
-            &identity,
+            Rule::partition_clause,
+            Self::parse_partition_clause,
+            identity,
         )?;
-
-        match function_name.to_lowercase().as_str() {
-            "duration_millis" => {
-                if parameters.len() == 1 {
-                    Ok(FunctionCall::DurationMillis {
-                        duration_millis: Box::new(parameters[0].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "duration_millis() takes exactly one parameter (duration_millis)."
-                    )))
-                }
-            }
-            "duration_secs" => {
-                if parameters.len() == 1 {
-                    Ok(FunctionCall::DurationSecs {
-                        duration_secs: Box::new(parameters[0].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "duration_secs() takes exactly one parameter (duration_secs)."
-                    )))
-                }
-            }
-            "floor_time" => {
-                if parameters.len() == 2 {
-                    Ok(FunctionCall::FloorTime {
-                        target: Box::new(parameters[0].clone()),
-                        resolution: Box::new(parameters[1].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "floor_time() takes exactly two parameters (target, resolution)."
-                    )))
-                }
-            }
-            "floor" => unimplemented!(),
-            _ => Err(SpringError::Sql(anyhow!(
-                "unknown function {}",
-                function_name.to_lowercase()
-            ))),
-        }
+        try_parse_child(
+            &mut params,
+            Rule::analytic_order_clause,
+            |_| Ok(()),
+            identity,
+        )?;
+        let preceding_rows = try_parse_child(
+            &mut params,
+            Rule::analytic_frame_clause,
+            Self::parse_analytic_frame_clause,
+            identity,
+        )?;
+        Ok(AnalyticExpr {
+            func,
+            argument,
+            partition_by,
+            preceding_rows,
+        })
     }
 
-    fn parse_function_name(mut params: FnParseParams) -> Result<String> {
-        Ok(self_as_str(&mut params).to_string())
+    fn parse_analytic_function(
+        mut params: FnParseParams,
+    ) -> Result<(AnalyticFunctionParameter, Option<ValueExpr>)> {
+        try_parse_child(
+            &mut params,
+            Rule::analytic_lag,
+            Self::parse_analytic_lag,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::analytic_first_value,
+            |mut params| {
+                let argument = parse_child(
+                    &mut params,
+                    Rule::value_expr,
+                    Self::parse_value_expr,
+                    identity,
+                )?;
+                Ok((AnalyticFunctionParameter::FirstValue, Some(argument)))
+            },
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::analytic_row_number,
+            |_| Ok((AnalyticFunctionParameter::RowNumber, None)),
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::analytic_aggregate,
+            Self::parse_analytic_aggregate,
+            identity,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of analytic function: {}",
+                params.sql
+            ))
+        })
     }
 
-    /*
-     * ----------------------------------------------------------------------------
-     * Aggregate
-     * ----------------------------------------------------------------------------
-     */
+    fn parse_analytic_lag(
+        mut params: FnParseParams,
+    ) -> Result<(AnalyticFunctionParameter, Option<ValueExpr>)> {
+        let argument = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        let offset = try_parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .map(|offset| offset.to_i64())
+        .transpose()?
+        .unwrap_or(1);
+
+        if offset > 0 {
+            Ok((
+                AnalyticFunctionParameter::Lag {
+                    offset: offset as u64,
+                },
+                Some(argument),
+            ))
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "offset of LAG() must be positive but got {}",
+                offset
+            )))
+        }
+    }
 
-    fn parse_aggr_expr(mut params: FnParseParams) -> Result<AggrExpr> {
+    fn parse_analytic_aggregate(
+        mut params: FnParseParams,
+    ) -> Result<(AnalyticFunctionParameter, Option<ValueExpr>)> {
         let func = parse_child(
             &mut params,
-            Rule::aggregate_name,
-            Self::parse_aggregate_name,
+            Rule::analytic_aggregate_name,
+            Self::parse_analytic_aggregate_name,
             identity,
         )?;
-        let aggregated = parse_child(
+        let argument = parse_child(
             &mut params,
             Rule::value_expr,
-            &Self::parse_value_expr,
-            &identity,
+            Self::parse_value_expr,
+            identity,
         )?;
-        Ok(AggrExpr { func, aggregated })
+        Ok((func, Some(argument)))
     }
 
-    fn parse_aggregate_name(mut params: FnParseParams) -> Result<AggregateFunctionParameter> {
+    fn parse_analytic_aggregate_name(
+        mut params: FnParseParams,
+    ) -> Result<AnalyticFunctionParameter> {
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_str() {
-            "avg" => Ok(AggregateFunctionParameter::Avg),
+            "sum" => Ok(AnalyticFunctionParameter::Sum),
+            "avg" => Ok(AnalyticFunctionParameter::Avg),
             _ => Err(SpringError::Sql(anyhow!(
-                "unknown aggregate function {}",
+                "unknown analytic aggregate function {}",
                 s.to_lowercase()
             ))),
         }
     }
 
+    fn parse_partition_clause(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )
+    }
+
+    fn parse_analytic_frame_clause(mut params: FnParseParams) -> Result<u64> {
+        let n = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .to_i64()?;
+
+        if n >= 0 {
+            Ok(n as u64)
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "number of preceding rows must not be negative but got {}",
+                n
+            )))
+        }
+    }
+
     /*
      * ================================================================================================
      * Identifier:
@@ -1054,6 +2381,12 @@ impl PestParserImpl {
             Self::parse_float_type,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::decimal_type,
+            Self::parse_decimal_type,
+            identity,
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::boolean_type,
@@ -1066,12 +2399,36 @@ impl PestParserImpl {
             Self::parse_character_type,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::binary_type,
+            Self::parse_binary_type,
+            identity,
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::timestamp_type,
             Self::parse_timestamp_type,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::duration_type,
+            |_| Ok(SqlType::duration()),
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::array_type,
+            Self::parse_array_type,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::struct_type,
+            Self::parse_struct_type,
+            identity,
+        )?)
         .ok_or_else(|| {
             SpringError::Sql(anyhow!(
                 "Does not match any child rule of data type: {}",
@@ -1088,8 +2445,13 @@ impl PestParserImpl {
 
     fn parse_integer_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
-        match s.to_ascii_uppercase().as_str() {
+        match normalize_type_name(s).as_str() {
+            "SMALLINT" => Ok(SqlType::small_int()),
             "INTEGER" => Ok(SqlType::integer()),
+            "BIGINT" => Ok(SqlType::big_int()),
+            "SMALLINT UNSIGNED" => Ok(SqlType::unsigned_small_int()),
+            "INTEGER UNSIGNED" => Ok(SqlType::unsigned_integer()),
+            "BIGINT UNSIGNED" => Ok(SqlType::unsigned_big_int()),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1099,8 +2461,9 @@ impl PestParserImpl {
 
     fn parse_float_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
-        match s.to_ascii_uppercase().as_str() {
+        match normalize_type_name(s).as_str() {
             "FLOAT" => Ok(SqlType::float()),
+            "DOUBLE" | "DOUBLE PRECISION" => Ok(SqlType::double()),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1108,6 +2471,39 @@ impl PestParserImpl {
         }
     }
 
+    /// `DECIMAL` is `DECIMAL(38, 0)` and `DECIMAL(p)` is `DECIMAL(p, 0)`.
+    fn parse_decimal_type(mut params: FnParseParams) -> Result<SqlType> {
+        let mut digits = || -> Result<Option<u8>> {
+            try_parse_child(
+                &mut params,
+                Rule::integer_constant,
+                Self::parse_integer_constant,
+                identity,
+            )?
+            .map(|v| {
+                v.to_i64().and_then(|i| {
+                    u8::try_from(i).map_err(|_| {
+                        SpringError::Sql(anyhow!("DECIMAL precision and scale must be <= 38"))
+                    })
+                })
+            })
+            .transpose()
+        };
+        let precision = digits()?.unwrap_or(MAX_DECIMAL_PRECISION);
+        let scale = digits()?.unwrap_or(0);
+
+        if precision == 0 || precision > MAX_DECIMAL_PRECISION || scale > precision {
+            Err(SpringError::Sql(anyhow!(
+                "DECIMAL({}, {}) is invalid: precision must be 1 - {} and scale must be 0 - precision",
+                precision,
+                scale,
+                MAX_DECIMAL_PRECISION
+            )))
+        } else {
+            Ok(SqlType::decimal(precision, scale))
+        }
+    }
+
     fn parse_boolean_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
@@ -1130,10 +2526,78 @@ impl PestParserImpl {
         }
     }
 
-    fn parse_timestamp_type(mut params: FnParseParams) -> Result<SqlType> {
+    fn parse_binary_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
-            "TIMESTAMP" => Ok(SqlType::timestamp()),
+            "BLOB" | "BYTEA" => Ok(SqlType::blob()),
+            x => {
+                eprintln!("Unexpected data type parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    /*
+     * ----------------------------------------------------------------------------
+     * Composite Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_array_type(mut params: FnParseParams) -> Result<SqlType> {
+        parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            SqlType::array,
+        )
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - a field name is declared twice.
+    fn parse_struct_type(mut params: FnParseParams) -> Result<SqlType> {
+        let fields = parse_child_seq(
+            &mut params,
+            Rule::struct_field,
+            &Self::parse_struct_field,
+            &identity,
+        )?;
+        if let Some((name, _)) = fields
+            .iter()
+            .enumerate()
+            .find(|(i, (name, _))| fields[..*i].iter().any(|(n, _)| n == name))
+            .map(|(_, field)| field)
+        {
+            return Err(SpringError::Sql(anyhow!(
+                "field `{}` is declared twice in STRUCT",
+                name
+            )));
+        }
+        Ok(SqlType::structure(fields))
+    }
+
+    fn parse_struct_field(mut params: FnParseParams) -> Result<(String, SqlType)> {
+        let name = parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            identity,
+        )?;
+        let data_type = parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            identity,
+        )?;
+        Ok((name, data_type))
+    }
+
+    fn parse_timestamp_type(mut params: FnParseParams) -> Result<SqlType> {
+        let s = self_as_str(&mut params);
+        match normalize_type_name(s).as_str() {
+            "TIMESTAMP" | "TIMESTAMP WITHOUT TIME ZONE" => Ok(SqlType::timestamp()),
+            "TIMESTAMPTZ" | "TIMESTAMP WITH TIME ZONE" => Ok(SqlType::timestamp_tz()),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1239,6 +2703,15 @@ impl PestParserImpl {
         )
     }
 
+    fn parse_pattern_symbol(mut params: FnParseParams) -> Result<PatternSymbol> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            PatternSymbol::new,
+        )
+    }
+
     fn parse_aggr_alias(mut params: FnParseParams) -> Result<AggrAlias> {
         parse_child(
             &mut params,
@@ -1276,12 +2749,36 @@ impl PestParserImpl {
     }
 
     fn parse_option_value(mut params: FnParseParams) -> Result<String> {
-        parse_child(
+        match try_parse_child(
             &mut params,
-            Rule::string_content,
-            Self::parse_string_content,
+            Rule::placeholder,
+            Self::parse_option_placeholder,
             identity,
-        )
+        )? {
+            Some(option_value) => Ok(option_value),
+            None => parse_child(
+                &mut params,
+                Rule::string_content,
+                Self::parse_string_content,
+                identity,
+            ),
+        }
+    }
+
+    /// TEXT values are used as they are and integer values are formatted, as option values are strings.
+    fn parse_option_placeholder(params: FnParseParams) -> Result<String> {
+        let placeholder = params.self_string.clone();
+        match Self::parse_placeholder(params)? {
+            SqlValue::NotNull(NnSqlValue::Text(s)) => Ok(s),
+            SqlValue::NotNull(NnSqlValue::SmallInt(i)) => Ok(i.to_string()),
+            SqlValue::NotNull(NnSqlValue::Integer(i)) => Ok(i.to_string()),
+            SqlValue::NotNull(NnSqlValue::BigInt(i)) => Ok(i.to_string()),
+            value => Err(SpringError::Sql(anyhow!(
+                "placeholder `{}` in OPTIONS must be bound to TEXT or an integer but got {:?}",
+                placeholder,
+                value
+            ))),
+        }
     }
 
     /*
@@ -1291,6 +2788,15 @@ impl PestParserImpl {
      */
 
     fn parse_column_constraint(mut params: FnParseParams) -> Result<ColumnConstraintSyntax> {
+        if let Some(format) = try_parse_child(
+            &mut params,
+            Rule::column_format,
+            Self::parse_column_format,
+            ColumnConstraintSyntax::Format,
+        )? {
+            return Ok(format);
+        }
+
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_str() {
             "not null" => Ok(ColumnConstraintSyntax::NotNull),
@@ -1302,6 +2808,16 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_column_format(mut params: FnParseParams) -> Result<TimestampFormat> {
+        let format = parse_child(
+            &mut params,
+            Rule::string_content,
+            Self::parse_string_content,
+            identity,
+        )?;
+        format.parse()
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * Column Definitions
@@ -1321,6 +2837,21 @@ impl PestParserImpl {
             Self::parse_data_type,
             identity,
         )?;
+        // DURATION is a type of constants and intermediate values, not of stream columns.
+        fn has_duration(sql_type: &SqlType) -> bool {
+            match sql_type {
+                SqlType::DurationComparable => true,
+                SqlType::Array(element_type) => has_duration(element_type),
+                SqlType::Struct(fields) => fields.iter().any(|(_, t)| has_duration(t)),
+                _ => false,
+            }
+        }
+        if has_duration(&data_type) {
+            return Err(SpringError::Sql(anyhow!(
+                "DURATION cannot be a column type: {}",
+                params.sql
+            )));
+        }
         let column_constraints_syntax = parse_child_seq(
             &mut params,
             Rule::column_constraint,
@@ -1337,6 +2868,7 @@ impl PestParserImpl {
             .into_iter()
             .filter_map(|constraint_syntax| match constraint_syntax {
                 ColumnConstraintSyntax::Rowtime => Some(ColumnConstraint::Rowtime),
+                ColumnConstraintSyntax::Format(format) => Some(ColumnConstraint::Format(format)),
                 ColumnConstraintSyntax::NotNull => None,
             })
             .collect::<Vec<_>>();
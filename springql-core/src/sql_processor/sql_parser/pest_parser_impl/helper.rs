@@ -2,11 +2,12 @@
 
 use super::generated_parser::Rule;
 use crate::error::{Result, SpringError};
+use crate::stream_engine::SqlValue;
 use anyhow::Context;
 use pest::iterators::{Pair, Pairs};
 use std::collections::VecDeque;
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Debug)]
 pub(super) struct FnParseParams<'a> {
     pub(super) sql: &'a str,
 
@@ -20,6 +21,12 @@ pub(super) struct FnParseParams<'a> {
 
     /// Used for leaves.
     pub(super) self_string: String,
+
+    /// Byte offset of `self_string` in `sql`.
+    pub(super) self_start: usize,
+
+    /// Values bound to placeholders (`$1`, `:name`) in `sql`.
+    pub(super) bind_params: &'a [(String, SqlValue)],
 }
 
 /// Parse the next child term as `child_term` by `child_parser`.
@@ -53,12 +60,15 @@ pub(super) fn parse_child<T, ChildRet>(
 
     if child_pair.as_rule() == child_term {
         let child_str = child_pair.as_str();
+        let self_start = child_pair.as_span().start();
         let grand_children_pairs: Pairs<Rule> = child_pair.into_inner();
 
         let child_params = FnParseParams {
             sql: params.sql,
             children_pairs: grand_children_pairs.collect(),
             self_string: child_str.to_string(),
+            self_start,
+            bind_params: params.bind_params,
         };
         let child_ast = child_parser(child_params)?;
 
@@ -94,12 +104,15 @@ pub(super) fn try_parse_child<T, ChildRet>(
     if let Some(child_pair) = params.children_pairs.pop_front() {
         if child_pair.as_rule() == child_term {
             let child_str = child_pair.as_str();
+            let self_start = child_pair.as_span().start();
             let grand_children_pairs: Pairs<Rule> = child_pair.into_inner();
 
             let child_params = FnParseParams {
                 sql: params.sql,
                 children_pairs: grand_children_pairs.collect(),
                 self_string: child_str.to_string(),
+                self_start,
+                bind_params: params.bind_params,
             };
             let child_ast = child_parser(child_params)?;
 
@@ -133,3 +146,11 @@ pub(super) fn parse_child_seq<T, ChildRet>(
 pub(super) fn self_as_str<'a>(params: &'a mut FnParseParams) -> &'a str {
     params.self_string.as_str()
 }
+
+/// Upper-cased type name with single spaces between words (`double  Precision` -> `DOUBLE PRECISION`).
+pub(super) fn normalize_type_name(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase()
+}
@@ -1,19 +1,35 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 use crate::{
-    expression::{AggrExpr, ValueExpr},
+    expression::{AggrExpr, AnalyticExpr, ValueExpr},
     pipeline::{
-        name::{AggrAlias, CorrelationAlias, StreamName, ValueAlias},
+        name::{AggrAlias, ColumnName, CorrelationAlias, PatternSymbol, StreamName, ValueAlias},
         pump_model::{
-            window_operation_parameter::join_parameter::JoinType, window_parameter::WindowParameter,
+            match_recognize_parameter::RowPattern, order_limit_parameter::SortOrder,
+            window_operation_parameter::join_parameter::JoinType,
+            window_parameter::WindowParameter, window_trigger::WindowTrigger,
         },
     },
+    stream_engine::time::{
+        duration::{event_duration::EventDuration, wall_clock_duration::WallClockDuration},
+        timestamp::timestamp_format::TimestampFormat,
+    },
 };
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(in crate::sql_processor) enum ColumnConstraintSyntax {
     NotNull, // this is treated as data type in pipeline
     Rowtime,
+    Format(TimestampFormat),
+}
+
+/// `ROWTIME ...` clause of a source stream.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(in crate::sql_processor) enum SourceRowtimeSyntax {
+    /// Arrival time to the source stream is ROWTIME.
+    IngestionTime,
+    /// The column is ROWTIME (same as ROWTIME constraint to the column).
+    EventTime(ColumnName),
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -24,10 +40,18 @@ pub(in crate::sql_processor) struct OptionSyntax {
 
 #[derive(Clone, PartialEq, Debug)]
 pub(in crate::sql_processor) struct SelectStreamSyntax {
+    /// `SELECT STREAM DISTINCT`
+    pub(in crate::sql_processor) distinct: bool,
     pub(in crate::sql_processor) fields: Vec<SelectFieldSyntax>,
     pub(in crate::sql_processor) from_item: FromItemSyntax,
+    pub(in crate::sql_processor) match_recognize: Option<MatchRecognizeSyntax>,
+    pub(in crate::sql_processor) deduplicate: Option<DeduplicateSyntax>,
     pub(in crate::sql_processor) grouping_element: Option<GroupingElementSyntax>,
     pub(in crate::sql_processor) window_clause: Option<WindowParameter>,
+    pub(in crate::sql_processor) idle_timeout: Option<WallClockDuration>,
+    pub(in crate::sql_processor) emit_trigger: Option<WindowTrigger>,
+    pub(in crate::sql_processor) order_by: Vec<SortSpecificationSyntax>,
+    pub(in crate::sql_processor) limit: Option<u64>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -40,6 +64,12 @@ pub(crate) enum SelectFieldSyntax {
         aggr_expr: AggrExpr,
         alias: Option<AggrAlias>,
     },
+    AnalyticExpr {
+        analytic_expr: AnalyticExpr,
+        alias: Option<ValueAlias>,
+    },
+    /// `*` (stream_name: None) or `stream_name.*`, expanded into column references by `SelectSyntaxAnalyzer`.
+    Wildcard { stream_name: Option<StreamName> },
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -61,6 +91,31 @@ pub(in crate::sql_processor) struct SubFromItemSyntax {
     pub(in crate::sql_processor) alias: Option<CorrelationAlias>,
 }
 
+/// `MATCH_RECOGNIZE (...) AS alias` following FROM.
+#[derive(Clone, PartialEq, Debug)]
+pub(in crate::sql_processor) struct MatchRecognizeSyntax {
+    pub(in crate::sql_processor) partition_by: Option<ValueExpr>,
+    pub(in crate::sql_processor) measures: Vec<(ValueExpr, ValueAlias)>,
+    pub(in crate::sql_processor) pattern: RowPattern,
+    pub(in crate::sql_processor) within: EventDuration,
+    pub(in crate::sql_processor) defines: Vec<(PatternSymbol, ValueExpr)>,
+    pub(in crate::sql_processor) alias: CorrelationAlias,
+}
+
+/// `DEDUPLICATE BY expr, ... WITHIN duration` following FROM.
+#[derive(Clone, PartialEq, Debug)]
+pub(in crate::sql_processor) struct DeduplicateSyntax {
+    pub(in crate::sql_processor) keys: Vec<ValueExpr>,
+    pub(in crate::sql_processor) within: EventDuration,
+}
+
+/// `expr [ASC|DESC]` in ORDER BY.
+#[derive(Clone, PartialEq, Debug)]
+pub(in crate::sql_processor) struct SortSpecificationSyntax {
+    pub(in crate::sql_processor) expr: ValueExpr,
+    pub(in crate::sql_processor) order: SortOrder,
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub(in crate::sql_processor) enum GroupingElementSyntax {
     ValueExpr(ValueExpr),
@@ -72,3 +127,12 @@ pub(in crate::sql_processor) enum DurationFunction {
     Millis,
     Secs,
 }
+
+/// Accessor to an element of ARRAY or STRUCT following a value expression.
+#[derive(Clone, PartialEq, Debug)]
+pub(in crate::sql_processor) enum ElementAccessorSyntax {
+    /// `[index]`
+    Subscript(ValueExpr),
+    /// `.field`
+    Field(String),
+}
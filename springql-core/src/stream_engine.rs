@@ -36,7 +36,10 @@ mod sql_executor;
 pub(crate) use autonomous_executor::{
     row::value::{
         sql_convertible::SqlConvertible,
-        sql_value::{nn_sql_value::NnSqlValue, sql_compare_result::SqlCompareResult, SqlValue},
+        sql_value::{
+            blob, decimal::Decimal, nn_sql_value::NnSqlValue, sql_compare_result::SqlCompareResult,
+            SqlValue,
+        },
     },
     task::tuple::Tuple,
     SinkRow,
@@ -49,8 +52,13 @@ use crate::{
 };
 
 use self::{
-    autonomous_executor::AutonomousExecutor, command::alter_pipeline_command::AlterPipelineCommand,
-    in_memory_queue_repository::InMemoryQueueRepository, sql_executor::SqlExecutor,
+    autonomous_executor::AutonomousExecutor,
+    command::{
+        alter_pipeline_command::AlterPipelineCommand, explain_command::ExplainCommand,
+        show_command::ShowCommand,
+    },
+    in_memory_queue_repository::InMemoryQueueRepository,
+    sql_executor::SqlExecutor,
 };
 
 /// Stream engine has reactive executor and autonomous executor inside.
@@ -81,6 +89,53 @@ impl StreamEngine {
         self.autonomous_executor.notify_pipeline_update(pipeline)
     }
 
+    /// Renders query plans of pumps and the task graph without altering the current pipeline.
+    ///
+    /// `EXPLAIN CREATE PUMP` renders the task graph of the pipeline as if the pump were created.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the pump cannot be added to the current pipeline.
+    pub(crate) fn explain(&self, command: ExplainCommand) -> Result<String> {
+        let (pumps, pipeline) = match command {
+            ExplainCommand::CreatePump(pump) => {
+                let mut pipeline = self.current_pipeline().clone();
+                pipeline.add_pump(pump.as_ref().clone())?;
+                (vec![*pump], pipeline)
+            }
+            ExplainCommand::Pipeline => {
+                let pipeline = self.current_pipeline().clone();
+                let pumps = pipeline.all_pumps().into_iter().cloned().collect();
+                (pumps, pipeline)
+            }
+        };
+
+        let mut explained = String::new();
+        for pump in pumps {
+            explained.push_str(&format!(
+                "Pump {} ({:?} input) INSERT INTO {} {:?}\n",
+                pump.name(),
+                pump.input_type(),
+                pump.downstream(),
+                pump.insert_plan().column_order()
+            ));
+            explained.push_str(&pump.query_plan().explain());
+        }
+        explained.push_str(&AutonomousExecutor::explain_task_graph(&pipeline));
+        Ok(explained)
+    }
+
+    /// Lists objects in the current pipeline as rows.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - stream to describe does not exist in pipeline.
+    pub(crate) fn show(&self, command: ShowCommand) -> Result<Vec<SinkRow>> {
+        self.reactive_executor.show(command)
+    }
+
     /// Blocking call
     ///
     /// # Failure
@@ -30,6 +30,7 @@ use self::purger_worker::purger_worker_thread::PurgerWorkerThreadArg;
 use self::purger_worker::PurgerWorker;
 use self::repositories::Repositories;
 use self::task_executor::task_executor_lock::TaskExecutorLock;
+use self::task_graph::TaskGraph;
 use self::worker::worker_handle::WorkerStopCoordinate;
 use self::{
     event_queue::{event::Event, EventQueue},
@@ -116,6 +117,11 @@ impl AutonomousExecutor {
         Ok(())
     }
 
+    /// Renders the task graph deduced from `pipeline`.
+    pub(in crate::stream_engine) fn explain_task_graph(pipeline: &Pipeline) -> String {
+        TaskGraph::from(pipeline).to_string()
+    }
+
     /// Workers in autonomous executor may get SpringError but it must continue their work.
     /// This method provides common way, like logging, to handle an error and then continue their work.
     fn handle_error(e: SpringError) {
@@ -131,6 +137,7 @@ impl AutonomousExecutor {
             SpringError::InvalidOption { .. }
             | SpringError::InvalidFormat { .. }
             | SpringError::Sql(_)
+            | SpringError::SqlSyntax { .. }
             | SpringError::ThreadPoisoned(_) => log::error!("{:?}", e),
 
             SpringError::InvalidConfig { .. } => unreachable!("must be handled on startup"),
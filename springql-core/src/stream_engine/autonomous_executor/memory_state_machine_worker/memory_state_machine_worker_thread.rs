@@ -1,6 +1,6 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::{sync::Arc, thread, time::Duration};
+use std::{sync::Arc, time::Duration};
 
 use crate::stream_engine::autonomous_executor::{
     event_queue::{
@@ -17,6 +17,7 @@ use crate::stream_engine::autonomous_executor::{
     pipeline_derivatives::PipelineDerivatives,
     worker::worker_thread::{WorkerThread, WorkerThreadLoopState},
 };
+use crate::stream_engine::time::clock;
 
 /// Runs a worker thread.
 #[derive(Debug)]
@@ -68,7 +69,7 @@ impl WorkerThread for MemoryStateMachineWorkerThread {
         _event_queue: &EventQueue,
     ) -> Self::LoopState {
         // Do nothing in loop. Only curious about ReportMetricsSummary event.
-        thread::sleep(Duration::from_millis(
+        clock::sleep(Duration::from_millis(
             thread_arg.memory_state_transition_interval_msec as u64,
         ));
         current_state
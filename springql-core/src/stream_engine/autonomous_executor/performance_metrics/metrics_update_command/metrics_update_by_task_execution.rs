@@ -74,6 +74,10 @@ impl MetricsUpdateByTaskExecution {
         self.window_queue_waiting_gain_bytes(id) + self.window_queue_window_gain_bytes(id)
     }
 
+    pub(in crate::stream_engine::autonomous_executor) fn task_late_rows(&self) -> u64 {
+        self.task.late_rows
+    }
+
     fn task_execution_time(&self) -> WallClockDuration {
         self.task.execution_time
     }
@@ -223,6 +227,18 @@ impl MetricsUpdateByTaskExecution {
 pub(in crate::stream_engine::autonomous_executor) struct TaskMetricsUpdateByTask {
     task_id: TaskId,
     execution_time: WallClockDuration,
+
+    /// Rows dropped by window (or inserted into `LATE ROWS INTO` stream) for their rowtime behind the watermark.
+    #[new(default)]
+    late_rows: u64,
+}
+impl TaskMetricsUpdateByTask {
+    pub(in crate::stream_engine::autonomous_executor) fn with_late_rows(
+        self,
+        late_rows: u64,
+    ) -> Self {
+        Self { late_rows, ..self }
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -264,6 +280,22 @@ pub(in crate::stream_engine::autonomous_executor) enum InQueueMetricsUpdateByCol
 }
 
 impl InQueueMetricsUpdateByCollect {
+    /// Nothing collected from the queue (e.g. a task closing windows while its input queue is empty).
+    pub(in crate::stream_engine::autonomous_executor) fn none_collected(queue_id: QueueId) -> Self {
+        match queue_id {
+            QueueId::Row(queue_id) => Self::Row {
+                queue_id,
+                rows_used: 0,
+                bytes_used: 0,
+            },
+            QueueId::Window(queue_id) => Self::Window {
+                queue_id,
+                waiting_bytes_dispatched: 0,
+                waiting_rows_dispatched: 0,
+            },
+        }
+    }
+
     pub(in crate::stream_engine::autonomous_executor) fn queue_id(&self) -> QueueId {
         match self {
             Self::Row { queue_id, .. } => queue_id.clone().into(),
@@ -303,3 +335,31 @@ pub(in crate::stream_engine::autonomous_executor) struct OutQueueMetricsUpdateBy
     rows_put: u64,
     bytes_put: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_gain_on_idle() {
+        let queue_id = WindowQueueId::fx_q3_1();
+
+        // window closed by idle timeout while nothing is collected from the queue
+        let metrics = MetricsUpdateByTaskExecution::new(
+            TaskMetricsUpdateByTask::new(
+                TaskId::fx_split_join_t3(),
+                WallClockDuration::from_micros(10),
+            ),
+            vec![InQueueMetricsUpdateByTask::new(
+                InQueueMetricsUpdateByCollect::none_collected(queue_id.clone().into()),
+                Some(WindowInFlowByWindowTask::new(-80, -20)),
+            )],
+            vec![],
+        );
+
+        assert_eq!(metrics.updated_queues(), vec![queue_id.clone().into()]);
+        assert_eq!(metrics.window_queue_waiting_gain_rows(&queue_id), 0);
+        assert_eq!(metrics.window_queue_gain_bytes(&queue_id), -100);
+        assert!(metrics.task_gain_bytes_per_sec() < 0.0);
+    }
+}
@@ -11,6 +11,9 @@ use super::metrics_update_command::metrics_update_by_task_execution::MetricsUpda
 pub(in crate::stream_engine::autonomous_executor) struct TaskMetrics {
     avg_gain_bytes_per_sec: f32,
     n_executions: u64,
+
+    /// Total number of late rows on window (only for pump tasks with window).
+    n_late_rows: u64,
 }
 
 impl TaskMetrics {
@@ -26,9 +29,14 @@ impl TaskMetrics {
             n,
             command.task_gain_bytes_per_sec(),
         );
+        self.n_late_rows += command.task_late_rows();
     }
 
     pub(in crate::stream_engine::autonomous_executor) fn avg_gain_bytes_per_sec(&self) -> f32 {
         self.avg_gain_bytes_per_sec
     }
+
+    pub(in crate::stream_engine::autonomous_executor) fn late_rows(&self) -> u64 {
+        self.n_late_rows
+    }
 }
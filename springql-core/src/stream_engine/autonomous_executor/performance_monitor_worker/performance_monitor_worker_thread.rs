@@ -1,7 +1,8 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::{sync::Arc, thread, time::Duration};
+use std::{sync::Arc, time::Duration};
 
+use crate::stream_engine::time::clock;
 use crate::{
     low_level_rs::SpringConfig,
     stream_engine::{
@@ -127,7 +128,7 @@ impl WorkerThread for PerformanceMonitorWorkerThread {
                 );
             }
 
-            thread::sleep(Duration::from_millis(CLOCK_MSEC));
+            clock::sleep(Duration::from_millis(CLOCK_MSEC));
 
             state
         } else {
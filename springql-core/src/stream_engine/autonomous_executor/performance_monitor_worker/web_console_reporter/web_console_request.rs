@@ -63,6 +63,7 @@ struct TaskRequest {
     id: String,
     type_: String,
     avg_gain_bytes_per_sec: f32,
+    late_rows: u64,
 }
 
 impl TaskRequest {
@@ -76,6 +77,7 @@ impl TaskRequest {
             }
             .to_string(),
             avg_gain_bytes_per_sec: metrics.avg_gain_bytes_per_sec(),
+            late_rows: metrics.late_rows(),
         }
     }
 
@@ -85,6 +87,7 @@ impl TaskRequest {
                 "id": self.id.clone(),
                 "type": self.type_.clone(),
                 "avg-gain-bytes-per-sec": self.avg_gain_bytes_per_sec,
+                "late-rows": self.late_rows,
             }
         )
     }
@@ -1,16 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::{borrow::BorrowMut, collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::anyhow;
 
 use crate::{
     error::{Result, SpringError},
     pipeline::pipeline_graph::PipelineGraph,
-    stream_engine::autonomous_executor::{
-        task::{window::Window, Task},
-        task_graph::task_id::TaskId,
-    },
+    stream_engine::autonomous_executor::{task::Task, task_graph::task_id::TaskId},
 };
 
 #[derive(Debug, Default)]
@@ -35,13 +32,7 @@ impl TaskRepository {
     pub(in crate::stream_engine::autonomous_executor) fn purge_windows(&self) {
         for task in self.repo.values() {
             if let Task::Pump(pump_task) = task.as_ref() {
-                if let Some(mut w) = pump_task.get_join_window_mut() {
-                    w.borrow_mut().purge()
-                } else if let Some(mut w) = pump_task.get_aggr_window_mut() {
-                    w.borrow_mut().purge()
-                } else {
-                    // nothing to purge
-                }
+                pump_task.purge_windows()
             }
         }
     }
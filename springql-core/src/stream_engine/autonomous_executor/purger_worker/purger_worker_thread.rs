@@ -1,6 +1,6 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::{sync::Arc, thread, time::Duration};
+use std::{sync::Arc, time::Duration};
 
 use crate::stream_engine::autonomous_executor::{
     event_queue::{
@@ -17,6 +17,7 @@ use crate::stream_engine::autonomous_executor::{
     task_executor::task_executor_lock::TaskExecutorLock,
     worker::worker_thread::{WorkerThread, WorkerThreadLoopState},
 };
+use crate::stream_engine::time::clock;
 
 #[derive(Debug, new)]
 pub(in crate::stream_engine::autonomous_executor) struct PurgerWorkerThreadArg {
@@ -66,7 +67,7 @@ impl WorkerThread for PurgerWorkerThread {
         _event_queue: &EventQueue,
     ) -> Self::LoopState {
         // Do nothing in loop. Only curious about TransitMemoryState event.
-        thread::sleep(Duration::from_millis(100));
+        clock::sleep(Duration::from_millis(100));
         current_state
     }
 
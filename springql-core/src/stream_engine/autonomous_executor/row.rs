@@ -52,8 +52,11 @@ impl Row {
     ///
     /// ROWTIME is a:
     ///
-    /// - (default) Arrival time to a stream.
-    /// - Promoted from a column in a stream.
+    /// - (default) Arrival time to a stream: ingestion time for source streams (also explicitly set by `ROWTIME INGESTION_TIME`),
+    ///   and the time a pump inserts the row for other streams.
+    /// - Promoted from a column in a stream (`ROWTIME` column constraint or `ROWTIME EVENT_TIME column`).
+    ///
+    /// Processing-time windows do not use ROWTIME but wall-clock time.
     pub(in crate::stream_engine::autonomous_executor) fn rowtime(&self) -> Timestamp {
         self.arrival_rowtime.unwrap_or_else(|| {
             self.cols
@@ -6,10 +6,16 @@ use crate::{
     error::{Result, SpringError},
     mem_size::{arc_overhead_size, MemSize},
     pipeline::name::ColumnName,
-    pipeline::{relation::column::column_definition::ColumnDefinition, stream_model::StreamModel},
+    pipeline::{
+        relation::{column::column_definition::ColumnDefinition, sql_type::SqlType},
+        stream_model::StreamModel,
+    },
     stream_engine::{
-        autonomous_executor::row::{column_values::ColumnValues, value::sql_value::SqlValue},
-        time::timestamp::Timestamp,
+        autonomous_executor::row::{
+            column_values::ColumnValues,
+            value::sql_value::{nn_sql_value::NnSqlValue, SqlValue},
+        },
+        time::timestamp::{timestamp_format::TimestampFormat, Timestamp},
     },
 };
 use std::{sync::Arc, vec};
@@ -42,8 +48,25 @@ impl StreamColumns {
     ///   - `column_values` lacks any of `stream.columns()`.
     ///   - Type mismatch (and failed to convert type) with `stream_shape` and `column_values`.
     pub(in crate::stream_engine::autonomous_executor) fn new(
+        stream_model: Arc<StreamModel>,
+        column_values: ColumnValues,
+    ) -> Result<Self> {
+        Self::with_timestamp_format(stream_model, column_values, None)
+    }
+
+    /// Same as [new()](Self::new) but TIMESTAMP and TIMESTAMPTZ values are parsed by `default_timestamp_format` unless the column has its own `FORMAT '...'`.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `column_values` lacks any of `stream.columns()`.
+    ///   - Type mismatch (and failed to convert type) with `stream_shape` and `column_values`.
+    /// - [SpringError::InvalidFormat](crate::error::SpringError::InvalidFormat) when:
+    ///   - a value does not match the timestamp format of its column.
+    pub(in crate::stream_engine::autonomous_executor) fn with_timestamp_format(
         stream_model: Arc<StreamModel>,
         mut column_values: ColumnValues,
+        default_timestamp_format: Option<&TimestampFormat>,
     ) -> Result<Self> {
         let values = stream_model
             .shape()
@@ -51,6 +74,11 @@ impl StreamColumns {
             .iter()
             .map(|coldef| {
                 let value = column_values.remove(coldef.column_data_type().column_name())?;
+                let value = Self::parse_timestamp(
+                    value,
+                    coldef,
+                    coldef.timestamp_format().or(default_timestamp_format),
+                )?;
                 Self::validate_or_try_convert_value_type(value, coldef)
             })
             .collect::<Result<Vec<SqlValue>>>()?;
@@ -116,6 +144,23 @@ impl StreamColumns {
             .expect("self.values must be sorted to the same as self.stream.columns()"))
     }
 
+    fn parse_timestamp(
+        value: SqlValue,
+        coldef: &ColumnDefinition,
+        timestamp_format: Option<&TimestampFormat>,
+    ) -> Result<SqlValue> {
+        match (
+            &value,
+            timestamp_format,
+            coldef.column_data_type().sql_type(),
+        ) {
+            (SqlValue::NotNull(nn_value), Some(format), SqlType::TimestampComparable(_)) => Ok(
+                SqlValue::NotNull(NnSqlValue::TimestampTz(format.parse(nn_value)?)),
+            ),
+            _ => Ok(value),
+        }
+    }
+
     fn validate_or_try_convert_value_type(
         value: SqlValue,
         coldef: &ColumnDefinition,
@@ -124,7 +169,9 @@ impl StreamColumns {
 
         match &value {
             SqlValue::NotNull(nn_value) => {
-                if &nn_value.sql_type() == cdt.sql_type() {
+                // Elements of ARRAY and STRUCT values may have types other than inferred by `sql_type()`.
+                let is_composite = matches!(cdt.sql_type(), SqlType::Array(_) | SqlType::Struct(_));
+                if !is_composite && &nn_value.sql_type() == cdt.sql_type() {
                     Ok(value)
                 } else {
                     let nn_value = nn_value
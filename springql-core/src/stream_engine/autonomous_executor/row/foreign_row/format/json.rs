@@ -29,7 +29,9 @@ impl JsonObject {
     /// # Failure
     ///
     /// - [SpringError::InvalidFormat](crate::error::SpringError::InvalidFormat) when:
-    ///   - Internal JSON cannot be mapped to SQL type (nested, for example).
+    ///   - Internal JSON cannot be mapped to SQL type.
+    ///
+    /// Nested JSON arrays and objects are mapped to ARRAY and STRUCT values.
     ///
     /// # TODO
     ///
@@ -1,8 +1,14 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use std::sync::Arc;
+
 use super::format::json::JsonObject;
 use crate::error::Result;
-use crate::stream_engine::autonomous_executor::row::{value::sql_value::SqlValue, Row};
+use crate::pipeline::stream_model::StreamModel;
+use crate::stream_engine::autonomous_executor::row::{
+    column::stream_column::StreamColumns, column_values::ColumnValues, value::sql_value::SqlValue,
+    Row,
+};
 
 /// Output row into foreign systems (retrieved by SinkWriter).
 ///
@@ -29,6 +35,27 @@ impl From<Row> for SinkRow {
 }
 
 impl SinkRow {
+    /// Row not flowing in the pipeline but made by the stream engine itself (results of `SHOW`, for example).
+    ///
+    /// `values` are ordered as columns of `stream_model`.
+    ///
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `values` lacks any of columns.
+    ///   - Type mismatch (and failed to convert type) with columns.
+    pub(in crate::stream_engine) fn from_values(
+        stream_model: Arc<StreamModel>,
+        values: Vec<SqlValue>,
+    ) -> Result<Self> {
+        let mut column_values = ColumnValues::default();
+        for (column_name, value) in stream_model.shape().column_names().into_iter().zip(values) {
+            column_values.insert(column_name, value)?;
+        }
+        let cols = StreamColumns::new(stream_model, column_values)?;
+        Ok(Self(Row::new(cols)))
+    }
+
     /// # Failure
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
@@ -5,7 +5,10 @@ use std::sync::Arc;
 use crate::{
     error::Result,
     pipeline::stream_model::StreamModel,
-    stream_engine::autonomous_executor::row::{column::stream_column::StreamColumns, Row},
+    stream_engine::{
+        autonomous_executor::row::{column::stream_column::StreamColumns, Row},
+        time::timestamp::timestamp_format::TimestampFormat,
+    },
 };
 
 use super::format::json::JsonObject;
@@ -21,6 +24,8 @@ impl SourceRow {
         Self(json)
     }
 
+    /// `timestamp_format` is the source reader's default to parse TIMESTAMP and TIMESTAMPTZ columns.
+    ///
     /// # Failure
     ///
     /// - [SpringError::InvalidFormat](crate::error::SpringError::InvalidFormat) when:
@@ -28,11 +33,13 @@ impl SourceRow {
     pub(in crate::stream_engine::autonomous_executor) fn into_row(
         self,
         stream_model: Arc<StreamModel>,
+        timestamp_format: Option<&TimestampFormat>,
     ) -> Result<Row> {
         // SourceRow -> JsonObject -> HashMap<ColumnName, SqlValue> -> StreamColumns -> Row
 
         let column_values = self.0.into_column_values()?;
-        let stream_columns = StreamColumns::new(stream_model, column_values)?;
+        let stream_columns =
+            StreamColumns::with_timestamp_format(stream_model, column_values, timestamp_format)?;
         Ok(Row::new(stream_columns))
     }
 }
@@ -48,6 +55,6 @@ mod tests {
 
         let fr = SourceRow::fx_city_temperature_tokyo();
         let r = Row::fx_city_temperature_tokyo();
-        assert_eq!(fr.into_row(stream).unwrap(), r);
+        assert_eq!(fr.into_row(stream, None).unwrap(), r);
     }
 }
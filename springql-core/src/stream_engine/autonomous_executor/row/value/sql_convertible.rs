@@ -1,20 +1,26 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+mod blob;
 mod boolean;
+mod decimal;
 mod event_duration;
 mod float;
 mod int;
 mod text;
 mod timestamp;
+mod uint;
 
 use crate::{
     error::{Result, SpringError},
-    stream_engine::time::{duration::event_duration::EventDuration, timestamp::Timestamp},
+    stream_engine::time::{
+        duration::event_duration::EventDuration,
+        timestamp::{timestamp_tz::TimestampTz, Timestamp},
+    },
 };
 use anyhow::anyhow;
 use std::any::type_name;
 
-use super::sql_value::nn_sql_value::NnSqlValue;
+use super::sql_value::{decimal::Decimal, nn_sql_value::NnSqlValue};
 
 /// Rust values which can have bidirectional mapping to/from SQL [NnSqlValue](crate::NnSqlValue).
 pub(crate) trait SqlConvertible: Sized {
@@ -45,6 +51,30 @@ pub(crate) trait SqlConvertible: Sized {
         Self::default_err("i64")
     }
 
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from u16
+    fn try_from_u16(_: &u16) -> Result<Self> {
+        Self::default_err("u16")
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from u32
+    fn try_from_u32(_: &u32) -> Result<Self> {
+        Self::default_err("u32")
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from u64
+    fn try_from_u64(_: &u64) -> Result<Self> {
+        Self::default_err("u64")
+    }
+
     /// # Failures
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
@@ -53,6 +83,22 @@ pub(crate) trait SqlConvertible: Sized {
         Self::default_err("f32")
     }
 
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from f64
+    fn try_from_f64(_: &f64) -> Result<Self> {
+        Self::default_err("f64")
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from Decimal
+    fn try_from_decimal(_: &Decimal) -> Result<Self> {
+        Self::default_err("Decimal")
+    }
+
     /// # Failures
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
@@ -77,6 +123,14 @@ pub(crate) trait SqlConvertible: Sized {
         Self::default_err("Timestamp")
     }
 
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from TimestampTz
+    fn try_from_timestamp_tz(_: &TimestampTz) -> Result<Self> {
+        Self::default_err("TimestampTz")
+    }
+
     /// # Failures
     ///
     /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
@@ -85,6 +139,14 @@ pub(crate) trait SqlConvertible: Sized {
         Self::default_err("EventDuration")
     }
 
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the type implementing SqlConvertible is not convertible from Vec<u8>
+    fn try_from_blob(_: &[u8]) -> Result<Self> {
+        Self::default_err("Vec<u8>")
+    }
+
     #[doc(hidden)]
     fn default_err(from_type: &str) -> Result<Self> {
         Err(SpringError::Sql(anyhow!(
@@ -0,0 +1,42 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    error::Result,
+    stream_engine::autonomous_executor::row::value::sql_value::{
+        blob::from_base64, nn_sql_value::NnSqlValue,
+    },
+};
+
+use super::SqlConvertible;
+
+impl SqlConvertible for Vec<u8> {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Blob(self)
+    }
+
+    fn try_from_blob(v: &[u8]) -> Result<Self> {
+        Ok(v.to_vec())
+    }
+
+    /// Decoded as base64 (BLOB values in JSON).
+    fn try_from_string(v: &str) -> Result<Self> {
+        from_base64(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack_into_blob() {
+        let blob = NnSqlValue::Blob(vec![0x00, 0xff]);
+        assert_eq!(blob.unpack::<Vec<u8>>().unwrap(), vec![0x00, 0xff]);
+
+        let base64 = NnSqlValue::Text("AP8=".to_string());
+        assert_eq!(base64.unpack::<Vec<u8>>().unwrap(), vec![0x00, 0xff]);
+
+        assert!(NnSqlValue::Integer(1).unpack::<Vec<u8>>().is_err());
+        assert!(blob.unpack::<String>().is_err());
+    }
+}
@@ -0,0 +1,75 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use super::SqlConvertible;
+use crate::error::Result;
+use crate::stream_engine::autonomous_executor::row::value::sql_value::decimal::Decimal;
+use crate::stream_engine::autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue;
+
+impl SqlConvertible for Decimal {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Decimal(self)
+    }
+
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        Ok(Decimal::from(*v as i64))
+    }
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        Ok(Decimal::from(*v as i64))
+    }
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        Ok(Decimal::from(*v))
+    }
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(Decimal::from(*v as u64))
+    }
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(Decimal::from(*v as u64))
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(Decimal::from(*v))
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        // f32's shortest representation (`0.1` instead of `0.10000000149011612`)
+        v.to_string().parse()
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Decimal::from_f64(*v)
+    }
+
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        Ok(*v)
+    }
+
+    /// Exact values from JSON strings like `"12345678901234567890.12"`.
+    fn try_from_string(v: &str) -> Result<Self> {
+        v.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests_decimal {
+    use ordered_float::OrderedFloat;
+
+    use super::*;
+
+    #[test]
+    fn test_unpack_into_decimal() -> Result<()> {
+        assert_eq!(
+            NnSqlValue::UnsignedBigInt(u64::MAX).unpack::<Decimal>()?,
+            "18446744073709551615".parse()?
+        );
+        assert_eq!(
+            NnSqlValue::Float(OrderedFloat(0.1)).unpack::<Decimal>()?,
+            "0.1".parse()?
+        );
+        assert_eq!(
+            NnSqlValue::Text("-12.50".to_string()).unpack::<Decimal>()?,
+            "-12.5".parse()?
+        );
+        assert!(NnSqlValue::Double(OrderedFloat(f64::NAN))
+            .unpack::<Decimal>()
+            .is_err());
+        Ok(())
+    }
+}
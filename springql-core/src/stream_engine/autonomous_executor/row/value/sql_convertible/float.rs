@@ -4,6 +4,7 @@ use ordered_float::OrderedFloat;
 
 use super::SqlConvertible;
 use crate::error::Result;
+use crate::stream_engine::autonomous_executor::row::value::sql_value::decimal::Decimal;
 use crate::stream_engine::autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue;
 
 impl SqlConvertible for f32 {
@@ -20,10 +21,62 @@ impl SqlConvertible for f32 {
     fn try_from_i64(v: &i64) -> Result<Self> {
         Ok(*v as f32)
     }
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as f32)
+    }
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as f32)
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(*v as f32)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(*v)
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(*v as f32)
+    }
+
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        Ok(v.to_f64() as f32)
+    }
+}
+
+impl SqlConvertible for f64 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Double(OrderedFloat(self))
+    }
+
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(*v as f64)
+    }
 
     fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
         Ok(*v)
     }
+
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        Ok(v.to_f64())
+    }
 }
 
 #[cfg(test)]
@@ -52,3 +105,31 @@ mod tests_f32 {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests_f64 {
+    use super::*;
+
+    #[test]
+    fn test_pack_unpack_f64() -> Result<()> {
+        let rust_values = vec![0f64, 1., -1., f64::MAX, f64::MIN, 35.681236];
+
+        for v in rust_values {
+            let sql_value = NnSqlValue::Double(OrderedFloat(v));
+            let unpacked: f64 = sql_value.unpack()?;
+            assert_eq!(unpacked, v);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_into_f64() -> Result<()> {
+        assert_eq!(NnSqlValue::BigInt(1 << 40).unpack::<f64>()?, 1099511627776.);
+        assert_eq!(NnSqlValue::Float(OrderedFloat(0.5)).unpack::<f64>()?, 0.5);
+        assert_eq!(
+            NnSqlValue::Decimal("139.767125".parse()?).unpack::<f64>()?,
+            139.767125
+        );
+        Ok(())
+    }
+}
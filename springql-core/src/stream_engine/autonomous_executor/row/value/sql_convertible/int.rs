@@ -4,6 +4,7 @@ use anyhow::Context;
 
 use super::SqlConvertible;
 use crate::error::{Result, SpringError};
+use crate::stream_engine::autonomous_executor::row::value::sql_value::decimal::Decimal;
 use crate::stream_engine::autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue;
 
 impl SqlConvertible for i16 {
@@ -27,9 +28,38 @@ impl SqlConvertible for i16 {
             .map_err(SpringError::Sql)
     }
 
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        i16::try_from(*v)
+            .with_context(|| format!("cannot convert u16 value ({}) into i16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        i16::try_from(*v)
+            .with_context(|| format!("cannot convert u32 value ({}) into i16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        i16::try_from(*v)
+            .with_context(|| format!("cannot convert u64 value ({}) into i16", v))
+            .map_err(SpringError::Sql)
+    }
+
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i16)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i16)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        i16::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i16", v))
+            .map_err(SpringError::Sql)
+    }
 }
 
 impl SqlConvertible for i32 {
@@ -51,9 +81,36 @@ impl SqlConvertible for i32 {
             .map_err(SpringError::Sql)
     }
 
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as i32)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        i32::try_from(*v)
+            .with_context(|| format!("cannot convert u32 value ({}) into i32", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        i32::try_from(*v)
+            .with_context(|| format!("cannot convert u64 value ({}) into i32", v))
+            .map_err(SpringError::Sql)
+    }
+
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i32)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i32)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        i32::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i32", v))
+            .map_err(SpringError::Sql)
+    }
 }
 
 impl SqlConvertible for i64 {
@@ -73,9 +130,34 @@ impl SqlConvertible for i64 {
         Ok(*v)
     }
 
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as i64)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as i64)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        i64::try_from(*v)
+            .with_context(|| format!("cannot convert u64 value ({}) into i64", v))
+            .map_err(SpringError::Sql)
+    }
+
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i64)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i64)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        i64::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i64", v))
+            .map_err(SpringError::Sql)
+    }
 }
 
 #[cfg(test)]
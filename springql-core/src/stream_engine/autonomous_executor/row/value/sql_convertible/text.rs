@@ -4,7 +4,7 @@ use crate::{
     error::Result,
     stream_engine::{
         autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue,
-        time::timestamp::Timestamp,
+        time::timestamp::{timestamp_tz::TimestampTz, Timestamp},
     },
 };
 
@@ -22,4 +22,8 @@ impl SqlConvertible for String {
     fn try_from_timestamp(v: &Timestamp) -> Result<Self> {
         Ok(v.to_string())
     }
+
+    fn try_from_timestamp_tz(v: &TimestampTz) -> Result<Self> {
+        Ok(v.to_string())
+    }
 }
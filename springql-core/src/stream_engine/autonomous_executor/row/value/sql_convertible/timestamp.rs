@@ -4,7 +4,10 @@ use crate::{
     error::Result,
     stream_engine::{
         autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue,
-        time::timestamp::Timestamp,
+        time::timestamp::{
+            timestamp_tz::{utc_offset, TimestampTz},
+            Timestamp,
+        },
     },
 };
 
@@ -22,4 +25,27 @@ impl SqlConvertible for Timestamp {
     fn try_from_timestamp(v: &Timestamp) -> Result<Self> {
         Ok(*v)
     }
+
+    fn try_from_timestamp_tz(v: &TimestampTz) -> Result<Self> {
+        Ok(v.to_timestamp())
+    }
+}
+
+/// TIMESTAMP values are regarded as in UTC.
+impl SqlConvertible for TimestampTz {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::TimestampTz(self)
+    }
+
+    fn try_from_string(s: &str) -> Result<Self> {
+        s.parse()
+    }
+
+    fn try_from_timestamp(v: &Timestamp) -> Result<Self> {
+        Ok(TimestampTz::from_timestamp(*v, utc_offset()))
+    }
+
+    fn try_from_timestamp_tz(v: &TimestampTz) -> Result<Self> {
+        Ok(*v)
+    }
 }
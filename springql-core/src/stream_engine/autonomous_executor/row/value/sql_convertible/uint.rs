@@ -0,0 +1,202 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::Context;
+
+use super::SqlConvertible;
+use crate::error::{Result, SpringError};
+use crate::stream_engine::autonomous_executor::row::value::sql_value::decimal::Decimal;
+use crate::stream_engine::autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue;
+
+impl SqlConvertible for u16 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::UnsignedSmallInt(self)
+    }
+
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        u16::try_from(*v)
+            .with_context(|| format!("cannot convert i16 value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        u16::try_from(*v)
+            .with_context(|| format!("cannot convert i32 value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        u16::try_from(*v)
+            .with_context(|| format!("cannot convert i64 value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        u16::try_from(*v)
+            .with_context(|| format!("cannot convert u32 value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        u16::try_from(*v)
+            .with_context(|| format!("cannot convert u64 value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(v.ceil() as u16)
+    }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as u16)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        u16::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into u16", v))
+            .map_err(SpringError::Sql)
+    }
+}
+
+impl SqlConvertible for u32 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::UnsignedInteger(self)
+    }
+
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        u32::try_from(*v)
+            .with_context(|| format!("cannot convert i16 value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        u32::try_from(*v)
+            .with_context(|| format!("cannot convert i32 value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        u32::try_from(*v)
+            .with_context(|| format!("cannot convert i64 value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as u32)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        u32::try_from(*v)
+            .with_context(|| format!("cannot convert u64 value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(v.ceil() as u32)
+    }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as u32)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        u32::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
+}
+
+impl SqlConvertible for u64 {
+    fn into_sql_value(self) -> NnSqlValue {
+        NnSqlValue::UnsignedBigInt(self)
+    }
+
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        u64::try_from(*v)
+            .with_context(|| format!("cannot convert i16 value ({}) into u64", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        u64::try_from(*v)
+            .with_context(|| format!("cannot convert i32 value ({}) into u64", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        u64::try_from(*v)
+            .with_context(|| format!("cannot convert i64 value ({}) into u64", v))
+            .map_err(SpringError::Sql)
+    }
+
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(*v as u64)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as u64)
+    }
+
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(*v)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(v.ceil() as u64)
+    }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as u64)
+    }
+
+    /// Rounded half away from zero.
+    fn try_from_decimal(v: &Decimal) -> Result<Self> {
+        u64::try_from(v.rescale(0)?.mantissa())
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into u64", v))
+            .map_err(SpringError::Sql)
+    }
+}
+
+#[cfg(test)]
+mod tests_u64 {
+    use crate::{
+        error::Result,
+        stream_engine::autonomous_executor::row::value::sql_value::nn_sql_value::NnSqlValue,
+    };
+
+    #[test]
+    fn test_pack_unpack_u64() -> Result<()> {
+        let rust_values = vec![0, 1, u32::MAX as u64, u64::MAX];
+
+        for v in rust_values {
+            let sql_value = NnSqlValue::UnsignedBigInt(v);
+            let unpacked: u64 = sql_value.unpack()?;
+            assert_eq!(unpacked, v);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_out_of_range() {
+        assert!(NnSqlValue::Integer(-1).unpack::<u32>().is_err());
+        assert!(NnSqlValue::UnsignedBigInt(u64::MAX)
+            .unpack::<i64>()
+            .is_err());
+        assert!(NnSqlValue::UnsignedInteger(70000).unpack::<u16>().is_err());
+        assert_eq!(
+            NnSqlValue::UnsignedBigInt(i64::MAX as u64)
+                .unpack::<i64>()
+                .unwrap(),
+            i64::MAX
+        );
+    }
+}
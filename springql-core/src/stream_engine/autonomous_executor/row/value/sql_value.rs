@@ -1,14 +1,20 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+pub(crate) mod blob;
+pub(crate) mod decimal;
 pub(crate) mod nn_sql_value;
 pub(crate) mod sql_compare_result;
 pub(in crate::stream_engine::autonomous_executor) mod sql_value_hash_key;
 
-use self::{nn_sql_value::NnSqlValue, sql_compare_result::SqlCompareResult};
+use self::{decimal::Decimal, nn_sql_value::NnSqlValue, sql_compare_result::SqlCompareResult};
 use crate::{
     error::{Result, SpringError},
     mem_size::MemSize,
-    stream_engine::time::duration::event_duration::EventDuration,
+    pipeline::relation::sql_type::{
+        F32LooseType, F64LooseType, I64LooseType, NumericComparableType, SqlType,
+        StringComparableLoseType, TimestampComparableType, U64LooseType,
+    },
+    stream_engine::time::duration::{event_duration::EventDuration, SpringDuration},
 };
 use anyhow::anyhow;
 use ordered_float::OrderedFloat;
@@ -75,7 +81,10 @@ impl MemSize for SqlValue {
 
 impl PartialEq for SqlValue {
     fn eq(&self, other: &Self) -> bool {
-        matches!(self.sql_compare(other), Ok(SqlCompareResult::Eq))
+        match (self, other) {
+            (SqlValue::NotNull(v1), SqlValue::NotNull(v2)) => v1 == v2,
+            _ => matches!(self.sql_compare(other), Ok(SqlCompareResult::Eq)),
+        }
     }
 }
 
@@ -122,6 +131,19 @@ impl SqlValue {
         }
     }
 
+    /// NULL stays NULL. See [NnSqlValue::try_convert()](self::nn_sql_value::NnSqlValue::try_convert).
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - Value cannot be converted to `typ`.
+    pub(crate) fn try_convert(&self, typ: &SqlType) -> Result<SqlValue> {
+        match self {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(nn) => nn.try_convert(typ).map(SqlValue::NotNull),
+        }
+    }
+
     /// Eval as bool if possible.
     ///
     /// # Failures
@@ -170,6 +192,217 @@ impl SqlValue {
             SqlValue::NotNull(nn_sql_value) => nn_sql_value.unpack::<EventDuration>(),
         }
     }
+
+    /// SQL literal parsed into this value. `'` in literals are written as `''`.
+    ///
+    /// Non-negative signed integers, non-negative finite FLOAT values, TEXT and BOOLEAN values are written as they are.
+    /// BLOB values are written as `FROM_HEX('...')` and millisecond DURATION values as `DURATION_MILLIS(...)`.
+    /// Other values are written as typed literals like `DOUBLE '0.1'` (see [from_typed_literal()](Self::from_typed_literal)).
+    pub(crate) fn to_sql_literal(&self) -> String {
+        match self {
+            SqlValue::Null => "NULL".to_string(),
+            SqlValue::NotNull(nn_sql_value) => {
+                let plain = match nn_sql_value {
+                    NnSqlValue::SmallInt(v) if *v >= 0 => Some(v.to_string()),
+                    NnSqlValue::Integer(v) if *v >= 0 => Some(v.to_string()),
+                    NnSqlValue::BigInt(v) if *v >= 0 => Some(v.to_string()),
+                    NnSqlValue::Float(v) if v.is_finite() && v.is_sign_positive() => {
+                        let s = v.to_string();
+                        Some(if s.contains('.') {
+                            s
+                        } else {
+                            format!("{}.0", s)
+                        })
+                    }
+                    NnSqlValue::Text(v) => Some(format!("'{}'", v.replace('\'', "''"))),
+                    NnSqlValue::Boolean(v) => Some(if *v { "TRUE" } else { "FALSE" }.to_string()),
+                    NnSqlValue::Blob(v) => Some(format!("FROM_HEX('{}')", blob::to_hex(v))),
+                    NnSqlValue::Duration(v) if v.as_std().subsec_nanos() % 1_000_000 == 0 => {
+                        Some(format!("DURATION_MILLIS({})", v.as_std().as_millis()))
+                    }
+                    _ => None,
+                };
+                plain.unwrap_or_else(|| {
+                    format!(
+                        "{} '{}'",
+                        nn_sql_value.sql_type(),
+                        Self::literal_text(nn_sql_value).replace('\'', "''")
+                    )
+                })
+            }
+        }
+    }
+
+    /// Parses the text of a typed literal (`<type> '<text>'`).
+    ///
+    /// DURATION is written in seconds (`1.500000000`) and BLOB in hex.
+    /// ARRAY and STRUCT are written in JSON whose strings are the texts of the element types (`["1", null]`).
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `text` is not a value of `typ`.
+    pub(crate) fn from_typed_literal(typ: &SqlType, text: &str) -> Result<SqlValue> {
+        match typ {
+            SqlType::Array(_) | SqlType::Struct(_) => {
+                let json = serde_json::from_str(text).map_err(|e| {
+                    SpringError::Sql(anyhow!("`{}` is not a JSON of {}: {}", text, typ, e))
+                })?;
+                Self::from_literal_json(typ, &json)
+            }
+            _ => Self::from_literal_text(typ, text).map(SqlValue::NotNull),
+        }
+    }
+
+    fn literal_text(nn_sql_value: &NnSqlValue) -> String {
+        match Self::literal_json(nn_sql_value) {
+            serde_json::Value::String(s) => s,
+            json => json.to_string(),
+        }
+    }
+
+    fn literal_json(nn_sql_value: &NnSqlValue) -> serde_json::Value {
+        let element_json = |v: &SqlValue| match v {
+            SqlValue::Null => serde_json::Value::Null,
+            SqlValue::NotNull(v) => Self::literal_json(v),
+        };
+
+        let text = match nn_sql_value {
+            NnSqlValue::SmallInt(v) => v.to_string(),
+            NnSqlValue::Integer(v) => v.to_string(),
+            NnSqlValue::BigInt(v) => v.to_string(),
+            NnSqlValue::UnsignedSmallInt(v) => v.to_string(),
+            NnSqlValue::UnsignedInteger(v) => v.to_string(),
+            NnSqlValue::UnsignedBigInt(v) => v.to_string(),
+            NnSqlValue::Float(v) => v.to_string(),
+            NnSqlValue::Double(v) => v.to_string(),
+            NnSqlValue::Decimal(v) => v.to_string(),
+            NnSqlValue::Text(v) => v.clone(),
+            NnSqlValue::Boolean(v) => if *v { "TRUE" } else { "FALSE" }.to_string(),
+            NnSqlValue::Timestamp(v) => v.to_string(),
+            NnSqlValue::TimestampTz(v) => v.to_string(),
+            NnSqlValue::Duration(v) => {
+                format!("{}.{:09}", v.as_std().as_secs(), v.as_std().subsec_nanos())
+            }
+            NnSqlValue::Blob(v) => blob::to_hex(v),
+            NnSqlValue::Array(elements) => {
+                return serde_json::Value::Array(elements.iter().map(element_json).collect())
+            }
+            NnSqlValue::Struct(fields) => {
+                return serde_json::Value::Object(
+                    fields
+                        .iter()
+                        .map(|(name, v)| (name.clone(), element_json(v)))
+                        .collect(),
+                )
+            }
+        };
+        serde_json::Value::String(text)
+    }
+
+    fn from_literal_json(typ: &SqlType, json: &serde_json::Value) -> Result<SqlValue> {
+        match (typ, json) {
+            (_, serde_json::Value::Null) => Ok(SqlValue::Null),
+            (SqlType::Array(element_type), serde_json::Value::Array(elements)) => elements
+                .iter()
+                .map(|e| Self::from_literal_json(element_type, e))
+                .collect::<Result<Vec<_>>>()
+                .map(|elements| SqlValue::NotNull(NnSqlValue::Array(elements))),
+            // Missing fields are NULL.
+            (SqlType::Struct(field_types), serde_json::Value::Object(fields)) => field_types
+                .iter()
+                .map(|(name, field_type)| {
+                    let value = fields
+                        .get(name)
+                        .map(|v| Self::from_literal_json(field_type, v))
+                        .transpose()?
+                        .unwrap_or(SqlValue::Null);
+                    Ok((name.clone(), value))
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(|fields| SqlValue::NotNull(NnSqlValue::Struct(fields))),
+            (_, serde_json::Value::String(s)) => {
+                Self::from_literal_text(typ, s).map(SqlValue::NotNull)
+            }
+            (_, serde_json::Value::Number(_) | serde_json::Value::Bool(_)) => {
+                Self::from_literal_text(typ, &json.to_string()).map(SqlValue::NotNull)
+            }
+            _ => Err(SpringError::Sql(anyhow!(
+                "`{}` is not a JSON of {}",
+                json,
+                typ
+            ))),
+        }
+    }
+
+    fn from_literal_text(typ: &SqlType, text: &str) -> Result<NnSqlValue> {
+        let err = || SpringError::Sql(anyhow!("`{}` is not a literal of {}", text, typ));
+
+        match typ {
+            SqlType::NumericComparable(n) => match n {
+                NumericComparableType::I64Loose(I64LooseType::SmallInt) => {
+                    text.parse().map(NnSqlValue::SmallInt).map_err(|_| err())
+                }
+                NumericComparableType::I64Loose(I64LooseType::Integer) => {
+                    text.parse().map(NnSqlValue::Integer).map_err(|_| err())
+                }
+                NumericComparableType::I64Loose(I64LooseType::BigInt) => {
+                    text.parse().map(NnSqlValue::BigInt).map_err(|_| err())
+                }
+                NumericComparableType::U64Loose(U64LooseType::SmallInt) => text
+                    .parse()
+                    .map(NnSqlValue::UnsignedSmallInt)
+                    .map_err(|_| err()),
+                NumericComparableType::U64Loose(U64LooseType::Integer) => text
+                    .parse()
+                    .map(NnSqlValue::UnsignedInteger)
+                    .map_err(|_| err()),
+                NumericComparableType::U64Loose(U64LooseType::BigInt) => text
+                    .parse()
+                    .map(NnSqlValue::UnsignedBigInt)
+                    .map_err(|_| err()),
+                NumericComparableType::F32Loose(F32LooseType::Float) => text
+                    .parse()
+                    .map(|v| NnSqlValue::Float(OrderedFloat(v)))
+                    .map_err(|_| err()),
+                NumericComparableType::F64Loose(F64LooseType::Double) => text
+                    .parse()
+                    .map(|v| NnSqlValue::Double(OrderedFloat(v)))
+                    .map_err(|_| err()),
+                NumericComparableType::Decimal { precision, scale } => text
+                    .parse::<Decimal>()
+                    .and_then(|v| v.fit(*precision, *scale))
+                    .map(NnSqlValue::Decimal),
+            },
+            SqlType::StringComparableLoose(StringComparableLoseType::Text) => {
+                Ok(NnSqlValue::Text(text.to_string()))
+            }
+            SqlType::BooleanComparable => match text.to_lowercase().as_str() {
+                "true" => Ok(NnSqlValue::Boolean(true)),
+                "false" => Ok(NnSqlValue::Boolean(false)),
+                _ => Err(err()),
+            },
+            SqlType::TimestampComparable(TimestampComparableType::Timestamp) => {
+                text.parse().map(NnSqlValue::Timestamp)
+            }
+            SqlType::TimestampComparable(TimestampComparableType::TimestampTz) => {
+                text.parse().map(NnSqlValue::TimestampTz)
+            }
+            SqlType::DurationComparable => {
+                let (secs, nanos) = text.split_once('.').unwrap_or((text, "0"));
+                if nanos.len() > 9 || !nanos.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(err());
+                }
+                let secs = secs.parse().map_err(|_| err())?;
+                let nanos = format!("{:0<9}", nanos).parse().map_err(|_| err())?;
+                Ok(NnSqlValue::Duration(EventDuration::from_std(
+                    std::time::Duration::new(secs, nanos),
+                )))
+            }
+            SqlType::BinaryComparable => blob::from_hex(text).map(NnSqlValue::Blob),
+            SqlType::Array(_) | SqlType::Struct(_) => Err(err()),
+        }
+    }
 }
 
 impl TryFrom<&serde_json::Value> for SqlValue {
@@ -181,25 +414,31 @@ impl TryFrom<&serde_json::Value> for SqlValue {
             serde_json::Value::Bool(b) => Ok(SqlValue::NotNull(NnSqlValue::Boolean(*b))),
 
             serde_json::Value::Number(n) => {
-                if let Some(f) = n.as_f64() {
-                    Ok(SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(f as f32))))
-                } else if let Some(i) = n.as_i64() {
+                if let Some(i) = n.as_i64() {
                     Ok(SqlValue::NotNull(NnSqlValue::BigInt(i)))
+                } else if let Some(u) = n.as_u64() {
+                    Ok(SqlValue::NotNull(NnSqlValue::UnsignedBigInt(u)))
+                } else if let Some(f) = n.as_f64() {
+                    Ok(SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(f))))
                 } else {
                     Err(SpringError::Sql(anyhow!(
-                        "unsupported number as SQL type: {:?} cannot be evaluated as BIGINT",
+                        "unsupported number as SQL type: {:?} cannot be evaluated as DOUBLE",
                         n
                     )))
                 }
             }
 
             serde_json::Value::String(s) => Ok(SqlValue::NotNull(NnSqlValue::Text(s.clone()))),
-            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-                Err(SpringError::InvalidFormat {
-                    source: anyhow!("JSON array or object are not supported as SQL type"),
-                    s: format!("{:?}", value),
-                })
-            }
+            serde_json::Value::Array(elements) => elements
+                .iter()
+                .map(SqlValue::try_from)
+                .collect::<Result<Vec<_>>>()
+                .map(|elements| SqlValue::NotNull(NnSqlValue::Array(elements))),
+            serde_json::Value::Object(fields) => fields
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), SqlValue::try_from(value)?)))
+                .collect::<Result<Vec<_>>>()
+                .map(|fields| SqlValue::NotNull(NnSqlValue::Struct(fields))),
         }
     }
 }
@@ -342,4 +581,195 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_json_number() -> Result<()> {
+        let from_json = |json: serde_json::Value| SqlValue::try_from(&json);
+
+        assert_eq!(
+            from_json(serde_json::json!(9_007_199_254_740_993i64))?,
+            SqlValue::NotNull(NnSqlValue::BigInt(9_007_199_254_740_993))
+        );
+        assert_eq!(
+            from_json(serde_json::json!(u64::MAX))?,
+            SqlValue::NotNull(NnSqlValue::UnsignedBigInt(u64::MAX))
+        );
+        assert_eq!(
+            from_json(serde_json::json!(35.681236))?,
+            SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(35.681236)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json_nested() -> Result<()> {
+        let json = serde_json::json!({"id": 1, "tags": ["a", null]});
+        let value = SqlValue::try_from(&json)?;
+
+        // NULL elements are not equal to each other
+        assert_eq!(value.to_string(), r#"{id: 1, tags: ["a", NULL]}"#);
+        assert_eq!(
+            value.clone().unwrap().sql_type(),
+            SqlType::structure(vec![
+                ("id".to_string(), SqlType::big_int()),
+                ("tags".to_string(), SqlType::array(SqlType::text())),
+            ])
+        );
+        assert_eq!(serde_json::Value::from(value.unwrap()), json);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_convert_struct() -> Result<()> {
+        let value = SqlValue::NotNull(NnSqlValue::Struct(vec![
+            (
+                "extra".to_string(),
+                SqlValue::NotNull(NnSqlValue::BigInt(0)),
+            ),
+            ("id".to_string(), SqlValue::NotNull(NnSqlValue::BigInt(1))),
+        ]));
+        let typ = SqlType::structure(vec![
+            ("id".to_string(), SqlType::integer()),
+            ("tags".to_string(), SqlType::array(SqlType::text())),
+        ]);
+
+        // undeclared field is dropped and missing field is NULL
+        let converted = value.try_convert(&typ)?;
+        assert_eq!(converted.to_string(), "{id: 1, tags: NULL}");
+        assert_eq!(
+            converted.unwrap().sql_type(),
+            SqlType::structure(vec![
+                ("id".to_string(), SqlType::integer()),
+                ("tags".to_string(), SqlType::text()),
+            ])
+        );
+
+        let array = SqlValue::NotNull(NnSqlValue::Array(vec![SqlValue::NotNull(
+            NnSqlValue::Text("a".to_string()),
+        )]));
+        assert!(array
+            .try_convert(&SqlType::array(SqlType::integer()))
+            .is_err());
+        assert!(array.try_convert(&SqlType::text()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_sql_literal() {
+        let literal = |nn_sql_value| SqlValue::NotNull(nn_sql_value).to_sql_literal();
+
+        assert_eq!(SqlValue::Null.to_sql_literal(), "NULL");
+        assert_eq!(literal(NnSqlValue::SmallInt(3)), "3");
+        assert_eq!(literal(NnSqlValue::Integer(-3)), "INTEGER '-3'");
+        assert_eq!(literal(NnSqlValue::BigInt(3)), "3");
+        assert_eq!(
+            literal(NnSqlValue::UnsignedInteger(3)),
+            "INTEGER UNSIGNED '3'"
+        );
+        assert_eq!(
+            literal(NnSqlValue::UnsignedBigInt(u64::MAX)),
+            "BIGINT UNSIGNED '18446744073709551615'"
+        );
+        assert_eq!(literal(NnSqlValue::Float(OrderedFloat(2.0))), "2.0");
+        assert_eq!(
+            literal(NnSqlValue::Float(OrderedFloat(f32::NAN))),
+            "FLOAT 'NaN'"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Double(OrderedFloat(0.1))),
+            "DOUBLE '0.1'"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Decimal("-1.50".parse().unwrap())),
+            "DECIMAL(38, 2) '-1.50'"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Text("q_vehicle".to_string())),
+            "'q_vehicle'"
+        );
+        assert_eq!(literal(NnSqlValue::Text("O'RCL".to_string())), "'O''RCL'");
+        assert_eq!(
+            literal(NnSqlValue::Blob(vec![0x0a, 0xff])),
+            "FROM_HEX('0AFF')"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Duration(EventDuration::from_secs(10))),
+            "DURATION_MILLIS(10000)"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Duration(EventDuration::from_micros(1))),
+            "DURATION '0.000001000'"
+        );
+        assert_eq!(
+            literal(NnSqlValue::Array(vec![
+                SqlValue::NotNull(NnSqlValue::Text("O'RCL".to_string())),
+                SqlValue::Null,
+            ])),
+            r#"ARRAY<TEXT> '["O''RCL",null]'"#
+        );
+    }
+
+    #[test]
+    fn test_from_typed_literal() {
+        let nn = SqlValue::NotNull;
+        let timestamp = "2020-01-01 00:00:00.000000001";
+
+        let values = vec![
+            nn(NnSqlValue::Integer(-3)),
+            nn(NnSqlValue::UnsignedBigInt(u64::MAX)),
+            nn(NnSqlValue::Float(OrderedFloat(f32::INFINITY))),
+            nn(NnSqlValue::Double(OrderedFloat(-0.1))),
+            nn(NnSqlValue::Decimal(
+                "12345678901234567890.120".parse().unwrap(),
+            )),
+            nn(NnSqlValue::Boolean(true)),
+            nn(NnSqlValue::Timestamp(timestamp.parse().unwrap())),
+            nn(NnSqlValue::TimestampTz(
+                format!("{}+09:00", timestamp).parse().unwrap(),
+            )),
+            nn(NnSqlValue::Duration(EventDuration::from_micros(1_500_001))),
+            nn(NnSqlValue::Blob(vec![0x00, 0xff])),
+            nn(NnSqlValue::Array(vec![
+                nn(NnSqlValue::Double(OrderedFloat(1.5))),
+                SqlValue::Null,
+            ])),
+            nn(NnSqlValue::Struct(vec![
+                (
+                    "ts".to_string(),
+                    nn(NnSqlValue::Timestamp(timestamp.parse().unwrap())),
+                ),
+                (
+                    "tags".to_string(),
+                    nn(NnSqlValue::Array(vec![nn(NnSqlValue::Text(
+                        "a\"b".to_string(),
+                    ))])),
+                ),
+            ])),
+        ];
+
+        for value in values {
+            let nn_sql_value = match &value {
+                SqlValue::NotNull(v) => v,
+                SqlValue::Null => unreachable!(),
+            };
+            let text = SqlValue::literal_text(nn_sql_value);
+            let parsed = SqlValue::from_typed_literal(&nn_sql_value.sql_type(), &text).unwrap();
+            // compares texts since NaN != NaN and TIMESTAMPTZ values are compared by instants
+            assert_eq!(
+                SqlValue::literal_text(match &parsed {
+                    SqlValue::NotNull(v) => v,
+                    SqlValue::Null => unreachable!(),
+                }),
+                text
+            );
+            assert_eq!(parsed.to_sql_literal(), value.to_sql_literal());
+        }
+
+        assert!(SqlValue::from_typed_literal(&SqlType::integer(), "1.5").is_err());
+        assert!(SqlValue::from_typed_literal(&SqlType::decimal(3, 1), "123.4").is_err());
+        assert!(SqlValue::from_typed_literal(&SqlType::duration(), "1.0000000001").is_err());
+        assert!(
+            SqlValue::from_typed_literal(&SqlType::array(SqlType::integer()), "[1, 2").is_err()
+        );
+    }
 }
@@ -0,0 +1,148 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Encodings of and accesses to BLOB values (`Vec<u8>`).
+
+use anyhow::anyhow;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::error::{Result, SpringError};
+
+/// Max number of bits `get_bits()` can extract not to overflow BIGINT.
+const MAX_GET_BITS_LEN: i64 = 63;
+
+/// Upper-case hex string (`[0x0a, 0xff]` -> `"0AFF"`).
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `s` has odd length or non-hex characters.
+pub(crate) fn from_hex(s: &str) -> Result<Vec<u8>> {
+    let invalid = || SpringError::Sql(anyhow!("invalid hex string: `{}`", s));
+
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| match std::str::from_utf8(pair) {
+            Ok(pair) if pair.len() == 2 => u8::from_str_radix(pair, 16).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+/// Standard base64 with padding.
+pub(crate) fn to_base64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `s` is not a standard base64 string.
+pub(crate) fn from_base64(s: &str) -> Result<Vec<u8>> {
+    STANDARD
+        .decode(s)
+        .map_err(|e| SpringError::Sql(anyhow!("invalid base64 string `{}`: {}", s, e)))
+}
+
+/// `offset`-th byte (0-origin).
+///
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `offset` is out of range.
+pub(crate) fn get_byte(bytes: &[u8], offset: i64) -> Result<u8> {
+    usize::try_from(offset)
+        .ok()
+        .and_then(|offset| bytes.get(offset))
+        .copied()
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "GET_BYTE offset {} is out of range of {} bytes",
+                offset,
+                bytes.len()
+            ))
+        })
+}
+
+/// Unsigned integer of `len` bits starting from `offset`-th bit.
+///
+/// Bits are numbered from the least significant bit of each byte: bit `n` is the `n % 8`-th bit of the `n / 8`-th byte.
+/// The bit at `offset` is the least significant bit of the result (little-endian signals, as Intel byte order in CAN DBC files).
+///
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `len` is not in 1 - 63.
+///   - `offset` or `offset + len` is out of range.
+pub(crate) fn get_bits(bytes: &[u8], offset: i64, len: i64) -> Result<i64> {
+    if !(1..=MAX_GET_BITS_LEN).contains(&len) {
+        return Err(SpringError::Sql(anyhow!(
+            "GET_BITS length must be 1 - {} but got {}",
+            MAX_GET_BITS_LEN,
+            len
+        )));
+    }
+    let n_bits = bytes.len() as i64 * 8;
+    if offset < 0 || offset > n_bits - len {
+        return Err(SpringError::Sql(anyhow!(
+            "GET_BITS range ({}, {}) is out of range of {} bits",
+            offset,
+            len,
+            n_bits
+        )));
+    }
+
+    let v = (0..len).fold(0_i64, |acc, i| {
+        let bit = offset + i;
+        let b = (bytes[(bit / 8) as usize] >> (bit % 8)) & 1;
+        acc | ((b as i64) << i)
+    });
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(to_hex(&[0x0a, 0xff, 0x00]), "0AFF00");
+        assert_eq!(from_hex("0aFF00").unwrap(), vec![0x0a, 0xff, 0x00]);
+        assert_eq!(from_hex("").unwrap(), Vec::<u8>::new());
+        assert!(from_hex("0AF").is_err());
+        assert!(from_hex("0G").is_err());
+        assert!(from_hex("é0").is_err());
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(to_base64(&[0x00, 0xff, 0x10]), "AP8Q");
+        assert_eq!(from_base64("AP8Q").unwrap(), vec![0x00, 0xff, 0x10]);
+        assert!(from_base64("AP8").is_err());
+    }
+
+    #[test]
+    fn test_get_byte() {
+        let bytes = [0x12, 0x34];
+        assert_eq!(get_byte(&bytes, 1).unwrap(), 0x34);
+        assert!(get_byte(&bytes, 2).is_err());
+        assert!(get_byte(&bytes, -1).is_err());
+    }
+
+    #[test]
+    fn test_get_bits() {
+        // bits (LSB first): 0x34 = 0b0011_0100, 0x12 = 0b0001_0010
+        let bytes = [0x34, 0x12];
+        assert_eq!(get_bits(&bytes, 0, 16).unwrap(), 0x1234);
+        assert_eq!(get_bits(&bytes, 2, 1).unwrap(), 1);
+        assert_eq!(get_bits(&bytes, 4, 8).unwrap(), 0x23);
+        assert_eq!(get_bits(&[0xff; 8], 0, 63).unwrap(), i64::MAX);
+
+        assert!(get_bits(&bytes, 9, 8).is_err());
+        assert!(get_bits(&bytes, -1, 8).is_err());
+        assert!(get_bits(&bytes, 0, 0).is_err());
+        assert!(get_bits(&[0xff; 8], 0, 64).is_err());
+    }
+}
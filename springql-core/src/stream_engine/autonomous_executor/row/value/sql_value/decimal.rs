@@ -0,0 +1,400 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{cmp::Ordering, fmt::Display, hash::Hash, str::FromStr};
+
+use anyhow::anyhow;
+
+use crate::{
+    error::{Result, SpringError},
+    pipeline::relation::sql_type::MAX_DECIMAL_PRECISION,
+};
+
+/// Fixed-point decimal number: `mantissa * 10^(-scale)`.
+///
+/// Scale is at most `MAX_DECIMAL_PRECISION` (38) while mantissa can be any i128 value.
+/// Precision is checked when a value is converted into `DECIMAL(precision, scale)` type.
+///
+/// Equality, ordering and hash do not depend on the scale (`1.50` equals to `1.5`).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `scale` exceeds `MAX_DECIMAL_PRECISION` (38).
+    pub(crate) fn new(mantissa: i128, scale: u8) -> Result<Self> {
+        if scale > MAX_DECIMAL_PRECISION {
+            Err(SpringError::Sql(anyhow!(
+                "DECIMAL scale {} exceeds {}",
+                scale,
+                MAX_DECIMAL_PRECISION
+            )))
+        } else {
+            Ok(Self { mantissa, scale })
+        }
+    }
+
+    /// Same as [Decimal::new()] but rounds `scale` larger than `MAX_DECIMAL_PRECISION` (38) down to it.
+    pub(crate) fn new_rounded(mantissa: i128, scale: u8) -> Self {
+        match scale.checked_sub(MAX_DECIMAL_PRECISION) {
+            None | Some(0) => Self { mantissa, scale },
+            // |mantissa| < 0.5 * 10^39
+            Some(excess) if excess > MAX_DECIMAL_PRECISION => Self {
+                mantissa: 0,
+                scale: MAX_DECIMAL_PRECISION,
+            },
+            Some(_) => Self::rescale_down(mantissa, scale, MAX_DECIMAL_PRECISION),
+        }
+    }
+
+    pub(crate) fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub(crate) fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Changes the scale, rounding half away from zero if digits are dropped.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the result exceeds `MAX_DECIMAL_PRECISION` (38) digits.
+    pub(crate) fn rescale(&self, scale: u8) -> Result<Self> {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => Self::new(self.mantissa, scale),
+            Ordering::Greater => {
+                let mantissa = pow10(scale - self.scale)
+                    .and_then(|p| self.mantissa.checked_mul(p))
+                    .ok_or_else(|| self.overflow())?;
+                Self::new(mantissa, scale)
+            }
+            Ordering::Less => Ok(Self::rescale_down(self.mantissa, self.scale, scale)),
+        }
+    }
+
+    /// `mantissa * 10^(-from_scale)` in `to_scale`, where `from_scale - to_scale <= MAX_DECIMAL_PRECISION`.
+    fn rescale_down(mantissa: i128, from_scale: u8, to_scale: u8) -> Self {
+        let p = pow10(from_scale - to_scale).expect("scale difference <= MAX_DECIMAL_PRECISION");
+        let (q, r) = (mantissa / p, mantissa % p);
+        let mantissa = if r.unsigned_abs() * 2 >= p as u128 {
+            q + r.signum()
+        } else {
+            q
+        };
+        Self {
+            mantissa,
+            scale: to_scale,
+        }
+    }
+
+    /// Rescales into `DECIMAL(precision, scale)`.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the integer part does not fit in `precision - scale` digits.
+    pub(crate) fn fit(&self, precision: u8, scale: u8) -> Result<Self> {
+        let d = self.rescale(scale)?;
+        if d.digits() > precision {
+            Err(SpringError::Sql(anyhow!(
+                "{} does not fit in DECIMAL({}, {})",
+                self,
+                precision,
+                scale
+            )))
+        } else {
+            Ok(d)
+        }
+    }
+
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the result overflows.
+    pub(crate) fn checked_add(&self, rhs: &Self) -> Result<Self> {
+        let scale = self.scale.max(rhs.scale);
+        let (lhs_mantissa, rhs_mantissa) =
+            (self.rescale(scale)?.mantissa, rhs.rescale(scale)?.mantissa);
+        lhs_mantissa
+            .checked_add(rhs_mantissa)
+            .ok_or_else(|| self.overflow())
+            .and_then(|mantissa| Self::new(mantissa, scale))
+    }
+
+    /// The scale of the result is the sum of scales (at most `MAX_DECIMAL_PRECISION` (38)).
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - the result overflows.
+    pub(crate) fn checked_mul(&self, rhs: &Self) -> Result<Self> {
+        let mantissa = self
+            .mantissa
+            .checked_mul(rhs.mantissa)
+            .ok_or_else(|| self.overflow())?;
+        Ok(Self::new_rounded(mantissa, self.scale + rhs.scale))
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        Self {
+            mantissa: -self.mantissa,
+            scale: self.scale,
+        }
+    }
+
+    /// Nearest f64 value.
+    pub(crate) fn to_f64(self) -> f64 {
+        self.to_string()
+            .parse()
+            .expect("decimal notation is parsed as f64")
+    }
+
+    /// Shortest decimal representation of `v` which is parsed back into `v`.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `v` is NaN or infinite.
+    ///   - `v` is too large or too small to be represented.
+    pub(crate) fn from_f64(v: f64) -> Result<Self> {
+        if v.is_finite() {
+            v.to_string().parse()
+        } else {
+            Err(SpringError::Sql(anyhow!("{} cannot be DECIMAL", v)))
+        }
+    }
+
+    /// Number of digits in the mantissa (`1` for 0).
+    fn digits(&self) -> u8 {
+        let mut n = self.mantissa.unsigned_abs();
+        let mut digits = 1;
+        while n >= 10 {
+            n /= 10;
+            digits += 1;
+        }
+        digits
+    }
+
+    /// Removes trailing zeros after the decimal point.
+    fn normalize(&self) -> Self {
+        let mut d = *self;
+        while d.scale > 0 && d.mantissa % 10 == 0 {
+            d.mantissa /= 10;
+            d.scale -= 1;
+        }
+        d
+    }
+
+    fn overflow(&self) -> SpringError {
+        SpringError::Sql(anyhow!("DECIMAL overflow"))
+    }
+}
+
+/// `10^exp` if it does not overflow.
+fn pow10(exp: u8) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+impl From<i64> for Decimal {
+    fn from(v: i64) -> Self {
+        Self {
+            mantissa: v as i128,
+            scale: 0,
+        }
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(v: u64) -> Self {
+        Self {
+            mantissa: v as i128,
+            scale: 0,
+        }
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Decimal {
+    /// Compares integer parts first, and then fractional parts aligned to the larger scale (fits in i128 since it is less than `10^38`).
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (self_p, other_p) = (
+            pow10(self.scale).expect("scale <= MAX_DECIMAL_PRECISION"),
+            pow10(other.scale).expect("scale <= MAX_DECIMAL_PRECISION"),
+        );
+        let (self_int, other_int) = (self.mantissa / self_p, other.mantissa / other_p);
+
+        self_int.cmp(&other_int).then_with(|| {
+            let scale = self.scale.max(other.scale);
+            let self_frac = (self.mantissa % self_p)
+                * pow10(scale - self.scale).expect("scale <= MAX_DECIMAL_PRECISION");
+            let other_frac = (other.mantissa % other_p)
+                * pow10(scale - other.scale).expect("scale <= MAX_DECIMAL_PRECISION");
+            self_frac.cmp(&other_frac)
+        })
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let d = self.normalize();
+        d.mantissa.hash(state);
+        d.scale.hash(state);
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let scale = self.scale as usize;
+
+        if scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else {
+            let digits = format!("{:0>width$}", digits, width = scale + 1);
+            let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+            write!(f, "{}{}.{}", sign, int_part, frac_part)
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = SpringError;
+
+    /// Parses `[+-]digits[.digits]`.
+    fn from_str(s: &str) -> Result<Self> {
+        let err = || SpringError::Sql(anyhow!("`{}` cannot be parsed as DECIMAL", s));
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        if int_part.is_empty()
+            || !int_part
+                .chars()
+                .chain(frac_part.chars())
+                .all(|c| c.is_ascii_digit())
+            || (unsigned.contains('.') && frac_part.is_empty())
+        {
+            return Err(err());
+        }
+
+        let scale = u8::try_from(frac_part.len()).map_err(|_| err())?;
+        let abs = format!("{}{}", int_part, frac_part)
+            .trim_start_matches('0')
+            .parse::<i128>()
+            .or_else(|e| {
+                if int_part.chars().chain(frac_part.chars()).all(|c| c == '0') {
+                    Ok(0)
+                } else {
+                    Err(e)
+                }
+            })
+            .map_err(|_| err())?;
+
+        Self::new(if negative { -abs } else { abs }, scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_display() {
+        for s in [
+            "0",
+            "1",
+            "-1",
+            "123.45",
+            "-0.05",
+            "0.10",
+            "1234567890.123456789",
+        ] {
+            assert_eq!(dec(s).to_string(), s);
+        }
+        assert_eq!(dec("+007.50").to_string(), "7.50");
+
+        for s in ["", "-", "1.", ".5", "1e3", "1.2.3", "abc"] {
+            assert!(s.parse::<Decimal>().is_err(), "{}", s);
+        }
+        assert!("9".repeat(39).parse::<Decimal>().is_err());
+        assert!(format!("0.{}", "1".repeat(39)).parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_eq_ord() {
+        assert_eq!(dec("1.50"), dec("1.5"));
+        assert_eq!(dec("-0.0"), dec("0"));
+        assert!(dec("1.05") < dec("1.5"));
+        assert!(dec("-1.5") < dec("-1.05"));
+        assert!(dec("-0.5") < dec("0.25"));
+        assert!(dec("10") > dec("9.99999999999999999999999999999999999"));
+    }
+
+    #[test]
+    fn test_rescale_fit() {
+        assert_eq!(dec("1.25").rescale(1).unwrap().to_string(), "1.3");
+        assert_eq!(dec("-1.25").rescale(1).unwrap().to_string(), "-1.3");
+        assert_eq!(dec("1.24").rescale(1).unwrap().to_string(), "1.2");
+        assert_eq!(dec("1.2").rescale(3).unwrap().to_string(), "1.200");
+
+        assert_eq!(dec("123.456").fit(5, 2).unwrap().to_string(), "123.46");
+        assert!(dec("1234.5").fit(5, 2).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(
+            dec("1.25").checked_add(&dec("-0.5")).unwrap().to_string(),
+            "0.75"
+        );
+        assert_eq!(
+            dec("1.25").checked_mul(&dec("0.2")).unwrap().to_string(),
+            "0.250"
+        );
+        let max = Decimal::new(i128::MAX, 0).unwrap();
+        assert!(max.checked_add(&dec("1")).is_err());
+        assert!(max.checked_mul(&dec("10")).is_err());
+    }
+
+    #[test]
+    fn test_new_rounded() {
+        assert_eq!(
+            Decimal::new_rounded(15, 39).to_string(),
+            format!("0.{}2", "0".repeat(37))
+        );
+        assert_eq!(Decimal::new_rounded(i128::MAX, 200), dec("0"));
+        assert_eq!(Decimal::new_rounded(-15, 1), dec("-1.5"));
+    }
+
+    #[test]
+    fn test_f64() {
+        assert_eq!(Decimal::from_f64(35.681236).unwrap(), dec("35.681236"));
+        assert_eq!(dec("139.767125").to_f64(), 139.767125);
+        assert!(Decimal::from_f64(f64::NAN).is_err());
+    }
+}
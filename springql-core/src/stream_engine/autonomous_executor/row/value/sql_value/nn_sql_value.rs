@@ -4,17 +4,22 @@ use std::mem::size_of;
 use std::ops::{Add, Mul};
 use std::{fmt::Display, hash::Hash};
 
+use super::blob;
+use super::decimal::Decimal;
 use super::sql_compare_result::SqlCompareResult;
+use super::SqlValue;
 use crate::error::{Result, SpringError};
 use crate::mem_size::MemSize;
 use crate::pipeline::relation::sql_type::{
-    self, NumericComparableType, SqlType, StringComparableLoseType,
+    self, NumericComparableType, SqlType, StringComparableLoseType, TimestampComparableType,
+    MAX_DECIMAL_PRECISION,
 };
 use crate::stream_engine::autonomous_executor::row::value::sql_convertible::SqlConvertible;
 use crate::stream_engine::time::duration::event_duration::EventDuration;
-use crate::stream_engine::time::timestamp::Timestamp;
+use crate::stream_engine::time::timestamp::{timestamp_tz::TimestampTz, Timestamp};
 use anyhow::anyhow;
 use ordered_float::OrderedFloat;
+use std::any::type_name;
 
 /// NOT NULL value.
 #[derive(Clone, Debug)]
@@ -26,11 +31,23 @@ pub(crate) enum NnSqlValue {
     /// BIGINT
     BigInt(i64),
 
+    /// SMALLINT UNSIGNED
+    UnsignedSmallInt(u16),
+    /// INTEGER UNSIGNED
+    UnsignedInteger(u32),
+    /// BIGINT UNSIGNED
+    UnsignedBigInt(u64),
+
     /// FLOAT
     Float(
         // to implement Hash
         OrderedFloat<f32>,
     ),
+    /// DOUBLE
+    Double(OrderedFloat<f64>),
+
+    /// DECIMAL
+    Decimal(Decimal),
 
     /// TEXT
     Text(String),
@@ -41,8 +58,20 @@ pub(crate) enum NnSqlValue {
     /// TIMESTAMP
     Timestamp(Timestamp),
 
+    /// TIMESTAMPTZ
+    TimestampTz(TimestampTz),
+
     /// DURATION
     Duration(EventDuration),
+
+    /// BLOB
+    Blob(Vec<u8>),
+
+    /// ARRAY
+    Array(Vec<SqlValue>),
+
+    /// STRUCT: (field name, value) in order.
+    Struct(Vec<(String, SqlValue)>),
 }
 
 impl MemSize for NnSqlValue {
@@ -52,15 +81,31 @@ impl MemSize for NnSqlValue {
             NnSqlValue::Integer(_) => size_of::<i32>(),
             NnSqlValue::BigInt(_) => size_of::<i64>(),
 
+            NnSqlValue::UnsignedSmallInt(_) => size_of::<u16>(),
+            NnSqlValue::UnsignedInteger(_) => size_of::<u32>(),
+            NnSqlValue::UnsignedBigInt(_) => size_of::<u64>(),
+
             NnSqlValue::Float(_) => size_of::<f32>(),
+            NnSqlValue::Double(_) => size_of::<f64>(),
+
+            NnSqlValue::Decimal(_) => size_of::<Decimal>(),
 
             NnSqlValue::Text(s) => s.capacity(),
 
             NnSqlValue::Boolean(_) => size_of::<bool>(),
 
             NnSqlValue::Timestamp(ts) => ts.mem_size(),
+            NnSqlValue::TimestampTz(ts) => ts.mem_size(),
 
             NnSqlValue::Duration(dur) => dur.mem_size(),
+
+            NnSqlValue::Blob(b) => b.capacity(),
+
+            NnSqlValue::Array(elements) => elements.iter().map(SqlValue::mem_size).sum(),
+            NnSqlValue::Struct(fields) => fields
+                .iter()
+                .map(|(name, value)| name.capacity() + value.mem_size())
+                .sum(),
         }
     }
 }
@@ -80,63 +125,151 @@ impl MemSize for NnSqlValue {
 ///
 /// does not work properly with closures which capture &mut environments.
 macro_rules! for_all_loose_types {
-    ( $nn_sql_value:expr, $closure_i64:expr, $closure_ordered_float:expr, $closure_string:expr, $closure_bool:expr, $closure_timestamp:expr, $closure_duration:expr ) => {{
+    ( $nn_sql_value:expr, $closure_i64:expr, $closure_u64:expr, $closure_ordered_float:expr, $closure_ordered_double:expr, $closure_decimal:expr, $closure_string:expr, $closure_bool:expr, $closure_timestamp:expr, $closure_duration:expr, $closure_blob:expr, $closure_array:expr, $closure_struct:expr ) => {{
         match &$nn_sql_value {
             NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
                 let v = $nn_sql_value.unpack::<i64>().unwrap();
                 $closure_i64(v)
             }
+            NnSqlValue::UnsignedSmallInt(_)
+            | NnSqlValue::UnsignedInteger(_)
+            | NnSqlValue::UnsignedBigInt(_) => {
+                let v = $nn_sql_value.unpack::<u64>().unwrap();
+                $closure_u64(v)
+            }
             NnSqlValue::Float(_) => {
                 let v = $nn_sql_value.unpack::<f32>().unwrap();
                 $closure_ordered_float(OrderedFloat(v))
             }
+            NnSqlValue::Double(d) => $closure_ordered_double(*d),
+            NnSqlValue::Decimal(d) => $closure_decimal(*d),
             NnSqlValue::Text(s) => $closure_string(s.to_string()),
             NnSqlValue::Boolean(b) => $closure_bool(b.clone()),
             NnSqlValue::Timestamp(t) => $closure_timestamp(*t),
+            NnSqlValue::TimestampTz(t) => $closure_timestamp(t.to_timestamp()),
             NnSqlValue::Duration(d) => $closure_duration(*d),
+            NnSqlValue::Blob(b) => $closure_blob(b.as_slice()),
+            NnSqlValue::Array(elements) => $closure_array(elements.as_slice()),
+            NnSqlValue::Struct(fields) => $closure_struct(fields.as_slice()),
         }
     }};
 }
 
 impl PartialEq for NnSqlValue {
+    /// DURATION, ARRAY and STRUCT values are not comparable in SQL but equal to the same values (to be `Eq`).
+    /// ARRAY and STRUCT values are compared structurally, where NULL elements are equal to each other.
     fn eq(&self, other: &Self) -> bool {
-        matches!(self.sql_compare(other), Ok(SqlCompareResult::Eq))
+        match (self, other) {
+            (NnSqlValue::Duration(d1), NnSqlValue::Duration(d2)) => d1 == d2,
+            (NnSqlValue::Array(a1), NnSqlValue::Array(a2)) => {
+                a1.len() == a2.len() && a1.iter().zip(a2).all(|(e1, e2)| element_eq(e1, e2))
+            }
+            (NnSqlValue::Struct(s1), NnSqlValue::Struct(s2)) => {
+                s1.len() == s2.len()
+                    && s1
+                        .iter()
+                        .zip(s2)
+                        .all(|((n1, e1), (n2, e2))| n1 == n2 && element_eq(e1, e2))
+            }
+            _ => matches!(self.sql_compare(other), Ok(SqlCompareResult::Eq)),
+        }
     }
 }
 impl Eq for NnSqlValue {}
 
+/// Equality of elements in ARRAY or STRUCT values, where NULL is equal to NULL.
+fn element_eq(e1: &SqlValue, e2: &SqlValue) -> bool {
+    match (e1, e2) {
+        (SqlValue::Null, SqlValue::Null) => true,
+        (SqlValue::NotNull(v1), SqlValue::NotNull(v2)) => v1 == v2,
+        _ => false,
+    }
+}
+
+/// Hash of an element in ARRAY or STRUCT values, which is the same for all NULLs (unlike `SqlValue::hash()`).
+fn element_hash<H: std::hash::Hasher>(e: &SqlValue, state: &mut H) {
+    match e {
+        SqlValue::Null => 0u8.hash(state),
+        SqlValue::NotNull(v) => {
+            1u8.hash(state);
+            v.hash(state)
+        }
+    }
+}
+
 impl Hash for NnSqlValue {
     /// Although raw format are different between two NnSqlValue, this hash function must return the same value if loosely typed values are the same.
-    /// E.g. `42 SMALLINT`'s hash value must be equal to that of `42 INTEGER`.
+    /// E.g. `42 SMALLINT`'s hash value must be equal to that of `42 INTEGER` and `42.00 DECIMAL(4, 2)`.
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         for_all_loose_types!(
             self,
             |i: i64| {
-                i.hash(state);
+                Decimal::from(i).hash(state);
+            },
+            |u: u64| {
+                Decimal::from(u).hash(state);
             },
             |f: OrderedFloat<f32>| {
-                f.hash(state);
+                OrderedFloat(f.into_inner() as f64).hash(state);
+            },
+            |d: OrderedFloat<f64>| {
+                d.hash(state);
+            },
+            |d: Decimal| {
+                d.hash(state);
             },
             |s: String| {
                 s.hash(state);
             },
             |b: bool| { b.hash(state) },
             |t: Timestamp| { t.hash(state) },
-            |d: EventDuration| { d.hash(state) }
+            |d: EventDuration| { d.hash(state) },
+            |b: &[u8]| { b.hash(state) },
+            |elements: &[SqlValue]| {
+                elements.len().hash(state);
+                elements.iter().for_each(|e| element_hash(e, state))
+            },
+            |fields: &[(String, SqlValue)]| {
+                fields.len().hash(state);
+                fields.iter().for_each(|(name, e)| {
+                    name.hash(state);
+                    element_hash(e, state)
+                })
+            }
         )
     }
 }
 
 impl Display for NnSqlValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // loosely typed as Timestamp, which loses the offset
+        if let NnSqlValue::TimestampTz(t) = self {
+            return write!(f, "{}", t);
+        }
+
         let s: String = for_all_loose_types!(
             self,
             |i: i64| i.to_string(),
+            |u: u64| u.to_string(),
             |f: OrderedFloat<f32>| f.to_string(),
+            |d: OrderedFloat<f64>| d.to_string(),
+            |d: Decimal| d.to_string(),
             |s: String| format!(r#""{}""#, s),
             |b: bool| (if b { "TRUE" } else { "FALSE" }).to_string(),
             |t: Timestamp| t.to_string(),
-            |d: EventDuration| d.to_string()
+            |d: EventDuration| d.to_string(),
+            |b: &[u8]| format!("X'{}'", blob::to_hex(b)),
+            |elements: &[SqlValue]| {
+                let elements = elements.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            },
+            |fields: &[(String, SqlValue)]| {
+                let fields = fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", fields.join(", "))
+            }
         );
         write!(f, "{}", s)
     }
@@ -160,25 +293,67 @@ impl NnSqlValue {
             NnSqlValue::SmallInt(i16_) => T::try_from_i16(i16_),
             NnSqlValue::Integer(i32_) => T::try_from_i32(i32_),
             NnSqlValue::BigInt(i64_) => T::try_from_i64(i64_),
+            NnSqlValue::UnsignedSmallInt(u16_) => T::try_from_u16(u16_),
+            NnSqlValue::UnsignedInteger(u32_) => T::try_from_u32(u32_),
+            NnSqlValue::UnsignedBigInt(u64_) => T::try_from_u64(u64_),
             NnSqlValue::Float(f32_) => T::try_from_f32(f32_),
+            NnSqlValue::Double(f64_) => T::try_from_f64(f64_),
+            NnSqlValue::Decimal(d) => T::try_from_decimal(d),
             NnSqlValue::Text(string) => T::try_from_string(string),
             NnSqlValue::Boolean(b) => T::try_from_bool(b),
             NnSqlValue::Timestamp(t) => T::try_from_timestamp(t),
+            NnSqlValue::TimestampTz(t) => T::try_from_timestamp_tz(t),
             NnSqlValue::Duration(d) => T::try_from_duration(d),
+            NnSqlValue::Blob(b) => T::try_from_blob(b),
+            NnSqlValue::Array(_) | NnSqlValue::Struct(_) => Err(SpringError::Sql(anyhow!(
+                "cannot convert {} -> {}",
+                self.sql_type(),
+                type_name::<T>()
+            ))),
         }
     }
 
-    /// SqlType of this value
+    /// SqlType of this value.
+    ///
+    /// Values do not keep declared types of ARRAY elements and STRUCT fields, so they are inferred from the values (TEXT for NULL).
     pub(crate) fn sql_type(&self) -> SqlType {
         match self {
             NnSqlValue::SmallInt(_) => SqlType::small_int(),
             NnSqlValue::Integer(_) => SqlType::integer(),
             NnSqlValue::BigInt(_) => SqlType::big_int(),
+            NnSqlValue::UnsignedSmallInt(_) => SqlType::unsigned_small_int(),
+            NnSqlValue::UnsignedInteger(_) => SqlType::unsigned_integer(),
+            NnSqlValue::UnsignedBigInt(_) => SqlType::unsigned_big_int(),
             NnSqlValue::Float(_) => SqlType::float(),
+            NnSqlValue::Double(_) => SqlType::double(),
+            NnSqlValue::Decimal(d) => SqlType::decimal(MAX_DECIMAL_PRECISION, d.scale()),
             NnSqlValue::Text(_) => SqlType::text(),
             NnSqlValue::Boolean(_) => SqlType::boolean(),
             NnSqlValue::Timestamp(_) => SqlType::timestamp(),
+            NnSqlValue::TimestampTz(_) => SqlType::timestamp_tz(),
             NnSqlValue::Duration(_) => SqlType::duration(),
+            NnSqlValue::Blob(_) => SqlType::blob(),
+            NnSqlValue::Array(elements) => SqlType::array(
+                elements
+                    .iter()
+                    .find_map(|e| match e {
+                        SqlValue::NotNull(e) => Some(e.sql_type()),
+                        SqlValue::Null => None,
+                    })
+                    .unwrap_or_else(SqlType::text),
+            ),
+            NnSqlValue::Struct(fields) => SqlType::structure(
+                fields
+                    .iter()
+                    .map(|(name, value)| {
+                        let typ = match value {
+                            SqlValue::NotNull(v) => v.sql_type(),
+                            SqlValue::Null => SqlType::text(),
+                        };
+                        (name.clone(), typ)
+                    })
+                    .collect(),
+            ),
         }
     }
 
@@ -206,11 +381,31 @@ impl NnSqlValue {
                         self.unpack::<i64>().map(|v| v.into_sql_value())
                     }
                 },
+                NumericComparableType::U64Loose(u) => match u {
+                    sql_type::U64LooseType::SmallInt => {
+                        self.unpack::<u16>().map(|v| v.into_sql_value())
+                    }
+                    sql_type::U64LooseType::Integer => {
+                        self.unpack::<u32>().map(|v| v.into_sql_value())
+                    }
+                    sql_type::U64LooseType::BigInt => {
+                        self.unpack::<u64>().map(|v| v.into_sql_value())
+                    }
+                },
                 NumericComparableType::F32Loose(f) => match f {
                     sql_type::F32LooseType::Float => {
                         self.unpack::<f32>().map(|v| v.into_sql_value())
                     }
                 },
+                NumericComparableType::F64Loose(f) => match f {
+                    sql_type::F64LooseType::Double => {
+                        self.unpack::<f64>().map(|v| v.into_sql_value())
+                    }
+                },
+                NumericComparableType::Decimal { precision, scale } => self
+                    .unpack::<Decimal>()
+                    .and_then(|v| v.fit(*precision, *scale))
+                    .map(|v| v.into_sql_value()),
             },
             SqlType::StringComparableLoose(s) => match s {
                 StringComparableLoseType::Text => {
@@ -218,26 +413,79 @@ impl NnSqlValue {
                 }
             },
             SqlType::BooleanComparable => self.unpack::<bool>().map(|v| v.into_sql_value()),
-            SqlType::TimestampComparable => self.unpack::<Timestamp>().map(|v| v.into_sql_value()),
+            SqlType::TimestampComparable(t) => match t {
+                TimestampComparableType::Timestamp => {
+                    self.unpack::<Timestamp>().map(|v| v.into_sql_value())
+                }
+                TimestampComparableType::TimestampTz => {
+                    self.unpack::<TimestampTz>().map(|v| v.into_sql_value())
+                }
+            },
             SqlType::DurationComparable => {
                 self.unpack::<EventDuration>().map(|v| v.into_sql_value())
             }
+            SqlType::BinaryComparable => self.unpack::<Vec<u8>>().map(|v| v.into_sql_value()),
+            SqlType::Array(element_type) => match self {
+                NnSqlValue::Array(elements) => elements
+                    .iter()
+                    .map(|e| e.try_convert(element_type))
+                    .collect::<Result<Vec<_>>>()
+                    .map(NnSqlValue::Array),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "cannot convert {} -> {}",
+                    self,
+                    typ
+                ))),
+            },
+            // Missing fields are NULL and undeclared fields are dropped.
+            SqlType::Struct(field_types) => match self {
+                NnSqlValue::Struct(fields) => field_types
+                    .iter()
+                    .map(|(name, field_type)| {
+                        let value = fields
+                            .iter()
+                            .find(|(n, _)| n == name)
+                            .map(|(_, value)| value.try_convert(field_type))
+                            .transpose()?
+                            .unwrap_or(SqlValue::Null);
+                        Ok((name.clone(), value))
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .map(NnSqlValue::Struct),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "cannot convert {} -> {}",
+                    self,
+                    typ
+                ))),
+            },
         }
     }
 
     pub(super) fn sql_compare(&self, other: &Self) -> Result<SqlCompareResult> {
         match (self.sql_type(), other.sql_type()) {
             (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref other_n)) => {
-                match (self_n, other_n) {
-                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
+                match self_n.promote(other_n) {
+                    Some(NumericComparableType::I64Loose(_)) => {
                         let (self_i64, other_i64) = (self.unpack::<i64>()?, other.unpack::<i64>()?);
                         Ok(SqlCompareResult::from(self_i64.cmp(&other_i64)))
                     }
-                    (NumericComparableType::F32Loose(_), NumericComparableType::F32Loose(_)) => {
+                    // compared in DECIMAL not to fail on negative values
+                    Some(
+                        NumericComparableType::U64Loose(_) | NumericComparableType::Decimal { .. },
+                    ) => {
+                        let (self_d, other_d) =
+                            (self.unpack::<Decimal>()?, other.unpack::<Decimal>()?);
+                        Ok(SqlCompareResult::from(self_d.cmp(&other_d)))
+                    }
+                    Some(NumericComparableType::F32Loose(_)) => {
                         let (self_f32, other_f32) = (self.unpack::<f32>()?, other.unpack::<f32>()?);
                         Ok(SqlCompareResult::from(self_f32.partial_cmp(&other_f32)))
                     }
-                    _ => Err(SpringError::Sql(anyhow!(
+                    Some(NumericComparableType::F64Loose(_)) => {
+                        let (self_f64, other_f64) = (self.unpack::<f64>()?, other.unpack::<f64>()?);
+                        Ok(SqlCompareResult::from(self_f64.partial_cmp(&other_f64)))
+                    }
+                    None => Err(SpringError::Sql(anyhow!(
                         "Cannot compare {:?} and {:?}",
                         self_n,
                         other_n
@@ -257,10 +505,15 @@ impl NnSqlValue {
                 let (self_b, other_b) = (self.unpack::<bool>()?, other.unpack::<bool>()?);
                 Ok(SqlCompareResult::from(self_b.cmp(&other_b)))
             }
-            (SqlType::TimestampComparable, SqlType::TimestampComparable) => {
+            // compared by instants
+            (SqlType::TimestampComparable(_), SqlType::TimestampComparable(_)) => {
                 let (self_t, other_t) = (self.unpack::<Timestamp>()?, other.unpack::<Timestamp>()?);
                 Ok(SqlCompareResult::from(self_t.cmp(&other_t)))
             }
+            (SqlType::BinaryComparable, SqlType::BinaryComparable) => {
+                let (self_b, other_b) = (self.unpack::<Vec<u8>>()?, other.unpack::<Vec<u8>>()?);
+                Ok(SqlCompareResult::from(self_b.cmp(&other_b)))
+            }
             (_, _) => Err(SpringError::Sql(anyhow!(
                 "`self` and `other` are not in comparable type - self: {:?}, other: {:?}",
                 self,
@@ -278,11 +531,20 @@ impl NnSqlValue {
             NnSqlValue::SmallInt(v) => Ok(Self::SmallInt(-v)),
             NnSqlValue::Integer(v) => Ok(Self::Integer(-v)),
             NnSqlValue::BigInt(v) => Ok(Self::BigInt(-v)),
+            NnSqlValue::UnsignedSmallInt(_)
+            | NnSqlValue::UnsignedInteger(_)
+            | NnSqlValue::UnsignedBigInt(_) => Ok(Self::BigInt(-self.unpack::<i64>()?)),
             NnSqlValue::Float(v) => Ok(Self::Float(-v)),
+            NnSqlValue::Double(v) => Ok(Self::Double(-v)),
+            NnSqlValue::Decimal(v) => Ok(Self::Decimal(v.negate())),
             NnSqlValue::Text(_)
             | NnSqlValue::Boolean(_)
             | NnSqlValue::Timestamp(_)
-            | NnSqlValue::Duration(_) => Err(SpringError::Sql(anyhow!("{} cannot negate", self))),
+            | NnSqlValue::TimestampTz(_)
+            | NnSqlValue::Duration(_)
+            | NnSqlValue::Blob(_)
+            | NnSqlValue::Array(_)
+            | NnSqlValue::Struct(_) => Err(SpringError::Sql(anyhow!("{} cannot negate", self))),
         }
     }
 }
@@ -293,10 +555,27 @@ impl From<NnSqlValue> for serde_json::Value {
             NnSqlValue::SmallInt(i) => serde_json::Value::from(i),
             NnSqlValue::Integer(i) => serde_json::Value::from(i),
             NnSqlValue::BigInt(i) => serde_json::Value::from(i),
+            NnSqlValue::UnsignedSmallInt(u) => serde_json::Value::from(u),
+            NnSqlValue::UnsignedInteger(u) => serde_json::Value::from(u),
+            NnSqlValue::UnsignedBigInt(u) => serde_json::Value::from(u),
             NnSqlValue::Float(f) => serde_json::Value::from(f.into_inner()),
+            NnSqlValue::Double(f) => serde_json::Value::from(f.into_inner()),
+            // JSON numbers are parsed as f64 by most readers, so DECIMAL values are written as strings to keep them exact.
+            NnSqlValue::Decimal(d) => serde_json::Value::from(d.to_string()),
             NnSqlValue::Text(s) => serde_json::Value::from(s),
             NnSqlValue::Boolean(b) => serde_json::Value::from(b),
             NnSqlValue::Timestamp(t) => serde_json::Value::from(t.to_string()),
+            NnSqlValue::TimestampTz(t) => serde_json::Value::from(t.to_string()),
+            NnSqlValue::Blob(b) => serde_json::Value::from(blob::to_base64(&b)),
+            NnSqlValue::Array(elements) => serde_json::Value::Array(
+                elements.into_iter().map(serde_json::Value::from).collect(),
+            ),
+            NnSqlValue::Struct(fields) => serde_json::Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, serde_json::Value::from(value)))
+                    .collect(),
+            ),
             NnSqlValue::Duration(_) => {
                 unimplemented!("never appear in stream definition (just an intermediate type)")
             }
@@ -304,70 +583,109 @@ impl From<NnSqlValue> for serde_json::Value {
     }
 }
 
-impl Add for NnSqlValue {
-    type Output = Result<Self>;
+impl NnSqlValue {
+    /// Applies an arithmetic operator after promoting operands by [NumericComparableType::promote()].
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - operands are not numeric or cannot be promoted into the same type.
+    ///   - the result overflows.
+    #[allow(clippy::too_many_arguments)]
+    fn arithmetic(
+        self,
+        rhs: Self,
+        operator: &str,
+        f_i64: fn(i64, i64) -> Option<i64>,
+        f_u64: fn(u64, u64) -> Option<u64>,
+        f_decimal: fn(&Decimal, &Decimal) -> Result<Decimal>,
+        f_f32: fn(f32, f32) -> f32,
+        f_f64: fn(f64, f64) -> f64,
+    ) -> Result<Self> {
+        let overflow = |typ: &str| {
+            SpringError::Sql(anyhow!("{} overflow: {} {} {}", typ, self, operator, rhs))
+        };
 
-    fn add(self, rhs: Self) -> Self::Output {
         match (self.sql_type(), rhs.sql_type()) {
             (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref rhs_n)) => {
-                match (self_n, rhs_n) {
-                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
+                match self_n.promote(rhs_n) {
+                    Some(NumericComparableType::I64Loose(_)) => {
                         let (self_i64, rhs_i64) = (self.unpack::<i64>()?, rhs.unpack::<i64>()?);
-                        Ok(Self::BigInt(self_i64 + rhs_i64))
+                        f_i64(self_i64, rhs_i64)
+                            .map(Self::BigInt)
+                            .ok_or_else(|| overflow("BIGINT"))
+                    }
+                    Some(NumericComparableType::U64Loose(_)) => {
+                        let (self_u64, rhs_u64) = (self.unpack::<u64>()?, rhs.unpack::<u64>()?);
+                        f_u64(self_u64, rhs_u64)
+                            .map(Self::UnsignedBigInt)
+                            .ok_or_else(|| overflow("BIGINT UNSIGNED"))
+                    }
+                    Some(NumericComparableType::Decimal { .. }) => {
+                        let (self_d, rhs_d) = (self.unpack::<Decimal>()?, rhs.unpack::<Decimal>()?);
+                        f_decimal(&self_d, &rhs_d).map(Self::Decimal)
                     }
-                    (NumericComparableType::F32Loose(_), NumericComparableType::F32Loose(_)) => {
+                    Some(NumericComparableType::F32Loose(_)) => {
                         let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
-                        Ok(Self::Float(OrderedFloat(self_f32 + rhs_f32)))
+                        Ok(Self::Float(OrderedFloat(f_f32(self_f32, rhs_f32))))
                     }
-                    _ => Err(SpringError::Sql(anyhow!(
-                        "Cannot add {:?} and {:?}",
+                    Some(NumericComparableType::F64Loose(_)) => {
+                        let (self_f64, rhs_f64) = (self.unpack::<f64>()?, rhs.unpack::<f64>()?);
+                        Ok(Self::Double(OrderedFloat(f_f64(self_f64, rhs_f64))))
+                    }
+                    None => Err(SpringError::Sql(anyhow!(
+                        "`{}` cannot be applied to {:?} and {:?}",
+                        operator,
                         self_n,
                         rhs_n
                     ))),
                 }
             }
             (_, _) => Err(SpringError::Sql(anyhow!(
-                "`self` + `rhs` is undefined - self: {:?}, other: {:?}",
+                "`self` {} `rhs` is undefined - self: {:?}, other: {:?}",
+                operator,
                 self,
                 rhs
             ))),
         }
     }
 }
+
+impl Add for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.arithmetic(
+            rhs,
+            "+",
+            i64::checked_add,
+            u64::checked_add,
+            Decimal::checked_add,
+            |a, b| a + b,
+            |a, b| a + b,
+        )
+    }
+}
 impl Mul for NnSqlValue {
     type Output = Result<Self>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        match (self.sql_type(), rhs.sql_type()) {
-            (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref rhs_n)) => {
-                match (self_n, rhs_n) {
-                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
-                        let (self_i64, rhs_i64) = (self.unpack::<i64>()?, rhs.unpack::<i64>()?);
-                        Ok(Self::BigInt(self_i64 * rhs_i64))
-                    }
-                    (NumericComparableType::F32Loose(_), NumericComparableType::F32Loose(_)) => {
-                        let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
-                        Ok(Self::Float(OrderedFloat(self_f32 * rhs_f32)))
-                    }
-                    _ => Err(SpringError::Sql(anyhow!(
-                        "Cannot multiply {:?} by {:?}",
-                        self_n,
-                        rhs_n
-                    ))),
-                }
-            }
-            (_, _) => Err(SpringError::Sql(anyhow!(
-                "`self` + `rhs` is undefined - self: {:?}, other: {:?}",
-                self,
-                rhs
-            ))),
-        }
+        self.arithmetic(
+            rhs,
+            "*",
+            i64::checked_mul,
+            u64::checked_mul,
+            Decimal::checked_mul,
+            |a, b| a * b,
+            |a, b| a * b,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stream_engine::time::duration::SpringDuration;
 
     #[test]
     fn test_unpack_loosely() -> Result<()> {
@@ -398,4 +716,167 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_numeric_promotion() -> Result<()> {
+        let dec = |s: &str| NnSqlValue::Decimal(s.parse().unwrap());
+        let double = |f: f64| NnSqlValue::Double(OrderedFloat(f));
+
+        assert_eq!(
+            (NnSqlValue::UnsignedSmallInt(1) + NnSqlValue::UnsignedBigInt(2))?,
+            NnSqlValue::UnsignedBigInt(3)
+        );
+        assert_eq!(
+            (NnSqlValue::UnsignedInteger(1) + NnSqlValue::Integer(2))?,
+            dec("3")
+        );
+        // raw CAN signal with an offset
+        let decoded = (NnSqlValue::UnsignedSmallInt(100) + NnSqlValue::Integer(-40))?;
+        assert_eq!(decoded, dec("60"));
+        assert_eq!(
+            decoded.try_convert(&SqlType::integer())?.unpack::<i32>()?,
+            60
+        );
+        assert_eq!(
+            (NnSqlValue::SmallInt(-2) * NnSqlValue::UnsignedBigInt(u64::MAX))?,
+            dec("-36893488147419103230")
+        );
+        assert_eq!(
+            (NnSqlValue::SmallInt(1) + NnSqlValue::Integer(-2))?,
+            NnSqlValue::BigInt(-1)
+        );
+        assert_eq!((dec("1.25") * NnSqlValue::Integer(3))?, dec("3.75"));
+        assert_eq!(
+            (NnSqlValue::Float(OrderedFloat(0.5)) + double(0.25))?,
+            double(0.75)
+        );
+
+        assert!((dec("1.5") + double(1.5)).is_err());
+        assert!((NnSqlValue::Integer(1) + NnSqlValue::Float(OrderedFloat(1.))).is_err());
+        assert!((NnSqlValue::UnsignedBigInt(u64::MAX) + NnSqlValue::UnsignedBigInt(1)).is_err());
+        assert!((NnSqlValue::BigInt(i64::MAX) * NnSqlValue::SmallInt(2)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_eq_hash() {
+        fn hash(v: &NnSqlValue) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v.hash(&mut hasher);
+            std::hash::Hasher::finish(&hasher)
+        }
+
+        assert!(matches!(
+            NnSqlValue::UnsignedSmallInt(1).sql_compare(&NnSqlValue::SmallInt(-1)),
+            Ok(SqlCompareResult::GreaterThan)
+        ));
+
+        let values = [
+            NnSqlValue::SmallInt(42),
+            NnSqlValue::UnsignedBigInt(42),
+            NnSqlValue::Decimal("42.00".parse().unwrap()),
+        ];
+        for v in &values {
+            assert_eq!(v, &values[0]);
+            assert_eq!(hash(v), hash(&values[0]));
+        }
+
+        let float = NnSqlValue::Float(OrderedFloat(0.5));
+        let double = NnSqlValue::Double(OrderedFloat(0.5));
+        assert_eq!(float, double);
+        assert_eq!(hash(&float), hash(&double));
+
+        assert_ne!(NnSqlValue::Integer(1), NnSqlValue::Double(OrderedFloat(1.)));
+    }
+
+    #[test]
+    fn test_timestamp_tz_eq_hash() -> Result<()> {
+        fn hash(v: &NnSqlValue) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v.hash(&mut hasher);
+            std::hash::Hasher::finish(&hasher)
+        }
+
+        let ts = NnSqlValue::Timestamp("2020-01-01 00:00:00.000000000".parse()?);
+        let tz_utc = NnSqlValue::TimestampTz("2020-01-01T00:00:00Z".parse()?);
+        let tz_jst = NnSqlValue::TimestampTz("2020-01-01T09:00:00+09:00".parse()?);
+
+        for v in [&tz_utc, &tz_jst] {
+            assert_eq!(v, &ts);
+            assert_eq!(hash(v), hash(&ts));
+        }
+        assert_eq!(tz_jst.to_string(), "2020-01-01 09:00:00.000000000+09:00");
+        assert_eq!(
+            tz_jst.try_convert(&SqlType::timestamp())?.to_string(),
+            "2020-01-01 00:00:00.000000000"
+        );
+        assert_eq!(
+            ts.try_convert(&SqlType::timestamp_tz())?.to_string(),
+            "2020-01-01 00:00:00.000000000+00:00"
+        );
+        assert!(matches!(
+            NnSqlValue::TimestampTz("2020-01-01T08:59:59+09:00".parse()?).sql_compare(&ts),
+            Ok(SqlCompareResult::LessThan)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_struct_eq_hash_with_null() {
+        fn hash(v: &NnSqlValue) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v.hash(&mut hasher);
+            std::hash::Hasher::finish(&hasher)
+        }
+
+        let array = NnSqlValue::Array(vec![
+            SqlValue::NotNull(NnSqlValue::Integer(1)),
+            SqlValue::Null,
+        ]);
+        assert_eq!(array, array.clone());
+        assert_eq!(hash(&array), hash(&array.clone()));
+        assert_ne!(
+            array,
+            NnSqlValue::Array(vec![SqlValue::NotNull(NnSqlValue::Integer(1))])
+        );
+
+        let structure = NnSqlValue::Struct(vec![
+            ("speed".to_string(), SqlValue::Null),
+            (
+                "gear".to_string(),
+                SqlValue::NotNull(NnSqlValue::SmallInt(3)),
+            ),
+        ]);
+        assert_eq!(structure, structure.clone());
+        assert_eq!(hash(&structure), hash(&structure.clone()));
+        assert_ne!(
+            structure,
+            NnSqlValue::Struct(vec![
+                (
+                    "speed".to_string(),
+                    SqlValue::NotNull(NnSqlValue::SmallInt(3))
+                ),
+                ("gear".to_string(), SqlValue::Null),
+            ])
+        );
+
+        let mut groups = std::collections::HashMap::new();
+        *groups.entry(array.clone()).or_insert(0) += 1;
+        *groups.entry(array).or_insert(0) += 1;
+        assert_eq!(groups.len(), 1);
+    }
+
+    #[test]
+    fn test_duration_eq() {
+        assert_eq!(
+            NnSqlValue::Duration(EventDuration::from_secs(10)),
+            NnSqlValue::Duration(EventDuration::from_secs(10))
+        );
+        assert_ne!(
+            NnSqlValue::Duration(EventDuration::from_secs(10)),
+            NnSqlValue::Duration(EventDuration::from_secs(1))
+        );
+    }
 }
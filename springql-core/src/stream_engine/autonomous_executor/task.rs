@@ -2,6 +2,11 @@
 
 pub(crate) mod tuple;
 
+pub(super) mod analytic;
+pub(super) mod deduplicate;
+pub(super) mod match_recognize;
+pub(super) mod order_limit;
+
 pub(super) mod pump_task;
 pub(super) mod sink_task;
 pub(super) mod source_task;
@@ -32,7 +37,7 @@ pub(crate) enum Task {
 impl Task {
     pub(super) fn new(edge: &Edge, pipeline_graph: &PipelineGraph) -> Self {
         match edge {
-            Edge::Pump { pump_model, .. } => {
+            Edge::Pump { pump_model, .. } | Edge::LateRows { pump_model, .. } => {
                 Self::Pump(Box::new(PumpTask::new(pump_model.as_ref(), pipeline_graph)))
             }
             Edge::Source(s) => Self::Source(SourceTask::new(s)),
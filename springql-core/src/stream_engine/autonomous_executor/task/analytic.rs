@@ -0,0 +1,518 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! States of analytic expressions (`func(...) OVER (...)`).
+//!
+//! A state is kept for each pair of analytic expression and partition.
+//! Preceding rows are bounded by `ROWS n PRECEDING` (or LAG offset); unbounded frames only keep running aggregates.
+
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    iter,
+    mem::size_of,
+};
+
+use anyhow::anyhow;
+use ordered_float::OrderedFloat;
+
+use crate::{
+    error::{Result, SpringError},
+    expr_resolver::{expr_label::AnalyticExprLabel, ExprResolver},
+    mem_size::MemSize,
+    pipeline::pump_model::analytic_parameter::AnalyticFunctionParameter,
+    stream_engine::{
+        autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+        NnSqlValue, SqlValue, Tuple,
+    },
+};
+
+#[derive(Debug, Default)]
+pub(in crate::stream_engine::autonomous_executor) struct AnalyticStates {
+    /// NULL partition key (and no PARTITION BY) is represented by None.
+    partitions: HashMap<(AnalyticExprLabel, Option<NnSqlValue>), PartitionState>,
+}
+
+impl MemSize for AnalyticStates {
+    fn mem_size(&self) -> usize {
+        self.partitions
+            .iter()
+            .map(|((_, key), state)| Self::key_size(key) + state.mem_size())
+            .sum()
+    }
+}
+
+impl AnalyticStates {
+    /// Evaluates the analytic expressions for `tuple` and updates the states of the tuple's partitions.
+    ///
+    /// States are updated for all of `labels` or, on failure, for none of them.
+    ///
+    /// # Returns
+    ///
+    /// `(values ordered as labels, gain of states)`
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - column reference in expression is not found in `tuple`.
+    ///   - argument cannot be summed.
+    pub(in crate::stream_engine::autonomous_executor) fn next(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        labels: &[AnalyticExprLabel],
+        tuple: &Tuple,
+    ) -> Result<(Vec<SqlValue>, WindowInFlowByWindowTask)> {
+        let nexts = labels
+            .iter()
+            .map(|label| {
+                let analytic_expr = expr_resolver.resolve_analytic_expr(*label);
+                let (func, preceding_rows) = (analytic_expr.func, analytic_expr.preceding_rows);
+
+                let (argument, partition_key) =
+                    expr_resolver.eval_analytic_expr_inner(*label, tuple)?;
+                let partition_key = match partition_key {
+                    SqlValue::Null => None,
+                    SqlValue::NotNull(v) => Some(v),
+                };
+
+                let next = match self.partitions.get(&(*label, partition_key.clone())) {
+                    Some(state) => state.eval(func, preceding_rows, &argument)?,
+                    None => PartitionState::default().eval(func, preceding_rows, &argument)?,
+                };
+                Ok((
+                    (*label, partition_key),
+                    func,
+                    preceding_rows,
+                    argument,
+                    next,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut gain_bytes = 0;
+        let values = nexts
+            .into_iter()
+            .map(|(key, func, preceding_rows, argument, next)| {
+                let key_size = Self::key_size(&key.1);
+                let (state, bytes_before) = match self.partitions.entry(key) {
+                    Entry::Occupied(e) => {
+                        let state = e.into_mut();
+                        let bytes = key_size + state.mem_size();
+                        (state, bytes)
+                    }
+                    Entry::Vacant(e) => (e.insert(PartitionState::default()), 0),
+                };
+
+                let value = state.update(func, preceding_rows, argument, next);
+                gain_bytes += (key_size + state.mem_size()) as i64 - bytes_before as i64;
+                value
+            })
+            .collect();
+
+        Ok((values, WindowInFlowByWindowTask::new(gain_bytes, 0)))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn purge(&mut self) {
+        self.partitions.clear();
+    }
+
+    fn key_size(partition_key: &Option<NnSqlValue>) -> usize {
+        size_of::<AnalyticExprLabel>() + partition_key.as_ref().map_or(0, |v| v.mem_size())
+    }
+}
+
+#[derive(Debug, Default)]
+struct PartitionState {
+    /// Rows in the partition so far.
+    rows: u64,
+
+    /// Argument values of the preceding rows (oldest first).
+    preceding: VecDeque<SqlValue>,
+
+    /// First value in the partition, for unbounded FIRST_VALUE.
+    first: Option<SqlValue>,
+
+    /// Sum (widened) and count of non-NULL values in the partition, for unbounded SUM / AVG.
+    running_sum: Option<NnSqlValue>,
+    running_count: u64,
+}
+
+impl MemSize for PartitionState {
+    fn mem_size(&self) -> usize {
+        let preceding_size: usize = self.preceding.iter().map(|v| v.mem_size()).sum();
+        let first_size = self.first.as_ref().map_or(0, |v| v.mem_size());
+        let running_sum_size = self.running_sum.as_ref().map_or(0, |v| v.mem_size());
+
+        size_of::<u64>() * 2 + preceding_size + first_size + running_sum_size
+    }
+}
+
+/// What a row brings to a partition, evaluated before updating the partition state.
+#[derive(Debug)]
+struct PartitionNext {
+    value: SqlValue,
+    running_sum: Option<NnSqlValue>,
+    running_count: u64,
+}
+
+impl PartitionState {
+    #[cfg(test)]
+    fn next(
+        &mut self,
+        func: AnalyticFunctionParameter,
+        preceding_rows: Option<u64>,
+        value: SqlValue,
+    ) -> Result<SqlValue> {
+        let next = self.eval(func, preceding_rows, &value)?;
+        Ok(self.update(func, preceding_rows, value, next))
+    }
+
+    /// Evaluates the analytic function for a new row without updating the state.
+    fn eval(
+        &self,
+        func: AnalyticFunctionParameter,
+        preceding_rows: Option<u64>,
+        value: &SqlValue,
+    ) -> Result<PartitionNext> {
+        let (mut running_sum, mut running_count) = (self.running_sum.clone(), self.running_count);
+
+        let out = match (func, preceding_rows) {
+            (AnalyticFunctionParameter::RowNumber, _) => {
+                SqlValue::NotNull(NnSqlValue::BigInt(self.rows as i64 + 1))
+            }
+            (AnalyticFunctionParameter::Lag { offset }, _) => {
+                if self.preceding.len() as u64 == offset {
+                    self.preceding.front().cloned().expect("offset is positive")
+                } else {
+                    SqlValue::Null
+                }
+            }
+            (AnalyticFunctionParameter::FirstValue, Some(_)) => self
+                .preceding
+                .front()
+                .cloned()
+                .unwrap_or_else(|| value.clone()),
+            (AnalyticFunctionParameter::FirstValue, None) => {
+                self.first.clone().unwrap_or_else(|| value.clone())
+            }
+            (AnalyticFunctionParameter::Sum, Some(_)) => {
+                let (sum, _) = Self::sum(self.preceding.iter().chain(iter::once(value)))?;
+                sum.map_or(SqlValue::Null, SqlValue::NotNull)
+            }
+            (AnalyticFunctionParameter::Avg, Some(_)) => {
+                let (sum, count) = Self::sum(self.preceding.iter().chain(iter::once(value)))?;
+                Self::avg(sum, count)?
+            }
+            (AnalyticFunctionParameter::Sum, None) => {
+                (running_sum, running_count) = self.add_running(value)?;
+                running_sum
+                    .clone()
+                    .map_or(SqlValue::Null, SqlValue::NotNull)
+            }
+            (AnalyticFunctionParameter::Avg, None) => {
+                (running_sum, running_count) = self.add_running(value)?;
+                Self::avg(running_sum.clone(), running_count)?
+            }
+        };
+
+        Ok(PartitionNext {
+            value: out,
+            running_sum,
+            running_count,
+        })
+    }
+
+    /// Updates the state with a new row evaluated by [eval()](Self::eval).
+    ///
+    /// # Returns
+    ///
+    /// Value of the analytic function for the row.
+    fn update(
+        &mut self,
+        func: AnalyticFunctionParameter,
+        preceding_rows: Option<u64>,
+        value: SqlValue,
+        next: PartitionNext,
+    ) -> SqlValue {
+        self.rows += 1;
+        self.running_sum = next.running_sum;
+        self.running_count = next.running_count;
+
+        match (func, preceding_rows) {
+            (AnalyticFunctionParameter::RowNumber, _)
+            | (AnalyticFunctionParameter::Sum, None)
+            | (AnalyticFunctionParameter::Avg, None) => {}
+            (AnalyticFunctionParameter::Lag { offset }, _) => self.push_preceding(value, offset),
+            (AnalyticFunctionParameter::FirstValue, Some(n))
+            | (AnalyticFunctionParameter::Sum, Some(n))
+            | (AnalyticFunctionParameter::Avg, Some(n)) => self.push_preceding(value, n),
+            (AnalyticFunctionParameter::FirstValue, None) => {
+                self.first.get_or_insert(value);
+            }
+        }
+
+        next.value
+    }
+
+    /// Keeps at most `n` preceding values.
+    fn push_preceding(&mut self, value: SqlValue, n: u64) {
+        self.preceding.push_back(value);
+        while self.preceding.len() as u64 > n {
+            self.preceding.pop_front();
+        }
+    }
+
+    /// # Returns
+    ///
+    /// `(running sum, running count)` after adding `value`.
+    fn add_running(&self, value: &SqlValue) -> Result<(Option<NnSqlValue>, u64)> {
+        let (sum, count) = Self::sum(iter::once(value))?;
+        match sum {
+            Some(v) => {
+                let running_sum = match self.running_sum.clone() {
+                    Some(acc) => (acc + v)?,
+                    None => v,
+                };
+                Ok((Some(running_sum), self.running_count + count))
+            }
+            None => Ok((self.running_sum.clone(), self.running_count)),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// `(sum of non-NULL values, number of non-NULL values)`. Sum is None if no non-NULL value.
+    fn sum<'a>(values: impl Iterator<Item = &'a SqlValue>) -> Result<(Option<NnSqlValue>, u64)> {
+        values
+            .filter_map(|v| match v {
+                SqlValue::Null => None,
+                SqlValue::NotNull(v) => Some(v),
+            })
+            .try_fold((None, 0), |(acc, count), v| {
+                let v = Self::widen(v)?;
+                let sum = match acc {
+                    Some(acc) => (acc + v)?,
+                    None => v,
+                };
+                Ok((Some(sum), count + 1))
+            })
+    }
+
+    /// Integers are summed as BIGINT (BIGINT UNSIGNED for unsigned ones), so that a SUM always has the same type.
+    fn widen(value: &NnSqlValue) -> Result<NnSqlValue> {
+        match value {
+            NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
+                Ok(NnSqlValue::BigInt(value.unpack::<i64>()?))
+            }
+            NnSqlValue::UnsignedSmallInt(_)
+            | NnSqlValue::UnsignedInteger(_)
+            | NnSqlValue::UnsignedBigInt(_) => {
+                Ok(NnSqlValue::UnsignedBigInt(value.unpack::<u64>()?))
+            }
+            NnSqlValue::Float(_) | NnSqlValue::Double(_) | NnSqlValue::Decimal(_) => {
+                Ok(value.clone())
+            }
+            _ => Err(SpringError::Sql(anyhow!(
+                "SUM() and AVG() over analytic window require numeric value but got {}",
+                value
+            ))),
+        }
+    }
+
+    fn avg(sum: Option<NnSqlValue>, count: u64) -> Result<SqlValue> {
+        match sum {
+            Some(sum) => {
+                let avg = sum.unpack::<f64>()? / count as f64;
+                Ok(SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(avg))))
+            }
+            None => Ok(SqlValue::Null),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use crate::{
+        expression::{AnalyticExpr, ValueExpr},
+        pipeline::name::{ColumnName, StreamName},
+        sql_processor::sql_parser::syntax::SelectFieldSyntax,
+        stream_engine::time::timestamp::Timestamp,
+    };
+
+    fn int(i: i32) -> Option<NnSqlValue> {
+        Some(NnSqlValue::Integer(i))
+    }
+    fn bigint(i: i64) -> Option<NnSqlValue> {
+        Some(NnSqlValue::BigInt(i))
+    }
+    fn double(f: f64) -> Option<NnSqlValue> {
+        Some(NnSqlValue::Double(OrderedFloat(f)))
+    }
+
+    /// NULL is mapped to None since NULL does not equal to NULL.
+    fn run(
+        func: AnalyticFunctionParameter,
+        preceding_rows: Option<u64>,
+        values: &[i32],
+    ) -> Vec<Option<NnSqlValue>> {
+        let mut state = PartitionState::default();
+        values
+            .iter()
+            .map(|v| {
+                match state
+                    .next(
+                        func,
+                        preceding_rows,
+                        SqlValue::NotNull(NnSqlValue::Integer(*v)),
+                    )
+                    .unwrap()
+                {
+                    SqlValue::Null => None,
+                    SqlValue::NotNull(v) => Some(v),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lag() {
+        assert_eq!(
+            run(
+                AnalyticFunctionParameter::Lag { offset: 1 },
+                None,
+                &[1, 2, 3]
+            ),
+            vec![None, int(1), int(2)]
+        );
+        assert_eq!(
+            run(
+                AnalyticFunctionParameter::Lag { offset: 2 },
+                None,
+                &[1, 2, 3]
+            ),
+            vec![None, None, int(1)]
+        );
+    }
+
+    #[test]
+    fn test_first_value() {
+        assert_eq!(
+            run(AnalyticFunctionParameter::FirstValue, Some(1), &[1, 2, 3]),
+            vec![int(1), int(1), int(2)]
+        );
+        assert_eq!(
+            run(AnalyticFunctionParameter::FirstValue, None, &[1, 2, 3]),
+            vec![int(1), int(1), int(1)]
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(
+            run(AnalyticFunctionParameter::Sum, Some(1), &[1, 2, 3]),
+            vec![bigint(1), bigint(3), bigint(5)]
+        );
+        assert_eq!(
+            run(AnalyticFunctionParameter::Sum, None, &[1, 2, 3]),
+            vec![bigint(1), bigint(3), bigint(6)]
+        );
+    }
+
+    #[test]
+    fn test_avg_row_number() {
+        assert_eq!(
+            run(AnalyticFunctionParameter::Avg, Some(1), &[1, 2, 3]),
+            vec![double(1.0), double(1.5), double(2.5)]
+        );
+        assert_eq!(
+            run(AnalyticFunctionParameter::RowNumber, None, &[7, 8, 9]),
+            vec![bigint(1), bigint(2), bigint(3)]
+        );
+    }
+
+    #[test]
+    fn test_failure_leaves_states_unchanged() {
+        let analytic_expr = |func, argument| SelectFieldSyntax::AnalyticExpr {
+            analytic_expr: AnalyticExpr {
+                func,
+                argument,
+                partition_by: None,
+                preceding_rows: None,
+            },
+            alias: None,
+        };
+        let ticker_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+
+        // ROW_NUMBER() OVER (), SUM(ticker) OVER ()
+        let (expr_resolver, _, _, labels) = ExprResolver::new(vec![
+            analytic_expr(AnalyticFunctionParameter::RowNumber, None),
+            analytic_expr(AnalyticFunctionParameter::Sum, Some(ticker_expr)),
+        ]);
+        let tuple = Tuple::factory_trade(
+            Timestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+            "ORCL",
+            100,
+        );
+
+        let mut states = AnalyticStates::default();
+        assert!(states.next(&expr_resolver, &labels, &tuple).is_err());
+        assert!(states.partitions.is_empty());
+
+        let (values, window_in_flow) = states.next(&expr_resolver, &labels[..1], &tuple).unwrap();
+        assert_eq!(values, vec![SqlValue::NotNull(NnSqlValue::BigInt(1))]);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            states.mem_size() as i64
+        );
+    }
+
+    #[test]
+    fn test_running_sum_kept_on_overflow() {
+        let mut state = PartitionState::default();
+        let sum = |state: &mut PartitionState, v: i64| {
+            state.next(
+                AnalyticFunctionParameter::Sum,
+                None,
+                SqlValue::NotNull(NnSqlValue::BigInt(v)),
+            )
+        };
+
+        sum(&mut state, i64::MAX).unwrap();
+        assert!(sum(&mut state, 1).is_err());
+        assert_eq!(
+            sum(&mut state, -1).unwrap(),
+            SqlValue::NotNull(NnSqlValue::BigInt(i64::MAX - 1))
+        );
+        assert_eq!(state.rows, 2);
+    }
+
+    #[test]
+    fn test_bounded_mem_size() {
+        let mut state = PartitionState::default();
+        for i in 0..3 {
+            state
+                .next(
+                    AnalyticFunctionParameter::Sum,
+                    Some(2),
+                    SqlValue::NotNull(NnSqlValue::Integer(i)),
+                )
+                .unwrap();
+        }
+        let size = state.mem_size();
+        for i in 0..100 {
+            state
+                .next(
+                    AnalyticFunctionParameter::Sum,
+                    Some(2),
+                    SqlValue::NotNull(NnSqlValue::Integer(i)),
+                )
+                .unwrap();
+        }
+        assert_eq!(state.mem_size(), size);
+    }
+}
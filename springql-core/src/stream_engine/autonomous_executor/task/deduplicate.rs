@@ -0,0 +1,218 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! States of deduplication (`SELECT DISTINCT` with a fixed window and `DEDUPLICATE BY ... WITHIN`).
+//!
+//! A key of an emitted row is kept until it expires: at the end of its pane, or `WITHIN` duration after the row.
+//! Expired keys are evicted by the watermark (max ROWTIME so far minus allowed delay),
+//! so the number of keys is bounded by the number of distinct keys within a pane (or a `WITHIN` duration).
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    mem::size_of,
+};
+
+use crate::{
+    mem_size::MemSize,
+    pipeline::pump_model::deduplicate_parameter::DeduplicateHorizon,
+    stream_engine::{
+        autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+        time::{
+            duration::{event_duration::EventDuration, SpringDuration},
+            timestamp::Timestamp,
+        },
+        NnSqlValue, SqlValue,
+    },
+};
+
+/// NULL is represented by None, so that NULLs in keys are equal to each other.
+type Key = Vec<Option<NnSqlValue>>;
+
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct DeduplicateState {
+    horizon: DeduplicateHorizon,
+
+    /// Keys of emitted rows and their expiry.
+    keys: HashMap<Key, Timestamp>,
+    /// Keys by their expiry, to evict expired keys.
+    expiries: BTreeMap<Timestamp, Vec<Key>>,
+
+    max_rowtime: Option<Timestamp>,
+}
+
+impl MemSize for DeduplicateState {
+    fn mem_size(&self) -> usize {
+        self.keys.keys().map(Self::entry_size).sum()
+    }
+}
+
+impl DeduplicateState {
+    pub(in crate::stream_engine::autonomous_executor) fn new(horizon: DeduplicateHorizon) -> Self {
+        Self {
+            horizon,
+            keys: HashMap::new(),
+            expiries: BTreeMap::new(),
+            max_rowtime: None,
+        }
+    }
+
+    /// Whether a row with `key` and `rowtime` should be emitted. The key is kept if so.
+    ///
+    /// # Returns
+    ///
+    /// `(emit, gain of states)`
+    pub(in crate::stream_engine::autonomous_executor) fn dispatch(
+        &mut self,
+        key: Vec<SqlValue>,
+        rowtime: Timestamp,
+    ) -> (bool, WindowInFlowByWindowTask) {
+        let max_rowtime = match self.max_rowtime {
+            Some(t) if t > rowtime => t,
+            _ => rowtime,
+        };
+        self.max_rowtime = Some(max_rowtime);
+        let watermark = max_rowtime - self.allowed_delay().to_chrono();
+
+        let mut gain = -(self.evict(watermark) as i64);
+
+        let mut key = key
+            .into_iter()
+            .map(|v| match v {
+                SqlValue::Null => None,
+                SqlValue::NotNull(v) => Some(v),
+            })
+            .collect::<Key>();
+        let expiry = match self.horizon {
+            DeduplicateHorizon::Pane { length, .. } => {
+                let pane_start = rowtime.floor(length.to_chrono());
+                key.push(Some(NnSqlValue::Timestamp(pane_start)));
+                pane_start + length.to_chrono()
+            }
+            DeduplicateHorizon::Duration(within) => rowtime + within.to_chrono(),
+        };
+
+        let emit = if expiry <= watermark || self.keys.contains_key(&key) {
+            // pane already closed, or duplicate
+            false
+        } else {
+            gain += Self::entry_size(&key) as i64;
+            self.expiries.entry(expiry).or_default().push(key.clone());
+            self.keys.insert(key, expiry);
+            true
+        };
+
+        (emit, WindowInFlowByWindowTask::new(gain, 0))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn purge(&mut self) {
+        self.keys.clear();
+        self.expiries.clear();
+    }
+
+    /// # Returns
+    ///
+    /// Bytes of evicted keys.
+    fn evict(&mut self, watermark: Timestamp) -> usize {
+        let mut bytes = 0;
+        while let Some(entry) = self.expiries.first_entry() {
+            if *entry.key() > watermark {
+                break;
+            }
+            let expiry = *entry.key();
+            for key in entry.remove() {
+                if self.keys.get(&key) == Some(&expiry) {
+                    bytes += Self::entry_size(&key);
+                    self.keys.remove(&key);
+                }
+            }
+        }
+        bytes
+    }
+
+    fn allowed_delay(&self) -> EventDuration {
+        match self.horizon {
+            DeduplicateHorizon::Pane { allowed_delay, .. } => allowed_delay,
+            DeduplicateHorizon::Duration(_) => EventDuration::from_secs(0),
+        }
+    }
+
+    /// A key is held in both `keys` and `expiries`.
+    fn entry_size(key: &Key) -> usize {
+        let key_size: usize = key
+            .iter()
+            .map(|v| size_of::<Option<NnSqlValue>>() + v.as_ref().map_or(0, |v| v.mem_size()))
+            .sum();
+        (key_size + size_of::<Timestamp>()) * 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn key(s: &str) -> Vec<SqlValue> {
+        vec![SqlValue::NotNull(NnSqlValue::Text(s.to_string()))]
+    }
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::from_str(&format!("2020-01-01 00:00:{}.000000000", s)).unwrap()
+    }
+
+    #[test]
+    fn test_deduplicate_within_duration() {
+        let mut state =
+            DeduplicateState::new(DeduplicateHorizon::Duration(EventDuration::from_secs(5)));
+
+        assert!(state.dispatch(key("a"), ts("00")).0);
+        assert!(!state.dispatch(key("a"), ts("01")).0);
+        assert!(state.dispatch(key("b"), ts("02")).0);
+        assert!(!state.dispatch(key("a"), ts("04")).0);
+        // 5 seconds after the first "a"
+        assert!(state.dispatch(key("a"), ts("05")).0);
+
+        // first "b" is evicted
+        assert!(state.dispatch(key("c"), ts("07")).0);
+        assert_eq!(state.keys.len(), 2);
+    }
+
+    #[test]
+    fn test_deduplicate_in_pane() {
+        let mut state = DeduplicateState::new(DeduplicateHorizon::Pane {
+            length: EventDuration::from_secs(10),
+            allowed_delay: EventDuration::from_secs(1),
+        });
+
+        assert!(state.dispatch(key("a"), ts("00")).0);
+        assert!(!state.dispatch(key("a"), ts("09")).0);
+        assert!(state.dispatch(key("a"), ts("10")).0);
+        // allowed delay
+        assert!(!state.dispatch(key("a"), ts("08")).0);
+        assert!(state.dispatch(key("b"), ts("08")).0);
+        // closed pane
+        assert!(state.dispatch(key("c"), ts("12")).0);
+        assert!(!state.dispatch(key("d"), ts("05")).0);
+        assert_eq!(state.keys.len(), 2);
+    }
+
+    #[test]
+    fn test_null_key() {
+        let mut state =
+            DeduplicateState::new(DeduplicateHorizon::Duration(EventDuration::from_secs(5)));
+
+        assert!(state.dispatch(vec![SqlValue::Null], ts("00")).0);
+        assert!(!state.dispatch(vec![SqlValue::Null], ts("01")).0);
+    }
+
+    #[test]
+    fn test_purge() {
+        let mut state =
+            DeduplicateState::new(DeduplicateHorizon::Duration(EventDuration::from_secs(5)));
+
+        let (_, gain) = state.dispatch(key("a"), ts("00"));
+        assert_eq!(gain.window_gain_bytes_states as usize, state.mem_size());
+
+        state.purge();
+        assert_eq!(state.mem_size(), 0);
+        assert!(state.dispatch(key("a"), ts("01")).0);
+    }
+}
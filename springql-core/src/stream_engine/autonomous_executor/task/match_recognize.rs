@@ -0,0 +1,397 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! States of `MATCH_RECOGNIZE`.
+//!
+//! Each partition has runs (partial matches), each of which is at an NFA state and started at a row.
+//! A run is dropped when the current row does not continue it (rows in a match are contiguous in the partition)
+//! or when the current row is out of `WITHIN` duration from the first row of the run.
+//! So the number of runs in a partition is bounded by (number of NFA states) * (number of rows within `WITHIN` duration).
+//!
+//! A match is emitted as soon as a run reaches the end of the pattern (shortest match; the earliest started run is chosen),
+//! and then all the runs in the partition are discarded (`AFTER MATCH SKIP PAST LAST ROW`).
+
+mod nfa;
+
+use std::{collections::HashMap, iter, mem::size_of};
+
+use crate::{
+    error::Result,
+    expr_resolver::ExprResolver,
+    mem_size::MemSize,
+    pipeline::{
+        field::{field_name::ColumnReference, Field},
+        pump_model::match_recognize_parameter::MatchRecognizeParameter,
+    },
+    stream_engine::{
+        autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+        time::{duration::SpringDuration, timestamp::Timestamp},
+        NnSqlValue, SqlValue, Tuple,
+    },
+};
+
+use self::nfa::{Nfa, NfaStateId};
+
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct MatchRecognizeState {
+    param: MatchRecognizeParameter,
+    nfa: Nfa,
+
+    /// Index of `param.symbols` for each measure.
+    measure_symbols: Vec<usize>,
+
+    /// NULL partition key (and no PARTITION BY) is represented by None.
+    partitions: HashMap<Option<NnSqlValue>, Vec<Run>>,
+
+    /// Sequence number of rows to identify the start of runs.
+    row_seq: u64,
+}
+
+impl MemSize for MatchRecognizeState {
+    fn mem_size(&self) -> usize {
+        self.partitions
+            .iter()
+            .map(|(key, runs)| Self::partition_size(key, runs))
+            .sum()
+    }
+}
+
+impl MatchRecognizeState {
+    pub(in crate::stream_engine::autonomous_executor) fn new(
+        param: MatchRecognizeParameter,
+    ) -> Self {
+        let symbols = param
+            .symbols
+            .iter()
+            .map(|(symbol, _)| symbol.clone())
+            .collect::<Vec<_>>();
+        let nfa = Nfa::compile(&param.pattern, &symbols);
+        let measure_symbols = param
+            .measures
+            .iter()
+            .map(|measure| {
+                symbols
+                    .iter()
+                    .position(|s| s == &measure.symbol)
+                    .expect("measure symbol must be checked on planning")
+            })
+            .collect();
+
+        Self {
+            param,
+            nfa,
+            measure_symbols,
+            partitions: HashMap::new(),
+            row_seq: 0,
+        }
+    }
+
+    /// Feeds `tuple` to the runs in its partition.
+    ///
+    /// # Returns
+    ///
+    /// `(tuples of measures, gain of states)`. A tuple of measures has the `ROWTIME` of the last row in the match.
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - column reference in expression is not found in `tuple`.
+    ///   - DEFINE condition is not evaluated as BOOLEAN.
+    pub(in crate::stream_engine::autonomous_executor) fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let rowtime = *tuple.rowtime();
+
+        let symbol_matched = self
+            .param
+            .symbols
+            .iter()
+            .map(|(_, define)| match define {
+                Some(label) => expr_resolver.eval_value_expr(*label, &tuple)?.to_bool(),
+                None => Ok(true),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let key = match self.param.partition_by {
+            Some(label) => match expr_resolver.eval_value_expr(label, &tuple)? {
+                SqlValue::Null => None,
+                SqlValue::NotNull(v) => Some(v),
+            },
+            None => None,
+        };
+
+        let runs = self.partitions.remove(&key).unwrap_or_default();
+        let bytes_before = if runs.is_empty() {
+            0
+        } else {
+            Self::partition_size(&key, &runs)
+        };
+
+        self.row_seq += 1;
+        let new_run = Run::new(
+            self.nfa.start(),
+            self.row_seq,
+            rowtime,
+            self.param.symbols.len(),
+        );
+        let within = self.param.within.to_chrono();
+
+        let mut next_runs: Vec<Run> = Vec::new();
+        for run in runs
+            .into_iter()
+            .filter(|run| rowtime <= run.started_at + within)
+            .chain(iter::once(new_run))
+        {
+            for (symbol_idx, next_state) in self.nfa.transitions(run.state) {
+                let is_dup = next_runs
+                    .iter()
+                    .any(|r| r.state == next_state && r.start_seq == run.start_seq);
+                if symbol_matched[symbol_idx] && !is_dup {
+                    next_runs.push(run.advance(next_state, symbol_idx, &tuple));
+                }
+            }
+        }
+
+        let matched_run = next_runs
+            .iter()
+            .find(|run| self.nfa.is_accepting(run.state));
+
+        let (out, bytes_after) = match matched_run {
+            Some(run) => (vec![self.measures(expr_resolver, run, rowtime)?], 0),
+            None if next_runs.is_empty() => (vec![], 0),
+            None => {
+                let bytes = Self::partition_size(&key, &next_runs);
+                self.partitions.insert(key, next_runs);
+                (vec![], bytes)
+            }
+        };
+
+        Ok((
+            out,
+            WindowInFlowByWindowTask::new(bytes_after as i64 - bytes_before as i64, 0),
+        ))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn purge(&mut self) {
+        self.partitions.clear();
+    }
+
+    fn measures(
+        &self,
+        expr_resolver: &ExprResolver,
+        run: &Run,
+        rowtime: Timestamp,
+    ) -> Result<Tuple> {
+        let fields = self
+            .param
+            .measures
+            .iter()
+            .zip(self.measure_symbols.iter())
+            .map(|(measure, symbol_idx)| {
+                let value = match &run.bindings[*symbol_idx] {
+                    Some(tuple) => expr_resolver.eval_value_expr(measure.expr, tuple)?,
+                    None => SqlValue::Null,
+                };
+                let colref = ColumnReference::new(self.param.alias.clone(), measure.alias.clone());
+                Ok(Field::new(colref, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Tuple::new(rowtime, fields))
+    }
+
+    fn partition_size(key: &Option<NnSqlValue>, runs: &[Run]) -> usize {
+        let key_size = key.as_ref().map_or(0, |v| v.mem_size());
+        let runs_size: usize = runs.iter().map(|run| run.mem_size()).sum();
+        key_size + runs_size
+    }
+}
+
+/// Partial match.
+#[derive(Debug)]
+struct Run {
+    state: NfaStateId,
+
+    start_seq: u64,
+    started_at: Timestamp,
+
+    /// Last row matched to each symbol (indexed as `MatchRecognizeParameter::symbols`).
+    bindings: Vec<Option<Tuple>>,
+}
+
+impl MemSize for Run {
+    fn mem_size(&self) -> usize {
+        let bindings_size: usize = self
+            .bindings
+            .iter()
+            .map(|tuple| size_of::<Option<Tuple>>() + tuple.as_ref().map_or(0, |t| t.mem_size()))
+            .sum();
+        size_of::<NfaStateId>() + size_of::<u64>() + self.started_at.mem_size() + bindings_size
+    }
+}
+
+impl Run {
+    fn new(state: NfaStateId, start_seq: u64, started_at: Timestamp, n_symbols: usize) -> Self {
+        Self {
+            state,
+            start_seq,
+            started_at,
+            bindings: vec![None; n_symbols],
+        }
+    }
+
+    fn advance(&self, next_state: NfaStateId, symbol_idx: usize, tuple: &Tuple) -> Self {
+        let mut bindings = self.bindings.clone();
+        bindings[symbol_idx] = Some(tuple.clone());
+        Self {
+            state: next_state,
+            start_seq: self.start_seq,
+            started_at: self.started_at,
+            bindings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    use crate::{
+        expression::{
+            boolean_expression::{comparison_function::ComparisonFunction, BinaryExpr},
+            ValueExpr,
+        },
+        pipeline::{
+            name::{ColumnName, PatternSymbol, StreamName},
+            pump_model::match_recognize_parameter::{MeasureParameter, RowPattern},
+        },
+        stream_engine::time::duration::event_duration::EventDuration,
+    };
+
+    fn colref(column: &str) -> ColumnReference {
+        ColumnReference::new(
+            StreamName::new("s".to_string()),
+            ColumnName::new(column.to_string()),
+        )
+    }
+
+    fn tuple(ts: &str, event: &str) -> Tuple {
+        Tuple::new(
+            Timestamp::from_str(ts).unwrap(),
+            vec![
+                Field::new(
+                    colref("ts"),
+                    SqlValue::NotNull(NnSqlValue::Timestamp(Timestamp::from_str(ts).unwrap())),
+                ),
+                Field::new(
+                    colref("event"),
+                    SqlValue::NotNull(NnSqlValue::Text(event.to_string())),
+                ),
+            ],
+        )
+    }
+
+    fn event_is(event: &str) -> ValueExpr {
+        ValueExpr::BinaryExpr(BinaryExpr::ComparisonFunctionVariant(
+            ComparisonFunction::EqualVariant {
+                left: Box::new(ValueExpr::ColumnReference(colref("event"))),
+                right: Box::new(ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(
+                    event.to_string(),
+                )))),
+            },
+        ))
+    }
+
+    /// PATTERN (A B) WITHIN 2 secs, DEFINE A AS event = 'brake', B AS event = 'airbag', MEASURES A.ts AS brake_ts
+    fn brake_then_airbag(expr_resolver: &mut ExprResolver) -> MatchRecognizeState {
+        let a = PatternSymbol::new("A".to_string());
+        let b = PatternSymbol::new("B".to_string());
+
+        let define_a = expr_resolver.register_value_expr(event_is("brake"));
+        let define_b = expr_resolver.register_value_expr(event_is("airbag"));
+        let measure = expr_resolver.register_value_expr(ValueExpr::ColumnReference(colref("ts")));
+
+        MatchRecognizeState::new(MatchRecognizeParameter {
+            partition_by: None,
+            pattern: RowPattern::Concatenation(vec![
+                RowPattern::Symbol(a.clone()),
+                RowPattern::Symbol(b.clone()),
+            ]),
+            within: EventDuration::from_secs(2),
+            symbols: vec![(a.clone(), Some(define_a)), (b, Some(define_b))],
+            measures: vec![MeasureParameter {
+                symbol: a,
+                expr: measure,
+                alias: ColumnName::new("brake_ts".to_string()),
+            }],
+            alias: StreamName::new("m".to_string()),
+        })
+    }
+
+    fn brake_ts(out: &[Tuple]) -> Vec<String> {
+        let colref = ColumnReference::new(
+            StreamName::new("m".to_string()),
+            ColumnName::new("brake_ts".to_string()),
+        );
+        out.iter()
+            .map(|t| t.get_value(&colref).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_match_within() {
+        let (mut expr_resolver, ..) = ExprResolver::new(vec![]);
+        let mut state = brake_then_airbag(&mut expr_resolver);
+
+        let mut out = vec![];
+        for (ts, event) in [
+            ("2020-01-01 00:00:00.000000000", "brake"),
+            ("2020-01-01 00:00:01.000000000", "airbag"), // match
+            ("2020-01-01 00:00:02.000000000", "airbag"),
+            ("2020-01-01 00:00:03.000000000", "brake"),
+            ("2020-01-01 00:00:06.000000000", "airbag"), // out of WITHIN
+            ("2020-01-01 00:00:07.000000000", "brake"),
+            ("2020-01-01 00:00:08.000000000", "brake"),
+            ("2020-01-01 00:00:09.000000000", "airbag"), // match
+        ] {
+            let (mut o, _) = state.dispatch(&expr_resolver, tuple(ts, event)).unwrap();
+            out.append(&mut o);
+        }
+
+        assert_eq!(
+            brake_ts(&out),
+            vec![
+                "2020-01-01 00:00:00.000000000".to_string(),
+                "2020-01-01 00:00:08.000000000".to_string()
+            ]
+        );
+        assert_eq!(state.mem_size(), 0);
+    }
+
+    #[test]
+    fn test_window_in_flow() {
+        let (mut expr_resolver, ..) = ExprResolver::new(vec![]);
+        let mut state = brake_then_airbag(&mut expr_resolver);
+
+        let (_, gain) = state
+            .dispatch(
+                &expr_resolver,
+                tuple("2020-01-01 00:00:00.000000000", "brake"),
+            )
+            .unwrap();
+        assert!(gain.window_gain_bytes_states > 0);
+        assert_eq!(gain.window_gain_bytes_states, state.mem_size() as i64);
+
+        let (_, gain) = state
+            .dispatch(
+                &expr_resolver,
+                tuple("2020-01-01 00:00:01.000000000", "airbag"),
+            )
+            .unwrap();
+        assert!(gain.window_gain_bytes_states < 0);
+        assert_eq!(state.mem_size(), 0);
+    }
+}
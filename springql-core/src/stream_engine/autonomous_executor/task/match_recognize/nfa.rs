@@ -0,0 +1,197 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Thompson's construction of NFA from a row pattern.
+//!
+//! Each construct starts from its own fresh state, so that loops of quantifiers never leak into sibling alternatives.
+
+use crate::pipeline::{
+    name::PatternSymbol,
+    pump_model::match_recognize_parameter::{PatternQuantifier, RowPattern},
+};
+
+pub(super) type NfaStateId = usize;
+
+#[derive(Debug, Default)]
+struct NfaState {
+    /// `(symbol index, next state)`
+    transitions: Vec<(usize, NfaStateId)>,
+    epsilons: Vec<NfaStateId>,
+}
+
+#[derive(Debug)]
+pub(super) struct Nfa {
+    states: Vec<NfaState>,
+    start: NfaStateId,
+    accept: NfaStateId,
+}
+
+impl Nfa {
+    /// # Panics
+    ///
+    /// A symbol in `pattern` is not in `symbols`.
+    pub(super) fn compile(pattern: &RowPattern, symbols: &[PatternSymbol]) -> Self {
+        let mut nfa = Self {
+            states: vec![NfaState::default()],
+            start: 0,
+            accept: 0,
+        };
+        nfa.accept = nfa.build(pattern, symbols, nfa.start);
+        nfa
+    }
+
+    pub(super) fn start(&self) -> NfaStateId {
+        self.start
+    }
+
+    /// `(symbol index, next state)` from `state` and states reachable from it by epsilon transitions.
+    pub(super) fn transitions(&self, state: NfaStateId) -> Vec<(usize, NfaStateId)> {
+        self.epsilon_closure(state)
+            .into_iter()
+            .flat_map(|s| self.states[s].transitions.iter().copied())
+            .collect()
+    }
+
+    pub(super) fn is_accepting(&self, state: NfaStateId) -> bool {
+        self.epsilon_closure(state).contains(&self.accept)
+    }
+
+    fn epsilon_closure(&self, state: NfaStateId) -> Vec<NfaStateId> {
+        let mut closure = vec![state];
+        let mut i = 0;
+        while i < closure.len() {
+            for next in &self.states[closure[i]].epsilons {
+                if !closure.contains(next) {
+                    closure.push(*next);
+                }
+            }
+            i += 1;
+        }
+        closure
+    }
+
+    fn new_state(&mut self) -> NfaStateId {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    fn epsilon(&mut self, from: NfaStateId, to: NfaStateId) {
+        self.states[from].epsilons.push(to);
+    }
+
+    /// # Returns
+    ///
+    /// End state of `pattern` built from `from`.
+    fn build(
+        &mut self,
+        pattern: &RowPattern,
+        symbols: &[PatternSymbol],
+        from: NfaStateId,
+    ) -> NfaStateId {
+        match pattern {
+            RowPattern::Symbol(symbol) => {
+                let symbol_idx = symbols
+                    .iter()
+                    .position(|s| s == symbol)
+                    .expect("symbols are collected from pattern");
+                let to = self.new_state();
+                self.states[from].transitions.push((symbol_idx, to));
+                to
+            }
+            RowPattern::Concatenation(patterns) => patterns
+                .iter()
+                .fold(from, |from, pattern| self.build(pattern, symbols, from)),
+            RowPattern::Alternation(patterns) => {
+                let to = self.new_state();
+                for pattern in patterns {
+                    let start = self.new_state();
+                    self.epsilon(from, start);
+                    let end = self.build(pattern, symbols, start);
+                    self.epsilon(end, to);
+                }
+                to
+            }
+            RowPattern::Quantified(pattern, quantifier) => {
+                let start = self.new_state();
+                self.epsilon(from, start);
+                let end = self.build(pattern, symbols, start);
+                let to = self.new_state();
+                self.epsilon(end, to);
+
+                match quantifier {
+                    PatternQuantifier::ZeroOrMore => {
+                        self.epsilon(end, start);
+                        self.epsilon(start, to);
+                    }
+                    PatternQuantifier::OneOrMore => self.epsilon(end, start),
+                    PatternQuantifier::ZeroOrOne => self.epsilon(start, to),
+                }
+                to
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sym(s: &str) -> RowPattern {
+        RowPattern::Symbol(PatternSymbol::new(s.to_string()))
+    }
+
+    /// Whether `input` (sequence of symbol indexes) matches the whole pattern.
+    fn matches(pattern: RowPattern, input: &[usize]) -> bool {
+        let symbols = pattern.symbols();
+        let nfa = Nfa::compile(&pattern, &symbols);
+
+        let states = input.iter().fold(vec![nfa.start()], |states, symbol| {
+            states
+                .into_iter()
+                .flat_map(|state| nfa.transitions(state))
+                .filter_map(|(s, next)| (s == *symbol).then(|| next))
+                .collect()
+        });
+        states.into_iter().any(|state| nfa.is_accepting(state))
+    }
+
+    #[test]
+    fn test_concatenation() {
+        let pattern = RowPattern::Concatenation(vec![sym("A"), sym("B")]);
+        assert!(matches(pattern.clone(), &[0, 1]));
+        assert!(!matches(pattern.clone(), &[0]));
+        assert!(!matches(pattern, &[1, 0]));
+    }
+
+    #[test]
+    fn test_quantifiers() {
+        // A B* C?
+        let pattern = RowPattern::Concatenation(vec![
+            sym("A"),
+            RowPattern::Quantified(Box::new(sym("B")), PatternQuantifier::ZeroOrMore),
+            RowPattern::Quantified(Box::new(sym("C")), PatternQuantifier::ZeroOrOne),
+        ]);
+        assert!(matches(pattern.clone(), &[0]));
+        assert!(matches(pattern.clone(), &[0, 1, 1, 2]));
+        assert!(!matches(pattern.clone(), &[0, 2, 2]));
+
+        // A+
+        let pattern = RowPattern::Quantified(Box::new(sym("A")), PatternQuantifier::OneOrMore);
+        assert!(!matches(pattern.clone(), &[]));
+        assert!(matches(pattern, &[0, 0, 0]));
+    }
+
+    #[test]
+    fn test_alternation_with_loop() {
+        // (A+ | B) C
+        let pattern = RowPattern::Concatenation(vec![
+            RowPattern::Alternation(vec![
+                RowPattern::Quantified(Box::new(sym("A")), PatternQuantifier::OneOrMore),
+                sym("B"),
+            ]),
+            sym("C"),
+        ]);
+        assert!(matches(pattern.clone(), &[0, 0, 2]));
+        assert!(matches(pattern.clone(), &[1, 2]));
+        assert!(!matches(pattern, &[0, 1, 2]));
+    }
+}
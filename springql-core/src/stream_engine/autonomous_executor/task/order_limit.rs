@@ -0,0 +1,370 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! States of ORDER BY and LIMIT in panes.
+//!
+//! Rows in a pane are kept in a bounded heap ([TopN]) whose top is the lowest-ranked row.
+//! When the heap gets more than LIMIT rows, the top is dropped,
+//! so a pane holds at most LIMIT rows instead of every row in it.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BinaryHeap},
+    mem::size_of,
+};
+
+use crate::{
+    mem_size::MemSize,
+    pipeline::pump_model::order_limit_parameter::{SortKey, SortOrder},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+            row::value::sql_value::sql_compare_result::SqlCompareResult,
+        },
+        time::{
+            duration::{event_duration::EventDuration, SpringDuration},
+            timestamp::Timestamp,
+        },
+        SqlValue,
+    },
+};
+
+/// Sorts `rows` and keeps first `limit` rows of them.
+pub(in crate::stream_engine::autonomous_executor) fn sort_and_limit(
+    sort_keys: &[SortKey],
+    limit: Option<u64>,
+    rows: Vec<Vec<SqlValue>>,
+) -> Vec<Vec<SqlValue>> {
+    let mut top_n = TopN::new(sort_keys.to_vec(), limit);
+    for row in rows {
+        top_n.push(row);
+    }
+    top_n.into_sorted()
+}
+
+/// Bounded heap of rows.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct TopN {
+    sort_keys: Vec<SortKey>,
+    limit: Option<u64>,
+
+    heap: BinaryHeap<RankedRow>,
+    /// Arrival order of the next row, to keep rows with the same sort keys in arrival order.
+    next_seq: u64,
+}
+
+impl MemSize for TopN {
+    fn mem_size(&self) -> usize {
+        self.heap.iter().map(RankedRow::mem_size).sum()
+    }
+}
+
+impl TopN {
+    pub(in crate::stream_engine::autonomous_executor) fn new(
+        sort_keys: Vec<SortKey>,
+        limit: Option<u64>,
+    ) -> Self {
+        Self {
+            sort_keys,
+            limit,
+            heap: BinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// # Returns
+    ///
+    /// Gain of bytes of rows kept.
+    pub(in crate::stream_engine::autonomous_executor) fn push(
+        &mut self,
+        row: Vec<SqlValue>,
+    ) -> i64 {
+        let keys = self
+            .sort_keys
+            .iter()
+            .map(|sort_key| (row[sort_key.field_index].clone(), sort_key.order))
+            .collect();
+        let ranked = RankedRow {
+            keys,
+            seq: self.next_seq,
+            row,
+        };
+        self.next_seq += 1;
+
+        let mut gain = ranked.mem_size() as i64;
+        self.heap.push(ranked);
+
+        if matches!(self.limit, Some(limit) if self.heap.len() as u64 > limit) {
+            let dropped = self.heap.pop().expect("heap has more rows than limit");
+            gain -= dropped.mem_size() as i64;
+        }
+        gain
+    }
+
+    /// Rows in rank order.
+    pub(in crate::stream_engine::autonomous_executor) fn into_sorted(self) -> Vec<Vec<SqlValue>> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| ranked.row)
+            .collect()
+    }
+}
+
+/// Ordered by rank: greater is ranked lower.
+#[derive(Debug)]
+struct RankedRow {
+    keys: Vec<(SqlValue, SortOrder)>,
+    seq: u64,
+    row: Vec<SqlValue>,
+}
+
+impl RankedRow {
+    fn mem_size(&self) -> usize {
+        let values_size: usize = self
+            .keys
+            .iter()
+            .map(|(v, _)| v)
+            .chain(self.row.iter())
+            .map(|v| size_of::<SqlValue>() + v.mem_size())
+            .sum();
+        values_size + size_of::<Self>()
+    }
+}
+
+impl Ord for RankedRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.keys
+            .iter()
+            .zip(other.keys.iter())
+            .map(|((v1, order), (v2, _))| {
+                let ord = Self::cmp_values(v1, v2);
+                match order {
+                    SortOrder::Asc => ord,
+                    SortOrder::Desc => ord.reverse(),
+                }
+            })
+            .find(|ord| ord != &Ordering::Equal)
+            .unwrap_or_else(|| self.seq.cmp(&other.seq))
+    }
+}
+impl PartialOrd for RankedRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for RankedRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for RankedRow {}
+
+impl RankedRow {
+    /// NULL is greater than any value (NULLS LAST in ascending order).
+    /// Values not ordered to each other are equal.
+    fn cmp_values(v1: &SqlValue, v2: &SqlValue) -> Ordering {
+        match (v1, v2) {
+            (SqlValue::Null, SqlValue::Null) => Ordering::Equal,
+            (SqlValue::Null, SqlValue::NotNull(_)) => Ordering::Greater,
+            (SqlValue::NotNull(_), SqlValue::Null) => Ordering::Less,
+            _ => match v1.sql_compare(v2) {
+                Ok(SqlCompareResult::LessThan) => Ordering::Less,
+                Ok(SqlCompareResult::GreaterThan) => Ordering::Greater,
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
+/// Bounded heaps for each pane of a time-based fixed window.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct OrderLimitState {
+    sort_keys: Vec<SortKey>,
+    limit: Option<u64>,
+
+    length: EventDuration,
+    allowed_delay: EventDuration,
+
+    /// Open panes by their open_at.
+    panes: BTreeMap<Timestamp, TopN>,
+
+    max_rowtime: Option<Timestamp>,
+}
+
+impl MemSize for OrderLimitState {
+    fn mem_size(&self) -> usize {
+        self.panes.values().map(TopN::mem_size).sum()
+    }
+}
+
+impl OrderLimitState {
+    pub(in crate::stream_engine::autonomous_executor) fn new(
+        sort_keys: Vec<SortKey>,
+        limit: Option<u64>,
+        length: EventDuration,
+        allowed_delay: EventDuration,
+    ) -> Self {
+        Self {
+            sort_keys,
+            limit,
+            length,
+            allowed_delay,
+            panes: BTreeMap::new(),
+            max_rowtime: None,
+        }
+    }
+
+    /// Puts a row with `rowtime` into its pane, and closes panes behind the watermark.
+    /// A row in a closed pane is dropped.
+    ///
+    /// # Returns
+    ///
+    /// `(rows of closed panes in rank order, gain of rows kept)`
+    pub(in crate::stream_engine::autonomous_executor) fn dispatch(
+        &mut self,
+        row: Vec<SqlValue>,
+        rowtime: Timestamp,
+    ) -> (Vec<Vec<SqlValue>>, WindowInFlowByWindowTask) {
+        let max_rowtime = match self.max_rowtime {
+            Some(t) if t > rowtime => t,
+            _ => rowtime,
+        };
+        self.max_rowtime = Some(max_rowtime);
+        let watermark = max_rowtime - self.allowed_delay.to_chrono();
+
+        let mut gain = 0;
+
+        let open_at = rowtime.floor(self.length.to_chrono());
+        if open_at + self.length.to_chrono() > watermark {
+            let (sort_keys, limit) = (&self.sort_keys, self.limit);
+            gain += self
+                .panes
+                .entry(open_at)
+                .or_insert_with(|| TopN::new(sort_keys.clone(), limit))
+                .push(row);
+        }
+
+        let mut out = Vec::new();
+        while let Some(entry) = self.panes.first_entry() {
+            if *entry.key() + self.length.to_chrono() > watermark {
+                break;
+            }
+            let top_n = entry.remove();
+            gain -= top_n.mem_size() as i64;
+            out.append(&mut top_n.into_sorted());
+        }
+
+        (out, WindowInFlowByWindowTask::new(0, gain))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn purge(&mut self) {
+        self.panes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::stream_engine::NnSqlValue;
+
+    fn row(name: &str, v: i32) -> Vec<SqlValue> {
+        vec![
+            SqlValue::NotNull(NnSqlValue::Text(name.to_string())),
+            SqlValue::NotNull(NnSqlValue::Integer(v)),
+        ]
+    }
+    fn names(rows: Vec<Vec<SqlValue>>) -> Vec<String> {
+        rows.into_iter()
+            .map(|row| row[0].clone().unwrap().unpack::<String>().unwrap())
+            .collect()
+    }
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::from_str(&format!("2020-01-01 00:00:{}.000000000", s)).unwrap()
+    }
+    fn desc() -> Vec<SortKey> {
+        vec![SortKey {
+            field_index: 1,
+            order: SortOrder::Desc,
+        }]
+    }
+
+    #[test]
+    fn test_sort_and_limit() {
+        let rows = vec![row("a", 10), row("b", 30), row("c", 20), row("d", 30)];
+
+        assert_eq!(
+            names(sort_and_limit(&desc(), Some(3), rows.clone())),
+            vec!["b", "d", "c"]
+        );
+        assert_eq!(
+            names(sort_and_limit(
+                &[SortKey {
+                    field_index: 1,
+                    order: SortOrder::Asc,
+                }],
+                None,
+                rows.clone()
+            )),
+            vec!["a", "c", "b", "d"]
+        );
+        // arrival order
+        assert_eq!(names(sort_and_limit(&[], Some(2), rows)), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_null_last() {
+        let rows = vec![
+            vec![
+                SqlValue::NotNull(NnSqlValue::Text("null".to_string())),
+                SqlValue::Null,
+            ],
+            row("a", 10),
+        ];
+        let asc = vec![SortKey {
+            field_index: 1,
+            order: SortOrder::Asc,
+        }];
+        assert_eq!(names(sort_and_limit(&asc, None, rows)), vec!["a", "null"]);
+    }
+
+    #[test]
+    fn test_bounded_heap() {
+        let mut top_n = TopN::new(desc(), Some(2));
+        for v in 0..100 {
+            top_n.push(row("a", v));
+        }
+        assert_eq!(top_n.heap.len(), 2);
+    }
+
+    #[test]
+    fn test_order_limit_in_fixed_panes() {
+        let mut state = OrderLimitState::new(
+            desc(),
+            Some(2),
+            EventDuration::from_secs(10),
+            EventDuration::from_secs(0),
+        );
+
+        let (out, _) = state.dispatch(row("a", 10), ts("00"));
+        assert!(out.is_empty());
+        state.dispatch(row("b", 30), ts("03"));
+        state.dispatch(row("c", 20), ts("09"));
+
+        let (out, _) = state.dispatch(row("d", 50), ts("10"));
+        assert_eq!(names(out), vec!["b", "c"]);
+
+        // closed pane
+        let (out, _) = state.dispatch(row("e", 90), ts("05"));
+        assert!(out.is_empty());
+
+        let (out, _) = state.dispatch(row("f", 0), ts("20"));
+        assert_eq!(names(out), vec!["d"]);
+        // pane [:20, :30) keeps "f"
+        assert!(state.mem_size() > 0);
+
+        state.purge();
+        assert_eq!(state.mem_size(), 0);
+    }
+}
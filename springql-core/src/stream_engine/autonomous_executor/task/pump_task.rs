@@ -2,21 +2,20 @@
 
 mod pump_subtask;
 
-use std::sync::MutexGuard;
-use std::thread;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use super::task_context::TaskContext;
-use super::window::aggregate::AggrWindow;
-use super::window::join_window::JoinWindow;
+use super::window::Window;
+use crate::stream_engine::time::clock;
 use crate::error::Result;
 use crate::pipeline::pipeline_graph::PipelineGraph;
 use crate::pipeline::pump_model::PumpModel;
-use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::{MetricsUpdateByTaskExecution, TaskMetricsUpdateByTask, OutQueueMetricsUpdateByTask, InQueueMetricsUpdateByTask};
+use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::{MetricsUpdateByTaskExecution, TaskMetricsUpdateByTask, OutQueueMetricsUpdateByTask, InQueueMetricsUpdateByTask, WindowInFlowByWindowTask};
 use crate::stream_engine::autonomous_executor::task_graph::task_id::TaskId;
 use crate::stream_engine::time::duration::wall_clock_duration::wall_clock_stopwatch::WallClockStopwatch;
 use pump_subtask::insert_subtask::InsertSubtask;
-use pump_subtask::query_subtask::QuerySubtask;
+use pump_subtask::query_subtask::{QuerySubtask, QuerySubtaskOut};
 
 /// Source tasks may require I/O but pump tasks are not.
 /// Pump tasks should yield CPU time when it cannot get input data, expecting source tasks get enough CPU time to feed source data.
@@ -25,7 +24,10 @@ const WAIT_ON_NO_INPUT: Duration = Duration::from_micros(100);
 #[derive(Debug)]
 pub(crate) struct PumpTask {
     id: TaskId,
-    query_subtask: QuerySubtask,
+    /// A query subtask for each branch of UNION ALL (only one without UNION ALL).
+    query_subtasks: Vec<QuerySubtask>,
+    /// Index of the query subtask to try first in the next run.
+    next_query_subtask: AtomicUsize,
     insert_subtask: InsertSubtask,
 }
 
@@ -35,11 +37,16 @@ impl PumpTask {
         pipeline_graph: &PipelineGraph,
     ) -> Self {
         let id = TaskId::from_pump(pump);
-        let query_subtask = QuerySubtask::new(pump.query_plan().clone());
+        let query_subtasks = pump
+            .query_plan()
+            .branches()
+            .map(|plan| QuerySubtask::new(plan.clone()))
+            .collect();
         let insert_subtask = InsertSubtask::new(pump.insert_plan(), pipeline_graph);
         Self {
             id,
-            query_subtask,
+            query_subtasks,
+            next_query_subtask: AtomicUsize::new(0),
             insert_subtask,
         }
     }
@@ -53,48 +60,100 @@ impl PumpTask {
         context: &TaskContext,
     ) -> Result<MetricsUpdateByTaskExecution> {
         let stopwatch = WallClockStopwatch::start();
-        let (in_queue_metrics, out_queues_metrics) = self.run_query_insert(context)?;
+        let (in_queue_metrics, out_queues_metrics, late_rows) = self.run_query_insert(context)?;
         let execution_time = stopwatch.stop();
 
-        let task_metrics = TaskMetricsUpdateByTask::new(context.task(), execution_time);
-        let metrics = MetricsUpdateByTaskExecution::new(
-            task_metrics,
-            in_queue_metrics.map_or_else(Vec::new, |m| vec![m]),
-            out_queues_metrics,
-        );
+        let task_metrics =
+            TaskMetricsUpdateByTask::new(context.task(), execution_time).with_late_rows(late_rows);
+        let metrics =
+            MetricsUpdateByTaskExecution::new(task_metrics, in_queue_metrics, out_queues_metrics);
 
         Ok(metrics)
     }
 
+    /// # Returns
+    ///
+    /// (in queues metrics, out queues metrics, number of late rows)
     fn run_query_insert(
         &self,
         context: &TaskContext,
     ) -> Result<(
-        Option<InQueueMetricsUpdateByTask>,
+        Vec<InQueueMetricsUpdateByTask>,
         Vec<OutQueueMetricsUpdateByTask>,
+        u64,
     )> {
-        if let Some(query_subtask_out) = self.query_subtask.run(context)? {
-            let insert_subtask_out = self
-                .insert_subtask
-                .run(query_subtask_out.values_seq, context);
+        if let Some(query_subtask_out) = self.run_query_subtasks(context)? {
+            let late_rows = query_subtask_out.late_tuples.len() as u64;
+            let insert_subtask_out = self.insert_subtask.run(
+                query_subtask_out.values_seq,
+                query_subtask_out.late_tuples,
+                context,
+            );
             Ok((
-                Some(query_subtask_out.in_queue_metrics_update),
+                vec![query_subtask_out.in_queue_metrics_update],
                 insert_subtask_out.out_queues_metrics_update,
+                late_rows,
             ))
         } else {
-            thread::sleep(WAIT_ON_NO_INPUT);
-            Ok((None, vec![]))
+            let mut values_seq = Vec::new();
+            let mut in_queues_metrics = Vec::new();
+            for query_subtask in &self.query_subtasks {
+                let (mut values, window_in_flow) = query_subtask.run_on_idle()?;
+                values_seq.append(&mut values);
+                if window_in_flow != WindowInFlowByWindowTask::zero() {
+                    in_queues_metrics
+                        .push(query_subtask.in_queue_metrics_on_idle(context, window_in_flow));
+                }
+            }
+
+            if values_seq.is_empty() {
+                clock::sleep(WAIT_ON_NO_INPUT);
+                Ok((in_queues_metrics, vec![], 0))
+            } else {
+                let insert_subtask_out = self.insert_subtask.run(values_seq, vec![], context);
+                Ok((
+                    in_queues_metrics,
+                    insert_subtask_out.out_queues_metrics_update,
+                    0,
+                ))
+            }
         }
     }
 
-    pub(in crate::stream_engine::autonomous_executor) fn get_aggr_window_mut(
-        &self,
-    ) -> Option<MutexGuard<AggrWindow>> {
-        self.query_subtask.get_aggr_window_mut()
+    /// Runs query subtasks in round-robin until one of them gets an input row.
+    fn run_query_subtasks(&self, context: &TaskContext) -> Result<Option<QuerySubtaskOut>> {
+        let n = self.query_subtasks.len();
+        let start = self.next_query_subtask.fetch_add(1, Ordering::Relaxed);
+        for i in 0..n {
+            if let Some(out) = self.query_subtasks[(start + i) % n].run(context)? {
+                return Ok(Some(out));
+            }
+        }
+        Ok(None)
     }
-    pub(in crate::stream_engine::autonomous_executor) fn get_join_window_mut(
-        &self,
-    ) -> Option<MutexGuard<JoinWindow>> {
-        self.query_subtask.get_join_window_mut()
+
+    /// Purges windows and states of every query subtask.
+    pub(in crate::stream_engine::autonomous_executor) fn purge_windows(&self) {
+        for query_subtask in &self.query_subtasks {
+            if let Some(mut w) = query_subtask.get_join_window_mut() {
+                w.purge()
+            } else if let Some(mut w) = query_subtask.get_aggr_window_mut() {
+                w.purge()
+            } else if let Some(mut s) = query_subtask.get_analytic_states_mut() {
+                s.purge()
+            } else if let Some(mut s) = query_subtask.get_match_recognize_mut() {
+                s.purge()
+            } else {
+                // nothing to purge
+            }
+
+            // deduplication and ORDER BY may follow JOIN
+            if let Some(mut s) = query_subtask.get_deduplicate_mut() {
+                s.purge()
+            }
+            if let Some(mut s) = query_subtask.get_order_limit_mut() {
+                s.purge()
+            }
+        }
     }
 }
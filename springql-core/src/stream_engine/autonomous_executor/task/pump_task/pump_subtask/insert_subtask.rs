@@ -9,6 +9,7 @@ use crate::pipeline::stream_model::StreamModel;
 use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::OutQueueMetricsUpdateByTask;
 use crate::stream_engine::autonomous_executor::row::Row;
 use crate::stream_engine::autonomous_executor::task::task_context::TaskContext;
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
 use crate::stream_engine::autonomous_executor::task_graph::queue_id::QueueId;
 use crate::stream_engine::command::insert_plan::InsertPlan;
 
@@ -20,6 +21,9 @@ pub(in crate::stream_engine::autonomous_executor) struct InsertSubtask {
 
     /// INSERT INTO stream (c2, c3, c1) -- this one!
     column_order: Vec<ColumnName>,
+
+    /// LATE ROWS INTO stream
+    late_rows_stream: Option<Arc<StreamModel>>,
 }
 
 #[derive(Debug, new)]
@@ -39,31 +43,64 @@ impl InsertSubtask {
         let into_stream = pipeline_graph
             .get_stream(plan.stream())
             .expect("plan has invalid stream name");
+        let late_rows_stream = plan.late_rows_stream().map(|stream| {
+            pipeline_graph
+                .get_stream(stream)
+                .expect("plan has invalid late rows stream name")
+        });
         Self {
             into_stream,
             column_order: plan.column_order().to_vec(),
+            late_rows_stream,
         }
     }
 
+    /// Late tuples are inserted to the late rows stream (if any) with their original columns.
     pub(in crate::stream_engine::autonomous_executor) fn run(
         &self,
         values_seq: Vec<SqlValues>,
+        late_tuples: Vec<Tuple>,
         context: &TaskContext,
     ) -> InsertSubtaskOut {
-        if values_seq.is_empty() {
-            InsertSubtaskOut::new(vec![])
+        let rows = values_seq
+            .into_iter()
+            .map(|values| values.into_row(self.into_stream.clone(), self.column_order.clone()))
+            .collect::<Vec<_>>();
+        let mut out_queues_metrics_update = self.put_rows(
+            rows,
+            context.output_queues_of_stream(self.into_stream.name()),
+            context,
+        );
+
+        if let Some(late_rows_stream) = &self.late_rows_stream {
+            let late_rows = late_tuples
+                .into_iter()
+                .map(|tuple| Self::late_tuple_into_row(tuple, late_rows_stream.clone()))
+                .collect::<Vec<_>>();
+            out_queues_metrics_update.append(&mut self.put_rows(
+                late_rows,
+                context.output_queues_of_stream(late_rows_stream.name()),
+                context,
+            ));
+        }
+
+        InsertSubtaskOut::new(out_queues_metrics_update)
+    }
+
+    fn put_rows(
+        &self,
+        rows: Vec<Row>,
+        output_queues: Vec<QueueId>,
+        context: &TaskContext,
+    ) -> Vec<OutQueueMetricsUpdateByTask> {
+        if rows.is_empty() {
+            vec![]
         } else {
             let repos = context.repos();
             let row_q_repo = repos.row_queue_repository();
             let window_q_repo = repos.window_queue_repository();
-            let output_queues = context.output_queues();
 
-            let rows = values_seq
-                .into_iter()
-                .map(|values| values.into_row(self.into_stream.clone(), self.column_order.clone()))
-                .collect::<Vec<_>>();
-
-            let out_queues_metrics_update = output_queues
+            output_queues
                 .into_iter()
                 .map(|q| match q {
                     QueueId::Row(queue_id) => {
@@ -83,12 +120,26 @@ impl InsertSubtask {
                         out
                     }
                 })
-                .collect();
-
-            InsertSubtaskOut::new(out_queues_metrics_update)
+                .collect()
         }
     }
 
+    /// # Panics
+    ///
+    /// A column of `late_rows_stream` is not in `tuple` (must be checked on pump creation).
+    fn late_tuple_into_row(tuple: Tuple, late_rows_stream: Arc<StreamModel>) -> Row {
+        let column_names = late_rows_stream.shape().column_names();
+        let values = column_names
+            .iter()
+            .map(|column_name| {
+                tuple
+                    .get_value_by_column_name(column_name)
+                    .expect("late rows stream has the same columns as upstream")
+            })
+            .collect();
+        SqlValues::new(values).into_row(late_rows_stream, column_names)
+    }
+
     fn out_queue_metrics_update(
         &self,
         queue_id: QueueId,
@@ -1,9 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 pub(super) mod aggr_projection_subtask;
+pub(super) mod analytic_projection_subtask;
 pub(super) mod collect_subtask;
+pub(super) mod deduplicate_subtask;
 pub(super) mod group_aggregate_window_subtask;
 pub(super) mod join_subtask;
+pub(super) mod match_recognize_subtask;
+pub(super) mod order_limit_subtask;
 pub(super) mod value_projection_subtask;
 
 use std::sync::{Arc, Mutex, MutexGuard};
@@ -18,8 +22,14 @@ use crate::{
     expr_resolver::ExprResolver,
     pipeline::{name::ColumnName, stream_model::StreamModel},
     stream_engine::{
-        autonomous_executor::task::window::{
-            aggregate::AggrWindow, join_window::JoinWindow, panes::pane::join_pane::JoinDir,
+        autonomous_executor::task::{
+            analytic::AnalyticStates,
+            deduplicate::DeduplicateState,
+            match_recognize::MatchRecognizeState,
+            order_limit::OrderLimitState,
+            window::{
+                aggregate::AggrWindow, join_window::JoinWindow, panes::pane::join_pane::JoinDir,
+            },
         },
         command::query_plan::{query_plan_operation::LowerOps, QueryPlan},
     },
@@ -43,8 +53,11 @@ use crate::{
 };
 
 use self::{
-    aggr_projection_subtask::AggrProjectionSubtask, collect_subtask::CollectSubtask,
+    aggr_projection_subtask::AggrProjectionSubtask,
+    analytic_projection_subtask::AnalyticProjectionSubtask, collect_subtask::CollectSubtask,
+    deduplicate_subtask::DeduplicateSubtask,
     group_aggregate_window_subtask::GroupAggregateWindowSubtask, join_subtask::JoinSubtask,
+    match_recognize_subtask::MatchRecognizeSubtask, order_limit_subtask::OrderLimitSubtask,
     value_projection_subtask::ValueProjectionSubtask,
 };
 
@@ -54,6 +67,11 @@ pub(in crate::stream_engine::autonomous_executor) struct QuerySubtask {
     expr_resolver: ExprResolver,
 
     value_projection_subtask: Option<ValueProjectionSubtask>,
+    match_recognize_subtask: Option<MatchRecognizeSubtask>,
+    deduplicate_subtask: Option<DeduplicateSubtask>,
+    /// Follows value projection (and deduplication), or aggregate projection.
+    order_limit_subtask: Option<OrderLimitSubtask>,
+    analytic_projection_subtask: Option<AnalyticProjectionSubtask>,
 
     aggr_projection_subtask: Option<AggrProjectionSubtask>,
     group_aggr_window_subtask: Option<GroupAggregateWindowSubtask>,
@@ -115,10 +133,19 @@ impl SqlValues {
 #[derive(Debug, new)]
 pub(in crate::stream_engine::autonomous_executor) struct QuerySubtaskOut {
     pub(in crate::stream_engine::autonomous_executor) values_seq: Vec<SqlValues>,
+    /// Tuples dropped by window for their rowtime behind the watermark.
+    pub(in crate::stream_engine::autonomous_executor) late_tuples: Vec<Tuple>,
     pub(in crate::stream_engine::autonomous_executor) in_queue_metrics_update:
         InQueueMetricsUpdateByTask,
 }
 
+#[derive(Debug)]
+enum UpperOpsOut {
+    Values(Vec<SqlValues>, WindowInFlowByWindowTask),
+    /// Tuple dropped by window for its rowtime behind the watermark.
+    Late(Tuple),
+}
+
 impl QuerySubtask {
     pub(in crate::stream_engine::autonomous_executor) fn new(plan: QueryPlan) -> Self {
         let rng =
@@ -126,13 +153,44 @@ impl QuerySubtask {
 
         let (left_collect_subtask, join) = Self::subtasks_from_lower_ops(plan.lower_ops);
 
-        if plan.upper_ops.projection.aggr_expr_labels.is_empty() {
+        if !plan.upper_ops.projection.analytic_expr_labels().is_empty() {
+            let analytic_projection_subtask =
+                AnalyticProjectionSubtask::new(plan.upper_ops.projection.expr_labels);
+
+            Self {
+                expr_resolver: plan.expr_resolver,
+                value_projection_subtask: None,
+                match_recognize_subtask: None,
+                deduplicate_subtask: None,
+                order_limit_subtask: None,
+                analytic_projection_subtask: Some(analytic_projection_subtask),
+                aggr_projection_subtask: None,
+                group_aggr_window_subtask: None,
+                left_collect_subtask,
+                join,
+                rng,
+            }
+        } else if plan.upper_ops.projection.aggr_expr_labels().is_empty() {
+            let value_expr_labels = plan.upper_ops.projection.value_expr_labels();
+            let unnest_position = value_expr_labels
+                .iter()
+                .position(|label| plan.expr_resolver.is_unnest(*label));
             let value_projection_subtask =
-                ValueProjectionSubtask::new(plan.upper_ops.projection.value_expr_labels);
+                ValueProjectionSubtask::new(value_expr_labels, unnest_position);
+            let match_recognize_subtask = plan
+                .upper_ops
+                .match_recognize
+                .map(MatchRecognizeSubtask::new);
+            let deduplicate_subtask = plan.upper_ops.deduplicate.map(DeduplicateSubtask::new);
+            let order_limit_subtask = plan.upper_ops.order_limit.map(OrderLimitSubtask::new);
 
             Self {
                 expr_resolver: plan.expr_resolver,
                 value_projection_subtask: Some(value_projection_subtask),
+                match_recognize_subtask,
+                deduplicate_subtask,
+                order_limit_subtask,
+                analytic_projection_subtask: None,
                 aggr_projection_subtask: None,
                 group_aggr_window_subtask: None,
                 left_collect_subtask,
@@ -140,32 +198,52 @@ impl QuerySubtask {
                 rng,
             }
         } else {
+            let aggr_labels = plan.upper_ops.projection.aggr_expr_labels();
             assert_eq!(
-                plan.upper_ops.projection.aggr_expr_labels.len(),
+                aggr_labels.len(),
                 1,
                 "currently only 1 aggregate in select_list is supported"
             );
+
+            let group_by_labels = plan
+                .upper_ops
+                .projection
+                .value_expr_labels()
+                .into_iter()
+                .filter(|label| !plan.expr_resolver.has_window_function(*label))
+                .collect::<Vec<_>>();
             assert_eq!(
-                plan.upper_ops.projection.value_expr_labels.len(),
+                group_by_labels.len(),
                 1,
-                "currently only GROUP BY expression in select_list is supported"
+                "currently only GROUP BY expression and window functions in select_list are supported"
             );
 
             let aggr_projection_subtask = AggrProjectionSubtask::new(
-                plan.upper_ops.projection.value_expr_labels[0],
-                plan.upper_ops.projection.aggr_expr_labels[0],
+                group_by_labels[0],
+                aggr_labels[0],
+                plan.upper_ops.projection.expr_labels,
             );
 
+            let order_limit_subtask = plan.upper_ops.order_limit.map(OrderLimitSubtask::new);
+
             let op = plan
                 .upper_ops
                 .group_aggr_window
                 .expect("select_list includes aggregate ");
-            let group_aggr_window_subtask =
-                GroupAggregateWindowSubtask::new(op.window_param, op.op_param);
+            let group_aggr_window_subtask = GroupAggregateWindowSubtask::new(
+                op.window_param,
+                op.op_param,
+                op.idle_timeout,
+                op.trigger,
+            );
 
             Self {
                 expr_resolver: plan.expr_resolver,
                 value_projection_subtask: None,
+                match_recognize_subtask: None,
+                deduplicate_subtask: None,
+                order_limit_subtask,
+                analytic_projection_subtask: None,
                 aggr_projection_subtask: Some(aggr_projection_subtask),
                 group_aggr_window_subtask: Some(group_aggr_window_subtask),
                 left_collect_subtask,
@@ -210,30 +288,75 @@ impl QuerySubtask {
         match self.run_lower_ops(context) {
             None => Ok(None),
             Some((lower_tuples, in_queue_metrics_update_by_task)) => {
-                let (values_seq, in_queue_metrics_update) =
+                let (values_seq, late_tuples, in_queue_metrics_update) =
                     self.run_upper_ops(lower_tuples, in_queue_metrics_update_by_task)?;
 
                 Ok(Some(QuerySubtaskOut::new(
                     values_seq,
+                    late_tuples,
                     in_queue_metrics_update,
                 )))
             }
         }
     }
 
+    /// Called when input queue is empty.
+    ///
+    /// # Returns
+    ///
+    /// (values from window panes closed by idle timeout, window in-flow by the closed panes)
+    pub(in crate::stream_engine::autonomous_executor) fn run_on_idle(
+        &self,
+    ) -> Result<(Vec<SqlValues>, WindowInFlowByWindowTask)> {
+        match &self.group_aggr_window_subtask {
+            Some(group_aggr_window_subtask) => {
+                let (group_aggr_out_seq, window_in_flow) =
+                    group_aggr_window_subtask.run_on_idle(&self.expr_resolver);
+                let values_seq = self.run_aggr_projection_op(group_aggr_out_seq)?;
+                Ok((values_seq, window_in_flow))
+            }
+            None => Ok((vec![], WindowInFlowByWindowTask::zero())),
+        }
+    }
+
+    /// In-queue metrics of [run_on_idle()](Self::run_on_idle), which collects nothing from the input queue.
+    pub(in crate::stream_engine::autonomous_executor) fn in_queue_metrics_on_idle(
+        &self,
+        context: &TaskContext,
+        window_in_flow: WindowInFlowByWindowTask,
+    ) -> InQueueMetricsUpdateByTask {
+        InQueueMetricsUpdateByTask::new(
+            InQueueMetricsUpdateByCollect::none_collected(
+                self.left_collect_subtask.in_queue_id(context),
+            ),
+            Some(window_in_flow),
+        )
+    }
+
     fn run_upper_ops(
         &self,
         tuples: Vec<Tuple>,
         in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
-    ) -> Result<(Vec<SqlValues>, InQueueMetricsUpdateByTask)> {
-        let (values_seq, window_in_flow_upper_total) = tuples.into_iter().fold(
-            Ok((Vec::new(), WindowInFlowByWindowTask::zero())),
+    ) -> Result<(Vec<SqlValues>, Vec<Tuple>, InQueueMetricsUpdateByTask)> {
+        let (values_seq, late_tuples, window_in_flow_upper_total) = tuples.into_iter().fold(
+            Ok((Vec::new(), Vec::new(), WindowInFlowByWindowTask::zero())),
             |res, tuple| {
-                let (mut values_seq_acc, window_in_flow_acc) = res?;
-
-                let (mut values_seq, window_in_flow) = self.run_upper_ops_inner(tuple)?;
-                values_seq_acc.append(&mut values_seq);
-                Ok((values_seq_acc, window_in_flow_acc + window_in_flow))
+                let (mut values_seq_acc, mut late_tuples_acc, window_in_flow_acc) = res?;
+
+                match self.run_upper_ops_inner(tuple)? {
+                    UpperOpsOut::Values(mut values_seq, window_in_flow) => {
+                        values_seq_acc.append(&mut values_seq);
+                        Ok((
+                            values_seq_acc,
+                            late_tuples_acc,
+                            window_in_flow_acc + window_in_flow,
+                        ))
+                    }
+                    UpperOpsOut::Late(late_tuple) => {
+                        late_tuples_acc.push(late_tuple);
+                        Ok((values_seq_acc, late_tuples_acc, window_in_flow_acc))
+                    }
+                }
             },
         )?;
         let in_queue_metrics_update_by_task = InQueueMetricsUpdateByTask::new(
@@ -241,26 +364,82 @@ impl QuerySubtask {
             Some(window_in_flow_upper_total + in_queue_metrics_update_by_lower.window_in_flow),
         );
 
-        Ok((values_seq, in_queue_metrics_update_by_task))
+        Ok((values_seq, late_tuples, in_queue_metrics_update_by_task))
     }
-    fn run_upper_ops_inner(
-        &self,
-        tuple: Tuple,
-    ) -> Result<(Vec<SqlValues>, WindowInFlowByWindowTask)> {
+    fn run_upper_ops_inner(&self, tuple: Tuple) -> Result<UpperOpsOut> {
         if let Some(group_aggr_window_subtask) = &self.group_aggr_window_subtask {
-            let (group_aggr_out_seq, window_in_flow) =
-                group_aggr_window_subtask.run(&self.expr_resolver, tuple);
+            match group_aggr_window_subtask.run(&self.expr_resolver, tuple) {
+                Ok((group_aggr_out_seq, window_in_flow)) => {
+                    let values = self.run_aggr_projection_op(group_aggr_out_seq)?;
+                    Ok(UpperOpsOut::Values(values, window_in_flow))
+                }
+                Err(late_tuple) => Ok(UpperOpsOut::Late(late_tuple)),
+            }
+        } else if let Some(analytic_projection_subtask) = &self.analytic_projection_subtask {
+            let (values, window_in_flow) =
+                analytic_projection_subtask.run(&self.expr_resolver, &tuple)?;
+            Ok(UpperOpsOut::Values(vec![values], window_in_flow))
+        } else if let Some(match_recognize_subtask) = &self.match_recognize_subtask {
+            let (match_tuples, window_in_flow) =
+                match_recognize_subtask.run(&self.expr_resolver, tuple)?;
+            let values_seq = match_tuples
+                .iter()
+                .map(|match_tuple| self.run_projection_op(match_tuple))
+                .collect::<Result<Vec<_>>>()?
+                .concat();
+            Ok(UpperOpsOut::Values(values_seq, window_in_flow))
+        } else {
+            let mut values_seq = Vec::new();
+            let mut window_in_flow_dedup = WindowInFlowByWindowTask::zero();
+            for values in self.run_projection_op(&tuple)? {
+                let (mut deduplicated, window_in_flow) = self.run_deduplicate_op(&tuple, values)?;
+                values_seq.append(&mut deduplicated);
+                window_in_flow_dedup = window_in_flow_dedup + window_in_flow;
+            }
+            let (values_seq, window_in_flow_order_limit) =
+                self.run_order_limit_op(&tuple, values_seq);
+            Ok(UpperOpsOut::Values(
+                values_seq,
+                window_in_flow_dedup + window_in_flow_order_limit,
+            ))
+        }
+    }
 
-            let values = self.run_aggr_projection_op(group_aggr_out_seq)?;
+    fn run_deduplicate_op(
+        &self,
+        tuple: &Tuple,
+        values: SqlValues,
+    ) -> Result<(Vec<SqlValues>, WindowInFlowByWindowTask)> {
+        match &self.deduplicate_subtask {
+            Some(deduplicate_subtask) => {
+                let (emit, window_in_flow) =
+                    deduplicate_subtask.run(&self.expr_resolver, tuple, &values)?;
+                let values_seq = if emit { vec![values] } else { vec![] };
+                Ok((values_seq, window_in_flow))
+            }
+            None => Ok((vec![values], WindowInFlowByWindowTask::zero())),
+        }
+    }
 
-            Ok((values, window_in_flow))
-        } else {
-            let values = self.run_projection_op(&tuple)?;
-            Ok((vec![values], WindowInFlowByWindowTask::zero()))
+    fn run_order_limit_op(
+        &self,
+        tuple: &Tuple,
+        values_seq: Vec<SqlValues>,
+    ) -> (Vec<SqlValues>, WindowInFlowByWindowTask) {
+        match &self.order_limit_subtask {
+            Some(order_limit_subtask) => values_seq.into_iter().fold(
+                (Vec::new(), WindowInFlowByWindowTask::zero()),
+                |(mut values_seq_acc, window_in_flow_acc), values| {
+                    let (mut values_seq, window_in_flow) = order_limit_subtask.run(tuple, values);
+                    values_seq_acc.append(&mut values_seq);
+                    (values_seq_acc, window_in_flow_acc + window_in_flow)
+                },
+            ),
+            None => (values_seq, WindowInFlowByWindowTask::zero()),
         }
     }
 
-    fn run_projection_op(&self, tuple: &Tuple) -> Result<SqlValues> {
+    fn run_projection_op(&self, tuple: &Tuple) -> Result<Vec<SqlValues>> {
         self.value_projection_subtask
             .as_ref()
             .unwrap()
@@ -271,15 +450,23 @@ impl QuerySubtask {
         &self,
         group_aggr_out_seq: Vec<GroupAggrOut>,
     ) -> Result<Vec<SqlValues>> {
-        group_aggr_out_seq
+        let values_seq = group_aggr_out_seq
             .into_iter()
             .map(|group_agg_out| {
-                self.aggr_projection_subtask
+                let pane = (group_agg_out.window_bounds(), group_agg_out.is_early());
+                let values = self
+                    .aggr_projection_subtask
                     .as_ref()
                     .unwrap()
-                    .run(group_agg_out)
+                    .run(&self.expr_resolver, group_agg_out)?;
+                Ok((pane, values))
             })
-            .collect()
+            .collect::<Result<Vec<_>>>()?;
+
+        match &self.order_limit_subtask {
+            Some(order_limit_subtask) => Ok(order_limit_subtask.run_aggregate(values_seq)),
+            None => Ok(values_seq.into_iter().map(|(_, values)| values).collect()),
+        }
     }
 
     /// # Returns
@@ -368,6 +555,34 @@ impl QuerySubtask {
             .as_ref()
             .map(|subtask| subtask.get_window_mut())
     }
+    pub(in crate::stream_engine::autonomous_executor) fn get_analytic_states_mut(
+        &self,
+    ) -> Option<MutexGuard<AnalyticStates>> {
+        self.analytic_projection_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_states_mut())
+    }
+    pub(in crate::stream_engine::autonomous_executor) fn get_match_recognize_mut(
+        &self,
+    ) -> Option<MutexGuard<MatchRecognizeState>> {
+        self.match_recognize_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_state_mut())
+    }
+    pub(in crate::stream_engine::autonomous_executor) fn get_deduplicate_mut(
+        &self,
+    ) -> Option<MutexGuard<DeduplicateState>> {
+        self.deduplicate_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_state_mut())
+    }
+    pub(in crate::stream_engine::autonomous_executor) fn get_order_limit_mut(
+        &self,
+    ) -> Option<MutexGuard<OrderLimitState>> {
+        self.order_limit_subtask
+            .as_ref()
+            .and_then(|subtask| subtask.get_state_mut())
+    }
     pub(in crate::stream_engine::autonomous_executor) fn get_join_window_mut(
         &self,
     ) -> Option<MutexGuard<JoinWindow>> {
@@ -1,7 +1,9 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 use crate::error::Result;
-use crate::expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel};
+use crate::expr_resolver::expr_label::{AggrExprLabel, ExprLabel, ValueExprLabel};
+use crate::expr_resolver::ExprResolver;
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
 use crate::stream_engine::autonomous_executor::task::window::aggregate::GroupAggrOut;
 
 use super::SqlValues;
@@ -10,19 +12,42 @@ use super::SqlValues;
 pub(in crate::stream_engine::autonomous_executor) struct AggrProjectionSubtask {
     group_by_expr: ValueExprLabel,
     aggr_expr: AggrExprLabel,
+
+    /// Ordered as select_list.
+    /// Value expressions other than `group_by_expr` must be WINDOW_START() / WINDOW_END() / EMIT_TIMING() (or expressions of them).
+    expr_labels: Vec<ExprLabel>,
 }
 
 impl AggrProjectionSubtask {
     pub(in crate::stream_engine::autonomous_executor) fn run(
         &self,
+        expr_resolver: &ExprResolver,
         group_aggr_out: GroupAggrOut,
     ) -> Result<SqlValues> {
         let aggr_label = self.aggr_expr;
         let group_by_label = self.group_by_expr;
 
+        let (window_start, window_end) = group_aggr_out.window_bounds();
+        let window_tuple = Tuple::new(window_end, vec![])
+            .with_window_bounds(window_start, window_end)
+            .with_early(group_aggr_out.is_early());
+
         let (aggr_result, group_by_result) =
             group_aggr_out.into_results(aggr_label, group_by_label)?;
 
-        Ok(SqlValues::new(vec![group_by_result, aggr_result])) // FIXME keep select list order: <https://gh01.base.toyota-tokyo.tech/SpringQL-internal/SpringQL/issues/174>
+        let values = self
+            .expr_labels
+            .iter()
+            .map(|label| match label {
+                ExprLabel::Aggr(_) => Ok(aggr_result.clone()),
+                ExprLabel::Value(label) if *label == group_by_label => Ok(group_by_result.clone()),
+                ExprLabel::Value(label) => expr_resolver.eval_value_expr(*label, &window_tuple),
+                ExprLabel::Analytic(_) => {
+                    unreachable!("analytic expressions are not available with GROUP BY aggregation")
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SqlValues::new(values))
     }
 }
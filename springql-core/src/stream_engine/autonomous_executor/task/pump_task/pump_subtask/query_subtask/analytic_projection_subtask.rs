@@ -0,0 +1,80 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::error::Result;
+use crate::expr_resolver::expr_label::ExprLabel;
+use crate::expr_resolver::ExprResolver;
+use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask;
+use crate::stream_engine::autonomous_executor::task::analytic::AnalyticStates;
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
+
+use super::SqlValues;
+
+/// Projection with analytic expressions. Emits a SqlValues for each tuple.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct AnalyticProjectionSubtask {
+    /// Ordered as select_list. Value expressions and analytic expressions.
+    expr_labels: Vec<ExprLabel>,
+
+    states: Mutex<AnalyticStates>,
+}
+
+impl AnalyticProjectionSubtask {
+    pub(in crate::stream_engine::autonomous_executor) fn new(expr_labels: Vec<ExprLabel>) -> Self {
+        Self {
+            expr_labels,
+            states: Mutex::new(AnalyticStates::default()),
+        }
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+    ) -> Result<(SqlValues, WindowInFlowByWindowTask)> {
+        // value expressions are evaluated before touching the states, so that a failure leaves them unchanged
+        let values = self
+            .expr_labels
+            .iter()
+            .map(|label| match label {
+                ExprLabel::Value(label) => expr_resolver.eval_value_expr(*label, tuple).map(Some),
+                ExprLabel::Analytic(_) => Ok(None),
+                ExprLabel::Aggr(_) => {
+                    unreachable!("analytic expressions are not available with GROUP BY aggregation")
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let analytic_labels = self
+            .expr_labels
+            .iter()
+            .filter_map(|label| match label {
+                ExprLabel::Analytic(label) => Some(*label),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let (analytic_values, window_in_flow) =
+            self.get_states_mut()
+                .next(expr_resolver, &analytic_labels, tuple)?;
+
+        let mut analytic_values = analytic_values.into_iter();
+        let values = values
+            .into_iter()
+            .map(|value| {
+                value
+                    .unwrap_or_else(|| analytic_values.next().expect("one for each analytic label"))
+            })
+            .collect();
+
+        Ok((SqlValues::new(values), window_in_flow))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn get_states_mut(
+        &self,
+    ) -> MutexGuard<AnalyticStates> {
+        self.states
+            .lock()
+            .expect("another thread accessing to analytic states gets poisoned")
+    }
+}
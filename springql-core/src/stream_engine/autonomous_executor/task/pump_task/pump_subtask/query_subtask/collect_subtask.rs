@@ -26,6 +26,16 @@ impl CollectSubtask {
         Self { upstream }
     }
 
+    pub(in crate::stream_engine::autonomous_executor) fn in_queue_id(
+        &self,
+        context: &TaskContext,
+    ) -> QueueId {
+        let pump_task_id = context.task();
+        let pipeline_derivatives = context.pipeline_derivatives();
+        let task_graph = pipeline_derivatives.task_graph();
+        task_graph.input_queue(&pump_task_id, &self.upstream)
+    }
+
     /// # Returns
     ///
     /// None when input queue does not exist or is empty.
@@ -34,13 +44,7 @@ impl CollectSubtask {
         context: &TaskContext,
     ) -> Option<(Tuple, InQueueMetricsUpdateByCollect)> {
         let repos = context.repos();
-        let pump_task_id = context.task();
-
-        let pipeline_derivatives = context.pipeline_derivatives();
-        let task_graph = pipeline_derivatives.task_graph();
-
-        let queue_id = task_graph.input_queue(&pump_task_id, &self.upstream);
-        match queue_id {
+        match self.in_queue_id(context) {
             QueueId::Row(queue_id) => self.collect_from_row_queue(queue_id, repos),
             QueueId::Window(queue_id) => self.collect_from_window_queue(queue_id, repos),
         }
@@ -0,0 +1,68 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::error::Result;
+use crate::expr_resolver::expr_label::ValueExprLabel;
+use crate::expr_resolver::ExprResolver;
+use crate::pipeline::pump_model::deduplicate_parameter::DeduplicateParameter;
+use crate::pipeline::pump_model::window_parameter::WindowTimeDomain;
+use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask;
+use crate::stream_engine::autonomous_executor::task::deduplicate::DeduplicateState;
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
+use crate::stream_engine::time::timestamp::system_timestamp::SystemTimestamp;
+
+use super::SqlValues;
+
+/// Drops projected values whose key was already emitted.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct DeduplicateSubtask {
+    /// Empty to use projected values as key.
+    keys: Vec<ValueExprLabel>,
+    time_domain: WindowTimeDomain,
+
+    state: Mutex<DeduplicateState>,
+}
+
+impl DeduplicateSubtask {
+    pub(in crate::stream_engine::autonomous_executor) fn new(param: DeduplicateParameter) -> Self {
+        Self {
+            keys: param.keys,
+            time_domain: param.time_domain,
+            state: Mutex::new(DeduplicateState::new(param.horizon)),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// `(whether to emit values, gain of states)`
+    pub(in crate::stream_engine::autonomous_executor) fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+        values: &SqlValues,
+    ) -> Result<(bool, WindowInFlowByWindowTask)> {
+        let key = if self.keys.is_empty() {
+            values.0.clone()
+        } else {
+            self.keys
+                .iter()
+                .map(|label| expr_resolver.eval_value_expr(*label, tuple))
+                .collect::<Result<Vec<_>>>()?
+        };
+        let rowtime = match self.time_domain {
+            WindowTimeDomain::EventTime => *tuple.rowtime(),
+            WindowTimeDomain::ProcessingTime => SystemTimestamp::now(),
+        };
+
+        Ok(self.get_state_mut().dispatch(key, rowtime))
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn get_state_mut(
+        &self,
+    ) -> MutexGuard<DeduplicateState> {
+        self.state
+            .lock()
+            .expect("another thread accessing to deduplicate state gets poisoned")
+    }
+}
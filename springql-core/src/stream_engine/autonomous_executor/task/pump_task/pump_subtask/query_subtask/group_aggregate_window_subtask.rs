@@ -5,6 +5,8 @@ use std::sync::{Mutex, MutexGuard};
 use crate::expr_resolver::ExprResolver;
 use crate::pipeline::pump_model::window_operation_parameter::WindowOperationParameter;
 use crate::pipeline::pump_model::window_parameter::WindowParameter;
+use crate::pipeline::pump_model::window_trigger::WindowTrigger;
+use crate::stream_engine::time::duration::wall_clock_duration::WallClockDuration;
 use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask;
 use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
 use crate::stream_engine::autonomous_executor::task::window::Window;
@@ -19,20 +21,55 @@ impl GroupAggregateWindowSubtask {
     pub(in crate::stream_engine::autonomous_executor) fn new(
         window_param: WindowParameter,
         op_param: WindowOperationParameter,
+        idle_timeout: Option<WallClockDuration>,
+        trigger: Option<WindowTrigger>,
     ) -> Self {
-        let window = AggrWindow::new(window_param, op_param);
+        let window = AggrWindow::new(window_param, op_param, idle_timeout, trigger);
         Self(Mutex::new(window))
     }
 
+    /// # Returns
+    ///
+    /// Final results of closed panes followed by early results (if the trigger fires).
+    ///
+    /// `Err(tuple)` if `tuple` is a late row, which is not dispatched to any pane.
     pub(in crate::stream_engine::autonomous_executor) fn run(
         &self,
         expr_resolver: &ExprResolver,
         tuple: Tuple,
+    ) -> Result<(Vec<GroupAggrOut>, WindowInFlowByWindowTask), Tuple> {
+        let mut window = self
+            .0
+            .lock()
+            .expect("another thread accessing to window gets poisoned");
+
+        let tuple = window.to_window_time(tuple);
+        let res = if window.is_late(*tuple.rowtime()) {
+            Err(tuple)
+        } else {
+            let (mut out, window_in_flow) = window.dispatch(expr_resolver, tuple, ());
+            out.append(&mut window.emit_early());
+            Ok((out, window_in_flow))
+        };
+
+        window.reset_idle();
+        res
+    }
+
+    /// Called when no row arrives.
+    ///
+    /// # Returns
+    ///
+    /// Final results of panes closed by idle timeout.
+    pub(in crate::stream_engine::autonomous_executor) fn run_on_idle(
+        &self,
+        expr_resolver: &ExprResolver,
     ) -> (Vec<GroupAggrOut>, WindowInFlowByWindowTask) {
-        self.0
+        let mut window = self
+            .0
             .lock()
-            .expect("another thread accessing to window gets poisoned")
-            .dispatch(expr_resolver, tuple, ())
+            .expect("another thread accessing to window gets poisoned");
+        window.close_on_idle(expr_resolver)
     }
 
     pub(in crate::stream_engine::autonomous_executor) fn get_window_mut(
@@ -29,10 +29,12 @@ impl JoinSubtask {
         tuple: Tuple,
         dir: JoinDir,
     ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
-        self.0
+        let mut window = self
+            .0
             .lock()
-            .expect("another thread accessing to window gets poisoned")
-            .dispatch(expr_resolver, tuple, dir)
+            .expect("another thread accessing to window gets poisoned");
+        let tuple = window.to_window_time(tuple);
+        window.dispatch(expr_resolver, tuple, dir)
     }
 
     pub(in crate::stream_engine::autonomous_executor) fn get_window_mut(
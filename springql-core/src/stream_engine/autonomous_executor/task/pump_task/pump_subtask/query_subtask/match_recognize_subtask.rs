@@ -0,0 +1,45 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::error::Result;
+use crate::expr_resolver::ExprResolver;
+use crate::pipeline::pump_model::match_recognize_parameter::MatchRecognizeParameter;
+use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask;
+use crate::stream_engine::autonomous_executor::task::match_recognize::MatchRecognizeState;
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
+
+/// Converts tuples into tuples of measures for each match.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct MatchRecognizeSubtask {
+    state: Mutex<MatchRecognizeState>,
+}
+
+impl MatchRecognizeSubtask {
+    pub(in crate::stream_engine::autonomous_executor) fn new(
+        param: MatchRecognizeParameter,
+    ) -> Self {
+        Self {
+            state: Mutex::new(MatchRecognizeState::new(param)),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// `(tuples of measures, gain of states)`
+    pub(in crate::stream_engine::autonomous_executor) fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        self.get_state_mut().dispatch(expr_resolver, tuple)
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn get_state_mut(
+        &self,
+    ) -> MutexGuard<MatchRecognizeState> {
+        self.state
+            .lock()
+            .expect("another thread accessing to match_recognize state gets poisoned")
+    }
+}
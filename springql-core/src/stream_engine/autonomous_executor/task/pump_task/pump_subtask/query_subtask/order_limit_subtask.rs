@@ -0,0 +1,123 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::pipeline::pump_model::order_limit_parameter::{
+    OrderLimitPanes, OrderLimitParameter, SortKey,
+};
+use crate::pipeline::pump_model::window_parameter::WindowTimeDomain;
+use crate::stream_engine::autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask;
+use crate::stream_engine::autonomous_executor::task::order_limit::{sort_and_limit, OrderLimitState};
+use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
+use crate::stream_engine::time::timestamp::system_timestamp::SystemTimestamp;
+
+use super::SqlValues;
+
+/// Sorts and limits projected values in each pane.
+#[derive(Debug)]
+pub(in crate::stream_engine::autonomous_executor) struct OrderLimitSubtask {
+    sort_keys: Vec<SortKey>,
+    limit: Option<u64>,
+
+    /// None for aggregation, whose results are sorted and limited for each pane on close.
+    fixed_panes: Option<(WindowTimeDomain, Mutex<OrderLimitState>)>,
+}
+
+impl OrderLimitSubtask {
+    pub(in crate::stream_engine::autonomous_executor) fn new(param: OrderLimitParameter) -> Self {
+        let fixed_panes = match param.panes {
+            OrderLimitPanes::Aggregate => None,
+            OrderLimitPanes::Fixed {
+                length,
+                allowed_delay,
+                time_domain,
+            } => {
+                let state = OrderLimitState::new(
+                    param.sort_keys.clone(),
+                    param.limit,
+                    length,
+                    allowed_delay,
+                );
+                Some((time_domain, Mutex::new(state)))
+            }
+        };
+
+        Self {
+            sort_keys: param.sort_keys,
+            limit: param.limit,
+            fixed_panes,
+        }
+    }
+
+    /// Puts projected values into its pane of fixed window.
+    ///
+    /// # Returns
+    ///
+    /// `(values of closed panes in rank order, gain of rows kept)`
+    ///
+    /// # Panics
+    ///
+    /// if this subtask is for aggregation.
+    pub(in crate::stream_engine::autonomous_executor) fn run(
+        &self,
+        tuple: &Tuple,
+        values: SqlValues,
+    ) -> (Vec<SqlValues>, WindowInFlowByWindowTask) {
+        let (time_domain, _) = self
+            .fixed_panes
+            .as_ref()
+            .expect("aggregation results are sorted by run_aggregate()");
+        let rowtime = match time_domain {
+            WindowTimeDomain::EventTime => *tuple.rowtime(),
+            WindowTimeDomain::ProcessingTime => SystemTimestamp::now(),
+        };
+
+        let (rows, window_in_flow) = self
+            .get_state_mut()
+            .expect("checked above")
+            .dispatch(values.0, rowtime);
+        (
+            rows.into_iter().map(SqlValues::new).collect(),
+            window_in_flow,
+        )
+    }
+
+    /// Sorts and limits aggregation results.
+    /// `values_seq` has results of a pane in a row, and they are tagged with the same `pane`.
+    pub(in crate::stream_engine::autonomous_executor) fn run_aggregate<P>(
+        &self,
+        values_seq: Vec<(P, SqlValues)>,
+    ) -> Vec<SqlValues>
+    where
+        P: PartialEq,
+    {
+        let mut out = Vec::new();
+
+        let mut values_seq = values_seq.into_iter().peekable();
+        while let Some((pane, values)) = values_seq.next() {
+            let mut rows = vec![values.0];
+            while let Some((_, values)) = values_seq.next_if(|(next_pane, _)| next_pane == &pane) {
+                rows.push(values.0);
+            }
+
+            out.extend(
+                sort_and_limit(&self.sort_keys, self.limit, rows)
+                    .into_iter()
+                    .map(SqlValues::new),
+            );
+        }
+
+        out
+    }
+
+    /// None for aggregation.
+    pub(in crate::stream_engine::autonomous_executor) fn get_state_mut(
+        &self,
+    ) -> Option<MutexGuard<OrderLimitState>> {
+        self.fixed_panes.as_ref().map(|(_, state)| {
+            state
+                .lock()
+                .expect("another thread accessing to ORDER BY state gets poisoned")
+        })
+    }
+}
@@ -4,26 +4,49 @@ use crate::error::Result;
 use crate::expr_resolver::expr_label::ValueExprLabel;
 use crate::expr_resolver::ExprResolver;
 use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
+use crate::stream_engine::{NnSqlValue, SqlValue};
 
 use super::SqlValues;
 
 #[derive(Debug, new)]
 pub(in crate::stream_engine::autonomous_executor) struct ValueProjectionSubtask {
     value_exprs: Vec<ValueExprLabel>,
+    /// Position of `UNNEST(...)` in `value_exprs`.
+    unnest_position: Option<usize>,
 }
 
 impl ValueProjectionSubtask {
+    /// # Returns
+    ///
+    /// A row for a tuple, or a row for each element of the array with `UNNEST(...)` (no row for NULL or an empty array).
     pub(in crate::stream_engine::autonomous_executor) fn run(
         &self,
         expr_resolver: &ExprResolver,
         tuple: &Tuple,
-    ) -> Result<SqlValues> {
+    ) -> Result<Vec<SqlValues>> {
         let values = self
             .value_exprs
             .iter()
             .map(|label| expr_resolver.eval_value_expr(*label, tuple))
             .collect::<Result<Vec<_>>>()?;
 
-        Ok(SqlValues::new(values))
+        match self.unnest_position {
+            None => Ok(vec![SqlValues::new(values)]),
+            Some(pos) => {
+                let elements = match &values[pos] {
+                    SqlValue::Null => vec![],
+                    SqlValue::NotNull(NnSqlValue::Array(elements)) => elements.clone(),
+                    SqlValue::NotNull(v) => vec![SqlValue::NotNull(v.clone())],
+                };
+                Ok(elements
+                    .into_iter()
+                    .map(|element| {
+                        let mut values = values.clone();
+                        values[pos] = element;
+                        SqlValues::new(values)
+                    })
+                    .collect())
+            }
+        }
     }
 }
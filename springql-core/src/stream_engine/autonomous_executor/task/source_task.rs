@@ -18,6 +18,7 @@ use crate::stream_engine::autonomous_executor::task_graph::queue_id::row_queue_i
 use crate::stream_engine::autonomous_executor::task_graph::queue_id::window_queue_id::WindowQueueId;
 use crate::stream_engine::autonomous_executor::task_graph::task_id::TaskId;
 use crate::stream_engine::time::duration::wall_clock_duration::wall_clock_stopwatch::WallClockStopwatch;
+use crate::stream_engine::time::timestamp::timestamp_format::TimestampFormat;
 
 use super::task_context::TaskContext;
 
@@ -26,6 +27,7 @@ pub(crate) struct SourceTask {
     id: TaskId,
     source_reader_name: SourceReaderName,
     source_stream_name: StreamName,
+    timestamp_format: Option<TimestampFormat>,
 }
 
 impl SourceTask {
@@ -35,6 +37,9 @@ impl SourceTask {
             id,
             source_reader_name: source_reader.name().clone(),
             source_stream_name: source_reader.dest_source_stream().clone(),
+            timestamp_format: source_reader
+                .timestamp_format()
+                .expect("TIMESTAMP_FORMAT option is validated on CREATE SOURCE READER"),
         }
     }
 
@@ -129,7 +134,9 @@ impl SourceTask {
             .expect("other worker threads sharing the same subtask must not get panic");
         source_reader
             .next_row()
-            .and_then(|source_row| source_row.into_row(source_stream))
+            .and_then(|source_row| {
+                source_row.into_row(source_stream, self.timestamp_format.as_ref())
+            })
             .map_or_else(
                 |e| {
                     AutonomousExecutor::handle_error(e);
@@ -8,6 +8,7 @@ use std::{
     time::Duration,
 };
 
+use crate::stream_engine::time::clock;
 use crate::{
     error::{foreign_info::ForeignInfo, Result, SpringError},
     low_level_rs::SpringSourceReaderConfig,
@@ -78,7 +79,7 @@ impl SourceReader for NetServerSourceReader {
 
         rx.try_recv()
             .or_else(|_| {
-                thread::sleep(self.timeout);
+                clock::sleep(self.timeout);
                 rx.try_recv()
             })
             .map(|json| {
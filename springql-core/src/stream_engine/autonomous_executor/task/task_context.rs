@@ -2,10 +2,13 @@
 
 use std::sync::Arc;
 
-use crate::stream_engine::autonomous_executor::{
-    pipeline_derivatives::PipelineDerivatives,
-    repositories::Repositories,
-    task_graph::{queue_id::QueueId, task_id::TaskId},
+use crate::{
+    pipeline::name::StreamName,
+    stream_engine::autonomous_executor::{
+        pipeline_derivatives::PipelineDerivatives,
+        repositories::Repositories,
+        task_graph::{queue_id::QueueId, task_id::TaskId},
+    },
 };
 
 /// Holds everything needed for a task execution.
@@ -45,6 +48,14 @@ impl TaskContext {
         task_graph.output_queues(&self.task)
     }
 
+    pub(in crate::stream_engine) fn output_queues_of_stream(
+        &self,
+        stream: &StreamName,
+    ) -> Vec<QueueId> {
+        let task_graph = self.pipeline_derivatives.task_graph();
+        task_graph.output_queues_of_stream(&self.task, stream)
+    }
+
     pub(in crate::stream_engine) fn repos(&self) -> Arc<Repositories> {
         self.repos.clone()
     }
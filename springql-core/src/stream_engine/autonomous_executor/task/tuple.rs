@@ -3,7 +3,10 @@
 use crate::{
     error::{Result, SpringError},
     mem_size::MemSize,
-    pipeline::field::{field_name::ColumnReference, Field},
+    pipeline::{
+        field::{field_name::ColumnReference, Field},
+        name::ColumnName,
+    },
     stream_engine::{autonomous_executor::row::Row, time::timestamp::Timestamp, SqlValue},
 };
 use anyhow::anyhow;
@@ -22,13 +25,24 @@ pub(crate) struct Tuple {
     rowtime: Timestamp,
 
     fields: Vec<Field>,
+
+    /// `(open_at, close_at)` of the window pane this tuple is emitted from.
+    #[new(default)]
+    window_bounds: Option<(Timestamp, Timestamp)>,
+
+    /// True if this tuple is an early (partial) result of the window pane.
+    #[new(default)]
+    early: bool,
 }
 
 impl MemSize for Tuple {
     fn mem_size(&self) -> usize {
         let rowtime_size = self.rowtime.mem_size();
         let fields_size: usize = self.fields.iter().map(|f| f.mem_size()).sum();
-        rowtime_size + fields_size
+        let window_bounds_size = self.window_bounds.map_or(0, |(open_at, close_at)| {
+            open_at.mem_size() + close_at.mem_size()
+        });
+        rowtime_size + fields_size + window_bounds_size
     }
 }
 
@@ -45,13 +59,67 @@ impl Tuple {
             })
             .collect();
 
-        Self { rowtime, fields }
+        Self::new(rowtime, fields)
     }
 
     pub(in crate::stream_engine::autonomous_executor) fn rowtime(&self) -> &Timestamp {
         &self.rowtime
     }
 
+    /// Replace ROWTIME (e.g. with processing time).
+    pub(in crate::stream_engine::autonomous_executor) fn with_rowtime(
+        self,
+        rowtime: Timestamp,
+    ) -> Self {
+        Self { rowtime, ..self }
+    }
+
+    /// Mark this tuple as emitted from a window pane.
+    pub(in crate::stream_engine::autonomous_executor) fn with_window_bounds(
+        self,
+        open_at: Timestamp,
+        close_at: Timestamp,
+    ) -> Self {
+        Self {
+            window_bounds: Some((open_at, close_at)),
+            ..self
+        }
+    }
+
+    /// Mark this tuple as an early (partial) result of a window pane.
+    pub(in crate::stream_engine::autonomous_executor) fn with_early(self, early: bool) -> Self {
+        Self { early, ..self }
+    }
+
+    /// # Failures
+    ///
+    /// `SpringError::Sql` if this tuple is not emitted from a window pane.
+    pub(crate) fn window_start(&self) -> Result<Timestamp> {
+        self.window_bounds
+            .map(|(open_at, _)| open_at)
+            .ok_or_else(|| SpringError::Sql(anyhow!("WINDOW_START() is used outside a window")))
+    }
+
+    /// # Failures
+    ///
+    /// `SpringError::Sql` if this tuple is not emitted from a window pane.
+    pub(crate) fn window_end(&self) -> Result<Timestamp> {
+        self.window_bounds
+            .map(|(_, close_at)| close_at)
+            .ok_or_else(|| SpringError::Sql(anyhow!("WINDOW_END() is used outside a window")))
+    }
+
+    /// `"EARLY"` for an early result of a window pane and `"FINAL"` for a result on pane close.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` if this tuple is not emitted from a window pane.
+    pub(crate) fn emit_timing(&self) -> Result<&'static str> {
+        self.window_bounds
+            .map(|_| if self.early { "EARLY" } else { "FINAL" })
+            .ok_or_else(|| SpringError::Sql(anyhow!("EMIT_TIMING() is used outside a window")))
+    }
+
     /// # Failures
     ///
     /// `SpringError::Sql` if `column_reference` does not match any field.
@@ -65,6 +133,16 @@ impl Tuple {
             .ok_or_else(|| SpringError::Sql(anyhow!("cannot find field `{:?}`", column_reference)))
     }
 
+    /// Value of the field named `column_name`, regardless of its stream name.
+    pub(in crate::stream_engine::autonomous_executor) fn get_value_by_column_name(
+        &self,
+        column_name: &ColumnName,
+    ) -> Option<SqlValue> {
+        self.fields.iter().find_map(|field| {
+            (&field.name().column_name == column_name).then(|| field.sql_value().clone())
+        })
+    }
+
     /// Left rowtime is used for joined tuple.
     pub(in crate::stream_engine::autonomous_executor) fn join(self, right: Self) -> Tuple {
         let rowtime = self.rowtime;
@@ -75,6 +153,8 @@ impl Tuple {
         Self {
             rowtime,
             fields: new_fields,
+            window_bounds: self.window_bounds,
+            early: self.early,
         }
     }
 }
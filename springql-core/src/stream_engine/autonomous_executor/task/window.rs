@@ -1,6 +1,6 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::{expr_resolver::ExprResolver, stream_engine::{Tuple, autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask}};
+use crate::{expr_resolver::ExprResolver, pipeline::pump_model::window_parameter::WindowTimeDomain, stream_engine::{Tuple, autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask, time::timestamp::{Timestamp, system_timestamp::SystemTimestamp}}};
 
 use self::{
     panes::{pane::Pane, Panes},
@@ -24,6 +24,25 @@ pub(in crate::stream_engine::autonomous_executor) trait Window {
 
     fn purge(&mut self);
 
+    /// Processing-time windows replace ROWTIME of a tuple with current wall-clock time.
+    /// Event-time windows return the tuple as-is.
+    ///
+    /// Must be called before `is_late()` and `dispatch()`.
+    fn to_window_time(&self, tuple: Tuple) -> Tuple {
+        match self.panes().window_param().time_domain() {
+            WindowTimeDomain::EventTime => tuple,
+            WindowTimeDomain::ProcessingTime => tuple.with_rowtime(SystemTimestamp::now()),
+        }
+    }
+
+    /// Whether a tuple with `rowtime` is too late to be dispatched to any pane.
+    ///
+    /// Count-based windows do not use watermark and no tuple is late.
+    fn is_late(&self, rowtime: Timestamp) -> bool {
+        self.panes().window_param().count_length_period().is_none()
+            && rowtime < self.watermark().as_timestamp()
+    }
+
     /// A task dispatches a tuple from waiting queue.
     fn dispatch(
         &mut self,
@@ -36,7 +55,23 @@ pub(in crate::stream_engine::autonomous_executor) trait Window {
     ) {
         let rowtime = *tuple.rowtime();
 
-        if rowtime < self.watermark().as_timestamp() {
+        if self.panes().window_param().count_length_period().is_some() {
+            // count-based window does not care about watermark.
+            let (panes_to_close, window_in_flow_dispatch) =
+                self.panes_mut()
+                    .dispatch_by_count(expr_resolver, &tuple, arg);
+
+            let (out, window_in_flow_close) = panes_to_close.into_iter().fold(
+                (Vec::new(), WindowInFlowByWindowTask::zero()),
+                |(mut out_acc, window_in_flow_acc), pane| {
+                    let (mut out_seq, window_in_flow) = pane.close(expr_resolver);
+                    out_acc.append(&mut out_seq);
+                    (out_acc, window_in_flow_acc + window_in_flow)
+                },
+            );
+
+            (out, window_in_flow_dispatch + window_in_flow_close)
+        } else if self.is_late(rowtime) {
             // too late tuple does not have any chance to be dispatched nor to close a pane.
             (Vec::new(), WindowInFlowByWindowTask::zero())
         } else {
@@ -2,17 +2,36 @@
 
 use crate::{
     error::{Result, SpringError},
-    expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel},
+    expr_resolver::{
+        expr_label::{AggrExprLabel, ValueExprLabel},
+        ExprResolver,
+    },
     pipeline::pump_model::{
         window_operation_parameter::WindowOperationParameter, window_parameter::WindowParameter,
+        window_trigger::WindowTrigger,
+    },
+    stream_engine::{
+        autonomous_executor::performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+        time::{
+            duration::{
+                wall_clock_duration::{
+                    clock_stopwatch::ClockStopwatch, WallClockDuration,
+                },
+                SpringDuration,
+            },
+            timestamp::Timestamp,
+        },
+        SqlValue,
     },
-    stream_engine::SqlValue,
 };
 
 use anyhow::anyhow;
 
 use super::{
-    panes::{pane::aggregate_pane::AggrPane, Panes},
+    panes::{
+        pane::{aggregate_pane::AggrPane, Pane},
+        Panes,
+    },
     watermark::Watermark,
     Window,
 };
@@ -24,8 +43,28 @@ pub(in crate::stream_engine::autonomous_executor) struct GroupAggrOut {
 
     group_by_label: ValueExprLabel,
     group_by_result: SqlValue,
+
+    /// open_at of the pane
+    window_start: Timestamp,
+    /// close_at of the pane
+    window_end: Timestamp,
+
+    /// True for a partial result of a pane still open.
+    early: bool,
 }
 impl GroupAggrOut {
+    /// `(open_at, close_at)` of the pane this result is calculated in.
+    pub(in crate::stream_engine::autonomous_executor) fn window_bounds(
+        &self,
+    ) -> (Timestamp, Timestamp) {
+        (self.window_start, self.window_end)
+    }
+
+    /// Whether this is an early (partial) result, not a final one on pane close.
+    pub(in crate::stream_engine::autonomous_executor) fn is_early(&self) -> bool {
+        self.early
+    }
+
     /// # Returns
     ///
     /// (aggregate result, group by result)
@@ -59,6 +98,18 @@ impl GroupAggrOut {
 pub(in crate::stream_engine::autonomous_executor) struct AggrWindow {
     watermark: Watermark,
     panes: Panes<AggrPane>,
+
+    idle_timeout: Option<WallClockDuration>,
+    /// Started at the last row arrival.
+    idle_stopwatch: ClockStopwatch,
+    /// `max(ROWTIME)` at the last row arrival.
+    idle_base: Timestamp,
+
+    trigger: Option<WindowTrigger>,
+    /// Rows dispatched since the last early emission.
+    rows_since_emit: u64,
+    /// Started at the last early emission.
+    stopwatch: ClockStopwatch,
 }
 
 impl Window for AggrWindow {
@@ -89,17 +140,92 @@ impl AggrWindow {
     pub(in crate::stream_engine::autonomous_executor) fn new(
         window_param: WindowParameter,
         op_param: WindowOperationParameter,
+        idle_timeout: Option<WallClockDuration>,
+        trigger: Option<WindowTrigger>,
     ) -> Self {
         let watermark = Watermark::new(window_param.allowed_delay());
         Self {
             watermark,
             panes: Panes::new(window_param, op_param),
+            idle_timeout,
+            idle_stopwatch: ClockStopwatch::start(),
+            idle_base: watermark.max_rowtime(),
+            trigger,
+            rows_since_emit: 0,
+            stopwatch: ClockStopwatch::start(),
+        }
+    }
+
+    /// Called after a row arrives, to restart idle time.
+    pub(in crate::stream_engine::autonomous_executor) fn reset_idle(&mut self) {
+        self.idle_stopwatch = ClockStopwatch::start();
+        self.idle_base = self.watermark.max_rowtime();
+    }
+
+    /// Called when no row arrives.
+    ///
+    /// Once idle time (processing time since the last row arrival) exceeds `idle_timeout`,
+    /// watermark advances as if a row with `ROWTIME = max(ROWTIME) + idle time` arrived, and panes behind it are closed.
+    pub(in crate::stream_engine::autonomous_executor) fn close_on_idle(
+        &mut self,
+        expr_resolver: &ExprResolver,
+    ) -> (Vec<GroupAggrOut>, WindowInFlowByWindowTask) {
+        match self.idle_timeout {
+            Some(idle_timeout) => {
+                let idle = self.idle_stopwatch.stop();
+                if idle >= idle_timeout {
+                    self.watermark.update(self.idle_base + idle.to_chrono());
+                    let wm = self.watermark;
+
+                    self.panes.remove_panes_to_close(&wm).into_iter().fold(
+                        (Vec::new(), WindowInFlowByWindowTask::zero()),
+                        |(mut out_acc, window_in_flow_acc), pane| {
+                            let (mut out_seq, window_in_flow) = pane.close(expr_resolver);
+                            out_acc.append(&mut out_seq);
+                            (out_acc, window_in_flow_acc + window_in_flow)
+                        },
+                    )
+                } else {
+                    (Vec::new(), WindowInFlowByWindowTask::zero())
+                }
+            }
+            None => (Vec::new(), WindowInFlowByWindowTask::zero()),
+        }
+    }
+
+    /// Called after a row is dispatched.
+    ///
+    /// # Returns
+    ///
+    /// Early results of all open panes if the trigger fires. Empty otherwise.
+    pub(in crate::stream_engine::autonomous_executor) fn emit_early(
+        &mut self,
+    ) -> Vec<GroupAggrOut> {
+        self.rows_since_emit += 1;
+
+        let fires = match self.trigger {
+            None => false,
+            Some(WindowTrigger::EveryRows(n)) => self.rows_since_emit >= n,
+            Some(WindowTrigger::EveryInterval(interval)) => self.stopwatch.stop() >= interval,
+        };
+
+        if fires {
+            self.rows_since_emit = 0;
+            self.stopwatch = ClockStopwatch::start();
+
+            self.panes
+                .open_panes()
+                .flat_map(|pane| pane.early_out())
+                .collect()
+        } else {
+            vec![]
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::pipeline::pump_model::window_parameter::WindowTimeDomain;
     use springql_test_logger::setup_test_logger;
 
     use super::*;
@@ -122,9 +248,18 @@ mod tests {
                 duration::{event_duration::EventDuration, SpringDuration},
                 timestamp::Timestamp,
             },
+            NnSqlValue,
         },
     };
 
+    /// Bytes of a group of `AVG` accumulator in a pane.
+    fn avg_group_size(ticker: &str) -> i64 {
+        AggrPane::group_size(
+            &NnSqlValue::Text(ticker.to_string()),
+            AggrPane::new_accumulator(&AggregateFunctionParameter::Avg).as_ref(),
+        ) as i64
+    }
+
     fn t_expect(group_aggr_out: GroupAggrOut, expected_ticker: &str, expected_avg_amount: i16) {
         let ticker = group_aggr_out.group_by_result.unwrap();
         assert_eq!(
@@ -171,7 +306,7 @@ mod tests {
             },
         ];
 
-        let (mut expr_resolver, _, aggr_labels_select_list) = ExprResolver::new(select_list);
+        let (mut expr_resolver, _, aggr_labels_select_list, _) = ExprResolver::new(select_list);
 
         let group_by_expr = ValueExpr::factory_colref(
             StreamName::fx_trade().as_ref(),
@@ -184,12 +319,15 @@ mod tests {
                 length: EventDuration::from_secs(10),
                 period: EventDuration::from_secs(5),
                 allowed_delay: EventDuration::from_secs(1),
+                time_domain: WindowTimeDomain::EventTime,
             },
             WindowOperationParameter::GroupAggregation(GroupAggregateParameter {
                 aggr_func: AggregateFunctionParameter::Avg,
                 aggr_expr: aggr_labels_select_list[0],
                 group_by: group_by_label,
             }),
+            None,
+            None,
         );
 
         // [:55, :05): ("GOOGL", 100)
@@ -204,7 +342,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            2 * avg_group_size("GOOGL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:55, :05): ("GOOGL", 100), ("ORCL", 100)
@@ -219,7 +360,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            2 * avg_group_size("ORCL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:55, :05): -> "GOOGL" AVG = 100; "ORCL" AVG = 100
@@ -246,7 +390,10 @@ mod tests {
         });
         t_expect(out.get(0).cloned().unwrap(), "GOOGL", 100);
         t_expect(out.get(1).cloned().unwrap(), "ORCL", 100);
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            -avg_group_size("GOOGL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:00, :10): ("GOOGL", 100), ("ORCL", 100), ("ORCL", 400) <-- !!NOT CLOSED YET (within delay)!!
@@ -262,7 +409,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            avg_group_size("ORCL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // too late data to be ignored
@@ -323,7 +473,10 @@ mod tests {
         });
         t_expect(out.get(0).cloned().unwrap(), "GOOGL", 100);
         t_expect(out.get(1).cloned().unwrap(), "ORCL", 200);
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            -(avg_group_size("GOOGL") + avg_group_size("ORCL"))
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:05, :15): -> "ORCL" = 175
@@ -379,7 +532,7 @@ mod tests {
             },
         ];
 
-        let (mut expr_resolver, _, aggr_labels_select_list) = ExprResolver::new(select_list);
+        let (mut expr_resolver, _, aggr_labels_select_list, _) = ExprResolver::new(select_list);
 
         let group_by_expr = ValueExpr::factory_colref(
             StreamName::fx_trade().as_ref(),
@@ -391,12 +544,15 @@ mod tests {
             WindowParameter::TimedFixedWindow {
                 length: EventDuration::from_secs(10),
                 allowed_delay: EventDuration::from_secs(1),
+                time_domain: WindowTimeDomain::EventTime,
             },
             WindowOperationParameter::GroupAggregation(GroupAggregateParameter {
                 aggr_func: AggregateFunctionParameter::Avg,
                 aggr_expr: aggr_labels_select_list[0],
                 group_by: group_by_label,
             }),
+            None,
+            None,
         );
 
         // [:00, :10): ("GOOGL", 100)
@@ -410,7 +566,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            avg_group_size("GOOGL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:00, :10): ("GOOGL", 100), ("ORCL", 100)
@@ -424,7 +583,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            avg_group_size("ORCL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:00, :10): ("GOOGL", 100), ("ORCL", 100), ("ORCL", 400)
@@ -453,7 +615,10 @@ mod tests {
             (),
         );
         assert!(out.is_empty());
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            avg_group_size("ORCL")
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // too late data to be ignored
@@ -511,7 +676,10 @@ mod tests {
         });
         t_expect(out.get(0).cloned().unwrap(), "GOOGL", 100);
         t_expect(out.get(1).cloned().unwrap(), "ORCL", 200);
-        assert_eq!(window_in_flow.window_gain_bytes_states, 0);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            -(avg_group_size("GOOGL") + avg_group_size("ORCL"))
+        );
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
 
         // [:10, :20): -> "ORCL" = 100
@@ -531,4 +699,379 @@ mod tests {
         assert_eq!(window_in_flow.window_gain_bytes_states, 0);
         assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
     }
+
+    /// SELECT ticker, AVG(amount) AS avg_amount
+    ///   FROM trade
+    ///   GROUP BY ticker
+    ///   <window_param>
+    ///   <trigger>;
+    fn ticker_avg_amount_window(
+        window_param: WindowParameter,
+        trigger: Option<WindowTrigger>,
+    ) -> (ExprResolver, AggrWindow) {
+        let ticker_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+        let avg_amount_expr = AggrExpr {
+            func: AggregateFunctionParameter::Avg,
+            aggregated: ValueExpr::factory_colref(
+                StreamName::fx_trade().as_ref(),
+                ColumnName::fx_amount().as_ref(),
+            ),
+        };
+
+        let select_list = vec![
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ticker_expr,
+                alias: None,
+            },
+            SelectFieldSyntax::AggrExpr {
+                aggr_expr: avg_amount_expr,
+                alias: Some(AggrAlias::new("avg_amount".to_string())),
+            },
+        ];
+
+        let (mut expr_resolver, _, aggr_labels_select_list, _) = ExprResolver::new(select_list);
+
+        let group_by_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+        let group_by_label = expr_resolver.register_value_expr(group_by_expr);
+
+        let window = AggrWindow::new(
+            window_param,
+            WindowOperationParameter::GroupAggregation(GroupAggregateParameter {
+                aggr_func: AggregateFunctionParameter::Avg,
+                aggr_expr: aggr_labels_select_list[0],
+                group_by: group_by_label,
+            }),
+            None,
+            trigger,
+        );
+
+        (expr_resolver, window)
+    }
+
+    #[test]
+    fn test_count_fixed_window_aggregation() {
+        setup_test_logger();
+
+        let (expr_resolver, mut window) =
+            ticker_avg_amount_window(WindowParameter::CountFixedWindow { length: 2 }, None);
+
+        // GOOGL: [("GOOGL", 100)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                "GOOGL",
+                100,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // GOOGL: [("GOOGL", 100)
+        // ORCL:  [("ORCL", 100)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:10.000000000").unwrap(),
+                "ORCL",
+                100,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // GOOGL: [("GOOGL", 100)
+        // ORCL:  [("ORCL", 100), ("ORCL", 400)] -> "ORCL" AVG = 250
+        let (out, window_in_flow) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:20.000000000").unwrap(),
+                "ORCL",
+                400,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].window_bounds(),
+            (
+                Timestamp::from_str("2020-01-01 00:00:10.000000000").unwrap(),
+                Timestamp::from_str("2020-01-01 00:00:20.000000000").unwrap()
+            )
+        );
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 250);
+        assert_eq!(
+            window_in_flow.window_gain_bytes_states,
+            -(Panes::<AggrPane>::row_counter_size(&Some(NnSqlValue::Text("ORCL".to_string())))
+                as i64)
+                - avg_group_size("ORCL"),
+            "row counter and accumulator of ORCL are evicted"
+        );
+        assert_eq!(window_in_flow.window_gain_bytes_rows, 0);
+
+        // rowtime older than the latest one is not dropped (watermark is not used).
+        //
+        // GOOGL: [("GOOGL", 100), ("GOOGL", 300)] -> "GOOGL" AVG = 200
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:05.000000000").unwrap(),
+                "GOOGL",
+                300,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].window_bounds(),
+            (
+                Timestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                Timestamp::from_str("2020-01-01 00:00:05.000000000").unwrap()
+            ),
+            "close_at of a count-based pane is the latest rowtime"
+        );
+        t_expect(out.get(0).cloned().unwrap(), "GOOGL", 200);
+    }
+
+    #[test]
+    fn test_timed_fixed_window_bounds() {
+        setup_test_logger();
+
+        let (expr_resolver, mut window) = ticker_avg_amount_window(
+            WindowParameter::TimedFixedWindow {
+                length: EventDuration::from_secs(10),
+                allowed_delay: EventDuration::from_secs(0),
+                time_domain: WindowTimeDomain::EventTime,
+            },
+            None,
+        );
+
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:03.000000000").unwrap(),
+                "GOOGL",
+                100,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // [:00, :10) closes
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:10.000000000").unwrap(),
+                "GOOGL",
+                200,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].window_bounds(),
+            (
+                Timestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                Timestamp::from_str("2020-01-01 00:00:10.000000000").unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_count_sliding_window_aggregation() {
+        setup_test_logger();
+
+        let (expr_resolver, mut window) = ticker_avg_amount_window(
+            WindowParameter::CountSlidingWindow {
+                length: 3,
+                period: 2,
+            },
+            None,
+        );
+
+        // pane1 [("ORCL", 100)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                "ORCL",
+                100,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // pane1 [("ORCL", 100), ("ORCL", 200)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:01.000000000").unwrap(),
+                "ORCL",
+                200,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // pane1 [("ORCL", 100), ("ORCL", 200), ("ORCL", 600)] -> "ORCL" AVG = 300
+        // pane2                               [("ORCL", 600)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:02.000000000").unwrap(),
+                "ORCL",
+                600,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 300);
+
+        // pane2 [("ORCL", 600), ("ORCL", 300)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:03.000000000").unwrap(),
+                "ORCL",
+                300,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+
+        // pane2 [("ORCL", 600), ("ORCL", 300), ("ORCL", 0)] -> "ORCL" AVG = 300
+        // pane3                               [("ORCL", 0)
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:04.000000000").unwrap(),
+                "ORCL",
+                0,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 300);
+    }
+
+    #[test]
+    fn test_emit_every_rows() {
+        setup_test_logger();
+
+        let (expr_resolver, mut window) = ticker_avg_amount_window(
+            WindowParameter::TimedFixedWindow {
+                length: EventDuration::from_secs(10),
+                allowed_delay: EventDuration::from_secs(0),
+                time_domain: WindowTimeDomain::EventTime,
+            },
+            Some(WindowTrigger::EveryRows(2)),
+        );
+
+        // [:00, :10): ("ORCL", 100)
+        window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:01.000000000").unwrap(),
+                "ORCL",
+                100,
+            ),
+            (),
+        );
+        assert!(window.emit_early().is_empty());
+
+        // [:00, :10): ("ORCL", 100), ("ORCL", 300) -> early "ORCL" AVG = 200
+        window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:02.000000000").unwrap(),
+                "ORCL",
+                300,
+            ),
+            (),
+        );
+        let out = window.emit_early();
+        assert_eq!(out.len(), 1);
+        assert!(out[0].is_early());
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 200);
+
+        // [:00, :10): ("ORCL", 100), ("ORCL", 300), ("ORCL", 500)
+        window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:03.000000000").unwrap(),
+                "ORCL",
+                500,
+            ),
+            (),
+        );
+        assert!(window.emit_early().is_empty());
+
+        // [:00, :10) closes -> final "ORCL" AVG = 300
+        // [:10, :20): ("ORCL", 100) -> early "ORCL" AVG = 100
+        let (out, _) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:10.000000000").unwrap(),
+                "ORCL",
+                100,
+            ),
+            (),
+        );
+        assert_eq!(out.len(), 1);
+        assert!(!out[0].is_early());
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 300);
+
+        let out = window.emit_early();
+        assert_eq!(out.len(), 1);
+        assert!(out[0].is_early());
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 100);
+    }
+
+    #[test]
+    fn test_close_on_idle() {
+        setup_test_logger();
+
+        let (expr_resolver, mut window) = ticker_avg_amount_window(
+            WindowParameter::TimedFixedWindow {
+                length: EventDuration::from_millis(10),
+                allowed_delay: EventDuration::from_secs(0),
+                time_domain: WindowTimeDomain::EventTime,
+            },
+            None,
+        );
+        window.idle_timeout = Some(WallClockDuration::from_millis(1));
+
+        // [.000, .010): ("ORCL", 100)
+        let (out, window_in_flow_dispatch) = window.dispatch(
+            &expr_resolver,
+            Tuple::factory_trade(
+                Timestamp::from_str("2020-01-01 00:00:00.001000000").unwrap(),
+                "ORCL",
+                100,
+            ),
+            (),
+        );
+        assert!(out.is_empty());
+        assert!(window_in_flow_dispatch.window_gain_bytes_states > 0);
+        window.reset_idle();
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+
+        // watermark advances to .021 or later
+        let (out, window_in_flow_idle) = window.close_on_idle(&expr_resolver);
+        assert_eq!(out.len(), 1);
+        t_expect(out.get(0).cloned().unwrap(), "ORCL", 100);
+
+        // closed pane frees the states it gained
+        assert_eq!(
+            window_in_flow_idle.window_gain_bytes_states,
+            -window_in_flow_dispatch.window_gain_bytes_states
+        );
+    }
 }
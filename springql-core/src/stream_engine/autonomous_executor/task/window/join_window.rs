@@ -56,6 +56,7 @@ impl JoinWindow {
 
 #[cfg(test)]
 mod tests {
+    use crate::pipeline::pump_model::window_parameter::WindowTimeDomain;
     use std::str::FromStr;
 
     use springql_test_logger::setup_test_logger;
@@ -158,7 +159,7 @@ mod tests {
             },
         ];
 
-        let (mut expr_resolver, _, _) = ExprResolver::new(select_list);
+        let (mut expr_resolver, _, _, _) = ExprResolver::new(select_list);
 
         let on_expr_label = expr_resolver.register_value_expr(on_expr);
 
@@ -166,6 +167,7 @@ mod tests {
             WindowParameter::TimedFixedWindow {
                 length: EventDuration::from_secs(10),
                 allowed_delay: EventDuration::from_secs(1),
+                time_domain: WindowTimeDomain::EventTime,
             },
             JoinParameter {
                 join_type: JoinType::LeftOuter,
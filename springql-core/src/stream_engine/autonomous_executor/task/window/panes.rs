@@ -2,13 +2,28 @@
 
 pub(in crate::stream_engine::autonomous_executor) mod pane;
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap, mem::size_of};
+
+use anyhow::anyhow;
 
 use crate::{
+    error::{Result, SpringError},
+    expr_resolver::ExprResolver,
+    mem_size::MemSize,
     pipeline::pump_model::{
         window_operation_parameter::WindowOperationParameter, window_parameter::WindowParameter,
     },
-    stream_engine::time::{duration::SpringDuration, timestamp::Timestamp},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
+            AutonomousExecutor,
+        },
+        time::{
+            duration::{event_duration::EventDuration, SpringDuration},
+            timestamp::Timestamp,
+        },
+        NnSqlValue, SqlValue, Tuple,
+    },
 };
 
 use self::pane::Pane;
@@ -25,10 +40,34 @@ where
     /// Sorted by `Pane::open_at`.
     panes: Vec<P>,
 
+    /// Used only for count-based windows instead of `panes`.
+    ///
+    /// Sorted by opening order for each group.
+    count_panes: Vec<CountPane<P>>,
+    /// Used only for count-based windows.
+    ///
+    /// Number of rows dispatched so far for each group (`None` for a window without GROUP BY).
+    /// A counter is evicted when its group has no open pane and the next row opens a new pane.
+    row_counts: HashMap<Option<NnSqlValue>, u64>,
+
     window_param: WindowParameter,
     op_param: WindowOperationParameter,
 }
 
+/// A pane in a count-based window.
+///
+/// Holds at most `length` rows in a group.
+#[derive(Debug)]
+struct CountPane<P>
+where
+    P: Pane,
+{
+    group: Option<NnSqlValue>,
+    n_rows: u64,
+
+    pane: P,
+}
+
 impl<P> Panes<P>
 where
     P: Pane,
@@ -36,11 +75,17 @@ where
     pub(super) fn new(window_param: WindowParameter, op_param: WindowOperationParameter) -> Self {
         Self {
             panes: vec![],
+            count_panes: vec![],
+            row_counts: HashMap::new(),
             window_param,
             op_param,
         }
     }
 
+    pub(super) fn window_param(&self) -> &WindowParameter {
+        &self.window_param
+    }
+
     /// Generate new panes if not exists.
     /// Then, return all panes to get a tuple with the `rowtime`.
     ///
@@ -71,11 +116,151 @@ where
         panes_to_close
     }
 
+    /// Dispatch a tuple to count-based panes of the tuple's group, and then close panes which got enough rows.
+    ///
+    /// Watermark is not involved: any tuple is dispatched regardless of its rowtime.
+    ///
+    /// A count-based pane opens at the rowtime of its first tuple and closes at the maximum rowtime of its tuples.
+    ///
+    /// A tuple whose GROUP BY key fails to evaluate or is NULL is skipped and reported as an error.
+    ///
+    /// # Panics
+    ///
+    /// If this is not a count-based window.
+    pub(super) fn dispatch_by_count(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+        arg: P::DispatchArg,
+    ) -> (Vec<P>, WindowInFlowByWindowTask) {
+        let (length, period) = self
+            .window_param
+            .count_length_period()
+            .expect("only count-based window can dispatch by count");
+
+        let group = match self.count_group(expr_resolver, tuple) {
+            Ok(group) => group,
+            Err(e) => {
+                AutonomousExecutor::handle_error(e);
+                return (vec![], WindowInFlowByWindowTask::zero());
+            }
+        };
+
+        let mut counter_gain_bytes = 0;
+        let row_count = self.row_counts.entry(group.clone()).or_insert_with(|| {
+            counter_gain_bytes += Self::row_counter_size(&group) as i64;
+            0
+        });
+        let count = *row_count;
+        *row_count += 1;
+
+        let rowtime = *tuple.rowtime();
+        if count.is_multiple_of(period) {
+            self.count_panes.push(CountPane {
+                group: group.clone(),
+                n_rows: 0,
+                pane: P::new(rowtime, rowtime, self.op_param.clone()),
+            });
+        }
+
+        let window_in_flow = self
+            .count_panes
+            .iter_mut()
+            .filter(|count_pane| count_pane.group == group)
+            .map(|count_pane| {
+                count_pane.n_rows += 1;
+
+                let pane = &mut count_pane.pane;
+                if pane.close_at() < rowtime {
+                    pane.set_close_at(rowtime);
+                }
+                pane.dispatch(expr_resolver, tuple, arg.clone())
+            })
+            .fold(WindowInFlowByWindowTask::zero(), |acc, window_in_flow| {
+                acc + window_in_flow
+            });
+
+        let mut panes_to_close = vec![];
+        let mut idx = 0;
+        while idx < self.count_panes.len() {
+            if self.count_panes[idx].n_rows >= length {
+                let count_pane = self.count_panes.remove(idx);
+                panes_to_close.push(count_pane.pane);
+            } else {
+                idx += 1;
+            }
+        }
+
+        if !panes_to_close.is_empty()
+            && (count + 1).is_multiple_of(period)
+            && !self
+                .count_panes
+                .iter()
+                .any(|count_pane| count_pane.group == group)
+        {
+            // the next row of this group opens a new pane as if it were the first row.
+            self.row_counts.remove(&group);
+            counter_gain_bytes -= Self::row_counter_size(&group) as i64;
+        }
+
+        (
+            panes_to_close,
+            window_in_flow + WindowInFlowByWindowTask::new(counter_gain_bytes, 0),
+        )
+    }
+
+    /// All panes currently open, including count-based ones.
+    pub(super) fn open_panes(&self) -> impl Iterator<Item = &P> {
+        self.panes
+            .iter()
+            .chain(self.count_panes.iter().map(|count_pane| &count_pane.pane))
+    }
+
     pub(super) fn purge(&mut self) {
-        self.panes.clear()
+        self.panes.clear();
+        self.count_panes.clear();
+        self.row_counts.clear();
+    }
+
+    /// Group of a tuple to count rows by.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - GROUP BY key fails to evaluate or is NULL.
+    fn count_group(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+    ) -> Result<Option<NnSqlValue>> {
+        match &self.op_param {
+            WindowOperationParameter::GroupAggregation(group_aggregation_parameter) => {
+                match expr_resolver.eval_value_expr(group_aggregation_parameter.group_by, tuple)? {
+                    SqlValue::NotNull(v) => Ok(Some(v)),
+                    SqlValue::Null => Err(SpringError::Sql(anyhow!(
+                        "GROUP BY key of a count-based window is NULL (rowtime: {:?})",
+                        tuple.rowtime()
+                    ))),
+                }
+            }
+            WindowOperationParameter::Join(_) => Ok(None),
+        }
+    }
+
+    /// Bytes of an entry in `row_counts`.
+    pub(super) fn row_counter_size(group: &Option<NnSqlValue>) -> usize {
+        size_of::<Option<NnSqlValue>>()
+            + group.as_ref().map_or(0, |v| v.mem_size())
+            + size_of::<u64>()
     }
 
+    /// Time-based panes are not generated for count-based windows.
     fn generate_panes_if_not_exist(&mut self, rowtime: Timestamp) {
+        let length = match self.window_param.length() {
+            Some(length) => length,
+            None => return,
+        };
+
         // Sort-Merge Join like algorithm
         let mut pane_idx = 0;
         for open_at in self.valid_open_at_s(rowtime) {
@@ -94,46 +279,52 @@ where
                     }
                 } else {
                     // no pane has the open_at
-                    self.panes.push(self.generate_pane(open_at));
+                    self.panes.push(self.generate_pane(open_at, length));
                     break; // next open_at
                 }
             }
         }
     }
 
+    /// Empty for count-based windows.
     fn valid_open_at_s(&self, rowtime: Timestamp) -> Vec<Timestamp> {
         let mut ret = vec![];
 
+        let (length, period) = match (self.window_param.length(), self.window_param.period()) {
+            (Some(length), Some(period)) => (length.to_chrono(), period.to_chrono()),
+            _ => return ret,
+        };
+
         let leftmost_open_at = {
-            let l = (rowtime - self.window_param.length().to_chrono())
-                .ceil(self.window_param.period().to_chrono());
+            let l = (rowtime - length).ceil(period);
 
             // edge case
-            if l == rowtime - self.window_param.length().to_chrono() {
-                l + self.window_param.period().to_chrono()
+            if l == rowtime - length {
+                l + period
             } else {
                 l
             }
         };
-        let rightmost_open_at = rowtime.floor(self.window_param.period().to_chrono());
+        let rightmost_open_at = rowtime.floor(period);
 
         let mut open_at = leftmost_open_at;
         while open_at <= rightmost_open_at {
             ret.push(open_at);
-            open_at = open_at + self.window_param.period().to_chrono();
+            open_at = open_at + period;
         }
 
         ret
     }
 
-    fn generate_pane(&self, open_at: Timestamp) -> P {
-        let close_at = open_at + self.window_param.length().to_chrono();
+    fn generate_pane(&self, open_at: Timestamp, length: EventDuration) -> P {
+        let close_at = open_at + length.to_chrono();
         P::new(open_at, close_at, self.op_param.clone())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::pipeline::pump_model::window_parameter::WindowTimeDomain;
     use std::str::FromStr;
 
     use crate::{
@@ -163,7 +354,7 @@ mod tests {
             aggr_expr,
             alias: None,
         }];
-        let (mut expr_resolver, _, aggr_labels_select_list) = ExprResolver::new(select_list);
+        let (mut expr_resolver, _, aggr_labels_select_list, _) = ExprResolver::new(select_list);
 
         let group_by_label = expr_resolver.register_value_expr(group_by_expr);
 
@@ -182,6 +373,7 @@ mod tests {
                     length,
                     period,
                     allowed_delay: EventDuration::from_secs(0),
+                    time_domain: WindowTimeDomain::EventTime,
                 },
                 dont_care_window_operation_parameter(),
             )
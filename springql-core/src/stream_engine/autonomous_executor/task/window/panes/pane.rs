@@ -25,6 +25,9 @@ pub(in crate::stream_engine::autonomous_executor) trait Pane {
     fn open_at(&self) -> Timestamp;
     fn close_at(&self) -> Timestamp;
 
+    /// Count-based panes extend their close_at to the latest rowtime they get.
+    fn set_close_at(&mut self, close_at: Timestamp);
+
     fn is_acceptable(&self, rowtime: &Timestamp) -> bool {
         &self.open_at() <= rowtime && rowtime < &self.close_at()
     }
@@ -2,12 +2,15 @@
 
 mod aggregate_state;
 
-use std::collections::HashMap;
-
-use ordered_float::OrderedFloat;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    mem::size_of,
+};
 
 use crate::{
     expr_resolver::ExprResolver,
+    low_level_rs::{SpringAccumulator, SpringValue},
+    mem_size::MemSize,
     pipeline::pump_model::window_operation_parameter::{
         aggregate::{AggregateFunctionParameter, GroupAggregateParameter},
         WindowOperationParameter,
@@ -16,6 +19,7 @@ use crate::{
         autonomous_executor::{
             performance_metrics::metrics_update_command::metrics_update_by_task_execution::WindowInFlowByWindowTask,
             task::{tuple::Tuple, window::aggregate::GroupAggrOut},
+            AutonomousExecutor,
         },
         time::timestamp::Timestamp,
         NnSqlValue, SqlValue,
@@ -33,7 +37,8 @@ pub(in crate::stream_engine::autonomous_executor) struct AggrPane {
 
     group_aggregation_parameter: GroupAggregateParameter,
 
-    inner: AggrPaneInner,
+    /// Accumulator of each group.
+    states: HashMap<NnSqlValue, Box<dyn SpringAccumulator>>,
 }
 
 impl Pane for AggrPane {
@@ -45,17 +50,11 @@ impl Pane for AggrPane {
     /// if `op_param` is not `GroupAggregateParameter`
     fn new(open_at: Timestamp, close_at: Timestamp, op_param: WindowOperationParameter) -> Self {
         if let WindowOperationParameter::GroupAggregation(group_aggregation_parameter) = op_param {
-            let inner = match group_aggregation_parameter.aggr_func {
-                AggregateFunctionParameter::Avg => AggrPaneInner::Avg {
-                    states: HashMap::new(),
-                },
-            };
-
             Self {
                 open_at,
                 close_at,
                 group_aggregation_parameter,
-                inner,
+                states: HashMap::new(),
             }
         } else {
             panic!("op_param {:?} is not GroupAggregateParameter", op_param)
@@ -70,6 +69,11 @@ impl Pane for AggrPane {
         self.close_at
     }
 
+    fn set_close_at(&mut self, close_at: Timestamp) {
+        self.close_at = close_at;
+    }
+
+    /// An aggregated value which cannot be converted into the argument type, or is rejected by the accumulator, is dropped and reported as an error.
     fn dispatch(
         &mut self,
         expr_resolver: &ExprResolver,
@@ -94,55 +98,114 @@ impl Pane for AggrPane {
             unimplemented!("aggregation with NULL value is not supported")
         };
 
-        match &mut self.inner {
-            AggrPaneInner::Avg { states } => {
-                let state = states
-                    .entry(group_by_value)
-                    .or_insert_with(AvgState::default);
-
-                state.next(
-                    aggregated_value
-                        .unpack::<f32>()
-                        .expect("only f32 is supported currently"),
-                );
-
-                WindowInFlowByWindowTask::zero() // state in AVG is constant
+        let aggr_func = &self.group_aggregation_parameter.aggr_func;
+        let (state, mut gain_bytes) = match self.states.entry(group_by_value) {
+            Entry::Occupied(entry) => {
+                let state = entry.into_mut();
+                let bytes_before = state.mem_size_hint() as i64;
+                (state, -bytes_before)
             }
+            Entry::Vacant(entry) => {
+                let key_bytes = Self::group_key_size(entry.key()) as i64;
+                (entry.insert(Self::new_accumulator(aggr_func)), key_bytes)
+            }
+        };
+
+        let res = aggregated_value
+            .try_convert(&aggr_func.arg_type())
+            .and_then(|v| state.accumulate(SpringValue::from(SqlValue::NotNull(v))));
+        if let Err(e) = res {
+            AutonomousExecutor::handle_error(e);
         }
+        gain_bytes += state.mem_size_hint() as i64;
+
+        WindowInFlowByWindowTask::new(gain_bytes, 0)
     }
 
     fn close(
         self,
         _expr_resolver: &ExprResolver,
     ) -> (Vec<Self::CloseOut>, WindowInFlowByWindowTask) {
-        let aggr_label = self.group_aggregation_parameter.aggr_expr;
-        let group_by_label = self.group_aggregation_parameter.group_by;
-
-        match self.inner {
-            AggrPaneInner::Avg { states } => {
-                let group_aggr_out_seq = states
-                    .into_iter()
-                    .map(|(group_by, state)| {
-                        let aggr_value =
-                            SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(state.finalize())));
-                        GroupAggrOut::new(
-                            aggr_label,
-                            aggr_value,
-                            group_by_label,
-                            SqlValue::NotNull(group_by),
-                        )
-                    })
-                    .collect();
-
-                (group_aggr_out_seq, WindowInFlowByWindowTask::zero())
-            }
-        }
+        let group_aggr_out_seq = self
+            .states
+            .iter()
+            .filter_map(|(group_by, state)| self.aggr_out(group_by, state.as_ref(), false))
+            .collect();
+
+        let states_bytes = self
+            .states
+            .iter()
+            .map(|(group_by, state)| Self::group_size(group_by, state.as_ref()))
+            .sum::<usize>();
+
+        (
+            group_aggr_out_seq,
+            WindowInFlowByWindowTask::new(-(states_bytes as i64), 0),
+        )
     }
 }
 
-#[derive(Debug)]
-pub(in crate::stream_engine::autonomous_executor) enum AggrPaneInner {
-    Avg {
-        states: HashMap<NnSqlValue, AvgState>,
-    },
+impl AggrPane {
+    /// Partial results of this pane, which is still open.
+    pub(in crate::stream_engine::autonomous_executor) fn early_out(&self) -> Vec<GroupAggrOut> {
+        self.states
+            .iter()
+            .filter_map(|(group_by, state)| self.aggr_out(group_by, state.as_ref(), true))
+            .collect()
+    }
+
+    pub(in crate::stream_engine::autonomous_executor) fn new_accumulator(
+        aggr_func: &AggregateFunctionParameter,
+    ) -> Box<dyn SpringAccumulator> {
+        match aggr_func {
+            AggregateFunctionParameter::Avg => Box::new(AvgState::init()),
+            AggregateFunctionParameter::UserDefined(function) => function.init(),
+        }
+    }
+
+    /// Bytes of an entry in `states`.
+    pub(in crate::stream_engine::autonomous_executor) fn group_size(
+        group_by: &NnSqlValue,
+        state: &dyn SpringAccumulator,
+    ) -> usize {
+        Self::group_key_size(group_by) + state.mem_size_hint()
+    }
+
+    /// Bytes of an entry in `states` except for the accumulator.
+    fn group_key_size(group_by: &NnSqlValue) -> usize {
+        size_of::<NnSqlValue>() + group_by.mem_size() + size_of::<Box<dyn SpringAccumulator>>()
+    }
+
+    /// None (reported as an error) if the accumulator fails to finalize or its result cannot be converted into the return type.
+    fn aggr_out(
+        &self,
+        group_by: &NnSqlValue,
+        state: &dyn SpringAccumulator,
+        is_early: bool,
+    ) -> Option<GroupAggrOut> {
+        let aggr_func = &self.group_aggregation_parameter.aggr_func;
+
+        let aggr_value = state.finalize().and_then(|v| match SqlValue::from(v) {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(v) => v
+                .try_convert(&aggr_func.return_type())
+                .map(SqlValue::NotNull),
+        });
+
+        match aggr_value {
+            Ok(aggr_value) => Some(GroupAggrOut::new(
+                self.group_aggregation_parameter.aggr_expr,
+                aggr_value,
+                self.group_aggregation_parameter.group_by,
+                SqlValue::NotNull(group_by.clone()),
+                self.open_at,
+                self.close_at,
+                is_early,
+            )),
+            Err(e) => {
+                AutonomousExecutor::handle_error(e);
+                None
+            }
+        }
+    }
 }
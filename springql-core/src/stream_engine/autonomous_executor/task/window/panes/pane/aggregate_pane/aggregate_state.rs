@@ -1,27 +1,60 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-// TODO more generic avg
+use anyhow::anyhow;
+
+use crate::{
+    error::{Result, SpringError},
+    low_level_rs::{SpringAccumulator, SpringValue},
+};
+
+/// Accumulator of `AVG`. Aggregated values are converted into DOUBLE.
 #[derive(Debug, Default)]
 pub(in crate::stream_engine::autonomous_executor) struct AvgState {
-    current_avg: f32,
+    current_avg: f64,
     current_n: u64,
 }
 
 impl AvgState {
-    pub(in crate::stream_engine::autonomous_executor) fn next<V>(&mut self, next_val: V)
-    where
-        V: Into<f32>,
-    {
-        let next_val: f32 = next_val.into();
+    fn next(&mut self, next_val: f64) {
         let next_n = self.current_n + 1;
 
         self.current_avg =
-            self.current_avg + (next_val - self.current_avg) * (1.0 / (next_n as f32));
+            self.current_avg + (next_val - self.current_avg) * (1.0 / (next_n as f64));
         self.current_n = next_n;
     }
+}
+
+impl SpringAccumulator for AvgState {
+    fn init() -> Self {
+        Self::default()
+    }
+
+    fn accumulate(&mut self, value: SpringValue) -> Result<()> {
+        match value {
+            SpringValue::Double(v) => {
+                self.next(v);
+                Ok(())
+            }
+            _ => Err(SpringError::Sql(anyhow!(
+                "AVG takes DOUBLE value but got {:?}",
+                value
+            ))),
+        }
+    }
+
+    fn merge(&mut self, other: Self) -> Result<()> {
+        let n = self.current_n + other.current_n;
+        if n > 0 {
+            self.current_avg = (self.current_avg * self.current_n as f64
+                + other.current_avg * other.current_n as f64)
+                / n as f64;
+            self.current_n = n;
+        }
+        Ok(())
+    }
 
-    pub(in crate::stream_engine::autonomous_executor) fn finalize(self) -> f32 {
-        self.current_avg.round()
+    fn finalize(&self) -> Result<SpringValue> {
+        Ok(SpringValue::Double(self.current_avg))
     }
 }
 
@@ -31,10 +64,34 @@ mod tests {
 
     #[test]
     fn test_avg_state() {
-        let mut state = AvgState::default();
-        state.next(100.);
-        state.next(400.);
-        state.next(100.);
-        assert_eq!(state.finalize().round() as i32, 200);
+        let mut state = AvgState::init();
+        state.accumulate(SpringValue::Double(100.)).unwrap();
+        state.accumulate(SpringValue::Double(400.)).unwrap();
+        state.accumulate(SpringValue::Double(100.)).unwrap();
+        assert_eq!(state.finalize().unwrap(), SpringValue::Double(200.));
+    }
+
+    #[test]
+    fn test_avg_state_not_rounded() {
+        let mut state = AvgState::init();
+        state.accumulate(SpringValue::Double(35.681236)).unwrap();
+        state.accumulate(SpringValue::Double(35.681238)).unwrap();
+        assert!(matches!(
+            state.finalize().unwrap(),
+            SpringValue::Double(v) if (v - 35.681237).abs() < 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_avg_state_merge() {
+        let mut state = AvgState::init();
+        state.accumulate(SpringValue::Double(100.)).unwrap();
+
+        let mut other = AvgState::init();
+        other.accumulate(SpringValue::Double(400.)).unwrap();
+        other.accumulate(SpringValue::Double(100.)).unwrap();
+
+        state.merge(other).unwrap();
+        assert_eq!(state.finalize().unwrap(), SpringValue::Double(200.));
     }
 }
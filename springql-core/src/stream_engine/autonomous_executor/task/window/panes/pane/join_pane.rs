@@ -72,6 +72,10 @@ impl Pane for JoinPane {
         self.close_at
     }
 
+    fn set_close_at(&mut self, close_at: Timestamp) {
+        self.close_at = close_at;
+    }
+
     /// Dispatch to left_tuples
     fn dispatch(
         &mut self,
@@ -103,6 +107,7 @@ impl JoinPane {
     ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
         let window_in_flow = self.calc_window_in_flow_on_close();
 
+        let (open_at, close_at) = (self.open_at, self.close_at);
         let null_right = self.null_right_tuple();
 
         // using Nested Loop Join.
@@ -130,7 +135,11 @@ impl JoinPane {
                 joined_to_the_left.push(joined_tuple);
             }
 
-            res_tuples.extend(joined_to_the_left);
+            res_tuples.extend(
+                joined_to_the_left
+                    .into_iter()
+                    .map(|tuple| tuple.with_window_bounds(open_at, close_at)),
+            );
         }
 
         (res_tuples, window_in_flow)
@@ -30,6 +30,10 @@ impl Watermark {
         self.max_rowtime - self.allowed_delay.to_chrono()
     }
 
+    pub(in crate::stream_engine::autonomous_executor) fn max_rowtime(&self) -> Timestamp {
+        self.max_rowtime
+    }
+
     pub(in crate::stream_engine::autonomous_executor) fn update(&mut self, rowtime: Timestamp) {
         self.max_rowtime = max(rowtime, self.max_rowtime);
     }
@@ -2,7 +2,7 @@
 
 //! Task execution logics commonly used by GenericWorkerThread and SourceWorkerThread.
 
-use std::{fmt::Display, sync::Arc, thread, time::Duration};
+use std::{fmt::Display, sync::Arc, time::Duration};
 
 use crate::stream_engine::autonomous_executor::{
     event_queue::{event::Event, EventQueue},
@@ -17,6 +17,7 @@ use crate::stream_engine::autonomous_executor::{
     worker::worker_thread::WorkerThreadLoopState,
     AutonomousExecutor,
 };
+use crate::stream_engine::time::clock;
 
 use super::{scheduler::Scheduler, task_executor_lock::TaskExecutorLock};
 
@@ -95,7 +96,7 @@ impl TaskWorkerThreadHandler {
                         event_queue,
                     );
                 } else {
-                    thread::sleep(Duration::from_millis(TASK_WAIT_MSEC));
+                    clock::sleep(Duration::from_millis(TASK_WAIT_MSEC));
                 }
             }
 
@@ -14,12 +14,13 @@ pub(super) mod task_id;
 
 mod edge_ref;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
 use petgraph::graph::{DiGraph, NodeIndex};
 
 use crate::pipeline::{
-    name::StreamName, pipeline_graph::edge::Edge, pipeline_version::PipelineVersion, Pipeline,
+    name::StreamName, pipeline_graph::edge::Edge, pipeline_version::PipelineVersion,
+    pump_model::pump_input_type::PumpInputType, Pipeline,
 };
 
 use self::{
@@ -95,6 +96,22 @@ impl TaskGraph {
             .collect()
     }
 
+    /// Output queues which carry rows of `stream`.
+    ///
+    /// A pump task with `LATE ROWS INTO` has output queues for its downstream and for the late rows stream.
+    pub(super) fn output_queues_of_stream(
+        &self,
+        task_id: &TaskId,
+        stream: &StreamName,
+    ) -> Vec<QueueId> {
+        let i = self.find_node(task_id);
+        self.g
+            .edges_directed(i, petgraph::EdgeDirection::Outgoing)
+            .filter(|e| &e.weight().upstream == stream)
+            .map(|e| e.weight().queue_id.clone())
+            .collect()
+    }
+
     /// # Panics
     ///
     /// if `task_id` does not have incoming edge (queue) from `upstream`.
@@ -207,6 +224,49 @@ impl TaskGraph {
     }
 }
 
+/// Lists tasks and queues (with their upstream and downstream tasks) in name order.
+impl Display for TaskGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tasks = self.task_id_node_map.keys().collect::<Vec<_>>();
+        tasks.sort();
+        writeln!(f, "Tasks:")?;
+        for task_id in tasks {
+            let kind = match task_id {
+                TaskId::Source { .. } => "source",
+                TaskId::Pump {
+                    input_type: PumpInputType::Row,
+                    ..
+                } => "pump (row)",
+                TaskId::Pump {
+                    input_type: PumpInputType::Window,
+                    ..
+                } => "pump (window)",
+                TaskId::Sink { .. } => "sink",
+            };
+            writeln!(f, "  {} [{}]", task_id, kind)?;
+        }
+
+        let mut queues = self.queue_id_edge_map.keys().collect::<Vec<_>>();
+        queues.sort();
+        writeln!(f, "Queues:")?;
+        for queue_id in queues {
+            let (kind, name) = match queue_id {
+                QueueId::Row(id) => ("row", id.to_string()),
+                QueueId::Window(id) => ("window", id.to_string()),
+            };
+            writeln!(
+                f,
+                "  {} [{}]: {} -> {}",
+                name,
+                kind,
+                self.upstream_task(queue_id),
+                self.downstream_task(queue_id)
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl From<&Pipeline> for TaskGraph {
     fn from(pipeline: &Pipeline) -> Self {
         let pipeline_graph = pipeline.as_graph();
@@ -257,7 +317,8 @@ impl From<&Pipeline> for TaskGraph {
                         target,
                     );
                 }
-                Edge::Source(_) => {} // no queue is created for source task
+                Edge::LateRows { .. } => {} // queues from late rows stream are created by its downstream edges
+                Edge::Source(_) => {}       // no queue is created for source task
             };
         }
         task_graph
@@ -13,8 +13,9 @@ use crate::stream_engine::autonomous_executor::task::tuple::Tuple;
 use crate::stream_engine::time::timestamp::Timestamp;
 use crate::{
     pipeline::{
-        name::ColumnName, option::options_builder::OptionsBuilder,
-        stream_model::stream_shape::StreamShape,
+        name::ColumnName,
+        option::options_builder::OptionsBuilder,
+        stream_model::{stream_kind::StreamKind, stream_shape::StreamShape},
     },
     stream_engine::autonomous_executor::row::{
         column::stream_column::StreamColumns,
@@ -127,6 +128,7 @@ impl StreamColumns {
         Self::new(
             Arc::new(StreamModel::new(
                 StreamName::new("st".to_string()),
+                StreamKind::Native,
                 StreamShape::fx_no_promoted_rowtime(),
             )),
             column_values,
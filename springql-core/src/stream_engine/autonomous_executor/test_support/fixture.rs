@@ -6,9 +6,12 @@ use serde_json::json;
 
 use crate::{
     pipeline::{
-        name::StreamName, pipeline_version::PipelineVersion,
-        pump_model::pump_input_type::PumpInputType, sink_writer_model::SinkWriterModel,
-        source_reader_model::SourceReaderModel, stream_model::StreamModel,
+        name::StreamName,
+        pipeline_version::PipelineVersion,
+        pump_model::pump_input_type::PumpInputType,
+        sink_writer_model::SinkWriterModel,
+        source_reader_model::SourceReaderModel,
+        stream_model::{stream_kind::StreamKind, StreamModel},
     },
     stream_engine::{
         autonomous_executor::{
@@ -210,7 +213,7 @@ impl StreamColumns {
 
 impl AlterPipelineCommand {
     pub(in crate::stream_engine) fn fx_create_source_stream_trade(stream_name: StreamName) -> Self {
-        let stream = StreamModel::fx_trade_with_name(stream_name);
+        let stream = StreamModel::fx_trade_with_name(stream_name, StreamKind::Source);
         Self::CreateSourceStream(stream)
     }
 
@@ -224,7 +227,7 @@ impl AlterPipelineCommand {
     }
 
     pub(in crate::stream_engine) fn fx_create_sink_stream_trade(stream_name: StreamName) -> Self {
-        let stream = StreamModel::fx_trade_with_name(stream_name);
+        let stream = StreamModel::fx_trade_with_name(stream_name, StreamKind::Sink);
         Self::CreateSinkStream(stream)
     }
 
@@ -5,6 +5,7 @@ use std::{
     thread,
 };
 
+use crate::low_level_rs::SpringClock;
 use crate::stream_engine::autonomous_executor::{
     memory_state_machine::MemoryStateTransition,
     performance_metrics::{
@@ -121,9 +122,11 @@ pub(in crate::stream_engine::autonomous_executor) trait WorkerThread {
             .into_iter()
             .map(|ev| event_queue.subscribe(ev))
             .collect();
+        let clock = SpringClock::current();
         let _ = thread::Builder::new()
             .name(Self::THREAD_NAME.into())
             .spawn(move || {
+                let _clock = clock.enter();
                 Self::main_loop(
                     event_queue,
                     event_polls,
@@ -1,12 +1,19 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use self::alter_pipeline_command::AlterPipelineCommand;
+use self::{
+    alter_pipeline_command::AlterPipelineCommand, explain_command::ExplainCommand,
+    show_command::ShowCommand,
+};
 
 pub(crate) mod alter_pipeline_command;
+pub(crate) mod explain_command;
 pub(crate) mod insert_plan;
 pub(crate) mod query_plan;
+pub(crate) mod show_command;
 
 #[derive(Clone, PartialEq, Debug)]
 pub(crate) enum Command {
     AlterPipeline(AlterPipelineCommand),
+    Explain(ExplainCommand),
+    Show(ShowCommand),
 }
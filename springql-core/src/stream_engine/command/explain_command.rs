@@ -0,0 +1,12 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::pipeline::pump_model::PumpModel;
+
+/// Renders how a pump or the whole pipeline is executed, without altering the pipeline.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum ExplainCommand {
+    /// `EXPLAIN CREATE PUMP ...`
+    CreatePump(Box<PumpModel>),
+    /// `EXPLAIN PIPELINE`
+    Pipeline,
+}
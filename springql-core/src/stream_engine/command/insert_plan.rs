@@ -8,6 +8,9 @@ use crate::pipeline::name::{ColumnName, StreamName};
 pub(crate) struct InsertPlan {
     stream: StreamName,
     column_order: Vec<ColumnName>,
+
+    /// `LATE ROWS INTO stream`
+    late_rows_stream: Option<StreamName>,
 }
 
 impl InsertPlan {
@@ -18,4 +21,8 @@ impl InsertPlan {
     pub(crate) fn column_order(&self) -> &[ColumnName] {
         &self.column_order
     }
+
+    pub(crate) fn late_rows_stream(&self) -> Option<&StreamName> {
+        self.late_rows_stream.as_ref()
+    }
 }
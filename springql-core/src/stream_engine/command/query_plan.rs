@@ -4,6 +4,8 @@ pub(crate) mod query_plan_operation;
 
 pub(in crate::stream_engine) mod child_direction;
 
+use std::{fmt::Write, iter};
+
 use crate::{
     expr_resolver::ExprResolver,
     pipeline::{name::StreamName, pump_model::pump_input_type::PumpInputType},
@@ -20,11 +22,19 @@ pub(crate) struct QueryPlan {
 
     /// to convert *Expr in *Syntax into *ExprLabel
     pub(crate) expr_resolver: ExprResolver,
+
+    /// Plans of `UNION ALL SELECT ...` branches, each of which reads from its own upstream.
+    /// Branches do not have `union_all` by themselves.
+    #[new(default)]
+    pub(crate) union_all: Vec<QueryPlan>,
 }
 
 impl QueryPlan {
     pub(crate) fn input_type(&self) -> PumpInputType {
-        if self.upper_ops.has_window() || self.lower_ops.has_window() {
+        if self
+            .branches()
+            .any(|plan| plan.upper_ops.has_window() || plan.lower_ops.has_window())
+        {
             PumpInputType::Window
         } else {
             PumpInputType::Row
@@ -32,11 +42,80 @@ impl QueryPlan {
     }
 
     pub(crate) fn upstreams(&self) -> Vec<&StreamName> {
+        self.branches()
+            .flat_map(|plan| match &plan.lower_ops.join {
+                JoinOp::Collect(collect) => vec![&collect.stream],
+                JoinOp::JoinWindow(JoinWindowOp { left, right, .. }) => {
+                    vec![&left.stream, &right.stream]
+                }
+            })
+            .collect()
+    }
+
+    /// Textual rendering of the plan for EXPLAIN: operations from the upper (projection side) to the lower (input side) and labeled expressions.
+    /// `UNION ALL` branches follow indented.
+    pub(crate) fn explain(&self) -> String {
+        let mut s = String::new();
+        self.write_explain(&mut s, 0)
+            .expect("writing into String never fails");
+        s
+    }
+
+    fn write_explain(&self, s: &mut String, depth: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        let upper = &self.upper_ops;
+
+        writeln!(s, "{}UpperOps:", indent)?;
+        if let Some(order_limit) = &upper.order_limit {
+            writeln!(s, "{}  OrderLimit: {:?}", indent, order_limit)?;
+        }
+        if let Some(deduplicate) = &upper.deduplicate {
+            writeln!(s, "{}  Deduplicate: {:?}", indent, deduplicate)?;
+        }
+        writeln!(
+            s,
+            "{}  Projection: {:?}",
+            indent, upper.projection.expr_labels
+        )?;
+        if let Some(match_recognize) = &upper.match_recognize {
+            writeln!(s, "{}  MatchRecognize: {:?}", indent, match_recognize)?;
+        }
+        if let Some(group_aggr_window) = &upper.group_aggr_window {
+            writeln!(
+                s,
+                "{}  GroupAggregateWindow: window={:?}, operation={:?}, idle_timeout={:?}, trigger={:?}",
+                indent,
+                group_aggr_window.window_param,
+                group_aggr_window.op_param,
+                group_aggr_window.idle_timeout,
+                group_aggr_window.trigger
+            )?;
+        }
+
+        writeln!(s, "{}LowerOps:", indent)?;
         match &self.lower_ops.join {
-            JoinOp::Collect(collect) => vec![&collect.stream],
-            JoinOp::JoinWindow(JoinWindowOp { left, right, .. }) => {
-                vec![&left.stream, &right.stream]
-            }
+            JoinOp::Collect(collect) => writeln!(s, "{}  Collect: {}", indent, collect.stream)?,
+            JoinOp::JoinWindow(join) => writeln!(
+                s,
+                "{}  JoinWindow: left={}, right={}, window={:?}, join={:?}",
+                indent, join.left.stream, join.right.stream, join.window_param, join.join_param
+            )?,
+        }
+
+        writeln!(s, "{}Expressions:", indent)?;
+        for (label, expr) in self.expr_resolver.labeled_expressions() {
+            writeln!(s, "{}  {:?}: {}", indent, label, expr)?;
         }
+
+        for (i, branch) in self.union_all.iter().enumerate() {
+            writeln!(s, "{}UnionAll #{}:", indent, i + 1)?;
+            branch.write_explain(s, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// This plan and `UNION ALL` branches.
+    pub(crate) fn branches(&self) -> impl Iterator<Item = &QueryPlan> {
+        iter::once(self).chain(self.union_all.iter())
     }
 }
@@ -1,24 +1,40 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 use crate::{
-    expr_resolver::expr_label::{AggrExprLabel, ValueExprLabel},
+    expr_resolver::expr_label::{AggrExprLabel, AnalyticExprLabel, ExprLabel, ValueExprLabel},
     pipeline::{
         name::StreamName,
         pump_model::{
+            deduplicate_parameter::DeduplicateParameter,
+            match_recognize_parameter::MatchRecognizeParameter,
+            order_limit_parameter::OrderLimitParameter,
             window_operation_parameter::{join_parameter::JoinParameter, WindowOperationParameter},
             window_parameter::WindowParameter,
+            window_trigger::WindowTrigger,
         },
     },
+    stream_engine::time::duration::wall_clock_duration::WallClockDuration,
 };
 
 #[derive(Clone, PartialEq, Debug)]
 pub(crate) struct UpperOps {
     pub(crate) projection: ProjectionOp,
     pub(crate) group_aggr_window: Option<GroupAggregateWindowOp>,
+    /// Converts tuples into matches before projection.
+    pub(crate) match_recognize: Option<MatchRecognizeParameter>,
+    /// Drops projected rows whose key was already emitted.
+    pub(crate) deduplicate: Option<DeduplicateParameter>,
+    /// Sorts and limits rows in each pane.
+    pub(crate) order_limit: Option<OrderLimitParameter>,
 }
 impl UpperOps {
+    /// Analytic expressions, MATCH_RECOGNIZE, deduplication and ORDER BY also have states (preceding rows, seen keys or ranked rows) like windows.
     pub(crate) fn has_window(&self) -> bool {
         self.group_aggr_window.is_some()
+            || self.match_recognize.is_some()
+            || self.deduplicate.is_some()
+            || self.order_limit.is_some()
+            || !self.projection.analytic_expr_labels().is_empty()
     }
 }
 
@@ -34,14 +50,49 @@ impl LowerOps {
 
 #[derive(Clone, PartialEq, Debug)]
 pub(crate) struct ProjectionOp {
-    pub(crate) value_expr_labels: Vec<ValueExprLabel>,
-    pub(crate) aggr_expr_labels: Vec<AggrExprLabel>,
+    /// Ordered as select_list.
+    pub(crate) expr_labels: Vec<ExprLabel>,
+}
+impl ProjectionOp {
+    pub(crate) fn value_expr_labels(&self) -> Vec<ValueExprLabel> {
+        self.expr_labels
+            .iter()
+            .filter_map(|label| match label {
+                ExprLabel::Value(l) => Some(*l),
+                ExprLabel::Aggr(_) | ExprLabel::Analytic(_) => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn aggr_expr_labels(&self) -> Vec<AggrExprLabel> {
+        self.expr_labels
+            .iter()
+            .filter_map(|label| match label {
+                ExprLabel::Value(_) | ExprLabel::Analytic(_) => None,
+                ExprLabel::Aggr(l) => Some(*l),
+            })
+            .collect()
+    }
+
+    pub(crate) fn analytic_expr_labels(&self) -> Vec<AnalyticExprLabel> {
+        self.expr_labels
+            .iter()
+            .filter_map(|label| match label {
+                ExprLabel::Value(_) | ExprLabel::Aggr(_) => None,
+                ExprLabel::Analytic(l) => Some(*l),
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
 pub(crate) struct GroupAggregateWindowOp {
     pub(crate) window_param: WindowParameter,
     pub(crate) op_param: WindowOperationParameter,
+    /// Advances watermark by processing time after no row arrives for this duration (`IDLE TIMEOUT ...`).
+    pub(crate) idle_timeout: Option<WallClockDuration>,
+    /// Emits early results before panes close (`EMIT EVERY ...`).
+    pub(crate) trigger: Option<WindowTrigger>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -0,0 +1,18 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::pipeline::name::StreamName;
+
+/// Lists objects in the current pipeline as rows, without altering the pipeline.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum ShowCommand {
+    /// `SHOW STREAMS`
+    Streams,
+    /// `SHOW PUMPS`
+    Pumps,
+    /// `SHOW SOURCE READERS`
+    SourceReaders,
+    /// `SHOW SINK WRITERS`
+    SinkWriters,
+    /// `DESCRIBE STREAM ...`
+    DescribeStream(StreamName),
+}
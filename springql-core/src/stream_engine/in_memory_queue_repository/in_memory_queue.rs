@@ -3,11 +3,11 @@
 use std::{
     collections::VecDeque,
     sync::{Mutex, MutexGuard},
-    thread,
     time::Duration,
 };
 
 use crate::stream_engine::autonomous_executor::row::foreign_row::sink_row::SinkRow;
+use crate::stream_engine::time::clock;
 
 const SLEEP_MSECS: u64 = 10;
 
@@ -24,7 +24,7 @@ impl InMemoryQueue {
             if let Some(r) = r {
                 return r;
             } else {
-                thread::sleep(Duration::from_millis(SLEEP_MSECS));
+                clock::sleep(Duration::from_millis(SLEEP_MSECS));
             }
         }
     }
@@ -1,8 +1,10 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+mod catalog;
+
 use std::sync::Arc;
 
-use super::command::alter_pipeline_command::AlterPipelineCommand;
+use super::command::{alter_pipeline_command::AlterPipelineCommand, show_command::ShowCommand};
 use crate::{
     error::Result,
     pipeline::{
@@ -10,6 +12,7 @@ use crate::{
         sink_writer_model::SinkWriterModel, source_reader_model::SourceReaderModel,
         stream_model::StreamModel, Pipeline,
     },
+    stream_engine::SinkRow,
 };
 
 /// Executor of SQL.
@@ -42,6 +45,14 @@ impl SqlExecutor {
         Ok(self.pipeline.clone())
     }
 
+    /// # Failure
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - stream to describe does not exist in pipeline.
+    pub(in crate::stream_engine) fn show(&self, command: ShowCommand) -> Result<Vec<SinkRow>> {
+        catalog::show(&self.pipeline, command)
+    }
+
     fn new_pipeline(pipeline: Pipeline, command: AlterPipelineCommand) -> Result<Pipeline> {
         match command {
             AlterPipelineCommand::CreateSourceStream(source_stream) => {
@@ -98,3 +109,112 @@ impl SqlExecutor {
         Ok(pipeline)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sql_processor::SqlProcessor, stream_engine::command::Command};
+    use pretty_assertions::assert_eq;
+
+    fn apply(ddls: &[String]) -> SqlExecutor {
+        let processor = SqlProcessor::default();
+        let mut executor = SqlExecutor::default();
+        for ddl in ddls {
+            match processor.compile(ddl, executor.current_pipeline()).unwrap() {
+                Command::AlterPipeline(command) => {
+                    let _ = executor.alter_pipeline(command).unwrap();
+                }
+                command => panic!("unexpected command: {:?}", command),
+            }
+        }
+        executor
+    }
+
+    #[test]
+    fn test_export_ddl_round_trip() {
+        let ddls = [
+            "
+            CREATE SOURCE STREAM source_trade (
+              ts TIMESTAMP NOT NULL,
+              ticker TEXT NOT NULL,
+              amount INTEGER
+            ) ROWTIME EVENT_TIME ts;
+            ",
+            "
+            CREATE STREAM trade (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              ticker TEXT NOT NULL,
+              amount INTEGER
+            );
+            ",
+            "
+            CREATE SINK STREAM sink_avg (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              avg_amount FLOAT
+            );
+            ",
+            "
+            CREATE SINK STREAM sink_late_trade (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              ticker TEXT NOT NULL,
+              amount INTEGER
+            );
+            ",
+            "
+            CREATE PUMP pu_double AS
+              INSERT INTO trade (ts, ticker, amount)
+              SELECT STREAM ts, ticker, amount * 2 FROM source_trade;
+            ",
+            "
+            CREATE PUMP pu_avg AS
+              INSERT INTO sink_avg (ts, avg_amount)
+              SELECT STREAM
+                FLOOR_TIME(trade.ts, DURATION_SECS(10)) AS sampled_ts,
+                AVG(trade.amount) AS avg_amount
+              FROM trade
+              GROUP BY sampled_ts
+              FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+              LATE ROWS INTO sink_late_trade;
+            ",
+            "
+            CREATE SINK WRITER queue_avg FOR sink_avg
+              TYPE IN_MEMORY_QUEUE OPTIONS (
+                NAME 'q_avg'
+              );
+            ",
+            "
+            CREATE SOURCE READER tcp_trade FOR source_trade
+              TYPE NET_CLIENT OPTIONS (
+                PROTOCOL 'TCP',
+                REMOTE_HOST '127.0.0.1',
+                REMOTE_PORT '17890'
+              );
+            ",
+        ]
+        .map(String::from);
+
+        let original = apply(&ddls);
+        let exported = original.current_pipeline().to_ddl();
+
+        assert_eq!(
+            exported[0],
+            "CREATE SINK STREAM sink_avg (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  avg_amount FLOAT\n)"
+        );
+        assert_eq!(
+            exported[2],
+            "CREATE SOURCE STREAM source_trade (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  ticker TEXT NOT NULL,\n  amount INTEGER\n)"
+        );
+        assert_eq!(
+            exported[3],
+            "CREATE STREAM trade (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  ticker TEXT NOT NULL,\n  amount INTEGER\n)"
+        );
+        assert_eq!(
+            exported[7],
+            "CREATE SOURCE READER tcp_trade FOR source_trade\n  TYPE NET_CLIENT OPTIONS (\n    PROTOCOL 'TCP',\n    REMOTE_HOST '127.0.0.1',\n    REMOTE_PORT '17890'\n  )"
+        );
+
+        let recreated = apply(&exported);
+        assert!(original.current_pipeline().as_graph() == recreated.current_pipeline().as_graph());
+        assert_eq!(recreated.current_pipeline().to_ddl(), exported);
+    }
+}
@@ -0,0 +1,195 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Results of `SHOW ...` and `DESCRIBE STREAM ...`.
+//!
+//! Each command has its result stream (not registered in pipeline) and returns rows of the stream.
+//! All columns are NOT NULL; flags are `'YES'` or `'NO'` as `information_schema` in standard SQL.
+
+use std::sync::Arc;
+
+use crate::{
+    error::Result,
+    pipeline::{
+        name::{ColumnName, StreamName},
+        pump_model::pump_input_type::PumpInputType,
+        relation::{
+            column::{column_data_type::ColumnDataType, column_definition::ColumnDefinition},
+            sql_type::SqlType,
+        },
+        stream_model::{stream_kind::StreamKind, stream_shape::StreamShape, StreamModel},
+        Pipeline,
+    },
+    stream_engine::{command::show_command::ShowCommand, NnSqlValue, SinkRow, SqlValue},
+};
+
+pub(super) fn show(pipeline: &Pipeline, command: ShowCommand) -> Result<Vec<SinkRow>> {
+    match command {
+        ShowCommand::Streams => {
+            let result_stream = result_stream(
+                "streams",
+                &[
+                    ("name", SqlType::text()),
+                    ("column_count", SqlType::integer()),
+                ],
+            );
+            pipeline
+                .all_streams()
+                .iter()
+                .map(|stream| {
+                    SinkRow::from_values(
+                        result_stream.clone(),
+                        vec![
+                            text(stream.name().to_string()),
+                            SqlValue::NotNull(NnSqlValue::Integer(
+                                stream.shape().columns().len() as i32
+                            )),
+                        ],
+                    )
+                })
+                .collect()
+        }
+        ShowCommand::Pumps => {
+            let result_stream = result_stream(
+                "pumps",
+                &[
+                    ("name", SqlType::text()),
+                    ("upstreams", SqlType::text()),
+                    ("downstream", SqlType::text()),
+                    ("input_type", SqlType::text()),
+                ],
+            );
+            pipeline
+                .all_pumps()
+                .iter()
+                .map(|pump| {
+                    let upstreams = pump
+                        .upstreams()
+                        .iter()
+                        .map(|upstream| upstream.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let input_type = match pump.input_type() {
+                        PumpInputType::Row => "ROW",
+                        PumpInputType::Window => "WINDOW",
+                    };
+                    SinkRow::from_values(
+                        result_stream.clone(),
+                        vec![
+                            text(pump.name().to_string()),
+                            text(upstreams),
+                            text(pump.downstream().to_string()),
+                            text(input_type.to_string()),
+                        ],
+                    )
+                })
+                .collect()
+        }
+        ShowCommand::SourceReaders => {
+            let result_stream = reader_writer_stream("source_readers");
+            let mut source_readers = pipeline.all_sources();
+            source_readers.sort_by(|a, b| a.name().cmp(b.name()));
+            source_readers
+                .iter()
+                .map(|source_reader| {
+                    SinkRow::from_values(
+                        result_stream.clone(),
+                        vec![
+                            text(source_reader.name().to_string()),
+                            text(source_reader.source_reader_type().to_string()),
+                            text(source_reader.dest_source_stream().to_string()),
+                        ],
+                    )
+                })
+                .collect()
+        }
+        ShowCommand::SinkWriters => {
+            let result_stream = reader_writer_stream("sink_writers");
+            let mut sink_writers = pipeline.all_sinks();
+            sink_writers.sort_by(|a, b| a.name().cmp(b.name()));
+            sink_writers
+                .iter()
+                .map(|sink_writer| {
+                    SinkRow::from_values(
+                        result_stream.clone(),
+                        vec![
+                            text(sink_writer.name().to_string()),
+                            text(sink_writer.sink_writer_type().to_string()),
+                            text(sink_writer.from_sink_stream().to_string()),
+                        ],
+                    )
+                })
+                .collect()
+        }
+        ShowCommand::DescribeStream(stream_name) => {
+            let result_stream = result_stream(
+                "columns",
+                &[
+                    ("column_name", SqlType::text()),
+                    ("data_type", SqlType::text()),
+                    ("is_nullable", SqlType::text()),
+                    ("is_rowtime", SqlType::text()),
+                ],
+            );
+            let stream = pipeline.get_stream(&stream_name)?;
+            let promoted_rowtime = stream.shape().promoted_rowtime();
+            stream
+                .shape()
+                .columns()
+                .iter()
+                .map(|column| {
+                    let data_type = column.column_data_type();
+                    SinkRow::from_values(
+                        result_stream.clone(),
+                        vec![
+                            text(column.column_name().to_string()),
+                            text(data_type.sql_type().to_string()),
+                            yes_no(data_type.nullable()),
+                            yes_no(promoted_rowtime == Some(column.column_name())),
+                        ],
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+fn result_stream(name: &str, columns: &[(&str, SqlType)]) -> Arc<StreamModel> {
+    let columns = columns
+        .iter()
+        .map(|(column_name, sql_type)| {
+            ColumnDefinition::new(
+                ColumnDataType::new(
+                    ColumnName::new(column_name.to_string()),
+                    sql_type.clone(),
+                    false,
+                ),
+                vec![],
+            )
+        })
+        .collect();
+    let shape = StreamShape::new(columns).expect("result stream has no ROWTIME column");
+    Arc::new(StreamModel::new(
+        StreamName::new(name.to_string()),
+        StreamKind::Native,
+        shape,
+    ))
+}
+
+fn reader_writer_stream(name: &str) -> Arc<StreamModel> {
+    result_stream(
+        name,
+        &[
+            ("name", SqlType::text()),
+            ("type", SqlType::text()),
+            ("stream", SqlType::text()),
+        ],
+    )
+}
+
+fn text(s: String) -> SqlValue {
+    SqlValue::NotNull(NnSqlValue::Text(s))
+}
+
+fn yes_no(flag: bool) -> SqlValue {
+    text(if flag { "YES" } else { "NO" }.to_string())
+}
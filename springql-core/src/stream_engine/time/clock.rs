@@ -0,0 +1,102 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Clock of the current thread.
+//!
+//! A pipeline enters its [SpringClock](crate::low_level_rs::SpringClock) in the main thread (for API calls)
+//! and in each worker thread (captured from the spawning thread).
+//! Code running in a thread without entered clock uses the system clock.
+
+use std::{cell::RefCell, thread, time::Duration};
+
+use chrono::NaiveDateTime;
+
+use crate::{low_level_rs::SpringClock, stream_engine::time::timestamp::Timestamp};
+
+/// Real time to yield on sleep with manual clock.
+const MANUAL_CLOCK_SLEEP: Duration = Duration::from_millis(1);
+
+thread_local! {
+    static CURRENT_CLOCK: RefCell<SpringClock> = const { RefCell::new(SpringClock::System) };
+}
+
+/// Clock entered to the current thread until dropped.
+#[derive(Debug)]
+pub(crate) struct ClockScope {
+    prev: Option<SpringClock>,
+}
+
+impl Drop for ClockScope {
+    fn drop(&mut self) {
+        if let Some(prev) = self.prev.take() {
+            CURRENT_CLOCK.with(|c| *c.borrow_mut() = prev);
+        }
+    }
+}
+
+impl SpringClock {
+    /// Clock of the current thread.
+    pub(crate) fn current() -> Self {
+        CURRENT_CLOCK.with(|c| c.borrow().clone())
+    }
+
+    /// Use this clock in the current thread while the returned scope lives.
+    pub(crate) fn enter(&self) -> ClockScope {
+        let prev = CURRENT_CLOCK.with(|c| c.replace(self.clone()));
+        ClockScope { prev: Some(prev) }
+    }
+
+    pub(crate) fn now(&self) -> Timestamp {
+        match self {
+            SpringClock::System => Timestamp::new(chrono::offset::Utc::now().naive_utc()),
+            SpringClock::Manual(clock) => {
+                let elapsed = chrono::Duration::from_std(clock.elapsed())
+                    .expect("manual clock elapsed out of range");
+                Timestamp::new(NaiveDateTime::from_timestamp(0, 0) + elapsed)
+            }
+        }
+    }
+
+    pub(crate) fn sleep(&self, duration: Duration) {
+        match self {
+            SpringClock::System => thread::sleep(duration),
+            SpringClock::Manual(_) => thread::sleep(duration.min(MANUAL_CLOCK_SLEEP)),
+        }
+    }
+}
+
+/// Sleep for `duration` by the clock of the current thread.
+pub(crate) fn sleep(duration: Duration) {
+    SpringClock::current().sleep(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use crate::low_level_rs::ManualClock;
+
+    #[test]
+    fn test_manual_clock_scope() {
+        let manual = ManualClock::default();
+        let before = SpringClock::current().now();
+
+        {
+            let _scope = SpringClock::Manual(manual.clone()).enter();
+            let t0 = SpringClock::current().now();
+            assert_eq!(
+                t0,
+                Timestamp::from_str("1970-01-01 00:00:00.000000000").unwrap()
+            );
+
+            manual.advance(Duration::from_millis(1500));
+            assert_eq!(
+                SpringClock::current().now(),
+                Timestamp::from_str("1970-01-01 00:00:01.500000000").unwrap()
+            );
+        }
+
+        assert!(SpringClock::current().now() >= before);
+    }
+}
@@ -1,5 +1,6 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+pub(in crate::stream_engine) mod clock_stopwatch;
 pub(in crate::stream_engine) mod wall_clock_stopwatch;
 
 use std::{
@@ -13,7 +14,7 @@ use super::SpringDuration;
 
 /// Real-time (wall-clock) duration.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
-pub(in crate::stream_engine) struct WallClockDuration(Duration);
+pub(crate) struct WallClockDuration(Duration);
 
 impl SpringDuration for WallClockDuration {
     fn as_std(&self) -> &Duration {
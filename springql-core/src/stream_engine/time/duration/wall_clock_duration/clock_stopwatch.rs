@@ -0,0 +1,34 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::stream_engine::time::{
+    duration::SpringDuration,
+    timestamp::{system_timestamp::SystemTimestamp, Timestamp},
+};
+
+use super::WallClockDuration;
+
+/// Processing-time stopwatch by the clock of the current thread.
+///
+/// Measures by [SpringClock](crate::low_level_rs::SpringClock) injected to a pipeline, so that it does not proceed unless a manual clock is advanced.
+#[derive(Debug)]
+pub(in crate::stream_engine) struct ClockStopwatch {
+    start_at: Timestamp,
+}
+
+impl ClockStopwatch {
+    pub(in crate::stream_engine) fn start() -> Self {
+        let start_at = SystemTimestamp::now();
+        Self { start_at }
+    }
+
+    /// # Panics
+    ///
+    /// If current clock is smaller than start time.
+    pub(in crate::stream_engine) fn stop(&self) -> WallClockDuration {
+        let stop_at = SystemTimestamp::now();
+        assert!(stop_at >= self.start_at);
+
+        let duration = stop_at - self.start_at;
+        WallClockDuration::from_std(duration.to_std().expect("chrono to_std"))
+    }
+}
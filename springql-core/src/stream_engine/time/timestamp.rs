@@ -3,6 +3,8 @@
 //! Timestamp.
 
 pub(crate) mod system_timestamp;
+pub(crate) mod timestamp_format;
+pub(crate) mod timestamp_tz;
 
 use anyhow::Context;
 use chrono::{naive::MIN_DATETIME, DateTime, Duration, NaiveDateTime};
@@ -60,6 +62,10 @@ impl Timestamp {
         }
     }
 
+    pub(crate) fn as_naive_date_time(&self) -> &NaiveDateTime {
+        &self.0
+    }
+
     fn try_parse_original(s: &str) -> Result<Self> {
         let ndt = NaiveDateTime::parse_from_str(s, FORMAT)
             .with_context(|| format!("failed to parse timestamp: {}", s))
@@ -3,13 +3,14 @@
 use super::Timestamp;
 use serde::{Deserialize, Serialize};
 
-/// Wall-clock timestamp from system clock.
+use crate::low_level_rs::SpringClock;
+
+/// Wall-clock timestamp from the clock of the current thread (system clock unless a pipeline injects another one).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize, new)]
 pub(crate) struct SystemTimestamp;
 
 impl SystemTimestamp {
     pub(crate) fn now() -> Timestamp {
-        let t = chrono::offset::Utc::now().naive_utc();
-        Timestamp::new(t)
+        SpringClock::current().now()
     }
 }
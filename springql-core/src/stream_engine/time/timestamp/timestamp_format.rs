@@ -0,0 +1,236 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Formats to parse timestamps from sources.
+
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    timestamp_tz::{utc_offset, TimestampTz},
+    Timestamp,
+};
+use crate::{
+    error::{Result, SpringError},
+    stream_engine::NnSqlValue,
+};
+
+/// How a source value is parsed into TIMESTAMP or TIMESTAMPTZ.
+///
+/// Given by `FORMAT '...'` in a column definition or by `TIMESTAMP_FORMAT` option of a source reader.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub(crate) enum TimestampFormat {
+    /// chrono strftime pattern (`%d/%m/%Y %H:%M:%S%z`, for example) for TEXT values.
+    /// Local time without `%z` is regarded as UTC.
+    Pattern(String),
+
+    /// `EPOCH_SECS`: seconds since 1970-01-01 00:00:00 UTC.
+    EpochSecs,
+    /// `EPOCH_MILLIS`: milliseconds since 1970-01-01 00:00:00 UTC.
+    EpochMillis,
+    /// `EPOCH_MICROS`: microseconds since 1970-01-01 00:00:00 UTC.
+    EpochMicros,
+}
+
+impl TimestampFormat {
+    /// Parse a value from a source.
+    /// TIMESTAMP and TIMESTAMPTZ values are used as they are.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::InvalidFormat](crate::error::SpringError::InvalidFormat) when:
+    ///   - `value` does not match this format.
+    pub(crate) fn parse(&self, value: &NnSqlValue) -> Result<TimestampTz> {
+        match value {
+            NnSqlValue::Timestamp(ts) => return Ok(TimestampTz::from_timestamp(*ts, utc_offset())),
+            NnSqlValue::TimestampTz(ts) => return Ok(*ts),
+            _ => {}
+        }
+
+        let parsed = match self {
+            TimestampFormat::Pattern(pattern) => value
+                .unpack::<String>()
+                .map_err(|e| anyhow!("{:?}", e))
+                .and_then(|s| Self::parse_pattern(&s, pattern)),
+            TimestampFormat::EpochSecs => Self::parse_epoch(value, 1_000_000_000),
+            TimestampFormat::EpochMillis => Self::parse_epoch(value, 1_000_000),
+            TimestampFormat::EpochMicros => Self::parse_epoch(value, 1_000),
+        };
+        parsed
+            .with_context(|| format!("failed to parse timestamp in {} format", self))
+            .map_err(|e| SpringError::InvalidFormat {
+                s: value.to_string(),
+                source: e,
+            })
+    }
+
+    fn parse_pattern(s: &str, pattern: &str) -> anyhow::Result<TimestampTz> {
+        if let Ok(dt) = DateTime::parse_from_str(s, pattern) {
+            return Ok(TimestampTz::new(dt));
+        }
+        let ndt = NaiveDateTime::parse_from_str(s, pattern).or_else(|e| {
+            NaiveDate::parse_from_str(s, pattern)
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or(e)
+        })?;
+        Ok(TimestampTz::from_timestamp(
+            Timestamp::new(ndt),
+            utc_offset(),
+        ))
+    }
+
+    /// Integers (or their TEXT) and DOUBLE values in a unit of `nanos_per_unit` nanoseconds.
+    fn parse_epoch(value: &NnSqlValue, nanos_per_unit: i64) -> anyhow::Result<TimestampTz> {
+        let nanos = match value {
+            NnSqlValue::Float(_) | NnSqlValue::Double(_) | NnSqlValue::Decimal(_) => {
+                let units = value.unpack::<f64>().map_err(|e| anyhow!("{:?}", e))?;
+                let nanos = units * nanos_per_unit as f64;
+                if !nanos.is_finite() || nanos.abs() >= i64::MAX as f64 {
+                    return Err(anyhow!("epoch out of range"));
+                }
+                nanos as i64
+            }
+            _ => {
+                let units = match value {
+                    NnSqlValue::Text(s) => s.trim().parse::<i64>()?,
+                    _ => value.unpack::<i64>().map_err(|e| anyhow!("{:?}", e))?,
+                };
+                units
+                    .checked_mul(nanos_per_unit)
+                    .context("epoch out of range")?
+            }
+        };
+        let dt = Utc
+            .timestamp_opt(
+                nanos.div_euclid(1_000_000_000),
+                nanos.rem_euclid(1_000_000_000) as u32,
+            )
+            .single()
+            .context("epoch out of range")?;
+        Ok(TimestampTz::from_timestamp(
+            Timestamp::new(dt.naive_utc()),
+            utc_offset(),
+        ))
+    }
+}
+
+impl FromStr for TimestampFormat {
+    type Err = SpringError;
+
+    /// `EPOCH_SECS`, `EPOCH_MILLIS`, `EPOCH_MICROS` (case-insensitive) or a chrono strftime pattern.
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `s` is neither an epoch unit nor a valid pattern.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "EPOCH_SECS" => Ok(Self::EpochSecs),
+            "EPOCH_MILLIS" => Ok(Self::EpochMillis),
+            "EPOCH_MICROS" => Ok(Self::EpochMicros),
+            _ => validate_strftime(s).map(|_| Self::Pattern(s.to_string())),
+        }
+    }
+}
+
+/// String to be parsed by `FromStr`.
+impl Display for TimestampFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampFormat::Pattern(pattern) => write!(f, "{}", pattern),
+            TimestampFormat::EpochSecs => write!(f, "EPOCH_SECS"),
+            TimestampFormat::EpochMillis => write!(f, "EPOCH_MILLIS"),
+            TimestampFormat::EpochMicros => write!(f, "EPOCH_MICROS"),
+        }
+    }
+}
+
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `pattern` has an invalid chrono strftime specifier.
+pub(crate) fn validate_strftime(pattern: &str) -> Result<()> {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        Err(SpringError::Sql(anyhow!(
+            "invalid timestamp format `{}` (see chrono's strftime specifiers)",
+            pattern
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_timestamp_format_parse() -> Result<()> {
+        fn t(format: &str, value: NnSqlValue, expected: &str) {
+            let format: TimestampFormat = format.parse().unwrap();
+            assert_eq!(format.parse(&value).unwrap().to_string(), expected);
+        }
+        let text = |s: &str| NnSqlValue::Text(s.to_string());
+
+        t(
+            "%d/%m/%Y %H:%M:%S%z",
+            text("31/12/2019 18:30:00+0900"),
+            "2019-12-31 18:30:00.000000000+09:00",
+        );
+        t(
+            "%d/%m/%Y %H:%M",
+            text("31/12/2019 18:30"),
+            "2019-12-31 18:30:00.000000000+00:00",
+        );
+        t(
+            "%Y%m%d",
+            text("20191231"),
+            "2019-12-31 00:00:00.000000000+00:00",
+        );
+        t(
+            "epoch_secs",
+            NnSqlValue::BigInt(1577836800),
+            "2020-01-01 00:00:00.000000000+00:00",
+        );
+        t(
+            "EPOCH_MILLIS",
+            NnSqlValue::UnsignedBigInt(1577836800123),
+            "2020-01-01 00:00:00.123000000+00:00",
+        );
+        t(
+            "EPOCH_MILLIS",
+            text("-1"),
+            "1969-12-31 23:59:59.999000000+00:00",
+        );
+        t(
+            "EPOCH_MICROS",
+            NnSqlValue::BigInt(1577836800000001),
+            "2020-01-01 00:00:00.000001000+00:00",
+        );
+        t(
+            "EPOCH_SECS",
+            NnSqlValue::Double(OrderedFloat(1577836800.5)),
+            "2020-01-01 00:00:00.500000000+00:00",
+        );
+
+        let format: TimestampFormat = "%Y-%m-%d".parse()?;
+        assert!(matches!(
+            format.parse(&text("2019/12/31")),
+            Err(SpringError::InvalidFormat { .. })
+        ));
+        assert!(TimestampFormat::EpochSecs
+            .parse(&NnSqlValue::BigInt(i64::MAX))
+            .is_err());
+        assert!("%Y-%Q".parse::<TimestampFormat>().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Timestamp with time zone.
+
+use std::{fmt::Display, mem::size_of, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, FixedOffset, TimeZone};
+
+use super::{timestamp_format::validate_strftime, Timestamp};
+use crate::{
+    error::{Result, SpringError},
+    mem_size::{chrono_naive_date_time_overhead_size, MemSize},
+};
+
+const FORMAT_TZ: &str = "%Y-%m-%d %H:%M:%S%.9f%:z";
+const PARSE_FORMAT_TZ: &str = "%Y-%m-%d %H:%M:%S%.f%:z";
+
+/// Timestamp with a time zone offset (`TIMESTAMPTZ`).
+///
+/// Values are compared and hashed by the instants they point to. The offset is only used to show local time.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, new)]
+pub(crate) struct TimestampTz(DateTime<FixedOffset>);
+
+impl MemSize for TimestampTz {
+    fn mem_size(&self) -> usize {
+        chrono_naive_date_time_overhead_size() + size_of::<FixedOffset>()
+    }
+}
+
+impl TimestampTz {
+    /// `timestamp` (in UTC) seen from `offset`.
+    pub(crate) fn from_timestamp(timestamp: Timestamp, offset: FixedOffset) -> Self {
+        Self(offset.from_utc_datetime(timestamp.as_naive_date_time()))
+    }
+
+    /// Instant in UTC.
+    pub(crate) fn to_timestamp(self) -> Timestamp {
+        Timestamp::new(self.0.naive_utc())
+    }
+
+    pub(crate) fn as_date_time(&self) -> &DateTime<FixedOffset> {
+        &self.0
+    }
+
+    /// The same instant seen from another offset.
+    pub(crate) fn with_offset(self, offset: FixedOffset) -> Self {
+        Self(self.0.with_timezone(&offset))
+    }
+
+    /// Formats local time by a chrono strftime pattern (`%Y-%m-%d %H:%M`, for example).
+    ///
+    /// # Failures
+    ///
+    /// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+    ///   - `pattern` has an invalid specifier.
+    pub(crate) fn format(&self, pattern: &str) -> Result<String> {
+        validate_strftime(pattern)?;
+        Ok(self.0.format(pattern).to_string())
+    }
+}
+
+impl FromStr for TimestampTz {
+    type Err = SpringError;
+
+    /// Parse as RFC-3339 or `"%Y-%m-%d %H:%M:%S%.9f%:z"` format.
+    /// Strings without offset are parsed as [Timestamp](super::Timestamp) in UTC.
+    fn from_str(s: &str) -> Result<Self> {
+        DateTime::parse_from_rfc3339(s)
+            .or_else(|_| DateTime::parse_from_str(s, PARSE_FORMAT_TZ))
+            .map(Self)
+            .or_else(|_| Timestamp::from_str(s).map(|ts| Self::from_timestamp(ts, utc_offset())))
+    }
+}
+
+impl Display for TimestampTz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(FORMAT_TZ))
+    }
+}
+
+pub(crate) fn utc_offset() -> FixedOffset {
+    FixedOffset::east_opt(0).expect("zero offset is valid")
+}
+
+/// Parse a time zone into a fixed offset.
+///
+/// Accepts `UTC`, `GMT`, `Z` and offsets like `+09:00`, `-0530` or `+09` (optionally prefixed by `UTC` or `GMT`).
+/// Named zones like `Asia/Tokyo` are not supported as no time zone database is bundled.
+///
+/// # Failures
+///
+/// - [SpringError::Sql](crate::error::SpringError::Sql) when:
+///   - `zone` is not one of the above.
+pub(crate) fn parse_time_zone(zone: &str) -> Result<FixedOffset> {
+    let upper = zone.trim().to_ascii_uppercase();
+    let offset_str = upper
+        .strip_prefix("UTC")
+        .or_else(|| upper.strip_prefix("GMT"))
+        .unwrap_or(&upper);
+
+    if offset_str.is_empty() || offset_str == "Z" {
+        return Ok(utc_offset());
+    }
+
+    parse_offset(offset_str)
+        .with_context(|| {
+            format!(
+                "invalid time zone `{}`: only UTC and fixed offsets like '+09:00' are supported",
+                zone
+            )
+        })
+        .map_err(SpringError::Sql)
+}
+
+fn parse_offset(s: &str) -> anyhow::Result<FixedOffset> {
+    let (sign, digits) = if let Some(digits) = s.strip_prefix('+') {
+        (1, digits)
+    } else if let Some(digits) = s.strip_prefix('-') {
+        (-1, digits)
+    } else {
+        return Err(anyhow!("offset must start with + or -"));
+    };
+    if !digits.is_ascii() {
+        return Err(anyhow!("malformed offset"));
+    }
+    let (hours, minutes) = match (digits.len(), digits.split_once(':')) {
+        (_, Some((h, m))) => (h, m),
+        (4, None) => digits.split_at(2),
+        (1 | 2, None) => (digits, "0"),
+        _ => return Err(anyhow!("malformed offset")),
+    };
+    let (hours, minutes): (i32, i32) = (hours.parse()?, minutes.parse()?);
+    if hours > 23 || minutes > 59 {
+        return Err(anyhow!("offset out of range"));
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).context("offset out of range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_time_zone() {
+        let east = |secs| FixedOffset::east_opt(secs).unwrap();
+
+        assert_eq!(parse_time_zone("UTC").unwrap(), east(0));
+        assert_eq!(parse_time_zone("z").unwrap(), east(0));
+        assert_eq!(parse_time_zone("+09:00").unwrap(), east(9 * 3600));
+        assert_eq!(parse_time_zone("UTC+9").unwrap(), east(9 * 3600));
+        assert_eq!(
+            parse_time_zone("-0530").unwrap(),
+            east(-(5 * 3600 + 30 * 60))
+        );
+
+        assert!(parse_time_zone("Asia/Tokyo").is_err());
+        assert!(parse_time_zone("+24:00").is_err());
+        assert!(parse_time_zone("+9:0:0").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_tz_parse_display() -> Result<()> {
+        let tz: TimestampTz = "2020-01-01T09:12:34.5+09:00".parse()?;
+        assert_eq!(tz.to_string(), "2020-01-01 09:12:34.500000000+09:00");
+        assert_eq!(
+            tz.to_timestamp(),
+            "2020-01-01 00:12:34.500000000".parse::<Timestamp>()?
+        );
+
+        // the same instant in different offsets
+        let utc: TimestampTz = "2020-01-01 00:12:34.500000000".parse()?;
+        assert_eq!(utc.to_string(), "2020-01-01 00:12:34.500000000+00:00");
+        assert_eq!(tz, utc);
+        assert_eq!(tz.with_offset(utc_offset()).to_string(), utc.to_string());
+
+        assert_eq!(tz.format("%H:%M %z")?, "09:12 +0900");
+        assert!(tz.format("%Q").is_err());
+
+        Ok(())
+    }
+}
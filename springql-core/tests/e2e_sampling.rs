@@ -329,3 +329,460 @@ fn test_e2e_sampling_pump_insert_to_window_queue() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_e2e_sampling_rows_window() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "ticker": "IBM", "amount": 50}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "ticker": "IBM", "amount": 70}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "ticker": "IBM", "amount": 90}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_sampled_trade_amount (ts, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          ROWS WINDOW 2;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_sampled_trade_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(&ddls, &test_sink);
+
+    // 00:10 group has only 1 row and its pane is not closed.
+    assert_eq!(sink_received.len(), 2);
+
+    let r = &sink_received[0];
+    assert_eq!(r["ts"].as_str().unwrap(), "2020-01-01 00:00:00.000000000");
+    assert_eq!(r["amount"].as_f64().unwrap().round() as i32, 20,);
+
+    let r = &sink_received[1];
+    assert_eq!(r["ts"].as_str().unwrap(), "2020-01-01 00:00:00.000000000");
+    assert_eq!(r["amount"].as_f64().unwrap().round() as i32, 60,);
+
+    Ok(())
+}
+
+#[test]
+fn test_e2e_sampling_window_bounds() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:09.000000000", "ticker": "GOOGL", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "ticker": "IBM", "amount": 50}),
+        json!({"ts": "2020-01-01 00:00:20.000000000", "ticker": "IBM", "amount": 70}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          w_start TIMESTAMP NOT NULL,
+          w_end TIMESTAMP NOT NULL,
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_sampled_trade_amount (ts, w_start, w_end, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            WINDOW_START(),
+            WINDOW_END(),
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_sampled_trade_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(&ddls, &test_sink);
+
+    assert_eq!(sink_received.len(), 2);
+
+    let r = &sink_received[0];
+    assert_eq!(r["ts"].as_str().unwrap(), "2020-01-01 00:00:00.000000000");
+    assert_eq!(
+        r["w_start"].as_str().unwrap(),
+        "2020-01-01 00:00:00.000000000"
+    );
+    assert_eq!(
+        r["w_end"].as_str().unwrap(),
+        "2020-01-01 00:00:10.000000000"
+    );
+    assert_eq!(r["amount"].as_f64().unwrap().round() as i32, 20);
+
+    let r = &sink_received[1];
+    assert_eq!(r["ts"].as_str().unwrap(), "2020-01-01 00:00:10.000000000");
+    assert_eq!(
+        r["w_start"].as_str().unwrap(),
+        "2020-01-01 00:00:10.000000000"
+    );
+    assert_eq!(
+        r["w_end"].as_str().unwrap(),
+        "2020-01-01 00:00:20.000000000"
+    );
+    assert_eq!(r["amount"].as_f64().unwrap().round() as i32, 50);
+
+    Ok(())
+}
+
+#[test]
+fn test_e2e_sampling_emit_every_rows() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:09.000000000", "ticker": "GOOGL", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "ticker": "IBM", "amount": 50}),
+        json!({"ts": "2020-01-01 00:00:20.000000000", "ticker": "IBM", "amount": 70}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          timing TEXT NOT NULL,
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_sampled_trade_amount (ts, timing, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            EMIT_TIMING(),
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+          EMIT EVERY 1 ROWS;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_sampled_trade_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(&ddls, &test_sink);
+
+    let received = sink_received
+        .iter()
+        .map(|r| {
+            (
+                r["ts"].as_str().unwrap().to_string(),
+                r["timing"].as_str().unwrap().to_string(),
+                r["amount"].as_f64().unwrap().round() as i32,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let t00 = "2020-01-01 00:00:00.000000000".to_string();
+    let t10 = "2020-01-01 00:00:10.000000000".to_string();
+    let t20 = "2020-01-01 00:00:20.000000000".to_string();
+    assert_eq!(
+        received,
+        vec![
+            (t00.clone(), "EARLY".to_string(), 10),
+            (t00.clone(), "EARLY".to_string(), 20),
+            (t00, "FINAL".to_string(), 20),
+            (t10.clone(), "EARLY".to_string(), 50),
+            (t10, "FINAL".to_string(), 50),
+            (t20, "EARLY".to_string(), 70),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_e2e_sampling_idle_timeout() -> Result<()> {
+    setup_test_logger();
+
+    // no more rows to close the pane [.000, .100)
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:00.050000000", "ticker": "GOOGL", "amount": 30}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_sampled_trade_amount (ts, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_MILLIS(100)) AS sampled_ts,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_MILLIS(100), DURATION_SECS(0)
+          IDLE TIMEOUT DURATION_MILLIS(100);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_sampled_trade_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(&ddls, &test_sink);
+
+    assert_eq!(sink_received.len(), 1);
+    let r = &sink_received[0];
+    assert_eq!(r["ts"].as_str().unwrap(), "2020-01-01 00:00:00.000000000");
+    assert_eq!(r["amount"].as_f64().unwrap().round() as i32, 20);
+
+    Ok(())
+}
+
+#[test]
+fn test_e2e_sampling_processing_time_window() -> Result<()> {
+    setup_test_logger();
+
+    // ROWTIMEs are far in the past but processing-time window does not care.
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:10:00.000000000", "ticker": "ORCL", "amount": 30}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        ) ROWTIME EVENT_TIME ts;
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_avg_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_avg AS
+          INSERT INTO sink_avg_amount (ts, ticker, amount)
+          SELECT STREAM
+            WINDOW_START(),
+            source_trade.ticker AS ticker,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY ticker
+          FIXED WINDOW DURATION_MILLIS(200), DURATION_SECS(0) PROCESSING_TIME
+          IDLE TIMEOUT DURATION_MILLIS(100);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_avg_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(&ddls, &test_sink);
+
+    // both rows are in the same pane (unless they arrive across a 200-millisecond boundary).
+    let amounts = sink_received
+        .iter()
+        .map(|r| r["amount"].as_f64().unwrap().round() as i32)
+        .collect::<Vec<_>>();
+    assert!(amounts == vec![20] || amounts == vec![10, 30]);
+
+    let r = &sink_received[0];
+    assert_eq!(r["ticker"].as_str().unwrap(), "ORCL");
+    assert!(
+        !r["ts"].as_str().unwrap().starts_with("2020-"),
+        "pane is in processing time"
+    );
+
+    Ok(())
+}
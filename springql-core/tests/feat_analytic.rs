@@ -0,0 +1,119 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::low_level_rs::*;
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_trade() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "ticker": "ORCL", "amount": 20}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "ticker": "ORCL", "amount": 40}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "ticker": "GOOGL", "amount": 50}),
+    ]
+}
+
+#[test]
+fn test_feat_analytic_functions() {
+    setup_test_logger();
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_trade())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_analytic (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          row_num INTEGER NOT NULL,
+          prev_amount INTEGER,
+          moving_sum INTEGER NOT NULL,
+          total_avg DOUBLE NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_analytic AS
+          INSERT INTO sink_analytic (ts, ticker, row_num, prev_amount, moving_sum, total_avg)
+          SELECT STREAM
+            source_trade.ts,
+            source_trade.ticker,
+            ROW_NUMBER() OVER (PARTITION BY source_trade.ticker ORDER BY ROWTIME),
+            LAG(source_trade.amount) OVER (PARTITION BY source_trade.ticker ORDER BY ROWTIME),
+            SUM(source_trade.amount) OVER (PARTITION BY source_trade.ticker ORDER BY ROWTIME ROWS 1 PRECEDING) AS moving_sum,
+            AVG(source_trade.amount) OVER (PARTITION BY source_trade.ticker) AS total_avg
+          FROM source_trade;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_analytic FOR sink_analytic
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|r| r["ts"].as_str().unwrap().to_string());
+
+    let rows = sink_received
+        .iter()
+        .map(|r| {
+            (
+                r["ticker"].as_str().unwrap(),
+                r["row_num"].as_i64().unwrap(),
+                r["prev_amount"].as_i64(),
+                r["moving_sum"].as_i64().unwrap(),
+                r["total_avg"].as_f64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        rows,
+        vec![
+            ("ORCL", 1, None, 10, 10.0),
+            ("GOOGL", 1, None, 30, 30.0),
+            ("ORCL", 2, Some(10), 30, 15.0),
+            ("ORCL", 3, Some(20), 60, 70.0 / 3.0),
+            ("GOOGL", 2, Some(30), 80, 40.0),
+        ]
+    );
+}
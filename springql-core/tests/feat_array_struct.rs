@@ -0,0 +1,250 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+fn source_ddl() -> String {
+    "
+    CREATE SOURCE STREAM source_device (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      device_id TEXT NOT NULL,
+      tags ARRAY<TEXT>,
+      position STRUCT<lat DOUBLE, lon DOUBLE, meta STRUCT<level INTEGER>>
+    );
+    "
+    .to_string()
+}
+
+fn source_reader_ddl(test_source: &ForeignSource) -> String {
+    format!(
+        "
+        CREATE SOURCE READER tcp_device FOR source_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+        remote_host = test_source.host_ip(),
+        remote_port = test_source.port()
+    )
+}
+
+fn sink_writer_ddl(test_sink: &ForeignSink) -> String {
+    format!(
+        "
+        CREATE SINK WRITER tcp_sink_device FOR sink_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+        remote_host = test_sink.host_ip(),
+        remote_port = test_sink.port()
+    )
+}
+
+#[test]
+fn test_feat_element_access() {
+    setup_test_logger();
+
+    let json1 = json!({
+        "ts": "2020-01-01 00:00:00.000000000",
+        "device_id": "d1",
+        "tags": ["indoor", "beta"],
+        "position": {"lon": 139.76, "lat": 35.68, "meta": {"level": 3}, "unknown": true},
+    });
+    let source_input = vec![json1];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        source_ddl(),
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          tags ARRAY<TEXT>,
+          first_tag TEXT,
+          third_tag TEXT,
+          n_tags BIGINT,
+          lat DOUBLE,
+          level INTEGER,
+          position STRUCT<lat DOUBLE, lon DOUBLE, meta STRUCT<level INTEGER>>
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_access AS
+          INSERT INTO sink_device (ts, tags, first_tag, third_tag, n_tags, lat, level, position)
+          SELECT STREAM
+            ts,
+            tags,
+            tags[1],
+            tags[3],
+            CARDINALITY(tags),
+            position.lat,
+            source_device.position.meta.level,
+            position
+          FROM source_device;
+        "
+        .to_string(),
+        sink_writer_ddl(&test_sink),
+        source_reader_ddl(&test_source),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+    let r = sink_received.get(0).unwrap();
+
+    assert_eq!(r["tags"], json!(["indoor", "beta"]));
+    assert_eq!(r["first_tag"], json!("indoor"));
+    assert_eq!(r["third_tag"], json!(null));
+    assert_eq!(r["n_tags"], json!(2));
+    assert_eq!(r["lat"], json!(35.68));
+    assert_eq!(r["level"], json!(3));
+    // undeclared field is dropped
+    assert_eq!(
+        r["position"],
+        json!({"lat": 35.68, "lon": 139.76, "meta": {"level": 3}})
+    );
+}
+
+#[test]
+fn test_feat_unnest() {
+    setup_test_logger();
+
+    let json1 = json!({
+        "ts": "2020-01-01 00:00:00.000000000",
+        "device_id": "d1",
+        "tags": ["indoor", "beta"],
+        "position": null,
+    });
+    let json2 = json!({
+        "ts": "2020-01-01 00:00:01.000000000",
+        "device_id": "d2",
+        "tags": [],
+        "position": null,
+    });
+    let json3 = json!({
+        "ts": "2020-01-01 00:00:02.000000000",
+        "device_id": "d3",
+        "tags": ["outdoor"],
+        "position": null,
+    });
+    let source_input = vec![json1, json2, json3];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        source_ddl(),
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          device_id TEXT NOT NULL,
+          tag TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_unnest AS
+          INSERT INTO sink_device (ts, device_id, tag)
+          SELECT STREAM ts, device_id, UNNEST(tags) FROM source_device;
+        "
+        .to_string(),
+        sink_writer_ddl(&test_sink),
+        source_reader_ddl(&test_source),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+
+    let rows = sink_received
+        .iter()
+        .map(|r| {
+            (
+                r["device_id"].as_str().unwrap().to_string(),
+                r["tag"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ("d1".to_string(), "indoor".to_string()),
+            ("d1".to_string(), "beta".to_string()),
+            ("d3".to_string(), "outdoor".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_array_struct_errors() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(&pipeline, &source_ddl()).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          answer TEXT
+        );
+        ",
+    )
+    .unwrap();
+
+    let create_pump = |select_fields: &str| {
+        spring_command(
+            &pipeline,
+            &format!(
+                "
+                CREATE PUMP pu_err AS
+                  INSERT INTO sink_device (ts, answer)
+                  SELECT STREAM ts, {} FROM source_device;
+                ",
+                select_fields
+            ),
+        )
+    };
+
+    // subscript to non-ARRAY
+    assert!(matches!(
+        create_pump("device_id[1]"),
+        Err(SpringError::Sql(_))
+    ));
+    // unknown field
+    assert!(matches!(
+        create_pump("position.alt"),
+        Err(SpringError::Sql(_))
+    ));
+    // ARRAY cannot be inserted into TEXT
+    assert!(matches!(create_pump("tags"), Err(SpringError::Sql(_))));
+    // UNNEST inside an expression
+    assert!(matches!(
+        create_pump("CARDINALITY(UNNEST(tags))"),
+        Err(SpringError::Sql(_))
+    ));
+    // STRUCT field declared twice
+    assert!(matches!(
+        spring_command(
+            &pipeline,
+            "CREATE STREAM s (ts TIMESTAMP NOT NULL ROWTIME, p STRUCT<a INTEGER, a TEXT>);",
+        ),
+        Err(SpringError::Sql(_))
+    ));
+}
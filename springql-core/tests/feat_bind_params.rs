@@ -0,0 +1,365 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use chrono::{FixedOffset, NaiveDate, TimeZone};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, high_level_rs::SpringPipelineHL, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn create_streams(pipeline: &SpringPipelineHL) {
+    pipeline
+        .command(
+            "
+            CREATE SOURCE STREAM source_trade (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              ticker TEXT NOT NULL,
+              amount INTEGER NOT NULL
+            );
+            ",
+        )
+        .unwrap();
+    pipeline
+        .command(
+            "
+            CREATE SINK STREAM sink_trade (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              ticker TEXT NOT NULL,
+              amount INTEGER NOT NULL
+            );
+            ",
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_feat_bind_params() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 30}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let pipeline = SpringPipelineHL::new(&spring_config_default()).unwrap();
+    create_streams(&pipeline);
+
+    pipeline
+        .command_with_params(
+            "
+            CREATE PUMP pu_multiply AS
+              INSERT INTO sink_trade (ts, ticker, amount)
+              SELECT STREAM ts, ticker, amount * :factor FROM source_trade;
+            ",
+            &SpringParams::default().bind(":factor", SpringValue::Integer(3)),
+        )
+        .unwrap();
+    pipeline
+        .command_with_params(
+            "
+            CREATE SINK WRITER tcp_sink_trade FOR sink_trade
+              TYPE NET_CLIENT OPTIONS (
+                PROTOCOL 'TCP',
+                REMOTE_HOST $1,
+                REMOTE_PORT $2
+              );
+            ",
+            &SpringParams::default()
+                .bind("$1", SpringValue::Text(test_sink.host_ip().to_string()))
+                .bind("$2", SpringValue::Integer(test_sink.port() as i32)),
+        )
+        .unwrap();
+    pipeline
+        .command_with_params(
+            "
+            CREATE SOURCE READER tcp_trade FOR source_trade
+              TYPE NET_CLIENT OPTIONS (
+                PROTOCOL 'TCP',
+                REMOTE_HOST :host,
+                REMOTE_PORT :port
+              );
+            ",
+            &SpringParams::default()
+                .bind(
+                    ":host",
+                    SpringValue::Text(test_source.host_ip().to_string()),
+                )
+                .bind(":port", SpringValue::Integer(test_source.port() as i32)),
+        )
+        .unwrap();
+
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|row| row["ts"].to_string());
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 30}),
+            json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 90}),
+        ]
+    );
+
+    let ddls = pipeline.export_ddl().unwrap();
+    assert!(ddls
+        .iter()
+        .any(|ddl| ddl.contains("SELECT STREAM ts, ticker, amount * 3 FROM source_trade")));
+    assert!(ddls
+        .iter()
+        .any(|ddl| ddl.contains(&format!("REMOTE_PORT '{}'", test_source.port()))));
+}
+
+#[test]
+fn test_feat_bind_params_errors() {
+    setup_test_logger();
+
+    let pipeline = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    create_streams(&pipeline);
+
+    let pump_sql = "
+        CREATE PUMP pu_multiply AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount * $1 FROM source_trade;
+        ";
+
+    // placeholder is not bound
+    let res = pipeline.command(pump_sql);
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // parameter does not appear in SQL
+    let res = pipeline.command_with_params(
+        pump_sql,
+        &SpringParams::default()
+            .bind("$1", SpringValue::Integer(3))
+            .bind("$2", SpringValue::Integer(4)),
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // INTEGER * TEXT
+    let res = pipeline.command_with_params(
+        pump_sql,
+        &SpringParams::default().bind("$1", SpringValue::Text("3".to_string())),
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    let sink_writer_sql = "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '127.0.0.1',
+            REMOTE_PORT $1
+          );
+        ";
+
+    // not a port number
+    let res = pipeline.command_with_params(
+        sink_writer_sql,
+        &SpringParams::default().bind("$1", SpringValue::Text("http".to_string())),
+    );
+    assert!(matches!(res, Err(SpringError::InvalidOption { .. })));
+
+    // option values must be TEXT or integers
+    let res = pipeline.command_with_params(
+        sink_writer_sql,
+        &SpringParams::default().bind("$1", SpringValue::Float(17890.0)),
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // `'` is a part of the option value
+    let res = pipeline.command_with_params(
+        sink_writer_sql,
+        &SpringParams::default().bind("$1", SpringValue::Text("17890', X 'y".to_string())),
+    );
+    assert!(matches!(res, Err(SpringError::InvalidOption { .. })));
+}
+
+#[test]
+fn test_feat_bind_params_typed_literals() {
+    setup_test_logger();
+
+    let source_input =
+        vec![json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10})];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let pipeline = SpringPipelineHL::new(&spring_config_default()).unwrap();
+    create_streams(&pipeline);
+    pipeline
+        .command(
+            "
+            CREATE SINK STREAM sink_bound (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              v_int INTEGER NOT NULL,
+              v_ubig BIGINT UNSIGNED NOT NULL,
+              v_float FLOAT NOT NULL,
+              v_double DOUBLE NOT NULL,
+              v_decimal DECIMAL(38, 2) NOT NULL,
+              v_ts TIMESTAMP NOT NULL,
+              v_tstz TIMESTAMPTZ NOT NULL,
+              v_blob BLOB NOT NULL,
+              v_array ARRAY<DOUBLE> NOT NULL,
+              v_struct STRUCT<name TEXT, at TIMESTAMP> NOT NULL
+            );
+            ",
+        )
+        .unwrap();
+
+    let ts = NaiveDate::from_ymd(2020, 1, 1).and_hms_nano(0, 0, 0, 1);
+    let tstz = FixedOffset::east(9 * 3600)
+        .from_local_datetime(&ts)
+        .unwrap();
+    pipeline
+        .command_with_params(
+            "
+            CREATE PUMP pu_bound AS
+              INSERT INTO sink_bound (ts, v_int, v_ubig, v_float, v_double, v_decimal, v_ts, v_tstz, v_blob, v_array, v_struct)
+              SELECT STREAM ts, $1, $2, $3, $4, $5, $6, $7, $8, $9, $10 FROM source_trade;
+            ",
+            &SpringParams::default()
+                .bind("$1", SpringValue::Integer(-3))
+                .bind("$2", SpringValue::UnsignedBigInt(u64::MAX))
+                .bind("$3", SpringValue::Float(-1.5))
+                .bind("$4", SpringValue::Double(0.1))
+                .bind(
+                    "$5",
+                    SpringValue::Decimal {
+                        mantissa: -150,
+                        scale: 2,
+                    },
+                )
+                .bind("$6", SpringValue::Timestamp(ts))
+                .bind("$7", SpringValue::TimestampTz(tstz))
+                .bind("$8", SpringValue::Blob(vec![0x00, 0xff]))
+                .bind(
+                    "$9",
+                    SpringValue::Array(vec![SpringValue::Double(1.5), SpringValue::Null]),
+                )
+                .bind(
+                    "$10",
+                    SpringValue::Struct(vec![
+                        ("name".to_string(), SpringValue::Text("O'RCL".to_string())),
+                        ("at".to_string(), SpringValue::Timestamp(ts)),
+                    ]),
+                ),
+        )
+        .unwrap();
+    pipeline
+        .command(format!(
+            "
+            CREATE SINK WRITER tcp_sink_bound FOR sink_bound
+              TYPE NET_CLIENT OPTIONS (
+                PROTOCOL 'TCP',
+                REMOTE_HOST '{remote_host}',
+                REMOTE_PORT '{remote_port}'
+              );
+            ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ))
+        .unwrap();
+    pipeline
+        .command(format!(
+            "
+            CREATE SOURCE READER tcp_trade FOR source_trade
+              TYPE NET_CLIENT OPTIONS (
+                PROTOCOL 'TCP',
+                REMOTE_HOST '{remote_host}',
+                REMOTE_PORT '{remote_port}'
+              );
+            ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ))
+        .unwrap();
+
+    let sink_received = drain_from_sink(&test_sink);
+    assert_eq!(
+        sink_received,
+        vec![json!({
+            "ts": "2020-01-01 00:00:00.000000000",
+            "v_int": -3,
+            "v_ubig": u64::MAX,
+            "v_float": -1.5,
+            "v_double": 0.1,
+            "v_decimal": "-1.50",
+            "v_ts": "2020-01-01 00:00:00.000000001",
+            "v_tstz": "2020-01-01 00:00:00.000000001+09:00",
+            "v_blob": "AP8=",
+            "v_array": [1.5, null],
+            "v_struct": {"name": "O'RCL", "at": "2020-01-01 00:00:00.000000001"},
+        })]
+    );
+
+    // readers and writers would connect to the foreign services again
+    let ddls = pipeline
+        .export_ddl()
+        .unwrap()
+        .into_iter()
+        .filter(|ddl| !ddl.contains("NET_CLIENT"))
+        .collect::<Vec<_>>();
+    assert!(ddls.iter().any(|ddl| ddl.contains("DOUBLE '0.1'")));
+
+    let recreated = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    for ddl in &ddls {
+        recreated.command(ddl).unwrap();
+    }
+    assert_eq!(recreated.export_ddl().unwrap(), ddls);
+}
+
+#[test]
+fn test_feat_bind_params_quote() {
+    setup_test_logger();
+
+    let pipeline = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    create_streams(&pipeline);
+
+    pipeline
+        .command_with_params(
+            "
+            CREATE PUMP pu_rename AS
+              INSERT INTO sink_trade (ts, ticker, amount)
+              SELECT STREAM ts, :ticker, amount FROM source_trade;
+            ",
+            &SpringParams::default().bind(":ticker", SpringValue::Text("O'RCL".to_string())),
+        )
+        .unwrap();
+    pipeline
+        .command_with_params(
+            "
+            CREATE SINK WRITER queue_trade FOR sink_trade
+              TYPE IN_MEMORY_QUEUE OPTIONS (
+                NAME $1
+              );
+            ",
+            &SpringParams::default().bind("$1", SpringValue::Text("q'trade".to_string())),
+        )
+        .unwrap();
+
+    let ddls = pipeline.export_ddl().unwrap();
+    assert!(ddls
+        .iter()
+        .any(|ddl| ddl.contains("SELECT STREAM ts, 'O''RCL', amount FROM source_trade")));
+    assert!(ddls.iter().any(|ddl| ddl.contains("NAME 'q''trade'")));
+
+    // in-memory queue names are global
+    let ddls = ddls
+        .iter()
+        .map(|ddl| ddl.replace("q''trade", "q''trade_2"))
+        .collect::<Vec<_>>();
+
+    let recreated = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    for ddl in &ddls {
+        recreated.command(ddl).unwrap();
+    }
+    assert_eq!(recreated.export_ddl().unwrap(), ddls);
+}
@@ -0,0 +1,167 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_decode_can_frame() {
+    setup_test_logger();
+
+    // frame: [0x34, 0x12, 0xab, 0x00] in base64
+    let json1 = json!({
+        "ts": "2020-01-01 00:00:00.000000000",
+        "can_id": 256,
+        "frame": "NBKrAA==",
+    });
+    let source_input = vec![json1];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          can_id INTEGER NOT NULL,
+          frame BYTEA NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          frame BLOB NOT NULL,
+          frame_hex TEXT NOT NULL,
+          frame_base64 TEXT NOT NULL,
+          frame_len BIGINT NOT NULL,
+          speed_raw BIGINT NOT NULL,
+          status BIGINT NOT NULL,
+          flag BIGINT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_decode AS
+          INSERT INTO sink_can (ts, frame, frame_hex, frame_base64, frame_len, speed_raw, status, flag)
+          SELECT STREAM
+            ts,
+            frame,
+            HEX(frame),
+            BASE64(frame),
+            LENGTH(frame),
+            GET_BITS(frame, 0, 16),
+            GET_BYTE(frame, 2),
+            GET_BITS(frame, 17, 1)
+          FROM source_can;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_can FOR sink_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_can FOR source_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+    let r = sink_received.get(0).unwrap();
+
+    // BLOB values are written as base64 JSON strings.
+    assert_eq!(r["frame"], json!("NBKrAA=="));
+    assert_eq!(r["frame_hex"], json!("3412AB00"));
+    assert_eq!(r["frame_base64"], json!("NBKrAA=="));
+    assert_eq!(r["frame_len"], json!(4));
+    assert_eq!(r["speed_raw"], json!(0x1234));
+    assert_eq!(r["status"], json!(0xab));
+    assert_eq!(r["flag"], json!(1));
+}
+
+#[test]
+fn test_feat_blob_function_errors() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SOURCE STREAM source_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          can_id INTEGER NOT NULL,
+          frame BLOB NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          answer TEXT NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+
+    // HEX takes BLOB
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_hex AS
+          INSERT INTO sink_can (ts, answer)
+          SELECT STREAM ts, HEX(can_id) FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // BLOB cannot be inserted into TEXT
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_blob AS
+          INSERT INTO sink_can (ts, answer)
+          SELECT STREAM ts, frame FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // GET_BITS takes (BLOB, offset, len)
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_bits AS
+          INSERT INTO sink_can (ts, answer)
+          SELECT STREAM ts, GET_BITS(frame, 0) FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
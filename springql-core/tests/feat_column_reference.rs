@@ -0,0 +1,144 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_trade() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 20}),
+    ]
+}
+
+const SOURCE_TRADE_DDL: &str = "
+    CREATE SOURCE STREAM source_trade (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      ticker TEXT NOT NULL,
+      amount INTEGER NOT NULL
+    );
+";
+
+const SINK_TRADE_DDL: &str = "
+    CREATE SINK STREAM sink_trade (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      ticker TEXT NOT NULL,
+      amount INTEGER NOT NULL
+    );
+";
+
+fn run_pump(pump_ddl: &str) -> Vec<serde_json::Value> {
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_trade())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        SOURCE_TRADE_DDL.to_string(),
+        SINK_TRADE_DDL.to_string(),
+        pump_ddl.to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    drain_from_sink(&test_sink)
+}
+
+#[test]
+fn test_feat_select_wildcard() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM * FROM source_trade;
+        ",
+    );
+    assert_eq!(sink_received, gen_source_trade());
+}
+
+#[test]
+fn test_feat_unqualified_column_reference() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE PUMP pu_projection AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, source_trade.ticker, amount + 1 FROM source_trade;
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 11}),
+            json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 21}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_ambiguous_column_reference() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in [
+        SOURCE_TRADE_DDL,
+        SINK_TRADE_DDL,
+        "
+        CREATE SOURCE STREAM source_city_temperature (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          city TEXT NOT NULL,
+          temperature INTEGER NOT NULL
+        );
+        ",
+    ] {
+        spring_command(&pipeline, ddl).unwrap();
+    }
+
+    // both streams have `ts`
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_join AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, temperature
+          FROM source_trade
+          LEFT OUTER JOIN source_city_temperature
+          ON source_trade.ts = source_city_temperature.ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
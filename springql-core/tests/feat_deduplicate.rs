@@ -0,0 +1,161 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_can() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "frame_id": 1, "payload": "a"}),
+        // resent
+        json!({"ts": "2020-01-01 00:00:01.000000000", "frame_id": 1, "payload": "a"}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "frame_id": 2, "payload": "b"}),
+        // resent
+        json!({"ts": "2020-01-01 00:00:04.000000000", "frame_id": 1, "payload": "a"}),
+        // 5 seconds after the first frame 1
+        json!({"ts": "2020-01-01 00:00:05.000000000", "frame_id": 1, "payload": "c"}),
+        // next pane of fixed window
+        json!({"ts": "2020-01-01 00:00:10.000000000", "frame_id": 2, "payload": "b"}),
+    ]
+}
+
+const SOURCE_CAN_DDL: &str = "
+    CREATE SOURCE STREAM source_can (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      frame_id INTEGER NOT NULL,
+      payload TEXT NOT NULL
+    );
+";
+
+fn run_pump(pump_ddl: &str) -> Vec<serde_json::Value> {
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_can())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        SOURCE_CAN_DDL.to_string(),
+        "
+        CREATE SINK STREAM sink_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          frame_id INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        pump_ddl.to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_can FOR sink_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_can FOR source_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+
+    // rows emitted for different input rows may arrive at the sink out of order
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|row| (row["ts"].to_string(), row["frame_id"].to_string()));
+    sink_received
+}
+
+#[test]
+fn test_feat_deduplicate_by_key() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE PUMP pu_dedup AS
+          INSERT INTO sink_can (ts, frame_id)
+          SELECT STREAM ts, frame_id
+          FROM source_can
+          DEDUPLICATE BY frame_id, payload WITHIN DURATION_SECS(5);
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "frame_id": 1}),
+            json!({"ts": "2020-01-01 00:00:02.000000000", "frame_id": 2}),
+            json!({"ts": "2020-01-01 00:00:05.000000000", "frame_id": 1}),
+            json!({"ts": "2020-01-01 00:00:10.000000000", "frame_id": 2}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_select_distinct_in_window() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE PUMP pu_distinct AS
+          INSERT INTO sink_can (ts, frame_id)
+          SELECT STREAM DISTINCT
+            FLOOR_TIME(ts, DURATION_SECS(10)),
+            frame_id
+          FROM source_can
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "frame_id": 1}),
+            json!({"ts": "2020-01-01 00:00:00.000000000", "frame_id": 2}),
+            json!({"ts": "2020-01-01 00:00:10.000000000", "frame_id": 2}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_select_distinct_without_window() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(&pipeline, SOURCE_CAN_DDL).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_can (
+          frame_id INTEGER NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_distinct AS
+          INSERT INTO sink_can (frame_id)
+          SELECT STREAM DISTINCT frame_id FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
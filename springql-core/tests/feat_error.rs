@@ -14,3 +14,31 @@ fn test_api_guidelines_c_good_err() {
     fn assert_display<T: Display>() {}
     assert_display::<SpringError>();
 }
+
+#[test]
+fn test_sql_syntax_error() {
+    use springql_core::low_level_rs::*;
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+
+    let res = spring_command(
+        &pipeline,
+        "CREATE SOURCE STREAM source_trade (
+  ts TIMESTAMP NOT NULL ROWTIME,
+  amount INTEGR
+);",
+    );
+    match res {
+        Err(SpringError::SqlSyntax {
+            line,
+            column,
+            snippet,
+            expected,
+        }) => {
+            assert_eq!((line, column), (3, 10));
+            assert_eq!(snippet, "INTEGR");
+            assert_eq!(expected, vec!["data_type"]);
+        }
+        res => panic!("syntax error is expected but got {:?}", res),
+    }
+}
@@ -0,0 +1,147 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_test_logger::setup_test_logger;
+
+fn open_pipeline(queue_name: &str) -> SpringPipeline {
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in [
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE STREAM trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_avg (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount FROM source_trade;
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_sink AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount FROM trade;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER queue_sink_trade FOR sink_trade
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME '{}'
+          );
+        ",
+            queue_name
+        ),
+    ] {
+        spring_command(&pipeline, &ddl).unwrap();
+    }
+    pipeline
+}
+
+#[test]
+fn test_feat_explain_create_pump() {
+    setup_test_logger();
+    let pipeline = open_pipeline("q_explain_create_pump");
+
+    let explained = spring_explain(
+        &pipeline,
+        "
+        EXPLAIN CREATE PUMP pu_avg AS
+          INSERT INTO sink_avg (ts, ticker, avg_amount)
+          SELECT STREAM
+            WINDOW_START() AS w_start,
+            ticker,
+            AVG(amount) AS avg_amount
+          FROM trade
+          GROUP BY ticker
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        ",
+    )
+    .unwrap();
+
+    for expected in [
+        "Pump pu_avg (Window input) INSERT INTO sink_avg",
+        "GroupAggregateWindow: window=",
+        "Collect: trade",
+        "Aggr(AggrExprLabel(0))",
+        "pu_avg [pump (window)]",
+        "pu_avg-trade [window]: pu_passthrough -> pu_avg",
+    ] {
+        assert!(
+            explained.contains(expected),
+            "`{}` is expected in:\n{}",
+            expected,
+            explained
+        );
+    }
+
+    // EXPLAIN does not create the pump
+    let explained = spring_explain(&pipeline, "EXPLAIN PIPELINE;").unwrap();
+    assert!(!explained.contains("pu_avg"), "{}", explained);
+}
+
+#[test]
+fn test_feat_explain_pipeline() {
+    setup_test_logger();
+    let pipeline = open_pipeline("q_explain_pipeline");
+
+    let explained = spring_explain(&pipeline, "EXPLAIN PIPELINE;").unwrap();
+    for expected in [
+        "Pump pu_passthrough (Row input) INSERT INTO trade",
+        "Pump pu_sink (Row input) INSERT INTO sink_trade",
+        "Projection: [Value(ValueExprLabel(0)), Value(ValueExprLabel(1)), Value(ValueExprLabel(2))]",
+        "queue_sink_trade [sink]",
+        "pu_sink-trade [row]: pu_passthrough -> pu_sink",
+        "queue_sink_trade [row]: pu_sink -> queue_sink_trade",
+    ] {
+        assert!(
+            explained.contains(expected),
+            "`{}` is expected in:\n{}",
+            expected,
+            explained
+        );
+    }
+
+    assert!(matches!(
+        spring_command(&pipeline, "EXPLAIN PIPELINE;"),
+        Err(SpringError::Sql(_))
+    ));
+    assert!(matches!(
+        spring_explain(
+            &pipeline,
+            "
+            CREATE SINK STREAM sink_x (
+              ticker TEXT NOT NULL
+            );
+            "
+        ),
+        Err(SpringError::Sql(_))
+    ));
+}
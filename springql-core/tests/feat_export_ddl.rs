@@ -0,0 +1,64 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use pretty_assertions::assert_eq;
+use springql_core::low_level_rs::*;
+use springql_test_logger::setup_test_logger;
+
+#[test]
+fn test_feat_export_ddl() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in [
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER
+        );
+        ",
+        "
+        CREATE STREAM trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER
+        );
+        ",
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL
+        );
+        ",
+        "
+        CREATE PUMP pu_source_trade AS
+          INSERT INTO trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount FROM source_trade;
+        ",
+        "
+        CREATE PUMP pu_trade AS
+          INSERT INTO sink_trade (ts, ticker)
+          SELECT STREAM ts, ticker FROM trade;
+        ",
+    ] {
+        spring_command(&pipeline, ddl).unwrap();
+    }
+
+    let exported = spring_export_ddl(&pipeline).unwrap();
+    // sink_trade has no sink writer but is still exported as a SINK STREAM.
+    assert_eq!(
+        exported[..3],
+        [
+            "CREATE SINK STREAM sink_trade (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  ticker TEXT NOT NULL\n)",
+            "CREATE SOURCE STREAM source_trade (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  ticker TEXT NOT NULL,\n  amount INTEGER\n)",
+            "CREATE STREAM trade (\n  ts TIMESTAMP NOT NULL ROWTIME,\n  ticker TEXT NOT NULL,\n  amount INTEGER\n)",
+        ]
+    );
+    assert_eq!(exported.len(), 5);
+
+    let recreated = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in &exported {
+        spring_command(&recreated, ddl).unwrap();
+    }
+    assert_eq!(spring_export_ddl(&recreated).unwrap(), exported);
+}
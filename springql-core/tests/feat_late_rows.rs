@@ -0,0 +1,148 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::error::{Result, SpringError};
+use springql_core::high_level_rs::SpringPipelineHL;
+use springql_core::low_level_rs::*;
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn ddl_source_trade() -> String {
+    "
+    CREATE SOURCE STREAM source_trade (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      ticker TEXT NOT NULL,
+      amount INTEGER NOT NULL
+    );
+    "
+    .to_string()
+}
+
+fn ddl_sink_late_trade() -> String {
+    "
+    CREATE SINK STREAM sink_late_trade (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      ticker TEXT NOT NULL,
+      amount INTEGER NOT NULL
+    );
+    "
+    .to_string()
+}
+
+#[test]
+fn test_feat_late_rows_into() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "ticker": "IBM", "amount": 50}),
+        // behind the watermark (00:00:10)
+        json!({"ts": "2020-01-01 00:00:05.000000000", "ticker": "GOOGL", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:20.000000000", "ticker": "IBM", "amount": 70}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+    let test_late_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        ddl_source_trade(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        ddl_sink_late_trade(),
+        "
+        CREATE PUMP pu_sampling AS
+          INSERT INTO sink_sampled_trade_amount (ts, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+          LATE ROWS INTO sink_late_trade;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_sampled_trade_amount
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_late_trade FOR sink_late_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_late_sink.host_ip(),
+            remote_port = test_late_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+
+    let sink_received = drain_from_sink(&test_sink);
+    assert_eq!(sink_received.len(), 2);
+
+    let late_sink_received = drain_from_sink(&test_late_sink);
+    assert_eq!(
+        late_sink_received,
+        vec![json!({"ts": "2020-01-01 00:00:05.000000000", "ticker": "GOOGL", "amount": 30})]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_feat_late_rows_into_without_window() {
+    setup_test_logger();
+
+    let pipeline = SpringPipelineHL::new(&spring_config_default()).unwrap();
+    pipeline.command(ddl_source_trade()).unwrap();
+    pipeline.command(ddl_sink_late_trade()).unwrap();
+
+    let res = pipeline.command(
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_late_trade (ts, ticker, amount)
+          SELECT STREAM source_trade.ts, source_trade.ticker, source_trade.amount
+          FROM source_trade
+          LATE ROWS INTO sink_late_trade;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
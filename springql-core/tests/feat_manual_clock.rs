@@ -0,0 +1,206 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::low_level_rs::*;
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+/// Advances `clock` by `step` in every real millisecond until dropped.
+struct Ticker(Arc<AtomicBool>);
+
+impl Ticker {
+    fn start(clock: ManualClock, step: Duration) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_ = stop.clone();
+        thread::spawn(move || {
+            while !stop_.load(Ordering::Relaxed) {
+                clock.advance(step);
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        Self(stop)
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_manual_clock_idle_timeout() {
+    setup_test_logger();
+
+    let queue_name = "queue_manual_clock";
+
+    // no more rows to close the pane [00:00:00, 00:00:10)
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:05.000000000", "ticker": "GOOGL", "amount": 30}),
+    ];
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sampled_trade_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_sampling AS
+          INSERT INTO sink_sampled_trade_amount (ts, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+          IDLE TIMEOUT DURATION_SECS(3600);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER queue_sink_trade FOR sink_sampled_trade_amount
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME '{queue_name}'
+          );
+        ",
+            queue_name = queue_name,
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let clock = ManualClock::default();
+    let config = SpringConfig {
+        clock: SpringClock::Manual(clock.clone()),
+        ..Default::default()
+    };
+    let pipeline = apply_ddls_low_level(&ddls, config);
+
+    // 1 hour of idle time passes in a few real seconds at most.
+    let _ticker = Ticker::start(clock.clone(), Duration::from_secs(60));
+
+    let row = spring_pop(&pipeline, queue_name).unwrap();
+    assert_eq!(
+        spring_column_text(&row, 0).unwrap(),
+        "2020-01-01 00:00:00.000000000"
+    );
+    assert!(clock.elapsed() >= Duration::from_secs(3600));
+}
+
+#[test]
+fn test_manual_clock_memory_reducing_scheduler() {
+    setup_test_logger();
+
+    let n_rows = 10000;
+
+    let source_input = (0..n_rows)
+        .map(|i| json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": i}))
+        .collect();
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_trade (ts, amount)
+          SELECT STREAM source_trade.ts, source_trade.amount FROM source_trade;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    // the clock is never advanced, while task execution time is still measured in real time.
+    let mut config = SpringConfig {
+        clock: SpringClock::Manual(ManualClock::default()),
+        ..Default::default()
+    };
+    // Severe (memory-reducing scheduler) from the first rows, never Moderate again nor Critical
+    config.memory.upper_limit_bytes = 1_000_000;
+    config.memory.severe_to_critical_percent = 99;
+    config.memory.critical_to_severe_percent = 98;
+    config.memory.moderate_to_severe_percent = 1;
+    config.memory.severe_to_moderate_percent = 0;
+
+    let _pipeline = apply_ddls(&ddls, config);
+    let sink_received = drain_from_sink(&test_sink);
+    assert_eq!(sink_received.len(), n_rows);
+}
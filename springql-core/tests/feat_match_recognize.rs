@@ -0,0 +1,113 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::low_level_rs::*;
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_vehicle() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle": "car1", "event": "HARD_BRAKE"}),
+        json!({"ts": "2020-01-01 00:00:00.500000000", "vehicle": "car2", "event": "HARD_BRAKE"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "vehicle": "car1", "event": "SPEED"}),
+        json!({"ts": "2020-01-01 00:00:01.500000000", "vehicle": "car1", "event": "AIRBAG"}),
+        // more than 2 seconds after car2's hard braking
+        json!({"ts": "2020-01-01 00:00:03.000000000", "vehicle": "car2", "event": "AIRBAG"}),
+        // airbag without hard braking
+        json!({"ts": "2020-01-01 00:00:04.000000000", "vehicle": "car1", "event": "AIRBAG"}),
+    ]
+}
+
+#[test]
+fn test_feat_match_recognize() {
+    setup_test_logger();
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_vehicle())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_vehicle (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle TEXT NOT NULL,
+          event TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_crash (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle TEXT NOT NULL,
+          brake_ts TIMESTAMP NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_crash AS
+          INSERT INTO sink_crash (ts, vehicle, brake_ts)
+          SELECT STREAM
+            m.airbag_ts,
+            m.vehicle,
+            m.brake_ts
+          FROM source_vehicle
+          MATCH_RECOGNIZE (
+            PARTITION BY source_vehicle.vehicle
+            ORDER BY ROWTIME
+            MEASURES
+              A.ts AS brake_ts,
+              A.vehicle AS vehicle,
+              B.ts AS airbag_ts
+            PATTERN (A X* B)
+            WITHIN DURATION_SECS(2)
+            DEFINE
+              A AS A.event = 'HARD_BRAKE',
+              B AS source_vehicle.event = 'AIRBAG'
+          ) AS m;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_crash FOR sink_crash
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_vehicle FOR source_vehicle
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![json!({
+            "ts": "2020-01-01 00:00:01.500000000",
+            "vehicle": "car1",
+            "brake_ts": "2020-01-01 00:00:00.000000000",
+        })]
+    );
+}
@@ -5,7 +5,7 @@ mod test_support;
 use float_cmp::approx_eq;
 use pretty_assertions::assert_eq;
 use serde_json::json;
-use springql_core::low_level_rs::*;
+use springql_core::{error::SpringError, low_level_rs::*};
 use springql_foreign_service::sink::ForeignSink;
 use springql_foreign_service::source::source_input::ForeignSourceInput;
 use springql_foreign_service::source::ForeignSource;
@@ -148,3 +148,136 @@ fn test_feat_add_mul_float() {
     assert!(approx_eq!(f64, r["answer_add"].as_f64().unwrap(), 2.5));
     assert!(approx_eq!(f64, r["answer_mul"].as_f64().unwrap(), 3.0));
 }
+
+#[test]
+fn test_feat_double_unsigned_decimal() {
+    setup_test_logger();
+
+    let json1 = json!({
+        "ts": "2020-01-01 00:00:00.000000000",
+        "lat": 35.681236,
+        "can_raw": 18_446_744_073_709_551_000u64,
+        "odometer": "123456789.125",
+    });
+    let source_input = vec![json1];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_1 (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          lat DOUBLE NOT NULL,
+          can_raw BIGINT UNSIGNED NOT NULL,
+          odometer DECIMAL(12, 3) NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_1 (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          lat DOUBLE PRECISION NOT NULL,
+          can_raw BIGINT UNSIGNED NOT NULL,
+          odometer DECIMAL(13, 3) NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_numeric AS
+          INSERT INTO sink_1 (ts, lat, can_raw, odometer)
+          SELECT STREAM
+            source_1.ts,
+            source_1.lat,
+            source_1.can_raw + 1,
+            source_1.odometer + source_1.odometer
+          FROM source_1;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_1 FOR sink_1
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_1 FOR source_1
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+    let r = sink_received.get(0).unwrap();
+
+    assert_eq!(r["lat"], json!(35.681236));
+    assert_eq!(r["can_raw"], json!(18_446_744_073_709_551_001u64));
+    // DECIMAL values are written as JSON strings to keep them exact.
+    assert_eq!(r["odometer"], json!("246913578.250"));
+
+    let ddls = pipeline.export_ddl().unwrap();
+    assert!(ddls.iter().any(|ddl| ddl.contains(
+        "lat DOUBLE NOT NULL,\n  can_raw BIGINT UNSIGNED NOT NULL,\n  odometer DECIMAL(12, 3) NOT NULL"
+    )));
+}
+
+#[test]
+fn test_feat_numeric_promotion_error() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SOURCE STREAM source_1 (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          lat DOUBLE NOT NULL,
+          odometer DECIMAL(12, 3) NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_1 (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          answer DOUBLE NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+
+    // exact (DECIMAL) and approximate (DOUBLE) numbers are not mixed
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_numeric AS
+          INSERT INTO sink_1 (ts, answer)
+          SELECT STREAM source_1.ts, source_1.lat * source_1.odometer FROM source_1;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // precision must be 1 - 38
+    let res = spring_command(
+        &pipeline,
+        "CREATE STREAM s (ts TIMESTAMP NOT NULL ROWTIME, d DECIMAL(39, 2) NOT NULL);",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
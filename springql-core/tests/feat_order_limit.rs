@@ -0,0 +1,182 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_cell() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c4", "temperature": 35}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "cell_id": "c1", "temperature": 30}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "cell_id": "c2", "temperature": 45}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "cell_id": "c3", "temperature": 38}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "cell_id": "c1", "temperature": 50}),
+        // second pane (within the allowed delay of the first pane)
+        json!({"ts": "2020-01-01 00:00:10.000000000", "cell_id": "c2", "temperature": 20}),
+        // closes the first pane
+        json!({"ts": "2020-01-01 00:00:25.000000000", "cell_id": "c2", "temperature": 20}),
+    ]
+}
+
+const SOURCE_CELL_DDL: &str = "
+    CREATE SOURCE STREAM source_cell (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      cell_id TEXT NOT NULL,
+      temperature INTEGER NOT NULL
+    );
+";
+
+fn run_pump(sink_ddl: &str, pump_ddl: &str) -> Vec<serde_json::Value> {
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_cell())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        SOURCE_CELL_DDL.to_string(),
+        sink_ddl.to_string(),
+        pump_ddl.to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_cell FOR sink_cell
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_cell FOR source_cell
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    drain_from_sink(&test_sink)
+}
+
+#[test]
+fn test_feat_top_n_groups_per_window() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE SINK STREAM sink_cell (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          cell_id TEXT NOT NULL,
+          avg_temperature FLOAT NOT NULL
+        );
+        ",
+        "
+        CREATE PUMP pu_hottest_cells AS
+          INSERT INTO sink_cell (ts, cell_id, avg_temperature)
+          SELECT STREAM
+            WINDOW_START() AS w_start,
+            cell_id AS cell,
+            AVG(temperature) AS avg_temperature
+          FROM source_cell
+          GROUP BY cell
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(10)
+          ORDER BY avg_temperature DESC
+          LIMIT 2;
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c2", "avg_temperature": 45.0}),
+            json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c1", "avg_temperature": 40.0}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_top_n_rows_per_window() {
+    setup_test_logger();
+
+    let sink_received = run_pump(
+        "
+        CREATE SINK STREAM sink_cell (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          cell_id TEXT NOT NULL,
+          temperature INTEGER NOT NULL
+        );
+        ",
+        "
+        CREATE PUMP pu_hottest_rows AS
+          INSERT INTO sink_cell (ts, cell_id, temperature)
+          SELECT STREAM ts, cell_id, temperature
+          FROM source_cell
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(10)
+          ORDER BY temperature DESC, cell_id
+          LIMIT 2;
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:04.000000000", "cell_id": "c1", "temperature": 50}),
+            json!({"ts": "2020-01-01 00:00:02.000000000", "cell_id": "c2", "temperature": 45}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_order_limit_errors() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(&pipeline, SOURCE_CELL_DDL).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_cell (
+          cell_id TEXT NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+
+    // without window
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_top AS
+          INSERT INTO sink_cell (cell_id)
+          SELECT STREAM cell_id FROM source_cell
+          ORDER BY cell_id LIMIT 2;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // sort key not in select list
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_top AS
+          INSERT INTO sink_cell (cell_id)
+          SELECT STREAM cell_id FROM source_cell
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+          ORDER BY temperature LIMIT 2;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
@@ -0,0 +1,239 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_test_logger::setup_test_logger;
+
+fn open_pipeline() -> SpringPipeline {
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL,
+          price FLOAT
+        );
+        ",
+    )
+    .unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        ",
+    )
+    .unwrap();
+    pipeline
+}
+
+/// Asserts that `sql` is rejected with an error message containing `message`.
+fn assert_rejected(pipeline: &SpringPipeline, sql: &str, message: &str) {
+    match spring_command(pipeline, sql) {
+        Err(SpringError::Sql(e)) => assert!(
+            format!("{:?}", e).contains(message),
+            "`{}` is expected in error: {:?}",
+            message,
+            e
+        ),
+        res => panic!("`{}` is expected to be rejected but got {:?}", sql, res),
+    }
+}
+
+#[test]
+fn test_feat_semantic_check_pump() {
+    setup_test_logger();
+    let pipeline = open_pipeline();
+
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker)
+          SELECT STREAM ts, ticker, amount FROM source_trade;
+        ",
+        r#"column "amount" of stream "sink_trade" is not listed"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker FROM source_trade;
+        ",
+        "lists 3 columns but the query has 2 select fields",
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, volume)
+          SELECT STREAM ts, ticker, amount FROM source_trade;
+        ",
+        r#"column "volume" is not in stream "sink_trade""#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM source_trade.ts, source_trade.ticker, source_trade.volume FROM source_trade;
+        ",
+        r#"column "volume" is not in stream "source_trade""#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, amount, ticker FROM source_trade;
+        ",
+        r#"select field #2 of type INTEGER cannot be inserted into column "ticker" of type TEXT"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount * price FROM source_trade;
+        ",
+        "`*` cannot be applied to INTEGER and FLOAT",
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, NULL FROM source_trade;
+        ",
+        r#"select field #3 is NULL but column "amount" of stream "sink_trade" is NOT NULL"#,
+    );
+
+    // nothing is added by rejected DDLs
+    spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount * 2 FROM source_trade;
+        ",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_feat_semantic_check_streams() {
+    setup_test_logger();
+    let pipeline = open_pipeline();
+
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE STREAM source_trade (
+          ticker TEXT NOT NULL
+        );
+        ",
+        r#"name "source_trade" already exists in pipeline"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE STREAM st (
+          ticker TEXT NOT NULL,
+          ticker INTEGER NOT NULL
+        );
+        ",
+        r#"column "ticker" is defined twice in stream "st""#,
+    );
+    assert!(matches!(
+        spring_command(
+            &pipeline,
+            "
+            CREATE SINK WRITER queue_sink FOR no_such_stream
+              TYPE IN_MEMORY_QUEUE OPTIONS (
+                NAME 'q'
+              );
+            ",
+        ),
+        Err(SpringError::Sql(_))
+    ));
+}
+
+#[test]
+fn test_feat_semantic_check_did_you_mean() {
+    setup_test_logger();
+    let pipeline = open_pipeline();
+
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu_misspelled_stream AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount FROM source_trde;
+        ",
+        r#"did you mean "source_trade"?"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu_misspelled_column AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, tickr, amount FROM source_trade;
+        ",
+        r#"did you mean "ticker"?"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu_misspelled_qualified_column AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM source_trade.ts, source_trade.ticker, source_trade.amout FROM source_trade;
+        ",
+        r#"did you mean "amount"?"#,
+    );
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu_unknown_stream AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM ts, ticker, amount FROM weather;
+        ",
+        r#"stream "weather" does not exist in pipeline"#,
+    );
+
+    for ddl in [
+        "
+        CREATE SINK STREAM sink_sampled_amount (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          amount DOUBLE NOT NULL
+        );
+        ",
+        "
+        CREATE SINK STREAM sink_late_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          tickr TEXT NOT NULL
+        );
+        ",
+    ] {
+        spring_command(&pipeline, ddl).unwrap();
+    }
+    assert_rejected(
+        &pipeline,
+        "
+        CREATE PUMP pu_misspelled_late_rows_column AS
+          INSERT INTO sink_sampled_amount (ts, amount)
+          SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS sampled_ts,
+            AVG(source_trade.amount) AS avg_amount
+          FROM source_trade
+          GROUP BY sampled_ts
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+          LATE ROWS INTO sink_late_trade;
+        ",
+        r#"did you mean "ticker"?"#,
+    );
+}
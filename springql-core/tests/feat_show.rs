@@ -0,0 +1,133 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use pretty_assertions::assert_eq;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+/// Text columns of rows.
+fn show(pipeline: &SpringPipeline, sql: &str, n_cols: usize) -> Vec<Vec<String>> {
+    spring_show(pipeline, sql)
+        .unwrap()
+        .iter()
+        .map(|row| {
+            (0..n_cols)
+                .map(|i_col| spring_column_text(row, i_col).unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_feat_show() {
+    setup_test_logger();
+
+    let test_source = ForeignSource::start(ForeignSourceInput::new_fifo_batch(vec![])).unwrap();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in [
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_passthrough AS
+          INSERT INTO sink_trade (ts, ticker)
+          SELECT STREAM ts, ticker FROM source_trade;
+        "
+        .to_string(),
+        "
+        CREATE SINK WRITER queue_sink_trade FOR sink_trade
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_show'
+          );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ] {
+        spring_command(&pipeline, &ddl).unwrap();
+    }
+
+    let streams = spring_show(&pipeline, "SHOW STREAMS;").unwrap();
+    let streams = streams
+        .iter()
+        .map(|row| {
+            (
+                spring_column_text(row, 0).unwrap(),
+                spring_column_i32(row, 1).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        streams,
+        vec![
+            ("sink_trade".to_string(), 2),
+            ("source_trade".to_string(), 3)
+        ]
+    );
+
+    assert_eq!(
+        show(&pipeline, "SHOW PUMPS;", 4),
+        vec![vec!["pu_passthrough", "source_trade", "sink_trade", "ROW"]]
+    );
+    assert_eq!(
+        show(&pipeline, "SHOW SOURCE READERS;", 3),
+        vec![vec!["tcp_trade", "NET_CLIENT", "source_trade"]]
+    );
+    assert_eq!(
+        show(&pipeline, "SHOW SINK WRITERS;", 3),
+        vec![vec!["queue_sink_trade", "IN_MEMORY_QUEUE", "sink_trade"]]
+    );
+    assert_eq!(
+        show(&pipeline, "DESCRIBE STREAM source_trade;", 4),
+        vec![
+            vec!["ts", "TIMESTAMP", "NO", "YES"],
+            vec!["ticker", "TEXT", "NO", "NO"],
+            vec!["amount", "INTEGER", "YES", "NO"],
+        ]
+    );
+}
+
+#[test]
+fn test_feat_show_errors() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    assert!(spring_show(&pipeline, "SHOW STREAMS;").unwrap().is_empty());
+
+    assert!(matches!(
+        spring_show(&pipeline, "DESCRIBE STREAM no_such_stream;"),
+        Err(SpringError::Sql(_))
+    ));
+    assert!(matches!(
+        spring_command(&pipeline, "SHOW PUMPS;"),
+        Err(SpringError::Sql(_))
+    ));
+    assert!(matches!(
+        spring_show(&pipeline, "EXPLAIN PIPELINE;"),
+        Err(SpringError::Sql(_))
+    ));
+}
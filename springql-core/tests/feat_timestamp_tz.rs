@@ -0,0 +1,170 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+fn source_ddl() -> String {
+    "
+    CREATE SOURCE STREAM source_device (
+      ts TIMESTAMP NOT NULL ROWTIME FORMAT 'EPOCH_MILLIS',
+      local_ts TIMESTAMP WITH TIME ZONE FORMAT '%Y-%m-%dT%H:%M:%S%:z',
+      day TIMESTAMP,
+      device_id TEXT NOT NULL
+    );
+    "
+    .to_string()
+}
+
+fn source_reader_ddl(test_source: &ForeignSource, timestamp_format: &str) -> String {
+    format!(
+        "
+        CREATE SOURCE READER tcp_device FOR source_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}',
+            TIMESTAMP_FORMAT '{timestamp_format}'
+          );
+        ",
+        remote_host = test_source.host_ip(),
+        remote_port = test_source.port(),
+        timestamp_format = timestamp_format,
+    )
+}
+
+fn sink_writer_ddl(test_sink: &ForeignSink) -> String {
+    format!(
+        "
+        CREATE SINK WRITER tcp_sink_device FOR sink_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+        remote_host = test_sink.host_ip(),
+        remote_port = test_sink.port()
+    )
+}
+
+#[test]
+fn test_feat_timestamp_tz() {
+    setup_test_logger();
+
+    let json1 = json!({
+        "ts": 1577836800123_i64,
+        "local_ts": "2020-01-01T09:00:00+09:00",
+        "day": "31/12/2019",
+        "device_id": "d1",
+    });
+    let source_input = vec![json1];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        source_ddl(),
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          local_ts TIMESTAMPTZ,
+          day TIMESTAMP,
+          ts_jst TIMESTAMPTZ,
+          local_ts_text TEXT,
+          ts_text TEXT
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_tz AS
+          INSERT INTO sink_device (ts, local_ts, day, ts_jst, local_ts_text, ts_text)
+          SELECT STREAM
+            ts,
+            local_ts,
+            day,
+            ts AT TIME ZONE '+09:00',
+            TO_CHAR(local_ts, '%Y/%m/%d %H:%M %z'),
+            TO_CHAR(ts, '%H:%M:%S%.3f')
+          FROM source_device;
+        "
+        .to_string(),
+        sink_writer_ddl(&test_sink),
+        source_reader_ddl(&test_source, "%d/%m/%Y"),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let sink_received = drain_from_sink(&test_sink);
+    let r = sink_received.get(0).unwrap();
+
+    assert_eq!(r["ts"], json!("2020-01-01 00:00:00.123000000"));
+    assert_eq!(r["local_ts"], json!("2020-01-01 09:00:00.000000000+09:00"));
+    assert_eq!(r["day"], json!("2019-12-31 00:00:00.000000000"));
+    assert_eq!(r["ts_jst"], json!("2020-01-01 09:00:00.123000000+09:00"));
+    assert_eq!(r["local_ts_text"], json!("2020/01/01 09:00 +0900"));
+    assert_eq!(r["ts_text"], json!("00:00:00.123"));
+}
+
+#[test]
+fn test_feat_timestamp_tz_errors() {
+    setup_test_logger();
+
+    let test_source = ForeignSource::start(ForeignSourceInput::new_fifo_batch(vec![])).unwrap();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    spring_command(&pipeline, &source_ddl()).unwrap();
+    spring_command(
+        &pipeline,
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          answer TEXT
+        );
+        ",
+    )
+    .unwrap();
+
+    // invalid TIMESTAMP_FORMAT option
+    assert!(matches!(
+        spring_command(&pipeline, &source_reader_ddl(&test_source, "%Y-%Q")),
+        Err(SpringError::InvalidOption { .. })
+    ));
+
+    let create_pump = |select_field: &str| {
+        spring_command(
+            &pipeline,
+            &format!(
+                "
+                CREATE PUMP pu_err AS
+                  INSERT INTO sink_device (ts, answer)
+                  SELECT STREAM ts, {} FROM source_device;
+                ",
+                select_field
+            ),
+        )
+    };
+
+    // non-timestamp argument
+    assert!(matches!(
+        create_pump("TO_CHAR(1, '%Y')"),
+        Err(SpringError::Sql(_))
+    ));
+    assert!(matches!(
+        create_pump("TO_CHAR(1 AT TIME ZONE 'UTC', '%Y')"),
+        Err(SpringError::Sql(_))
+    ));
+    // non-TEXT time zone
+    assert!(matches!(
+        create_pump("TO_CHAR(ts AT TIME ZONE 9, '%Y')"),
+        Err(SpringError::Sql(_))
+    ));
+}
@@ -0,0 +1,171 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_trade_tokyo() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "ticker": "GOOGL", "amount": 20}),
+    ]
+}
+
+fn gen_source_trade_ny() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "IBM", "amount": 30}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "ticker": "ORCL", "amount": 40}),
+    ]
+}
+
+fn source_trade_ddl(stream: &str) -> String {
+    format!(
+        "
+        CREATE SOURCE STREAM {stream} (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        ",
+        stream = stream
+    )
+}
+
+fn source_reader_ddl(stream: &str, source: &ForeignSource) -> String {
+    format!(
+        "
+        CREATE SOURCE READER tcp_{stream} FOR {stream}
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+        stream = stream,
+        remote_host = source.host_ip(),
+        remote_port = source.port()
+    )
+}
+
+#[test]
+fn test_feat_union_all() {
+    setup_test_logger();
+
+    let test_source_tokyo =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_trade_tokyo())).unwrap();
+    let test_source_ny =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(gen_source_trade_ny())).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        source_trade_ddl("source_trade_tokyo"),
+        source_trade_ddl("source_trade_ny"),
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_union AS
+          INSERT INTO sink_trade (ts, ticker, amount)
+          SELECT STREAM
+            source_trade_tokyo.ts,
+            source_trade_tokyo.ticker,
+            source_trade_tokyo.amount
+          FROM source_trade_tokyo
+          UNION ALL
+          SELECT STREAM
+            source_trade_ny.ts,
+            source_trade_ny.ticker,
+            source_trade_ny.amount
+          FROM source_trade_ny;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        source_reader_ddl("source_trade_tokyo", &test_source_tokyo),
+        source_reader_ddl("source_trade_ny", &test_source_ny),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, spring_config_default());
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|row| row["ts"].as_str().unwrap().to_string());
+
+    let mut expected = [gen_source_trade_tokyo(), gen_source_trade_ny()].concat();
+    expected.sort_by_key(|row| row["ts"].as_str().unwrap().to_string());
+
+    assert_eq!(sink_received, expected);
+}
+
+#[test]
+fn test_feat_union_all_type_mismatch() {
+    setup_test_logger();
+
+    let pipeline = spring_open(&SpringConfig::default()).unwrap();
+    for ddl in [
+        source_trade_ddl("source_trade_tokyo"),
+        source_trade_ddl("source_trade_ny"),
+        "
+        CREATE SINK STREAM sink_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL
+        );
+        "
+        .to_string(),
+    ] {
+        spring_command(&pipeline, &ddl).unwrap();
+    }
+
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_union AS
+          INSERT INTO sink_trade (ts, ticker)
+          SELECT STREAM source_trade_tokyo.ts, source_trade_tokyo.ticker
+          FROM source_trade_tokyo
+          UNION ALL
+          SELECT STREAM source_trade_ny.ts, source_trade_ny.amount
+          FROM source_trade_ny;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // expressions are type-checked as well as column references
+    let res = spring_command(
+        &pipeline,
+        "
+        CREATE PUMP pu_union AS
+          INSERT INTO sink_trade (ts, ticker)
+          SELECT STREAM source_trade_tokyo.ts, source_trade_tokyo.ticker
+          FROM source_trade_tokyo
+          UNION ALL
+          SELECT STREAM source_trade_ny.ts, source_trade_ny.amount + 1
+          FROM source_trade_ny;
+        ",
+    );
+    match res {
+        Err(SpringError::Sql(e)) => assert!(e.to_string().contains("UNION ALL")),
+        _ => panic!("expected UNION ALL type mismatch but got {:?}", res),
+    }
+}
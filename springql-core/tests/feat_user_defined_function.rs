@@ -0,0 +1,392 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use anyhow::anyhow;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::{error::SpringError, high_level_rs::SpringPipelineHL, low_level_rs::*};
+use springql_foreign_service::sink::ForeignSink;
+use springql_foreign_service::source::source_input::ForeignSourceInput;
+use springql_foreign_service::source::ForeignSource;
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+const SOURCE_CAN_DDL: &str = "
+    CREATE SOURCE STREAM source_can (
+      ts TIMESTAMP NOT NULL ROWTIME,
+      raw_speed INTEGER NOT NULL,
+      payload TEXT NOT NULL
+    );
+";
+
+/// Vehicle speed [km/h] from a CAN signal (0.5 km/h per bit, offset -10 km/h).
+fn register_decode_speed(pipeline: &mut SpringPipelineHL) {
+    pipeline
+        .register_function(
+            "decode_speed",
+            vec![SqlType::integer()],
+            SqlType::float(),
+            |args| match args {
+                [SpringValue::Integer(raw)] => Ok(SpringValue::Float(*raw as f32 * 0.5 - 10.0)),
+                _ => Err(SpringError::Sql(anyhow!("unexpected arguments"))),
+            },
+        )
+        .unwrap();
+}
+
+/// Root mean square.
+#[derive(Debug)]
+struct Rms {
+    sum_of_squares: f32,
+    n: u64,
+}
+
+impl SpringAccumulator for Rms {
+    fn init() -> Self {
+        Self {
+            sum_of_squares: 0.0,
+            n: 0,
+        }
+    }
+
+    fn accumulate(&mut self, value: SpringValue) -> springql_core::error::Result<()> {
+        match value {
+            SpringValue::Float(v) => {
+                self.sum_of_squares += v * v;
+                self.n += 1;
+                Ok(())
+            }
+            _ => Err(SpringError::Sql(anyhow!("unexpected value"))),
+        }
+    }
+
+    fn finalize(&self) -> springql_core::error::Result<SpringValue> {
+        if self.n == 0 {
+            Ok(SpringValue::Null)
+        } else {
+            Ok(SpringValue::Float(
+                (self.sum_of_squares / self.n as f32).sqrt(),
+            ))
+        }
+    }
+}
+
+/// Sum of bytes of `payload` modulo 256.
+fn register_checksum(pipeline: &mut SpringPipelineHL) {
+    pipeline
+        .register_function(
+            "CHECKSUM8",
+            vec![SqlType::text()],
+            SqlType::integer(),
+            |args| match args {
+                [SpringValue::Text(payload)] => {
+                    let sum = payload.bytes().map(u32::from).sum::<u32>() % 256;
+                    Ok(SpringValue::Integer(sum as i32))
+                }
+                _ => Err(SpringError::Sql(anyhow!("unexpected arguments"))),
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_feat_user_defined_function() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "raw_speed": 20, "payload": "a"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "raw_speed": 140, "payload": "ab"}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "raw_speed": 0, "payload": "abc"}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let mut pipeline = SpringPipelineHL::new(&spring_config_default()).unwrap();
+    register_decode_speed(&mut pipeline);
+    register_checksum(&mut pipeline);
+
+    let ddls = vec![
+        SOURCE_CAN_DDL.to_string(),
+        "
+        CREATE SINK STREAM sink_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          speed FLOAT NOT NULL,
+          checksum INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_decode AS
+          INSERT INTO sink_can (ts, speed, checksum)
+          SELECT STREAM ts, decode_speed(raw_speed) * 2.0, checksum8(payload)
+          FROM source_can;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_can FOR sink_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_can FOR source_can
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+    for ddl in ddls {
+        pipeline.command(ddl).unwrap();
+    }
+
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|row| row["ts"].to_string());
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "speed": 0.0, "checksum": 97}),
+            json!({"ts": "2020-01-01 00:00:01.000000000", "speed": 120.0, "checksum": 195}),
+            json!({"ts": "2020-01-01 00:00:02.000000000", "speed": -20.0, "checksum": 38}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_user_defined_function_errors() {
+    setup_test_logger();
+
+    let mut pipeline = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    register_decode_speed(&mut pipeline);
+
+    // already registered (case-insensitive)
+    let res = pipeline.register_function("DECODE_SPEED", vec![], SqlType::integer(), |_| {
+        Ok(SpringValue::Null)
+    });
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // keyword
+    let res =
+        pipeline.register_function("avg", vec![], SqlType::integer(), |_| Ok(SpringValue::Null));
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // built-in functions (case-insensitive)
+    for name in ["hex", "Length", "CARDINALITY", "to_char", "floor"] {
+        let res =
+            pipeline.register_function(name, vec![], SqlType::integer(), |_| Ok(SpringValue::Null));
+        assert!(matches!(res, Err(SpringError::Sql(_))), "{}", name);
+    }
+
+    pipeline.command(SOURCE_CAN_DDL).unwrap();
+    pipeline
+        .command(
+            "
+            CREATE SINK STREAM sink_can (
+              speed FLOAT NOT NULL
+            );
+            ",
+        )
+        .unwrap();
+
+    // not registered
+    let res = pipeline.command(
+        "
+        CREATE PUMP pu_decode AS
+          INSERT INTO sink_can (speed)
+          SELECT STREAM decode_rpm(raw_speed) FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // wrong number of arguments
+    let res = pipeline.command(
+        "
+        CREATE PUMP pu_decode AS
+          INSERT INTO sink_can (speed)
+          SELECT STREAM decode_speed(raw_speed, 2) FROM source_can;
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
+
+#[test]
+fn test_feat_user_defined_aggregate_function() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c0", "temperature": 0}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "cell_id": "c1", "temperature": 1}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "cell_id": "c2", "temperature": 4}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "cell_id": "c1", "temperature": 7}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "cell_id": "c2", "temperature": -4}),
+        // rows may be processed out of order: pads the pane not to drop rows above as late
+        json!({"ts": "2020-01-01 00:00:05.000000000", "cell_id": "c0", "temperature": 0}),
+        json!({"ts": "2020-01-01 00:00:06.000000000", "cell_id": "c0", "temperature": 0}),
+        json!({"ts": "2020-01-01 00:00:07.000000000", "cell_id": "c0", "temperature": 0}),
+        // closes the first pane
+        json!({"ts": "2020-01-01 00:00:10.000000000", "cell_id": "c1", "temperature": 0}),
+    ];
+
+    let test_source =
+        ForeignSource::start(ForeignSourceInput::new_fifo_batch(source_input)).unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let mut pipeline = SpringPipelineHL::new(&spring_config_default()).unwrap();
+    pipeline
+        .register_aggregate_function::<Rms>("rms", SqlType::float(), SqlType::float())
+        .unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_cell (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          cell_id TEXT NOT NULL,
+          temperature INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_cell (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          cell_id TEXT NOT NULL,
+          rms_temperature FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_rms AS
+          INSERT INTO sink_cell (ts, cell_id, rms_temperature)
+          SELECT STREAM
+            WINDOW_START() AS w_start,
+            cell_id AS cell,
+            RMS(temperature) AS rms_temperature
+          FROM source_cell
+          GROUP BY cell
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_cell FOR sink_cell
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_cell FOR source_cell
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+    for ddl in ddls {
+        pipeline.command(ddl).unwrap();
+    }
+
+    let mut sink_received = drain_from_sink(&test_sink);
+    // c0 only warms up and pads the pipeline
+    sink_received.retain(|row| row["cell_id"] != "c0");
+    sink_received.sort_by_key(|row| row["cell_id"].to_string());
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c1", "rms_temperature": 5.0}),
+            json!({"ts": "2020-01-01 00:00:00.000000000", "cell_id": "c2", "rms_temperature": 4.0}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_user_defined_aggregate_function_errors() {
+    setup_test_logger();
+
+    let mut pipeline = SpringPipelineHL::new(&SpringConfig::default()).unwrap();
+    register_decode_speed(&mut pipeline);
+    pipeline
+        .register_aggregate_function::<Rms>("rms", SqlType::float(), SqlType::float())
+        .unwrap();
+
+    // scalar and aggregate functions share names
+    let res = pipeline.register_aggregate_function::<Rms>(
+        "decode_speed",
+        SqlType::float(),
+        SqlType::float(),
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // built-in functions
+    let res =
+        pipeline.register_aggregate_function::<Rms>("Length", SqlType::float(), SqlType::float());
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    pipeline.command(SOURCE_CAN_DDL).unwrap();
+    pipeline
+        .command(
+            "
+            CREATE SINK STREAM sink_can (
+              ts TIMESTAMP NOT NULL ROWTIME,
+              raw_speed INTEGER NOT NULL,
+              speed FLOAT NOT NULL
+            );
+            ",
+        )
+        .unwrap();
+
+    // aggregate function inside an expression
+    let res = pipeline.command(
+        "
+        CREATE PUMP pu_rms AS
+          INSERT INTO sink_can (ts, raw_speed, speed)
+          SELECT STREAM
+            WINDOW_START() AS w_start,
+            raw_speed AS raw,
+            RMS(raw_speed) * 2.0 AS rms_speed
+          FROM source_can
+          GROUP BY raw
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+
+    // wrong number of arguments
+    let res = pipeline.command(
+        "
+        CREATE PUMP pu_rms AS
+          INSERT INTO sink_can (ts, raw_speed, speed)
+          SELECT STREAM
+            WINDOW_START() AS w_start,
+            raw_speed AS raw,
+            RMS(raw_speed, 2) AS rms_speed
+          FROM source_can
+          GROUP BY raw
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}